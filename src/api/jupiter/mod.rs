@@ -103,7 +103,6 @@ impl fmt::Debug for JupiterApiClient {
 }
 
 impl JupiterApiClient {
-    #[allow(dead_code)]
     pub fn new(
         client: reqwest::Client,
         quote_url: String,
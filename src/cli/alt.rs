@@ -0,0 +1,85 @@
+use std::str::FromStr;
+
+use anyhow::{Result, anyhow};
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::cli::args::AltCmd;
+use crate::cli::context::resolve_rpc_client;
+use crate::config::AppConfig;
+use crate::engine::{AltGcAction, AltManager, EngineIdentity};
+
+pub async fn handle_alt_command(cmd: &AltCmd, config: &AppConfig) -> Result<()> {
+    let resolved_rpc = resolve_rpc_client(&config.galileo.global, None, None)?;
+    let identity = EngineIdentity::from_private_key(&config.galileo.private_key)
+        .map_err(|err| anyhow!(err))?;
+    let manager = AltManager::new(
+        resolved_rpc.client.clone(),
+        identity.clone(),
+        config.galileo.bot.alt_manager.clone(),
+    );
+
+    match cmd {
+        AltCmd::List => {
+            let tables = manager.list_owned().await?;
+            if tables.is_empty() {
+                println!("钱包 {} 当前没有持有任何 ALT", identity.pubkey);
+                return Ok(());
+            }
+            println!("钱包 {} 持有 {} 个 ALT：", identity.pubkey, tables.len());
+            for table in tables {
+                let status = match table.deactivation_slot {
+                    None => "激活".to_string(),
+                    Some(slot) => format!("已停用 @ {slot}"),
+                };
+                println!(
+                    "  {}  地址数={:<3}  状态={}  最近扩展 slot={}  租金={:.6} SOL",
+                    table.address,
+                    table.addresses.len(),
+                    status,
+                    table.last_extended_slot,
+                    table.lamports as f64 / LAMPORTS_PER_SOL as f64
+                );
+            }
+        }
+        AltCmd::Gc { dry_run } => {
+            let report = manager.garbage_collect(*dry_run).await?;
+            if report.is_empty() {
+                println!("没有需要回收的 ALT");
+                return Ok(());
+            }
+            for entry in report {
+                let action = match entry.action {
+                    AltGcAction::Close => "关闭".to_string(),
+                    AltGcAction::Deactivate => "停用".to_string(),
+                    AltGcAction::Wait { remaining_slots } => {
+                        format!("冷却中，约 {remaining_slots} 个 slot 后可关闭")
+                    }
+                };
+                match entry.signature {
+                    Some(signature) => {
+                        println!("  {}  {}  签名={}", entry.address, action, signature)
+                    }
+                    None if *dry_run => println!("  {}  {} (dry-run)", entry.address, action),
+                    None => println!("  {}  {}", entry.address, action),
+                }
+            }
+        }
+        AltCmd::Deactivate { address } => {
+            let address = parse_address(address)?;
+            let signature = manager.deactivate_table(&address).await?;
+            println!("已停用 ALT {address}，签名 {signature}");
+        }
+        AltCmd::Close { address } => {
+            let address = parse_address(address)?;
+            let signature = manager.close_table(&address).await?;
+            println!("已关闭 ALT {address}，签名 {signature}");
+        }
+    }
+
+    Ok(())
+}
+
+fn parse_address(value: &str) -> Result<Pubkey> {
+    Pubkey::from_str(value.trim()).map_err(|err| anyhow!("ALT 地址无效 {value}: {err}"))
+}
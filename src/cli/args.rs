@@ -1,6 +1,6 @@
 use std::path::PathBuf;
 
-use clap::{Args, Parser, Subcommand};
+use clap::{Args, Parser, Subcommand, ValueEnum};
 
 #[derive(Parser, Debug)]
 #[command(name = "galileo", version, about = "Galileo 高性能套利调度器")]
@@ -27,7 +27,7 @@ pub enum Command {
     Tools(ToolsCmd),
     /// 运行已配置的套利策略循环
     #[command(name = "run", alias = "strategy")]
-    Run,
+    Run(RunArgs),
     /// 运行套利策略（dry-run 模式）
     #[command(name = "dry-run")]
     StrategyDryRun,
@@ -36,6 +36,45 @@ pub enum Command {
     /// 钱包管理
     #[command(subcommand)]
     Wallet(WalletCmd),
+    /// 自有地址查找表（ALT）管理
+    #[command(subcommand)]
+    Alt(AltCmd),
+    /// 汇总交易账本中的实际收益
+    Report(ReportArgs),
+    /// 连接运行中实例的控制接口，打开实时仪表盘
+    Dashboard(DashboardArgs),
+    /// 配置校验
+    #[command(subcommand)]
+    Config(ConfigCmd),
+}
+
+#[derive(Args, Debug, Clone, Default)]
+pub struct RunArgs {
+    #[arg(long, help = "以实时仪表盘代替滚动日志（退出面板即停止运行）")]
+    pub tui: bool,
+}
+
+#[derive(Args, Debug, Clone)]
+pub struct DashboardArgs {
+    #[arg(
+        long,
+        value_name = "URL",
+        help = "控制接口地址（默认 http://<bot.control_api.listen>）"
+    )]
+    pub url: Option<String>,
+    #[arg(
+        long,
+        value_name = "TOKEN",
+        help = "控制接口 token（默认读取 bot.control_api.token）"
+    )]
+    pub token: Option<String>,
+    #[arg(
+        long,
+        value_name = "MS",
+        default_value_t = 1000,
+        help = "刷新间隔（毫秒）"
+    )]
+    pub refresh_ms: u64,
 }
 
 #[derive(Args, Debug)]
@@ -51,11 +90,107 @@ pub enum WalletCmd {
     /// 交互式添加钱包私钥
     #[command(name = "add")]
     Add(WalletAddArgs),
+    /// 列出已保存钱包的备注与公钥（不解密）
+    #[command(name = "list", alias = "ls")]
+    List,
+    /// 删除指定备注的钱包
+    #[command(name = "remove", alias = "rm")]
+    Remove(WalletRemoveArgs),
+    /// 修改钱包备注
+    #[command(name = "rename")]
+    Rename(WalletRenameArgs),
+    /// 以新密码重新加密全部钱包
+    #[command(name = "change-password")]
+    ChangePassword,
+    /// 解密全部钱包并核对记录的公钥，未记录的公钥会被补写
+    #[command(name = "verify")]
+    Verify,
 }
 
 #[derive(Args, Debug, Default)]
 pub struct WalletAddArgs {}
 
+#[derive(Args, Debug)]
+pub struct WalletRemoveArgs {
+    #[arg(value_name = "REMARK")]
+    pub remark: String,
+    #[arg(long, short = 'y', help = "跳过删除确认")]
+    pub yes: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct WalletRenameArgs {
+    #[arg(value_name = "REMARK")]
+    pub remark: String,
+    #[arg(value_name = "NEW_REMARK")]
+    pub new_remark: String,
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum AltCmd {
+    /// 列出钱包持有的全部 ALT
+    #[command(name = "list", alias = "ls")]
+    List,
+    /// 回收：关闭冷却完成的停用表，停用空表
+    Gc {
+        #[arg(long, help = "仅展示将执行的操作，不发送交易")]
+        dry_run: bool,
+    },
+    /// 停用指定 ALT（冷却约 512 个 slot 后可关闭）
+    Deactivate {
+        #[arg(value_name = "ADDRESS")]
+        address: String,
+    },
+    /// 关闭已完成冷却的 ALT 并回收租金
+    Close {
+        #[arg(value_name = "ADDRESS")]
+        address: String,
+    },
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum ConfigCmd {
+    /// 合并全部配置来源并校验，输出脱敏后的生效配置，存在错误时以非零状态退出
+    Check(ConfigCheckArgs),
+}
+
+#[derive(Args, Debug, Clone, Default)]
+pub struct ConfigCheckArgs {
+    #[arg(long, help = "仅输出校验结果，不打印生效配置")]
+    pub quiet: bool,
+}
+
+#[derive(Args, Debug, Clone)]
+pub struct ReportArgs {
+    #[arg(
+        long,
+        value_name = "FILE",
+        help = "账本文件路径（默认读取 bot.ledger.path）"
+    )]
+    pub ledger: Option<PathBuf>,
+    #[arg(
+        long,
+        value_name = "YYYY-MM-DD",
+        help = "仅统计该日期（UTC）及之后的记录"
+    )]
+    pub since: Option<String>,
+    #[arg(
+        long,
+        value_enum,
+        value_delimiter = ',',
+        help = "汇总维度，可重复或以逗号分隔，缺省输出全部维度"
+    )]
+    pub by: Vec<ReportDimension>,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportDimension {
+    Day,
+    Strategy,
+    Route,
+    Lander,
+}
+
 #[derive(Subcommand, Debug, Clone)]
 pub enum ToolsCmd {
     /// 打开交互式工具面板
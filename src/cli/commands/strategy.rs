@@ -1,9 +1,10 @@
-use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::str::FromStr;
 use std::sync::Arc;
 use std::time::Duration;
 
 use anyhow::{Result, anyhow};
+use tokio::sync::mpsc;
 use tokio::task::JoinHandle;
 use tracing::{debug, info, warn};
 
@@ -40,16 +41,22 @@ use crate::engine::multi_leg::{
     runtime::{MultiLegRuntime, MultiLegRuntimeConfig},
     types::{AggregatorKind as MultiLegAggregatorKind, LegSide},
 };
-use crate::engine::plugins::flashloan::{MarginfiAccountRegistry, MarginfiFlashloanManager};
+use crate::engine::plugins::flashloan::{
+    FlashloanPreparation, FlashloanProvider, FlashloanRegistry, KaminoFlashloanManager,
+    MarginfiAccountRegistry, MarginfiFlashloanManager,
+};
 use crate::engine::{
-    AccountPrechecker, BuilderConfig, ComputeUnitPriceMode, ConsoleSummarySettings, EngineError,
-    EngineIdentity, EngineResult, EngineSettings, LighthouseSettings, MultiLegEngineContext,
-    ProfitConfig, ProfitEvaluator, QuoteCadence, QuoteConfig, QuoteExecutor, Scheduler,
-    SolPriceFeedSettings, StrategyEngine, SwapPreparer, TipConfig, TradeProfile,
+    AccountPrechecker, AltManager, BlindPriceGuard, BuilderConfig, ComputeUnitEstimator,
+    ComputeUnitPriceMode, ConsoleSummarySettings, EngineError, EngineIdentity, EngineReload,
+    EngineResult, EngineSettings, GuardSettlement, LighthouseSettings, MultiLegEngineContext,
+    NoncePool, PoolWallet, ProfitConfig, ProfitEvaluator, QuoteCadence, QuoteConfig, QuoteExecutor,
+    Scheduler, SolPriceFeedSettings, StrategyEngine, SwapPreparer, TipConfig, TradeProfile,
     TransactionBuilder,
 };
+use crate::instructions::guards::price_band::{PriceBand, PriceSource, PriceValueType};
+use crate::intermedium::loader::resolve_mint_universe;
 use crate::jupiter::{JupiterBinaryManager, JupiterError};
-use crate::lander::LanderFactory;
+use crate::lander::{LanderFactory, LanderStack};
 use crate::monitoring::events;
 use crate::network::IpAllocator;
 use crate::strategy::pure_blind::cache::PureBlindCacheManager;
@@ -304,6 +311,18 @@ async fn run_blind_engine(
         alt_cache.clone(),
         dry_run_enabled,
     );
+    let alt_manager = build_alt_manager(
+        &config.galileo.bot.alt_manager,
+        &rpc_client,
+        &identity,
+        dry_run_enabled,
+    );
+    let tx_builder = match &alt_manager {
+        Some(manager) => tx_builder.with_owned_lookup_tables(manager.tables()),
+        None => tx_builder,
+    };
+    let nonce_pool =
+        build_nonce_pool(&config.galileo.bot, &rpc_client, &identity, dry_run_enabled).await?;
 
     let marginfi_cfg = &config.galileo.flashloan.marginfi;
     let marginfi_accounts = parse_marginfi_accounts(marginfi_cfg)?;
@@ -311,7 +330,6 @@ async fn run_blind_engine(
         .galileo
         .bot
         .flashloan_enabled(FlashloanProduct::Marginfi);
-    let prefer_wallet_balance = config.galileo.bot.flashloan.prefer_wallet_balance;
 
     let prechecker = AccountPrechecker::new(rpc_client.clone(), marginfi_accounts.clone());
     let (summary, flashloan_precheck) = prechecker
@@ -348,26 +366,27 @@ async fn run_blind_engine(
         }
     }
 
-    let mut flashloan_manager = MarginfiFlashloanManager::new(
-        marginfi_cfg,
-        flashloan_enabled,
-        prefer_wallet_balance,
-        rpc_client.clone(),
-        marginfi_accounts.clone(),
-    );
-    let mut flashloan_precheck = flashloan_precheck;
-    if let Some(prep) = flashloan_precheck.clone() {
-        flashloan_manager.adopt_preparation(prep);
-    } else if flashloan_manager.is_enabled() {
-        flashloan_precheck = flashloan_manager
-            .prepare(&identity)
-            .await
-            .map_err(|err| anyhow!(err))?;
-        if let Some(prep) = &flashloan_precheck {
-            events::flashloan_account_precheck("blind", &prep.account, prep.created);
-        }
-    }
-    let flashloan = flashloan_manager.try_into_enabled();
+    let flashloan = build_flashloan_registry(
+        config,
+        &rpc_client,
+        &identity,
+        &marginfi_accounts,
+        flashloan_precheck,
+        "blind",
+    )
+    .await?;
+    let tx_builder = match &flashloan {
+        Some(registry) => tx_builder.with_flashloan_programs(registry.program_ids()),
+        None => tx_builder,
+    };
+    let mut liquidity_tasks = flashloan
+        .as_ref()
+        .map(|registry| {
+            registry.spawn_liquidity_refresh(Duration::from_secs(
+                config.galileo.flashloan.liquidity_refresh_secs.max(1),
+            ))
+        })
+        .unwrap_or_default();
 
     let lander_factory = LanderFactory::new(
         rpc_client.clone(),
@@ -376,28 +395,13 @@ async fn run_blind_engine(
         dry_run_enabled,
         config.galileo.bot.enable_simulation,
     );
-    let default_landers = ["rpc"];
-
-    let requested_landers: Vec<String> = if dry_run_enabled {
-        if blind_config.enable_landers.is_empty() {
-            vec!["rpc".to_string()]
-        } else {
-            blind_config.enable_landers.clone()
-        }
-    } else {
-        blind_config.enable_landers.clone()
-    };
-
-    let lander_stack = lander_factory
-        .build_stack(
-            &config.lander.lander,
-            &requested_landers,
-            &default_landers,
-            0,
-            Arc::clone(&ip_allocator),
-        )
-        .map_err(|err| anyhow!(err))?;
-    let lander_stack = Arc::new(lander_stack);
+    let lander_stack = build_lander_stack(
+        &lander_factory,
+        config,
+        &blind_config.enable_landers,
+        dry_run_enabled,
+        &ip_allocator,
+    )?;
 
     let quote_cadence = resolve_quote_cadence(&config.galileo.engine, backend);
 
@@ -418,8 +422,23 @@ async fn run_blind_engine(
             );
         }
         lighthouse_settings.existing_memory_ids = existing_memory_ids;
+        lighthouse_settings
+            .resolve_token_programs(&rpc_client)
+            .await
+            .map_err(|err| anyhow!(err))?;
     }
 
+    let (pool_wallets, pool_tasks) = prepare_pool_wallets(
+        config,
+        &rpc_client,
+        &identity,
+        &trade_pairs,
+        lighthouse_settings.enable,
+        "blind",
+    )
+    .await?;
+    liquidity_tasks.extend(pool_tasks);
+
     let console_summary_settings = ConsoleSummarySettings {
         enable: config.galileo.engine.enable_console_summary,
         summary_only: config.galileo.engine.console_summary_only,
@@ -433,8 +452,34 @@ async fn run_blind_engine(
         .with_cu_multiplier(1.0)
         .with_compute_unit_price_mode(compute_unit_price_mode.clone())
         .with_lighthouse(lighthouse_settings)
-        .with_console_summary(console_summary_settings);
-
+        .with_console_summary(console_summary_settings)
+        .with_compute_units(build_compute_unit_estimator(
+            &config.galileo.bot.compute_unit_tuning,
+            &rpc_client,
+        ))
+        .with_alt_manager(alt_manager)
+        .with_nonce_pool(nonce_pool);
+
+    let (reload_tx, reload_rx) = mpsc::channel(4);
+    let reload_loop = forward_blind_reloads(
+        reload_tx,
+        &lander_factory,
+        Arc::clone(&ip_allocator),
+        backend,
+        dry_run_enabled,
+        ip_capacity_hint,
+    );
+    let (pair_tx, pair_rx) = mpsc::channel(4);
+    // 新增 mint 需为每个可能签名的钱包补齐 ATA，否则轮换到钱包池成员时交易必然失败
+    let refresh_identities: Vec<EngineIdentity> = std::iter::once(identity.clone())
+        .chain(pool_wallets.iter().map(|wallet| wallet.identity.clone()))
+        .collect();
+    let mint_refresh_loop = refresh_blind_trade_pairs(
+        pair_tx,
+        config,
+        AccountPrechecker::new(rpc_client.clone(), marginfi_accounts.clone()),
+        refresh_identities,
+    );
     let strategy_engine = StrategyEngine::new(
         BlindStrategy::new(),
         lander_stack.clone(),
@@ -450,8 +495,18 @@ async fn run_blind_engine(
         trade_pairs,
         trade_profiles,
         multi_leg_context,
-    );
-    let result = drive_engine(strategy_engine).await;
+    )
+    .with_wallet_pool(pool_wallets, config.galileo.bot.wallet_pool.selection)
+    .with_reload(reload_rx)
+    .with_trade_pair_updates(pair_rx);
+    let result = tokio::select! {
+        result = drive_engine(strategy_engine) => result,
+        _ = reload_loop => unreachable!("配置热重载监听不会主动结束"),
+        _ = mint_refresh_loop => unreachable!("intermedium 刷新任务不会主动结束"),
+    };
+    for task in liquidity_tasks {
+        task.abort();
+    }
 
     if jupiter_started {
         if let StrategyBackend::Jupiter {
@@ -1066,12 +1121,29 @@ fn parse_lighthouse_settings(cfg: &config::LightHouseBotConfig) -> Result<Lighth
         }
     });
 
+    let mut settlements = HashMap::with_capacity(cfg.settle_accounts.len());
+    for (mint_text, target) in &cfg.settle_accounts {
+        let trimmed = mint_text.trim();
+        let mint = Pubkey::from_str(trimmed).map_err(|err| {
+            anyhow!(
+                "light_house.settle_accounts 中的 mint `{}` 无效: {err}",
+                trimmed
+            )
+        })?;
+        let settlement = GuardSettlement::from_str(target)
+            .map_err(|err| anyhow!("light_house.settle_accounts[{mint}]: {err}"))?;
+        settlements.insert(mint, settlement);
+    }
+
     Ok(LighthouseSettings {
         enable: true,
         profit_guard_mints: mints,
         memory_slots,
         existing_memory_ids: Vec::new(),
         sol_price_feed,
+        native_sol_guard: cfg.native_sol_guard,
+        settlements,
+        token_programs: HashMap::new(),
     })
 }
 
@@ -1359,6 +1431,18 @@ async fn run_pure_blind_engine(
         alt_cache.clone(),
         dry_run_enabled,
     );
+    let alt_manager = build_alt_manager(
+        &config.galileo.bot.alt_manager,
+        &rpc_client,
+        &identity,
+        dry_run_enabled,
+    );
+    let tx_builder = match &alt_manager {
+        Some(manager) => tx_builder.with_owned_lookup_tables(manager.tables()),
+        None => tx_builder,
+    };
+    let nonce_pool =
+        build_nonce_pool(&config.galileo.bot, &rpc_client, &identity, dry_run_enabled).await?;
 
     let marginfi_cfg = &config.galileo.flashloan.marginfi;
     let marginfi_accounts = parse_marginfi_accounts(marginfi_cfg)?;
@@ -1366,7 +1450,6 @@ async fn run_pure_blind_engine(
         .galileo
         .bot
         .flashloan_enabled(FlashloanProduct::Marginfi);
-    let prefer_wallet_balance = config.galileo.bot.flashloan.prefer_wallet_balance;
 
     let prechecker = AccountPrechecker::new(rpc_client.clone(), marginfi_accounts.clone());
     let (summary, flashloan_precheck) = prechecker
@@ -1403,26 +1486,37 @@ async fn run_pure_blind_engine(
         }
     }
 
-    let mut flashloan_manager = MarginfiFlashloanManager::new(
-        marginfi_cfg,
-        flashloan_enabled,
-        prefer_wallet_balance,
-        rpc_client.clone(),
-        marginfi_accounts.clone(),
-    );
-    let mut flashloan_precheck = flashloan_precheck;
-    if let Some(prep) = flashloan_precheck.clone() {
-        flashloan_manager.adopt_preparation(prep);
-    } else if flashloan_manager.is_enabled() {
-        flashloan_precheck = flashloan_manager
-            .prepare(&identity)
-            .await
-            .map_err(|err| anyhow!(err))?;
-        if let Some(prep) = &flashloan_precheck {
-            events::flashloan_account_precheck("pure_blind", &prep.account, prep.created);
-        }
-    }
-    let flashloan = flashloan_manager.try_into_enabled();
+    let flashloan = build_flashloan_registry(
+        config,
+        &rpc_client,
+        &identity,
+        &marginfi_accounts,
+        flashloan_precheck,
+        "pure_blind",
+    )
+    .await?;
+    let tx_builder = match &flashloan {
+        Some(registry) => tx_builder.with_flashloan_programs(registry.program_ids()),
+        None => tx_builder,
+    };
+    let mut liquidity_tasks = flashloan
+        .as_ref()
+        .map(|registry| {
+            registry.spawn_liquidity_refresh(Duration::from_secs(
+                config.galileo.flashloan.liquidity_refresh_secs.max(1),
+            ))
+        })
+        .unwrap_or_default();
+    let (pool_wallets, pool_tasks) = prepare_pool_wallets(
+        config,
+        &rpc_client,
+        &identity,
+        &trade_pairs,
+        false,
+        "pure_blind",
+    )
+    .await?;
+    liquidity_tasks.extend(pool_tasks);
 
     let lander_factory = LanderFactory::new(
         rpc_client.clone(),
@@ -1431,28 +1525,13 @@ async fn run_pure_blind_engine(
         dry_run_enabled,
         config.galileo.bot.enable_simulation,
     );
-    let default_landers = ["rpc"];
-
-    let requested_landers: Vec<String> = if dry_run_enabled {
-        if pure_config.enable_landers.is_empty() {
-            vec!["rpc".to_string()]
-        } else {
-            pure_config.enable_landers.clone()
-        }
-    } else {
-        pure_config.enable_landers.clone()
-    };
-
-    let lander_stack = lander_factory
-        .build_stack(
-            &config.lander.lander,
-            &requested_landers,
-            &default_landers,
-            0,
-            Arc::clone(&ip_allocator),
-        )
-        .map_err(|err| anyhow!(err))?;
-    let lander_stack = Arc::new(lander_stack);
+    let lander_stack = build_lander_stack(
+        &lander_factory,
+        config,
+        &pure_config.enable_landers,
+        dry_run_enabled,
+        &ip_allocator,
+    )?;
 
     let quote_cadence = resolve_quote_cadence(&config.galileo.engine, backend);
     let console_summary_settings = ConsoleSummarySettings {
@@ -1466,8 +1545,15 @@ async fn run_pure_blind_engine(
         .with_landing_timeout(landing_timeout)
         .with_dry_run(dry_run_enabled)
         .with_cu_multiplier(pure_config.cu_multiplier)
+        .with_price_guards(build_pure_price_guards(pure_config)?)
         .with_compute_unit_price_mode(compute_unit_price_mode.clone())
-        .with_console_summary(console_summary_settings);
+        .with_console_summary(console_summary_settings)
+        .with_compute_units(build_compute_unit_estimator(
+            &config.galileo.bot.compute_unit_tuning,
+            &rpc_client,
+        ))
+        .with_alt_manager(alt_manager)
+        .with_nonce_pool(nonce_pool);
 
     let decay_duration = Duration::from_secs(pure_config.activation.decay_seconds);
     let activation_policy = PoolActivationPolicy::new(
@@ -1494,6 +1580,7 @@ async fn run_pure_blind_engine(
         observer_queue_capacity,
         pure_config.cache.max_routes,
     ));
+    crate::control::runtime_control().register_route_catalog(Arc::clone(&route_catalog));
 
     let cache_manager = PureBlindCacheManager::new(&pure_config.cache);
 
@@ -1530,6 +1617,7 @@ async fn run_pure_blind_engine(
             endpoint: endpoint.to_string(),
             token,
             wallets,
+            backfill_rpc: Some(Arc::clone(&rpc_client)),
         };
 
         if let Err(err) = spawn_pool_observer(
@@ -1569,6 +1657,15 @@ async fn run_pure_blind_engine(
         .await
         .map_err(|err| anyhow!(err))?;
 
+    let (reload_tx, reload_rx) = mpsc::channel(4);
+    let reload_loop = forward_pure_blind_reloads(
+        reload_tx,
+        &lander_factory,
+        Arc::clone(&ip_allocator),
+        rpc_client.as_ref(),
+        backend,
+        dry_run_enabled,
+    );
     let strategy_engine = StrategyEngine::new(
         PureBlindStrategy::new(
             routes,
@@ -1591,8 +1688,16 @@ async fn run_pure_blind_engine(
         trade_pairs,
         trade_profiles,
         None,
-    );
-    let result = drive_engine(strategy_engine).await;
+    )
+    .with_wallet_pool(pool_wallets, config.galileo.bot.wallet_pool.selection)
+    .with_reload(reload_rx);
+    let result = tokio::select! {
+        result = drive_engine(strategy_engine) => result,
+        _ = reload_loop => unreachable!("配置热重载监听不会主动结束"),
+    };
+    for task in liquidity_tasks {
+        task.abort();
+    }
 
     if let Some(handle) = cache_task.take() {
         handle.abort();
@@ -1777,6 +1882,303 @@ async fn prepare_swap_components(
     }
 }
 
+fn build_lander_stack(
+    lander_factory: &LanderFactory,
+    config: &AppConfig,
+    enable_landers: &[String],
+    dry_run_enabled: bool,
+    ip_allocator: &Arc<IpAllocator>,
+) -> Result<Arc<LanderStack>> {
+    let default_landers = ["rpc"];
+    let requested_landers: Vec<String> = if dry_run_enabled && enable_landers.is_empty() {
+        vec!["rpc".to_string()]
+    } else {
+        enable_landers.to_vec()
+    };
+
+    let lander_stack = lander_factory
+        .build_stack(
+            &config.lander.lander,
+            &requested_landers,
+            &default_landers,
+            0,
+            Arc::clone(ip_allocator),
+        )
+        .map_err(|err| anyhow!(err))?;
+    Ok(Arc::new(lander_stack))
+}
+
+/// 监听配置热重载：按新配置重建落地器、节奏与交易规模，交由引擎在下一个 tick 应用。
+async fn forward_blind_reloads(
+    reload_tx: mpsc::Sender<EngineReload<BlindStrategy>>,
+    lander_factory: &LanderFactory,
+    ip_allocator: Arc<IpAllocator>,
+    backend: &StrategyBackend<'_>,
+    dry_run_enabled: bool,
+    ip_capacity_hint: usize,
+) {
+    let mut reloads = config::reload::subscribe_reloads();
+    while reloads.changed().await.is_ok() {
+        let Some(config) = reloads.borrow_and_update().clone() else {
+            continue;
+        };
+        let built = build_blind_reload(
+            &config,
+            lander_factory,
+            &ip_allocator,
+            backend,
+            dry_run_enabled,
+            ip_capacity_hint,
+        );
+        match built {
+            Ok(reload) => {
+                if reload_tx.send(reload).await.is_err() {
+                    break;
+                }
+            }
+            Err(err) => warn!(
+                target: "strategy::reload",
+                error = %err,
+                "盲发策略热重载失败，继续使用当前配置"
+            ),
+        }
+    }
+    std::future::pending::<()>().await
+}
+
+async fn forward_pure_blind_reloads(
+    reload_tx: mpsc::Sender<EngineReload<PureBlindStrategy>>,
+    lander_factory: &LanderFactory,
+    ip_allocator: Arc<IpAllocator>,
+    rpc_client: &RpcClient,
+    backend: &StrategyBackend<'_>,
+    dry_run_enabled: bool,
+) {
+    let mut reloads = config::reload::subscribe_reloads();
+    while reloads.changed().await.is_ok() {
+        let Some(config) = reloads.borrow_and_update().clone() else {
+            continue;
+        };
+        let built = build_pure_blind_reload(
+            &config,
+            lander_factory,
+            &ip_allocator,
+            rpc_client,
+            backend,
+            dry_run_enabled,
+        )
+        .await;
+        match built {
+            Ok(reload) => {
+                if reload_tx.send(reload).await.is_err() {
+                    break;
+                }
+            }
+            Err(err) => warn!(
+                target: "strategy::reload",
+                error = %err,
+                "纯盲发策略热重载失败，继续使用当前配置"
+            ),
+        }
+    }
+    std::future::pending::<()>().await
+}
+
+/// 按 `intermedium.refresh_interval_secs` 重新计算 mint 集合，为每个签名钱包补齐新增 mint 的 ATA 后推送新的盲发交易对。
+async fn refresh_blind_trade_pairs(
+    pair_tx: mpsc::Sender<Vec<crate::strategy::types::TradePair>>,
+    config: &AppConfig,
+    prechecker: AccountPrechecker,
+    identities: Vec<EngineIdentity>,
+) {
+    let mut intermedium = config.galileo.intermedium.clone();
+    if intermedium.refresh_interval_secs == 0 {
+        return std::future::pending().await;
+    }
+    let proxy = resolve_global_http_proxy(&config.galileo.global);
+    let client = match build_http_client_with_options(proxy.as_ref(), false, None, None) {
+        Ok(client) => client,
+        Err(err) => {
+            warn!(
+                target: "intermedium::refresh",
+                error = %err,
+                "构建 HTTP 客户端失败，intermedium 定时刷新未启动"
+            );
+            return std::future::pending().await;
+        }
+    };
+    let period = Duration::from_secs(intermedium.refresh_interval_secs);
+    loop {
+        tokio::time::sleep(period).await;
+        let mints = match resolve_mint_universe(&intermedium, &client).await {
+            Ok(mints) => mints,
+            Err(err) => {
+                warn!(
+                    target: "intermedium::refresh",
+                    error = %err,
+                    "刷新 intermedium mint 源失败，沿用当前交易对"
+                );
+                continue;
+            }
+        };
+        if mints == intermedium.mints {
+            debug!(target: "intermedium::refresh", total = mints.len(), "mint 集合无变化");
+            continue;
+        }
+        let (added, removed) = {
+            let previous: HashSet<&str> = intermedium.mints.iter().map(String::as_str).collect();
+            let current: HashSet<&str> = mints.iter().map(String::as_str).collect();
+            let added: HashSet<String> = current
+                .difference(&previous)
+                .map(|mint| mint.to_string())
+                .collect();
+            (added, previous.difference(&current).count())
+        };
+        let mut next = intermedium.clone();
+        next.mints = mints;
+        let pairs = match build_blind_trade_pairs(&config.galileo.blind_strategy, &next) {
+            Ok(pairs) => pairs,
+            Err(err) => {
+                warn!(
+                    target: "intermedium::refresh",
+                    error = %err,
+                    "按刷新后的 mint 集合构建交易对失败，沿用当前交易对"
+                );
+                continue;
+            }
+        };
+        let new_pairs: Vec<_> = pairs
+            .iter()
+            .filter(|pair| added.contains(pair.output_mint.as_str()))
+            .cloned()
+            .collect();
+        let mut prechecked = true;
+        if !new_pairs.is_empty() {
+            for identity in &identities {
+                match prechecker
+                    .ensure_accounts(identity, &new_pairs, false)
+                    .await
+                {
+                    Ok((summary, _)) => events::accounts_precheck(
+                        "blind",
+                        summary.total_mints,
+                        summary.created_accounts,
+                        summary.total_mints.saturating_sub(summary.processed_mints),
+                    ),
+                    Err(err) => {
+                        warn!(
+                            target: "intermedium::refresh",
+                            wallet = %identity.pubkey,
+                            error = %err,
+                            "新增 mint 账户预检失败，本轮不更新交易对"
+                        );
+                        prechecked = false;
+                        break;
+                    }
+                }
+            }
+        }
+        if !prechecked {
+            continue;
+        }
+        info!(
+            target: "intermedium::refresh",
+            added = added.len(),
+            removed,
+            pairs = pairs.len(),
+            "intermedium mint 集合已刷新"
+        );
+        if pair_tx.send(pairs).await.is_err() {
+            break;
+        }
+        intermedium = next;
+    }
+    std::future::pending::<()>().await
+}
+
+fn build_blind_reload(
+    config: &AppConfig,
+    lander_factory: &LanderFactory,
+    ip_allocator: &Arc<IpAllocator>,
+    backend: &StrategyBackend<'_>,
+    dry_run_enabled: bool,
+    ip_capacity_hint: usize,
+) -> Result<EngineReload<BlindStrategy>> {
+    let blind_config = &config.galileo.blind_strategy;
+    let compute_unit_price_mode = derive_compute_unit_price_mode(&config.lander.lander);
+    let profit_config = build_blind_profit_config(
+        blind_config,
+        &config.lander.lander,
+        &compute_unit_price_mode,
+    );
+    Ok(EngineReload {
+        landers: build_lander_stack(
+            lander_factory,
+            config,
+            &blind_config.enable_landers,
+            dry_run_enabled,
+            ip_allocator,
+        )?,
+        compute_unit_price_mode,
+        dispatch_strategy: config.lander.lander.sending_strategy,
+        cu_multiplier: 1.0,
+        quote_cadence: resolve_quote_cadence(&config.galileo.engine, backend),
+        price_guards: Vec::new(),
+        profit_evaluator: ProfitEvaluator::new(
+            profit_config,
+            config.galileo.bot.network.enable_multiple_ip,
+        ),
+        trade_profiles: build_blind_trade_profiles(blind_config, ip_capacity_hint)?,
+        strategy: None,
+    })
+}
+
+async fn build_pure_blind_reload(
+    config: &AppConfig,
+    lander_factory: &LanderFactory,
+    ip_allocator: &Arc<IpAllocator>,
+    rpc_client: &RpcClient,
+    backend: &StrategyBackend<'_>,
+    dry_run_enabled: bool,
+) -> Result<EngineReload<PureBlindStrategy>> {
+    let pure_config = &config.galileo.pure_blind_strategy;
+    let routes = PureBlindRouteBuilder::new(pure_config, rpc_client)
+        .build()
+        .await
+        .map_err(|err| anyhow!(err))?;
+    let compute_unit_price_mode = derive_compute_unit_price_mode(&config.lander.lander);
+    let profit_config = build_pure_profit_config(&config.lander.lander, &compute_unit_price_mode);
+    let strategy_config = pure_config.clone();
+    Ok(EngineReload {
+        landers: build_lander_stack(
+            lander_factory,
+            config,
+            &pure_config.enable_landers,
+            dry_run_enabled,
+            ip_allocator,
+        )?,
+        compute_unit_price_mode,
+        dispatch_strategy: config.lander.lander.sending_strategy,
+        cu_multiplier: pure_config.cu_multiplier,
+        quote_cadence: resolve_quote_cadence(&config.galileo.engine, backend),
+        price_guards: build_pure_price_guards(pure_config)?,
+        profit_evaluator: ProfitEvaluator::new(
+            profit_config,
+            config.galileo.bot.network.enable_multiple_ip,
+        ),
+        trade_profiles: build_pure_trade_profiles(pure_config)?,
+        strategy: Some(Box::new(move |strategy: &mut PureBlindStrategy| {
+            if let Err(err) = strategy.reload(routes, &strategy_config) {
+                warn!(
+                    target: "strategy::reload",
+                    error = %err,
+                    "纯盲发路由热重载失败，保留原有路由"
+                );
+            }
+        })),
+    })
+}
+
 async fn drive_engine<S>(engine: StrategyEngine<S>) -> EngineResult<()>
 where
     S: Strategy<Event = StrategyEvent>,
@@ -2316,6 +2718,56 @@ fn build_blind_quote_config(
     }
 }
 
+fn build_pure_price_guards(
+    config: &config::PureBlindStrategyConfig,
+) -> EngineResult<Vec<BlindPriceGuard>> {
+    let mut guards = Vec::with_capacity(config.price_guards.len());
+    for (idx, guard) in config.price_guards.iter().enumerate() {
+        let invalid = |message: String| {
+            EngineError::InvalidConfig(format!("pure_blind_strategy.price_guards[{idx}] {message}"))
+        };
+        let account_str = guard.account.trim();
+        let account = Pubkey::from_str(account_str)
+            .map_err(|err| invalid(format!("账户 `{account_str}` 解析失败: {err}")))?;
+        let source = match guard.source.trim().to_ascii_lowercase().as_str() {
+            "pyth" => PriceSource::PythReceiver,
+            "switchboard" => PriceSource::SwitchboardOnDemand,
+            "raw" => {
+                let offset = guard
+                    .offset
+                    .ok_or_else(|| invalid("source = raw 时必须配置 offset".into()))?;
+                let value_type = guard
+                    .value_type
+                    .as_deref()
+                    .ok_or_else(|| invalid("source = raw 时必须配置 value_type".into()))?
+                    .parse::<PriceValueType>()
+                    .map_err(invalid)?;
+                PriceSource::Raw { offset, value_type }
+            }
+            other => return Err(invalid(format!("未知的价格来源 `{other}`"))),
+        };
+        let band = PriceBand::new(account, source, guard.min, guard.max).map_err(invalid)?;
+        let markets = guard
+            .markets
+            .iter()
+            .map(|market| {
+                let trimmed = market.trim();
+                Pubkey::from_str(trimmed)
+                    .map_err(|err| invalid(format!("市场 `{trimmed}` 解析失败: {err}")))
+            })
+            .collect::<EngineResult<Vec<_>>>()?;
+        info!(
+            target: "strategy::pure_blind",
+            account = %band.account(),
+            source = %band.source(),
+            markets = markets.len(),
+            "已启用价格区间守护"
+        );
+        guards.push(BlindPriceGuard { band, markets });
+    }
+    Ok(guards)
+}
+
 fn build_pure_quote_config() -> QuoteConfig {
     QuoteConfig {
         slippage_bps: 0,
@@ -2405,6 +2857,233 @@ fn derive_compute_unit_price_mode(
     }
 }
 
+fn build_compute_unit_estimator(
+    cfg: &config::ComputeUnitTuningConfig,
+    rpc_client: &Arc<RpcClient>,
+) -> Option<ComputeUnitEstimator> {
+    if !cfg.enable {
+        return None;
+    }
+    info!(
+        target: "strategy",
+        window = cfg.window,
+        percentile = cfg.percentile,
+        headroom_ratio = cfg.headroom_ratio,
+        min_samples = cfg.min_samples,
+        "已启用按路线自动调整 compute unit limit"
+    );
+    Some(ComputeUnitEstimator::new(cfg).with_landed_probe(Arc::clone(rpc_client)))
+}
+
+fn build_alt_manager(
+    cfg: &config::AltManagerConfig,
+    rpc_client: &Arc<RpcClient>,
+    identity: &EngineIdentity,
+    dry_run: bool,
+) -> Option<AltManager> {
+    if !cfg.enable {
+        return None;
+    }
+    if dry_run {
+        info!(
+            target: "strategy",
+            "dry-run 模式下不创建或扩展自有 ALT"
+        );
+        return None;
+    }
+    let manager = AltManager::new(Arc::clone(rpc_client), identity.clone(), cfg.clone());
+    manager.clone().spawn();
+    info!(
+        target: "strategy",
+        max_tables = cfg.max_tables,
+        refresh_interval_secs = cfg.refresh_interval_secs,
+        "已启用自有 ALT 管理"
+    );
+    Some(manager)
+}
+
+async fn build_nonce_pool(
+    bot: &config::BotConfig,
+    rpc_client: &Arc<RpcClient>,
+    identity: &EngineIdentity,
+    dry_run: bool,
+) -> Result<Option<NoncePool>> {
+    let cfg = &bot.durable_nonce;
+    if !cfg.enable {
+        return Ok(None);
+    }
+    // nonce 账户的 authority 是主钱包，钱包池成员签名时无法推进它们。
+    if bot.wallet_pool.enable {
+        return Err(anyhow!("bot.durable_nonce 与 bot.wallet_pool 不能同时启用"));
+    }
+    let mut cfg = cfg.clone();
+    if dry_run && cfg.auto_create {
+        info!(
+            target: "strategy",
+            "dry-run 模式下不自动创建 nonce 账户"
+        );
+        cfg.auto_create = false;
+    }
+    let pool = NoncePool::initialize(Arc::clone(rpc_client), identity, &cfg).await?;
+    info!(
+        target: "strategy",
+        accounts = pool.capacity(),
+        "已启用 durable nonce 模式"
+    );
+    Ok(Some(pool))
+}
+
+/// 钱包池中除主钱包外的钱包逐个完成 ATA 与闪电贷账户预检，并探测各自的 Lighthouse memory 账户。
+async fn prepare_pool_wallets(
+    config: &AppConfig,
+    rpc_client: &Arc<RpcClient>,
+    primary: &EngineIdentity,
+    trade_pairs: &[crate::strategy::types::TradePair],
+    lighthouse_enabled: bool,
+    strategy: &str,
+) -> Result<(Vec<PoolWallet>, Vec<JoinHandle<()>>)> {
+    let mut wallets = Vec::new();
+    let mut tasks = Vec::new();
+    if !config.galileo.bot.wallet_pool.enable {
+        return Ok((wallets, tasks));
+    }
+
+    let flashloan_enabled = config
+        .galileo
+        .bot
+        .flashloan_enabled(FlashloanProduct::Marginfi);
+    // flashloan.marginfi.marginfi_account 属于主钱包，其余钱包按 authority 查找或新建。
+    let marginfi_accounts = MarginfiAccountRegistry::default();
+    let prechecker = AccountPrechecker::new(rpc_client.clone(), marginfi_accounts.clone());
+    let mut seen = HashSet::from([primary.pubkey]);
+    for private_key in &config.galileo.pool_private_keys {
+        let mut identity =
+            EngineIdentity::from_private_key(private_key).map_err(|err| anyhow!(err))?;
+        if !seen.insert(identity.pubkey) {
+            warn!(
+                target: "strategy",
+                wallet = %identity.pubkey,
+                "钱包池中存在重复钱包，已忽略"
+            );
+            continue;
+        }
+        identity.set_skip_user_accounts_rpc_calls(primary.skip_user_accounts_rpc_calls());
+
+        let (summary, flashloan_precheck) = prechecker
+            .ensure_accounts(&identity, trade_pairs, flashloan_enabled)
+            .await
+            .map_err(|err| anyhow!(err))?;
+        events::accounts_precheck(
+            strategy,
+            summary.total_mints,
+            summary.created_accounts,
+            summary.total_mints.saturating_sub(summary.processed_mints),
+        );
+        if let Some(prep) = &flashloan_precheck {
+            events::flashloan_account_precheck(strategy, &prep.account, prep.created);
+        }
+        let flashloan = build_flashloan_registry(
+            config,
+            rpc_client,
+            &identity,
+            &marginfi_accounts,
+            flashloan_precheck,
+            strategy,
+        )
+        .await?;
+        if let Some(registry) = &flashloan {
+            tasks.extend(registry.spawn_liquidity_refresh(Duration::from_secs(
+                config.galileo.flashloan.liquidity_refresh_secs.max(1),
+            )));
+        }
+        let lighthouse_memory_ids = if lighthouse_enabled {
+            prechecker
+                .detect_lighthouse_memory_accounts(&identity)
+                .await
+                .map_err(|err| anyhow!(err))?
+        } else {
+            Vec::new()
+        };
+
+        info!(
+            target: "strategy",
+            strategy,
+            wallet = %identity.pubkey,
+            lighthouse_memory_accounts = lighthouse_memory_ids.len(),
+            "钱包池成员已完成账户预检"
+        );
+        wallets.push(PoolWallet {
+            identity,
+            flashloan,
+            lighthouse_memory_ids,
+        });
+    }
+    Ok((wallets, tasks))
+}
+
+/// 按 `bot.flashloan.products` 的顺序注册闪电贷来源；新增协议只需在这里挂上对应的 provider。
+async fn build_flashloan_registry(
+    config: &AppConfig,
+    rpc_client: &Arc<RpcClient>,
+    identity: &EngineIdentity,
+    marginfi_accounts: &MarginfiAccountRegistry,
+    precheck: Option<FlashloanPreparation>,
+    strategy: &str,
+) -> Result<Option<FlashloanRegistry>> {
+    let toggle = &config.galileo.bot.flashloan;
+    let mut registry = FlashloanRegistry::new();
+    let mut seen = HashSet::new();
+    for product in toggle.products.iter().copied() {
+        if !seen.insert(product) {
+            continue;
+        }
+        let mut provider: Box<dyn FlashloanProvider> = match product {
+            FlashloanProduct::Marginfi => Box::new(MarginfiFlashloanManager::new(
+                &config.galileo.flashloan.marginfi,
+                true,
+                toggle.prefer_wallet_balance,
+                Arc::clone(rpc_client),
+                marginfi_accounts.clone(),
+            )),
+            FlashloanProduct::Kamino => Box::new(
+                KaminoFlashloanManager::new(
+                    &config.galileo.flashloan.kamino,
+                    true,
+                    toggle.prefer_wallet_balance,
+                    Arc::clone(rpc_client),
+                )
+                .map_err(|err| anyhow!(err))?,
+            ),
+        };
+        match precheck
+            .as_ref()
+            .filter(|prep| prep.protocol == provider.protocol())
+        {
+            Some(prep) => provider.adopt_preparation(prep),
+            None => {
+                let prepared = provider
+                    .prepare(identity)
+                    .await
+                    .map_err(|err| anyhow!(err))?;
+                if let Some(prep) = prepared {
+                    events::flashloan_account_precheck(strategy, &prep.account, prep.created);
+                }
+            }
+        }
+        registry.register(provider);
+    }
+
+    if registry.is_empty() {
+        return Ok(None);
+    }
+    info!(
+        target: "strategy",
+        providers = ?registry,
+        "闪电贷来源已就绪"
+    );
+    Ok(Some(registry))
+}
+
 fn parse_marginfi_accounts(
     cfg: &config::FlashloanMarginfiConfig,
 ) -> Result<MarginfiAccountRegistry> {
@@ -0,0 +1,66 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{Result, anyhow};
+
+use crate::cli::args::{ConfigCheckArgs, ConfigCmd};
+use crate::config::check::{ConfigCheckReport, Severity, check_config};
+
+pub fn handle_config_command(cmd: &ConfigCmd, override_path: Option<PathBuf>) -> Result<()> {
+    match cmd {
+        ConfigCmd::Check(args) => handle_config_check(args, override_path),
+    }
+}
+
+fn handle_config_check(args: &ConfigCheckArgs, override_path: Option<PathBuf>) -> Result<()> {
+    let report = check_config(override_path).map_err(|err| anyhow!("配置加载失败: {err}"))?;
+
+    print_sources(&report);
+    if !args.quiet {
+        for (name, value) in &report.effective {
+            println!();
+            println!("# 生效配置：{name}（敏感字段已脱敏）");
+            let rendered =
+                serde_yaml::to_string(value).map_err(|err| anyhow!("序列化生效配置失败: {err}"))?;
+            print!("{rendered}");
+        }
+    }
+
+    println!();
+    if report.issues.is_empty() {
+        println!("✅ 配置校验通过");
+        return Ok(());
+    }
+    println!("校验结果：");
+    for issue in &report.issues {
+        let marker = match issue.severity {
+            Severity::Error => "❌",
+            Severity::Warning => "⚠️",
+        };
+        println!(
+            "  {marker} [{}] {}: {}",
+            issue.severity, issue.location, issue.message
+        );
+    }
+    let errors = report.error_count();
+    println!("共 {} 个错误，{} 个警告", errors, report.warning_count());
+    if errors > 0 {
+        return Err(anyhow!("配置校验未通过：{errors} 个错误"));
+    }
+    Ok(())
+}
+
+fn print_sources(report: &ConfigCheckReport) {
+    let sources = &report.sources;
+    println!("配置来源：");
+    println!("  galileo   {}", display_source(sources.galileo.as_deref()));
+    for (section, path) in &sources.strategies {
+        println!("  {section}  {}", path.display());
+    }
+    println!("  lander    {}", display_source(sources.lander.as_deref()));
+    println!("  jupiter   {}", display_source(sources.jupiter.as_deref()));
+}
+
+fn display_source(path: Option<&Path>) -> String {
+    path.map(|path| path.display().to_string())
+        .unwrap_or_else(|| "未找到，使用默认值".to_string())
+}
@@ -13,6 +13,7 @@ use solana_rpc_client::http_sender::HttpSender;
 use time::{UtcOffset, macros::format_description};
 use tracing::info;
 use tracing_subscriber::fmt::time::OffsetTime;
+use tracing_subscriber::fmt::writer::BoxMakeWriter;
 use tracing_subscriber::{EnvFilter, fmt};
 
 use crate::config::{
@@ -100,8 +101,8 @@ impl DryRunMode {
     }
 }
 
-/// 初始化 tracing，兼顾 JSON 与文本输出模式。
-pub fn init_tracing(config: &crate::config::LoggingConfig) -> Result<()> {
+/// 初始化 tracing，兼顾 JSON 与文本输出模式；`dashboard` 为真时日志写入仪表盘日志区而非标准输出。
+pub fn init_tracing(config: &crate::config::LoggingConfig, dashboard: bool) -> Result<()> {
     let mut filter = EnvFilter::try_new(&config.level).unwrap_or_else(|_| EnvFilter::new("info"));
 
     // 默认压低外部依赖的调试输出，避免日志被噪声淹没；Verbose 模式可通过配置显式覆盖。
@@ -145,13 +146,22 @@ pub fn init_tracing(config: &crate::config::LoggingConfig) -> Result<()> {
     })?;
     let offset_timer = OffsetTime::new(offset, time_format);
 
-    let builder = fmt()
+    let writer = if dashboard {
+        BoxMakeWriter::new(crate::monitoring::dashboard::DashboardLogWriter)
+    } else {
+        BoxMakeWriter::new(std::io::stdout)
+    };
+    let mut builder = fmt()
         .with_timer(offset_timer.clone())
         .with_file(false)
         .with_line_number(false)
         .with_thread_ids(false)
         .with_target(true)
-        .with_level(true);
+        .with_level(true)
+        .with_writer(writer);
+    if dashboard {
+        builder = builder.with_ansi(false);
+    }
 
     if config.json {
         builder
@@ -1,7 +1,10 @@
+pub mod alt;
 pub mod args;
 pub mod commands;
+pub mod config;
 pub mod context;
 pub mod jupiter;
+pub mod report;
 pub mod runtime;
 pub mod wallet;
 
@@ -0,0 +1,222 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use anyhow::{Result, anyhow};
+use time::macros::format_description;
+use time::{Date, OffsetDateTime};
+
+use crate::cli::args::{ReportArgs, ReportDimension};
+use crate::config::AppConfig;
+use crate::monitoring::ledger::{LedgerEntry, LedgerOutcome, read_ledger};
+use crate::monitoring::short_mint_str;
+
+pub fn handle_report_command(args: &ReportArgs, config: &AppConfig) -> Result<()> {
+    let path = args
+        .ledger
+        .clone()
+        .unwrap_or_else(|| PathBuf::from(&config.galileo.bot.ledger.path));
+    let since = args.since.as_deref().map(parse_since).transpose()?;
+
+    let entries: Vec<LedgerEntry> = read_ledger(&path)?
+        .into_iter()
+        .filter(|entry| since.is_none_or(|since| entry.recorded_at >= since))
+        .collect();
+    if entries.is_empty() {
+        println!("账本 {} 中没有可统计的记录", path.display());
+        return Ok(());
+    }
+
+    let dimensions = if args.by.is_empty() {
+        vec![
+            ReportDimension::Day,
+            ReportDimension::Strategy,
+            ReportDimension::Route,
+            ReportDimension::Lander,
+        ]
+    } else {
+        args.by.clone()
+    };
+
+    println!("账本 {}，共 {} 条记录", path.display(), entries.len());
+    for dimension in dimensions {
+        println!();
+        println!("按{}汇总：", dimension_label(dimension));
+        println!(
+            "  {:<28} {:<10} {:>7} {:>7} {:>7} {:>16} {:>14} {:>12}",
+            "分组", "base", "提交", "落地", "失败", "实际收益", "tip", "手续费"
+        );
+        for row in aggregate(&entries, dimension) {
+            println!(
+                "  {:<28} {:<10} {:>7} {:>7} {:>7} {:>16} {:>14} {:>12}",
+                row.key,
+                short_mint_str(&row.base_mint),
+                row.trades,
+                row.landed,
+                row.failed,
+                row.realized,
+                row.tips,
+                row.fees
+            );
+        }
+    }
+    println!();
+    println!("实际收益为钱包 base mint 余额变化（最小单位），WSOL 已扣除交易费与 tip。");
+
+    Ok(())
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+struct ReportRow {
+    key: String,
+    base_mint: String,
+    trades: u64,
+    landed: u64,
+    failed: u64,
+    realized: i128,
+    tips: u128,
+    fees: u128,
+}
+
+/// 按维度与 base mint 分组汇总；不同 base mint 的收益单位不同，不能相加。
+fn aggregate(entries: &[LedgerEntry], dimension: ReportDimension) -> Vec<ReportRow> {
+    let mut rows: BTreeMap<(String, String), ReportRow> = BTreeMap::new();
+    for entry in entries {
+        let key = dimension_key(entry, dimension);
+        let base_mint = entry.base_mint.to_string();
+        let row = rows
+            .entry((key.clone(), base_mint.clone()))
+            .or_insert_with(|| ReportRow {
+                key,
+                base_mint,
+                ..ReportRow::default()
+            });
+        row.trades += 1;
+        match entry.outcome {
+            LedgerOutcome::Landed => {
+                row.landed += 1;
+                row.tips += u128::from(entry.tip_lamports);
+            }
+            LedgerOutcome::FailedOnChain => row.failed += 1,
+            LedgerOutcome::Unconfirmed | LedgerOutcome::SubmitFailed => {}
+        }
+        row.realized += i128::from(entry.realized_delta.unwrap_or_default());
+        row.fees += u128::from(entry.fee_lamports.unwrap_or_default());
+    }
+    rows.into_values().collect()
+}
+
+fn dimension_key(entry: &LedgerEntry, dimension: ReportDimension) -> String {
+    match dimension {
+        ReportDimension::Day => i64::try_from(entry.recorded_at)
+            .ok()
+            .and_then(|secs| OffsetDateTime::from_unix_timestamp(secs).ok())
+            .map(|at| at.date().to_string())
+            .unwrap_or_else(|| "-".to_string()),
+        ReportDimension::Strategy => entry.strategy.clone(),
+        ReportDimension::Route => format!(
+            "{}->{}",
+            short_mint_str(&entry.input_mint),
+            short_mint_str(&entry.output_mint)
+        ),
+        ReportDimension::Lander => entry
+            .lander
+            .clone()
+            .unwrap_or_else(|| entry.outcome.as_str().to_string()),
+    }
+}
+
+fn dimension_label(dimension: ReportDimension) -> &'static str {
+    match dimension {
+        ReportDimension::Day => "日期（UTC）",
+        ReportDimension::Strategy => "策略",
+        ReportDimension::Route => "交易对",
+        ReportDimension::Lander => "落地器",
+    }
+}
+
+fn parse_since(value: &str) -> Result<u64> {
+    let date = Date::parse(value.trim(), format_description!("[year]-[month]-[day]"))
+        .map_err(|err| anyhow!("--since 需为 YYYY-MM-DD 格式: {err}"))?;
+    Ok(date.midnight().assume_utc().unix_timestamp().max(0) as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::pubkey::Pubkey;
+
+    fn entry(
+        strategy: &str,
+        lander: Option<&str>,
+        outcome: LedgerOutcome,
+        delta: i64,
+        recorded_at: u64,
+    ) -> LedgerEntry {
+        LedgerEntry {
+            signature: None,
+            recorded_at,
+            strategy: strategy.to_string(),
+            input_mint: "So11111111111111111111111111111111111111112".to_string(),
+            output_mint: "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v".to_string(),
+            route: None,
+            base_mint: Pubkey::default(),
+            amount_in: 1_000,
+            expected_profit: 10,
+            tip_lamports: 5,
+            prioritization_fee_lamports: 1,
+            lander: lander.map(str::to_string),
+            endpoint: None,
+            slot: None,
+            outcome,
+            realized_delta: Some(delta),
+            fee_lamports: Some(2),
+            error: None,
+        }
+    }
+
+    #[test]
+    fn aggregates_by_strategy_and_lander() {
+        let entries = vec![
+            entry("blind", Some("jito"), LedgerOutcome::Landed, 30, 0),
+            entry("blind", Some("rpc"), LedgerOutcome::FailedOnChain, -2, 0),
+            entry("copy", None, LedgerOutcome::SubmitFailed, 0, 0),
+        ];
+
+        let by_strategy = aggregate(&entries, ReportDimension::Strategy);
+        assert_eq!(by_strategy.len(), 2);
+        assert_eq!(by_strategy[0].key, "blind");
+        assert_eq!(by_strategy[0].trades, 2);
+        assert_eq!(by_strategy[0].landed, 1);
+        assert_eq!(by_strategy[0].failed, 1);
+        assert_eq!(by_strategy[0].realized, 28);
+        assert_eq!(by_strategy[0].tips, 5);
+        assert_eq!(by_strategy[0].fees, 4);
+
+        let by_lander: Vec<String> = aggregate(&entries, ReportDimension::Lander)
+            .into_iter()
+            .map(|row| row.key)
+            .collect();
+        assert_eq!(by_lander, vec!["jito", "rpc", "submit_failed"]);
+
+        let by_route = aggregate(&entries, ReportDimension::Route);
+        assert_eq!(by_route.len(), 1);
+        assert_eq!(by_route[0].key, "WSOL->USDC");
+    }
+
+    #[test]
+    fn groups_by_utc_day_and_parses_since() {
+        let since = parse_since("2024-01-02").expect("since");
+        assert_eq!(since, 1_704_153_600);
+        assert!(parse_since("01/02/2024").is_err());
+
+        let entries = vec![
+            entry("blind", None, LedgerOutcome::Landed, 1, since - 1),
+            entry("blind", None, LedgerOutcome::Landed, 2, since + 10),
+        ];
+        let days: Vec<String> = aggregate(&entries, ReportDimension::Day)
+            .into_iter()
+            .map(|row| row.key)
+            .collect();
+        assert_eq!(days, vec!["2024-01-01", "2024-01-02"]);
+    }
+}
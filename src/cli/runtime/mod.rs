@@ -1,4 +1,5 @@
 use std::sync::Arc;
+use std::time::Duration;
 
 use anyhow::{Result, anyhow};
 use tracing::{info, warn};
@@ -7,7 +8,7 @@ use crate::api::dflow::DflowApiClient;
 use crate::api::jupiter::JupiterApiClient;
 use crate::api::kamino::KaminoApiClient;
 use crate::api::ultra::UltraApiClient;
-use crate::cli::args::{Cli, Command, ToolsCmd};
+use crate::cli::args::{Cli, Command, DashboardArgs, ToolsCmd};
 use crate::cli::context::{
     build_launch_overrides, init_configs, override_proxy_selection, resolve_global_http_proxy,
     resolve_jupiter_base_url, resolve_jupiter_defaults, resolve_proxy_profile, resolve_rpc_client,
@@ -21,6 +22,8 @@ use crate::engine::EngineIdentity;
 use crate::jupiter::JupiterBinaryManager;
 use crate::jupiter::error::JupiterError;
 
+const DASHBOARD_BALANCE_INTERVAL: Duration = Duration::from_secs(15);
+
 enum AggregatorContext {
     Jupiter {
         api_client: JupiterApiClient,
@@ -43,7 +46,15 @@ enum AggregatorContext {
     None,
 }
 
-pub async fn run(cli: Cli, config: AppConfig) -> Result<()> {
+pub async fn run(cli: Cli, mut config: AppConfig) -> Result<()> {
+    if let Command::Report(args) = &cli.command {
+        return crate::cli::report::handle_report_command(args, &config);
+    }
+
+    if let Command::Dashboard(args) = &cli.command {
+        return run_dashboard_attach(args, &config).await;
+    }
+
     if config.galileo.bot.prometheus.enable {
         crate::monitoring::try_init_prometheus(&config.galileo.bot.prometheus.listen)
             .map_err(|err| anyhow!(err))?;
@@ -71,6 +82,26 @@ pub async fn run(cli: Cli, config: AppConfig) -> Result<()> {
         return Ok(());
     }
 
+    if let Command::Alt(cmd) = &cli.command {
+        crate::cli::alt::handle_alt_command(cmd, &config).await?;
+        return Ok(());
+    }
+
+    let tui = matches!(&cli.command, Command::Run(args) if args.tui);
+    if matches!(cli.command, Command::Run(_)) {
+        init_trade_ledger(&config)?;
+        start_wallet_guardian(&config)?;
+        start_treasury_sweeper(&config).await?;
+    }
+    if matches!(cli.command, Command::Run(_) | Command::StrategyDryRun) {
+        hydrate_remote_mints(&mut config).await?;
+        start_control_api(&config).await?;
+        if tui || config.galileo.bot.control_api.enable {
+            start_dashboard_feed(&config)?;
+        }
+        crate::config::reload::spawn_config_reloader(cli.config.clone(), &config)?;
+    }
+
     let blind_enabled = config
         .galileo
         .bot
@@ -450,7 +481,12 @@ pub async fn run(cli: Cli, config: AppConfig) -> Result<()> {
         }
     };
 
-    let result = dispatch(cli.command, config, aggregator).await;
+    let result = if tui {
+        crate::tools::tui::dashboard::run_local_dashboard(dispatch(cli.command, config, aggregator))
+            .await
+    } else {
+        dispatch(cli.command, config, aggregator).await
+    };
 
     if let Some(guard) = managed_self_hosted.take() {
         guard.shutdown().await;
@@ -466,7 +502,7 @@ async fn dispatch(
 ) -> Result<()> {
     // 统一的命令分发入口，便于后续按子命令拆分到专门模块。
     match command {
-        Command::Run => match &aggregator {
+        Command::Run(_) => match &aggregator {
             AggregatorContext::Jupiter { api_client } => {
                 let backend = crate::cli::strategy::StrategyBackend::Jupiter {
                     manager: None,
@@ -563,14 +599,194 @@ async fn dispatch(
         Command::Init(args) => {
             init_configs(args)?;
         }
-        Command::Wallet(_) => {}
+        Command::Wallet(_) | Command::Config(_) => {}
         Command::Jupiter(_) => unreachable!("Jupiter 命令已在入口提前处理"),
         Command::Tools(_) => unreachable!("Tools 命令已在入口提前处理"),
+        Command::Alt(_) => unreachable!("ALT 命令已在入口提前处理"),
+        Command::Report(_) => unreachable!("Report 命令已在入口提前处理"),
+        Command::Dashboard(_) => unreachable!("Dashboard 命令已在入口提前处理"),
+    }
+
+    Ok(())
+}
+
+fn init_trade_ledger(config: &AppConfig) -> Result<()> {
+    let ledger_cfg = &config.galileo.bot.ledger;
+    if !ledger_cfg.enable {
+        return Ok(());
+    }
+    if config.galileo.bot.dry_run.enable {
+        info!(
+            target: "monitoring::ledger",
+            "dry-run 模式下不记录交易账本"
+        );
+        return Ok(());
+    }
+    let rpc_client = resolve_rpc_client(&config.galileo.global, None, None)?.client;
+    let identity = EngineIdentity::from_private_key(&config.galileo.private_key)
+        .map_err(|err| anyhow!(err))?;
+    crate::monitoring::ledger::init_trade_ledger(&ledger_cfg.path, rpc_client, identity.pubkey)
+}
+
+/// 启动前拉取 `intermedium.load_mints_from_url`，与文件源一起重新计算 mint 集合。
+async fn hydrate_remote_mints(config: &mut AppConfig) -> Result<()> {
+    if config.galileo.intermedium.load_mints_from_url.is_empty() {
+        return Ok(());
+    }
+    let proxy = resolve_global_http_proxy(&config.galileo.global);
+    let client = build_http_client_with_options(proxy.as_ref(), false, None, None)?;
+    let intermedium = &mut config.galileo.intermedium;
+    let mints = crate::intermedium::loader::resolve_mint_universe(intermedium, &client)
+        .await
+        .map_err(|err| anyhow!("加载 intermedium mint 源失败: {err}"))?;
+    info!(
+        target: "intermedium::loader",
+        sources = intermedium.load_mints_from_url.len(),
+        total = mints.len(),
+        "已合并 URL mint 源"
+    );
+    intermedium.mints = mints;
+    Ok(())
+}
+
+/// `auto_unwrap.enable` 时启动钱包守护任务；dry-run 不会真实消耗余额，因此跳过。
+fn start_wallet_guardian(config: &AppConfig) -> Result<()> {
+    let auto_unwrap = &config.galileo.auto_unwrap;
+    if !auto_unwrap.enable {
+        return Ok(());
+    }
+    if config.galileo.bot.dry_run.enable {
+        info!(target: "wallet::guardian", "dry-run 模式下不启用自动解包");
+        return Ok(());
+    }
+    let identity = EngineIdentity::from_private_key(&config.galileo.private_key)
+        .map_err(|err| anyhow!(err))?;
+    let rpc_client = resolve_rpc_client(&config.galileo.global, None, None)?.client;
+    crate::wallet::guardian::WalletGuardian::new(rpc_client, identity, auto_unwrap.clone()).spawn();
+    Ok(())
+}
+
+/// `treasury.enable` 时启动利润归集；全局 dry-run 下只记账不转账。
+async fn start_treasury_sweeper(config: &AppConfig) -> Result<()> {
+    let treasury = &config.galileo.treasury;
+    if !treasury.enable {
+        return Ok(());
+    }
+    let identity = EngineIdentity::from_private_key(&config.galileo.private_key)
+        .map_err(|err| anyhow!(err))?;
+    let rpc_client = resolve_rpc_client(&config.galileo.global, None, None)?.client;
+    let dry_run = treasury.dry_run || config.galileo.bot.dry_run.enable;
+    crate::wallet::treasury::TreasurySweeper::new(rpc_client, identity, &config.galileo, dry_run)
+        .await?
+        .spawn();
+    Ok(())
+}
+
+async fn start_control_api(config: &AppConfig) -> Result<()> {
+    let control_cfg = &config.galileo.bot.control_api;
+    if !control_cfg.enable {
+        return Ok(());
     }
+    let control = crate::control::runtime_control();
+    let rpc_client = resolve_rpc_client(&config.galileo.global, None, None)?.client;
+    control.register_rpc(rpc_client);
+    if let Some((name, endpoint)) = aggregator_health_endpoint(config)? {
+        control.register_aggregator(name, endpoint);
+    }
+    let token = control_cfg
+        .token
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string);
+    crate::control::spawn_control_api(&control_cfg.listen, token).await
+}
 
+/// 开启仪表盘数据采集：事件流写入内存快照，并定期刷新主钱包余额。
+fn start_dashboard_feed(config: &AppConfig) -> Result<()> {
+    crate::monitoring::dashboard::enable();
+    let identity = EngineIdentity::from_private_key(&config.galileo.private_key)
+        .map_err(|err| anyhow!(err))?;
+    let rpc_client = resolve_rpc_client(&config.galileo.global, None, None)?.client;
+    crate::monitoring::dashboard::spawn_balance_poller(
+        rpc_client,
+        identity.pubkey,
+        DASHBOARD_BALANCE_INTERVAL,
+    );
     Ok(())
 }
 
+/// `galileo dashboard`：轮询运行中实例的 `/dashboard` 接口渲染面板。
+async fn run_dashboard_attach(args: &DashboardArgs, config: &AppConfig) -> Result<()> {
+    let control_cfg = &config.galileo.bot.control_api;
+    let url = match args.url.as_deref().map(str::trim).filter(|v| !v.is_empty()) {
+        Some(url) => url.trim_end_matches('/').to_string(),
+        None => format!("http://{}", local_control_api_addr(&control_cfg.listen)),
+    };
+    let token = args
+        .token
+        .as_deref()
+        .or(control_cfg.token.as_deref())
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string);
+    let client = build_http_client_with_options(None, should_bypass_proxy(&url), None, None)?;
+    crate::tools::tui::dashboard::run_remote_dashboard(
+        client,
+        url,
+        token,
+        Duration::from_millis(args.refresh_ms.max(100)),
+    )
+    .await
+}
+
+/// 监听在通配地址时改为回环地址连接。
+fn local_control_api_addr(listen: &str) -> String {
+    match listen.parse::<std::net::SocketAddr>() {
+        Ok(addr) if addr.ip().is_unspecified() => {
+            format!("127.0.0.1:{}", addr.port())
+        }
+        _ => listen.trim().to_string(),
+    }
+}
+
+/// 健康检查探测的聚合器地址；自托管 Jupiter 探测本地二进制端口。
+fn aggregator_health_endpoint(config: &AppConfig) -> Result<Option<(&'static str, String)>> {
+    let engine = &config.galileo.engine;
+    let endpoint = match engine.backend {
+        crate::config::EngineBackend::Jupiter => engine
+            .jupiter
+            .primary()
+            .and_then(|cfg| cfg.api_quote_base.clone())
+            .map(|url| ("jupiter", url)),
+        crate::config::EngineBackend::JupiterSelfHosted => {
+            let jupiter_cfg =
+                resolve_jupiter_defaults(config.jupiter.clone(), &config.galileo.global)?;
+            Some((
+                "jupiter_self_hosted",
+                resolve_jupiter_base_url(&jupiter_cfg),
+            ))
+        }
+        crate::config::EngineBackend::Dflow => engine
+            .dflow
+            .api_quote_base
+            .clone()
+            .map(|url| ("dflow", url)),
+        crate::config::EngineBackend::Kamino => engine
+            .kamino
+            .api_quote_base
+            .clone()
+            .map(|url| ("kamino", url)),
+        crate::config::EngineBackend::Ultra => engine
+            .ultra
+            .api_quote_base
+            .clone()
+            .map(|url| ("ultra", url)),
+        crate::config::EngineBackend::MultiLegs | crate::config::EngineBackend::None => None,
+    };
+    Ok(endpoint.filter(|(_, url)| !url.trim().is_empty()))
+}
+
 async fn run_tools_command(_command: &ToolsCmd, config: &AppConfig) -> Result<()> {
     let tools_cfg = &config.galileo.global.tools;
     let dry_run_override = if config.galileo.bot.dry_run.enable {
@@ -622,13 +838,73 @@ async fn run_tools_command(_command: &ToolsCmd, config: &AppConfig) -> Result<()
         rpc: rpc_client.clone(),
         identity: identity.clone(),
         compute_unit_lamports,
+        swap_api: build_tools_swap_client(config)?,
     };
     crate::tools::tui::run_interactive_tui(ctx, exec).await
 }
 
+/// 工具箱「兑换回 SOL」使用的 Jupiter 客户端；其他引擎不提供该操作。
+fn build_tools_swap_client(config: &AppConfig) -> Result<Option<JupiterApiClient>> {
+    let (quote_url, swap_url, proxy_override, bypass_proxy) = match config.galileo.engine.backend {
+        crate::config::EngineBackend::Jupiter => {
+            let Some(jupiter_cfg) = config.galileo.engine.jupiter.primary() else {
+                return Ok(None);
+            };
+            let Some(quote_base) = jupiter_cfg
+                .api_quote_base
+                .as_ref()
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty())
+            else {
+                return Ok(None);
+            };
+            let swap_base = jupiter_cfg
+                .api_swap_base
+                .clone()
+                .unwrap_or_else(|| quote_base.clone());
+            let proxy_override = jupiter_cfg
+                .api_proxy
+                .as_ref()
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty());
+            (quote_base, swap_base, proxy_override, false)
+        }
+        crate::config::EngineBackend::JupiterSelfHosted => {
+            let jupiter_cfg =
+                resolve_jupiter_defaults(config.jupiter.clone(), &config.galileo.global)?;
+            let base_url = resolve_jupiter_base_url(&jupiter_cfg);
+            let base_endpoint = base_url.trim_end_matches('/');
+            let proxy_override =
+                resolve_self_hosted_jupiter_api_proxy(&config.galileo.engine.jupiter_self_hosted);
+            let bypass_proxy = proxy_override.is_none() && should_bypass_proxy(&base_url);
+            (
+                format!("{}/quote", base_endpoint),
+                format!("{}/swap-instructions", base_endpoint),
+                proxy_override,
+                bypass_proxy,
+            )
+        }
+        _ => return Ok(None),
+    };
+    let effective_proxy = override_proxy_selection(
+        proxy_override.as_deref(),
+        resolve_proxy_profile(&config.galileo.global, "quote"),
+        resolve_global_http_proxy(&config.galileo.global),
+    );
+    let client =
+        build_http_client_with_options(effective_proxy.as_ref(), bypass_proxy, None, None)?;
+    Ok(Some(JupiterApiClient::new(
+        client,
+        quote_url,
+        swap_url,
+        &config.galileo.engine.time_out,
+        &config.galileo.global.logging,
+    )))
+}
+
 fn command_needs_jupiter(command: &Command, config: &AppConfig) -> bool {
     match command {
-        Command::Run | Command::StrategyDryRun => config
+        Command::Run(_) | Command::StrategyDryRun => config
             .galileo
             .bot
             .strategy_enabled(StrategyToggle::BlindStrategy),
@@ -645,7 +921,7 @@ async fn maybe_start_managed_self_hosted(
     if !binary_cfg.enable_running {
         return Ok(None);
     }
-    if !matches!(command, Command::Run | Command::StrategyDryRun) {
+    if !matches!(command, Command::Run(_) | Command::StrategyDryRun) {
         return Ok(None);
     }
     if matches!(
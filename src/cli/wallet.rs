@@ -1,26 +1,32 @@
-use std::path::PathBuf;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
 
-use anyhow::{Result, anyhow};
+use anyhow::{Result, anyhow, bail};
 use serde_yaml;
 use solana_sdk::signer::Signer;
 
-use crate::cli::args::WalletCmd;
+use crate::cli::args::{WalletCmd, WalletRemoveArgs, WalletRenameArgs};
 use crate::config::GalileoConfig;
 use crate::config::loader::DEFAULT_CONFIG_PATHS;
-use crate::config::wallet::{WalletProcessingResult, add_wallet_to_config, parse_keypair_string};
+use crate::config::wallet::{
+    WalletProcessingResult, add_wallet_to_config, decrypt_entry_or_prompt, encrypt_wallet_entry,
+    obtain_existing_password, obtain_new_password, parse_keypair_string, persist_wallet_keys,
+};
 
 pub fn handle_wallet_command(cmd: &WalletCmd, override_path: Option<PathBuf>) -> Result<()> {
     match cmd {
         WalletCmd::Add(_args) => handle_wallet_add(override_path),
+        WalletCmd::List => handle_wallet_list(override_path),
+        WalletCmd::Remove(args) => handle_wallet_remove(args, override_path),
+        WalletCmd::Rename(args) => handle_wallet_rename(args, override_path),
+        WalletCmd::ChangePassword => handle_wallet_change_password(override_path),
+        WalletCmd::Verify => handle_wallet_verify(override_path),
     }
 }
 
 fn handle_wallet_add(override_path: Option<PathBuf>) -> Result<()> {
     let target_path = resolve_config_path(override_path)?;
-    let contents = std::fs::read_to_string(&target_path)
-        .map_err(|err| anyhow!("读取配置文件失败 {}: {err}", target_path.display()))?;
-    let mut config: GalileoConfig = serde_yaml::from_str(&contents)
-        .map_err(|err| anyhow!("解析配置文件失败 {}: {err}", target_path.display()))?;
+    let mut config = load_wallet_config(&target_path)?;
 
     let WalletProcessingResult {
         selected_remark, ..
@@ -43,6 +49,206 @@ fn handle_wallet_add(override_path: Option<PathBuf>) -> Result<()> {
     Ok(())
 }
 
+fn handle_wallet_list(override_path: Option<PathBuf>) -> Result<()> {
+    let target_path = resolve_config_path(override_path)?;
+    let config = load_wallet_config(&target_path)?;
+    if config.wallet_keys.is_empty() {
+        println!("尚未保存任何钱包，可运行 `galileo wallet add` 添加。");
+        return Ok(());
+    }
+
+    let width = config
+        .wallet_keys
+        .iter()
+        .map(|entry| entry.remark.chars().count())
+        .max()
+        .unwrap_or(0);
+    for (index, entry) in config.wallet_keys.iter().enumerate() {
+        let pubkey = entry
+            .pubkey
+            .as_deref()
+            .unwrap_or("（未记录，运行 `galileo wallet verify` 补写）");
+        println!("[{}] {:<width$}  {}", index + 1, entry.remark, pubkey);
+    }
+    Ok(())
+}
+
+fn handle_wallet_remove(args: &WalletRemoveArgs, override_path: Option<PathBuf>) -> Result<()> {
+    let target_path = resolve_config_path(override_path)?;
+    let mut config = load_wallet_config(&target_path)?;
+    let index = find_entry(&config, &args.remark)?;
+
+    if !args.yes {
+        let answer = prompt_line(&format!(
+            "确认删除钱包 [{}]？删除后无法从配置恢复 [y/N]: ",
+            config.wallet_keys[index].remark
+        ))?;
+        if !matches!(answer.trim(), "y" | "Y" | "yes" | "YES") {
+            println!("已取消。");
+            return Ok(());
+        }
+    }
+
+    let removed = config.wallet_keys.remove(index);
+    save_wallet_keys(&target_path, &config)?;
+    println!("🗑  已删除钱包 [{}]", removed.remark);
+    if config
+        .bot
+        .wallet_pool
+        .wallets
+        .iter()
+        .any(|remark| remark.trim() == removed.remark)
+    {
+        println!("提示：bot.wallet_pool.wallets 仍引用该备注，请同步修改。");
+    }
+    Ok(())
+}
+
+fn handle_wallet_rename(args: &WalletRenameArgs, override_path: Option<PathBuf>) -> Result<()> {
+    let target_path = resolve_config_path(override_path)?;
+    let mut config = load_wallet_config(&target_path)?;
+    let index = find_entry(&config, &args.remark)?;
+
+    let new_remark = args.new_remark.trim();
+    if new_remark.is_empty() {
+        bail!("新备注名不能为空");
+    }
+    if config
+        .wallet_keys
+        .iter()
+        .enumerate()
+        .any(|(other, entry)| other != index && entry.remark.eq_ignore_ascii_case(new_remark))
+    {
+        bail!("备注名 \"{new_remark}\" 已存在，请使用其它名称");
+    }
+
+    let old_remark = std::mem::replace(
+        &mut config.wallet_keys[index].remark,
+        new_remark.to_string(),
+    );
+    save_wallet_keys(&target_path, &config)?;
+    println!("✏️  钱包 [{old_remark}] 已更名为 [{new_remark}]");
+    Ok(())
+}
+
+fn handle_wallet_change_password(override_path: Option<PathBuf>) -> Result<()> {
+    let target_path = resolve_config_path(override_path)?;
+    let mut config = load_wallet_config(&target_path)?;
+    if config.wallet_keys.is_empty() {
+        bail!("尚未保存任何钱包");
+    }
+
+    println!("请输入当前密码：");
+    let current = obtain_existing_password().map_err(|message| anyhow!(message))?;
+    let mut private_keys = Vec::with_capacity(config.wallet_keys.len());
+    for entry in &config.wallet_keys {
+        let private_key =
+            decrypt_entry_or_prompt(entry, current.as_ref()).map_err(|message| anyhow!(message))?;
+        private_keys.push(private_key);
+    }
+
+    println!("请设置新密码：");
+    let password = obtain_new_password().map_err(|message| anyhow!(message))?;
+    let entries = config
+        .wallet_keys
+        .iter()
+        .zip(private_keys.iter())
+        .map(|(entry, private_key)| {
+            encrypt_wallet_entry(&entry.remark, private_key, password.as_ref())
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|message| anyhow!(message))?;
+
+    config.wallet_keys = entries;
+    save_wallet_keys(&target_path, &config)?;
+    println!(
+        "🔐 已使用新密码重新加密 {} 个钱包",
+        config.wallet_keys.len()
+    );
+    Ok(())
+}
+
+fn handle_wallet_verify(override_path: Option<PathBuf>) -> Result<()> {
+    let target_path = resolve_config_path(override_path)?;
+    let mut config = load_wallet_config(&target_path)?;
+    if config.wallet_keys.is_empty() {
+        bail!("尚未保存任何钱包");
+    }
+
+    let password = obtain_existing_password().map_err(|message| anyhow!(message))?;
+    let mut failures = 0usize;
+    let mut recorded = 0usize;
+    for entry in &mut config.wallet_keys {
+        let pubkey =
+            match decrypt_entry_or_prompt(entry, password.as_ref()).and_then(|private_key| {
+                parse_keypair_string(private_key.trim())
+                    .map_err(|err| format!("解析私钥失败: {err}"))
+            }) {
+                Ok(keypair) => keypair.pubkey().to_string(),
+                Err(message) => {
+                    failures += 1;
+                    println!("❌ [{}] {message}", entry.remark);
+                    continue;
+                }
+            };
+        match entry.pubkey.as_deref() {
+            Some(expected) if expected == pubkey => {
+                println!("✅ [{}] {pubkey}", entry.remark);
+            }
+            Some(expected) => {
+                failures += 1;
+                println!(
+                    "❌ [{}] 解密得到 {pubkey}，与记录的公钥 {expected} 不一致",
+                    entry.remark
+                );
+            }
+            None => {
+                recorded += 1;
+                println!("✅ [{}] {pubkey}（已补写公钥）", entry.remark);
+                entry.pubkey = Some(pubkey);
+            }
+        }
+    }
+
+    if recorded > 0 {
+        save_wallet_keys(&target_path, &config)?;
+    }
+    if failures > 0 {
+        bail!("{failures} 个钱包校验失败");
+    }
+    println!("全部 {} 个钱包校验通过", config.wallet_keys.len());
+    Ok(())
+}
+
+fn load_wallet_config(path: &Path) -> Result<GalileoConfig> {
+    let contents = std::fs::read_to_string(path)
+        .map_err(|err| anyhow!("读取配置文件失败 {}: {err}", path.display()))?;
+    serde_yaml::from_str(&contents)
+        .map_err(|err| anyhow!("解析配置文件失败 {}: {err}", path.display()))
+}
+
+fn save_wallet_keys(path: &Path, config: &GalileoConfig) -> Result<()> {
+    persist_wallet_keys(path, &config.wallet_keys).map_err(|err| anyhow!(err.to_string()))?;
+    println!("配置文件位置：{}", path.display());
+    Ok(())
+}
+
+fn find_entry(config: &GalileoConfig, remark: &str) -> Result<usize> {
+    config
+        .wallet_keys
+        .iter()
+        .position(|entry| entry.remark == remark.trim())
+        .ok_or_else(|| anyhow!("未找到备注为 \"{}\" 的钱包", remark.trim()))
+}
+
+fn prompt_line(prompt: &str) -> Result<String> {
+    print!("{prompt}");
+    io::stdout().flush()?;
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+    Ok(input)
+}
+
 fn resolve_config_path(override_path: Option<PathBuf>) -> Result<PathBuf> {
     if let Some(path) = override_path {
         if path.exists() {
@@ -0,0 +1,364 @@
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use once_cell::sync::Lazy;
+use parking_lot::RwLock;
+use serde::de::DeserializeOwned;
+use serde_yaml::Value as YamlValue;
+use toml::Value as TomlValue;
+
+use super::loader::ConfigError;
+use super::{GalileoConfig, JupiterConfig, LanderConfig};
+
+const FILE_PREFIX: &str = "file:";
+const REDACTED: &str = "***";
+/// 过短的值（例如 `1`、`true`）替换后会误伤大量无关输出，不做脱敏。
+const MIN_REDACT_LEN: usize = 4;
+
+/// 通过插值解析出的敏感值，`Debug` 输出时统一替换为 `***`；按长度降序保存，避免部分替换。
+static RESOLVED_SECRETS: Lazy<RwLock<Vec<String>>> = Lazy::new(|| RwLock::new(Vec::new()));
+
+#[derive(Debug)]
+pub struct InterpolationError {
+    pub location: String,
+    pub message: String,
+}
+
+/// 解析 YAML 配置文件并完成插值；不含引用时直接反序列化以保留行号信息。
+pub(super) fn deserialize_yaml<T>(contents: &str, path: &Path) -> Result<T, ConfigError>
+where
+    T: DeserializeOwned,
+{
+    let parse_error = |err: serde_yaml::Error| ConfigError::Parse {
+        path: path.to_path_buf(),
+        message: err.to_string(),
+    };
+    if !has_references(contents) {
+        return serde_yaml::from_str(contents).map_err(parse_error);
+    }
+    let mut value: YamlValue = serde_yaml::from_str(contents).map_err(parse_error)?;
+    interpolate_yaml(&mut value, path.parent(), "")
+        .map_err(|err| interpolation_error(path, err))?;
+    serde_yaml::from_value(value).map_err(parse_error)
+}
+
+/// 解析 TOML 配置（存在 `[jupiter]` 表时只取该表）并完成插值。
+pub(super) fn deserialize_toml<T>(contents: &str, path: &Path) -> Result<T, ConfigError>
+where
+    T: DeserializeOwned,
+{
+    let parse_error = |message: String| ConfigError::Parse {
+        path: path.to_path_buf(),
+        message,
+    };
+    let raw: TomlValue = toml::from_str(contents).map_err(|err| parse_error(err.to_string()))?;
+    let mut table = match raw {
+        TomlValue::Table(mut root) => match root.remove("jupiter") {
+            Some(subtable) => subtable,
+            None => TomlValue::Table(root),
+        },
+        other => other,
+    };
+    interpolate_toml(&mut table, path.parent(), "")
+        .map_err(|err| interpolation_error(path, err))?;
+    table
+        .try_into()
+        .map_err(|err: toml::de::Error| parse_error(err.to_string()))
+}
+
+fn has_references(contents: &str) -> bool {
+    contents.contains("${") || contents.contains(FILE_PREFIX)
+}
+
+fn interpolation_error(path: &Path, err: InterpolationError) -> ConfigError {
+    ConfigError::Interpolate {
+        path: path.to_path_buf(),
+        location: err.location,
+        message: err.message,
+    }
+}
+
+/// 解析 YAML 文档中所有字符串值里的 `${ENV_VAR}` 与 `file:/path` 引用。
+pub(super) fn interpolate_yaml(
+    value: &mut YamlValue,
+    base_dir: Option<&Path>,
+    location: &str,
+) -> Result<(), InterpolationError> {
+    match value {
+        YamlValue::String(text) => resolve_in_place(text, base_dir, location),
+        YamlValue::Sequence(items) => {
+            for (idx, item) in items.iter_mut().enumerate() {
+                interpolate_yaml(item, base_dir, &format!("{location}[{idx}]"))?;
+            }
+            Ok(())
+        }
+        YamlValue::Mapping(map) => {
+            for (key, item) in map.iter_mut() {
+                let name = key
+                    .as_str()
+                    .map(str::to_string)
+                    .unwrap_or_else(|| format!("{key:?}"));
+                interpolate_yaml(item, base_dir, &child_location(location, &name))?;
+            }
+            Ok(())
+        }
+        YamlValue::Tagged(tagged) => interpolate_yaml(&mut tagged.value, base_dir, location),
+        _ => Ok(()),
+    }
+}
+
+/// TOML 版本，供 `jupiter.toml` 使用。
+pub(super) fn interpolate_toml(
+    value: &mut TomlValue,
+    base_dir: Option<&Path>,
+    location: &str,
+) -> Result<(), InterpolationError> {
+    match value {
+        TomlValue::String(text) => resolve_in_place(text, base_dir, location),
+        TomlValue::Array(items) => {
+            for (idx, item) in items.iter_mut().enumerate() {
+                interpolate_toml(item, base_dir, &format!("{location}[{idx}]"))?;
+            }
+            Ok(())
+        }
+        TomlValue::Table(table) => {
+            for (key, item) in table.iter_mut() {
+                interpolate_toml(item, base_dir, &child_location(location, key))?;
+            }
+            Ok(())
+        }
+        _ => Ok(()),
+    }
+}
+
+fn child_location(parent: &str, key: &str) -> String {
+    if parent.is_empty() {
+        key.to_string()
+    } else {
+        format!("{parent}.{key}")
+    }
+}
+
+fn resolve_in_place(
+    text: &mut String,
+    base_dir: Option<&Path>,
+    location: &str,
+) -> Result<(), InterpolationError> {
+    match resolve_string(text, base_dir) {
+        Ok(Some(resolved)) => {
+            *text = resolved;
+            Ok(())
+        }
+        Ok(None) => Ok(()),
+        Err(message) => Err(InterpolationError {
+            location: location.to_string(),
+            message,
+        }),
+    }
+}
+
+/// 整个值以 `file:` 开头时读取文件内容（去除首尾空白，相对路径基于配置文件目录）；
+/// 否则替换其中所有 `${NAME}`。没有引用时返回 `None`。
+fn resolve_string(text: &str, base_dir: Option<&Path>) -> Result<Option<String>, String> {
+    if let Some(raw_path) = text.strip_prefix(FILE_PREFIX) {
+        let raw_path = raw_path.trim();
+        if raw_path.is_empty() {
+            return Err("`file:` 引用缺少文件路径".to_string());
+        }
+        let path = PathBuf::from(raw_path);
+        let path = match base_dir {
+            Some(dir) if path.is_relative() => dir.join(path),
+            _ => path,
+        };
+        let contents = fs::read_to_string(&path)
+            .map_err(|err| format!("读取密钥文件 {} 失败: {err}", path.display()))?;
+        let secret = contents.trim().to_string();
+        register_secret(&secret);
+        return Ok(Some(secret));
+    }
+
+    if !text.contains("${") {
+        return Ok(None);
+    }
+    let mut resolved = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find("${") {
+        resolved.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        let end = after
+            .find('}')
+            .ok_or_else(|| format!("`{text}` 中的 `${{` 缺少匹配的 `}}`"))?;
+        let name = after[..end].trim();
+        if name.is_empty() {
+            return Err(format!("`{text}` 中存在空的变量名"));
+        }
+        let value = std::env::var(name).map_err(|err| match err {
+            std::env::VarError::NotPresent => format!("环境变量 {name} 未设置"),
+            std::env::VarError::NotUnicode(_) => format!("环境变量 {name} 不是有效的 UTF-8"),
+        })?;
+        register_secret(&value);
+        resolved.push_str(&value);
+        rest = &after[end + 1..];
+    }
+    resolved.push_str(rest);
+    Ok(Some(resolved))
+}
+
+fn register_secret(secret: &str) {
+    if secret.chars().count() < MIN_REDACT_LEN {
+        return;
+    }
+    let mut secrets = RESOLVED_SECRETS.write();
+    if secrets.iter().any(|known| known == secret) {
+        return;
+    }
+    secrets.push(secret.to_string());
+    secrets.sort_by(|a, b| b.len().cmp(&a.len()));
+}
+
+/// 将文本中出现的已解析敏感值替换为 `***`（同时覆盖 `Debug` 转义后的形式）。
+pub fn redact_secrets(text: &str) -> String {
+    let secrets = RESOLVED_SECRETS.read();
+    let mut redacted = text.to_string();
+    for secret in secrets.iter() {
+        redacted = redacted.replace(secret.as_str(), REDACTED);
+        let escaped = format!("{secret:?}");
+        let escaped = &escaped[1..escaped.len() - 1];
+        if escaped != secret {
+            redacted = redacted.replace(escaped, REDACTED);
+        }
+    }
+    redacted
+}
+
+/// 先按原样格式化，再替换其中的敏感值。
+struct Redacted<'a, T>(&'a T);
+
+impl<T: fmt::Debug> fmt::Debug for Redacted<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rendered = if f.alternate() {
+            format!("{:#?}", self.0)
+        } else {
+            format!("{:?}", self.0)
+        };
+        f.write_str(&redact_secrets(&rendered))
+    }
+}
+
+// 字段列表通过解构穷举：新增字段时编译失败，避免在 `Debug` 输出中被静默遗漏。
+impl fmt::Debug for GalileoConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let GalileoConfig {
+            global,
+            engine,
+            intermedium,
+            wallet_keys,
+            auto_unwrap,
+            treasury,
+            private_key,
+            pool_private_keys,
+            bot,
+            flashloan,
+            blind_strategy,
+            pure_blind_strategy,
+            back_run_strategy,
+            copy_strategy,
+        } = self;
+        let private_key = if private_key.is_empty() { "" } else { REDACTED };
+        f.debug_struct("GalileoConfig")
+            .field("global", &Redacted(global))
+            .field("engine", &Redacted(engine))
+            .field("intermedium", &Redacted(intermedium))
+            .field("wallet_keys", &Redacted(wallet_keys))
+            .field("auto_unwrap", &Redacted(auto_unwrap))
+            .field("treasury", &Redacted(treasury))
+            .field("private_key", &private_key)
+            .field(
+                "pool_private_keys",
+                &vec![REDACTED; pool_private_keys.len()],
+            )
+            .field("bot", &Redacted(bot))
+            .field("flashloan", &Redacted(flashloan))
+            .field("blind_strategy", &Redacted(blind_strategy))
+            .field("pure_blind_strategy", &Redacted(pure_blind_strategy))
+            .field("back_run_strategy", &Redacted(back_run_strategy))
+            .field("copy_strategy", &Redacted(copy_strategy))
+            .finish()
+    }
+}
+
+impl fmt::Debug for LanderConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let LanderConfig { lander } = self;
+        f.debug_struct("LanderConfig")
+            .field("lander", &Redacted(lander))
+            .finish()
+    }
+}
+
+impl fmt::Debug for JupiterConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let JupiterConfig {
+            binary,
+            core,
+            launch,
+            performance,
+            process,
+            environment,
+            health_check,
+        } = self;
+        f.debug_struct("JupiterConfig")
+            .field("binary", &Redacted(binary))
+            .field("core", &Redacted(core))
+            .field("launch", &Redacted(launch))
+            .field("performance", &Redacted(performance))
+            .field("process", &Redacted(process))
+            .field("environment", &Redacted(environment))
+            .field("health_check", &Redacted(health_check))
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolves_env_and_file_references() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        fs::write(dir.path().join("token.txt"), "file-secret-token\n").expect("write");
+        // SAFETY: 测试专用变量名，不与其他测试共享。
+        unsafe {
+            std::env::set_var("GALILEO_TEST_INTERPOLATE_KEY", "env-secret-key");
+        }
+        let mut value: YamlValue = serde_yaml::from_str(
+            "global:\n  rpc_urls:\n    - \"https://rpc.example/?api-key=${GALILEO_TEST_INTERPOLATE_KEY}\"\n  token: \"file:token.txt\"\n  plain: \"no refs\"\n",
+        )
+        .expect("yaml");
+        interpolate_yaml(&mut value, Some(dir.path()), "").expect("interpolate");
+
+        assert_eq!(
+            value["global"]["rpc_urls"][0].as_str(),
+            Some("https://rpc.example/?api-key=env-secret-key")
+        );
+        assert_eq!(value["global"]["token"].as_str(), Some("file-secret-token"));
+        assert_eq!(value["global"]["plain"].as_str(), Some("no refs"));
+        assert_eq!(
+            redact_secrets(
+                "url=https://rpc.example/?api-key=env-secret-key token=file-secret-token"
+            ),
+            "url=https://rpc.example/?api-key=*** token=***"
+        );
+    }
+
+    #[test]
+    fn missing_variable_reports_location() {
+        let mut value: YamlValue =
+            serde_yaml::from_str("bot:\n  yellowstone_grpc_token: \"${GALILEO_TEST_UNSET_VAR}\"\n")
+                .expect("yaml");
+        let err = interpolate_yaml(&mut value, None, "").expect_err("missing var");
+        assert_eq!(err.location, "bot.yellowstone_grpc_token");
+        assert!(err.message.contains("GALILEO_TEST_UNSET_VAR"));
+    }
+}
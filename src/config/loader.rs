@@ -5,6 +5,7 @@ use serde::de::DeserializeOwned;
 
 use thiserror::Error;
 
+use super::interpolate::{deserialize_toml, deserialize_yaml};
 use super::strategy_loader::load_strategy_configs;
 use super::wallet::{parse_keypair_string, process_wallet_keys};
 use super::{AppConfig, GalileoConfig, JupiterConfig, LanderConfig};
@@ -25,9 +26,34 @@ pub enum ConfigError {
     },
     #[error("failed to parse config at {path}: {message}")]
     Parse { path: PathBuf, message: String },
+    #[error("failed to resolve `{location}` in {path}: {message}")]
+    Interpolate {
+        path: PathBuf,
+        location: String,
+        message: String,
+    },
+}
+
+/// 各配置来源实际读取到的文件，供 `galileo config check` 展示并逐文件校验。
+#[derive(Debug, Clone, Default)]
+pub struct ConfigSources {
+    pub galileo: Option<PathBuf>,
+    /// 从外部文件加载的策略配置：(策略段名称, 文件路径)。
+    pub strategies: Vec<(&'static str, PathBuf)>,
+    pub lander: Option<PathBuf>,
+    pub jupiter: Option<PathBuf>,
 }
 
 pub fn load_config(path: Option<PathBuf>) -> Result<AppConfig, ConfigError> {
+    let (mut config, sources) = load_config_sources(path)?;
+    unlock_wallets(&mut config.galileo, sources.galileo.as_deref())?;
+    Ok(config)
+}
+
+/// 合并 galileo.yaml、策略文件、mint 来源、lander.yaml 与 jupiter.toml，但不解锁钱包。
+pub fn load_config_sources(
+    path: Option<PathBuf>,
+) -> Result<(AppConfig, ConfigSources), ConfigError> {
     let candidate_paths = match path {
         Some(p) => vec![p],
         None => DEFAULT_CONFIG_PATHS
@@ -36,11 +62,11 @@ pub fn load_config(path: Option<PathBuf>) -> Result<AppConfig, ConfigError> {
             .collect::<Vec<PathBuf>>(),
     };
 
-    let (mut galileo, galileo_dir) = load_first_available_yaml::<GalileoConfig>(&candidate_paths)?;
-    let galileo_path = first_existing_path(&candidate_paths);
+    let (mut galileo, galileo_path) = load_first_available_yaml::<GalileoConfig>(&candidate_paths)?;
+    let galileo_dir = parent_dir(galileo_path.as_deref());
 
     // 加载策略配置（从外部文件或使用主配置中的值）
-    load_strategy_configs(&mut galileo, galileo_dir.as_deref())?;
+    let strategies = load_strategy_configs(&mut galileo, galileo_dir.as_deref())?;
 
     if let Err(err) = hydrate_mints_from_sources(&mut galileo.intermedium, galileo_dir.as_deref()) {
         return Err(match err {
@@ -50,11 +76,47 @@ pub fn load_config(path: Option<PathBuf>) -> Result<AppConfig, ConfigError> {
                 message: source.to_string(),
             },
             MintSourceError::Invalid { path, message } => ConfigError::Parse { path, message },
+            MintSourceError::Remote { url, message } => ConfigError::Parse {
+                path: PathBuf::from(url),
+                message,
+            },
         });
     }
 
-    // 处理 wallet_keys：解密并填充 private_key
-    let wallet_result = process_wallet_keys(&mut galileo, galileo_path.as_deref())?;
+    let (lander, lander_path) = load_first_available_yaml::<LanderConfig>(&companion_candidates(
+        galileo_dir.as_deref(),
+        "lander.yaml",
+        DEFAULT_LANDER_PATHS,
+    ))?;
+
+    let (jupiter, jupiter_path) =
+        load_first_available_toml::<JupiterConfig>(&companion_candidates(
+            galileo_dir.as_deref(),
+            "jupiter.toml",
+            DEFAULT_JUPITER_PATHS,
+        ))?;
+
+    Ok((
+        AppConfig {
+            galileo,
+            lander,
+            jupiter,
+        },
+        ConfigSources {
+            galileo: galileo_path,
+            strategies,
+            lander: lander_path,
+            jupiter: jupiter_path,
+        },
+    ))
+}
+
+/// 处理 wallet_keys：解密并填充 private_key。
+fn unlock_wallets(
+    galileo: &mut GalileoConfig,
+    galileo_path: Option<&Path>,
+) -> Result<(), ConfigError> {
+    let wallet_result = process_wallet_keys(galileo, galileo_path)?;
     if wallet_result.config_updated {
         if let Some(remark) = wallet_result.selected_remark.as_ref() {
             match parse_keypair_string(galileo.private_key.trim()) {
@@ -67,7 +129,7 @@ pub fn load_config(path: Option<PathBuf>) -> Result<AppConfig, ConfigError> {
             }
         }
 
-        if let Some(path) = galileo_path.as_ref() {
+        if let Some(path) = galileo_path {
             info!(
                 target: "config",
                 path = %path.display(),
@@ -95,37 +157,24 @@ pub fn load_config(path: Option<PathBuf>) -> Result<AppConfig, ConfigError> {
         }
     }
 
-    let mut lander_candidates = Vec::new();
-    if let Some(dir) = galileo_dir.as_ref() {
-        lander_candidates.push(dir.join("lander.yaml"));
-    }
-    lander_candidates.extend(DEFAULT_LANDER_PATHS.iter().map(PathBuf::from));
-
-    let (lander, _) = load_first_available_yaml::<LanderConfig>(&lander_candidates)?;
-
-    let mut jupiter_candidates = Vec::new();
-    if let Some(dir) = galileo_dir.as_ref() {
-        jupiter_candidates.push(dir.join("jupiter.toml"));
-    }
-    jupiter_candidates.extend(DEFAULT_JUPITER_PATHS.iter().map(PathBuf::from));
-
-    let (jupiter, _) = load_first_available_toml::<JupiterConfig>(&jupiter_candidates)?;
+    Ok(())
+}
 
-    Ok(AppConfig {
-        galileo,
-        lander,
-        jupiter,
-    })
+fn parent_dir(path: Option<&Path>) -> Option<PathBuf> {
+    path.and_then(Path::parent).map(Path::to_path_buf)
 }
 
-fn first_existing_path(paths: &[PathBuf]) -> Option<PathBuf> {
-    paths.iter().find_map(|path| {
-        if path.exists() {
-            Some(path.clone())
-        } else {
-            None
-        }
-    })
+fn companion_candidates(
+    galileo_dir: Option<&Path>,
+    filename: &str,
+    defaults: &[&str],
+) -> Vec<PathBuf> {
+    let mut candidates = Vec::new();
+    if let Some(dir) = galileo_dir {
+        candidates.push(dir.join(filename));
+    }
+    candidates.extend(defaults.iter().map(PathBuf::from));
+    candidates
 }
 
 fn load_first_available_yaml<T>(paths: &[PathBuf]) -> Result<(T, Option<PathBuf>), ConfigError>
@@ -134,8 +183,7 @@ where
 {
     for candidate in paths {
         if let Some(config) = try_load_file_yaml::<T>(candidate)? {
-            let parent = candidate.parent().map(|p| p.to_path_buf());
-            return Ok((config, parent));
+            return Ok((config, Some(candidate.clone())));
         }
     }
 
@@ -155,10 +203,7 @@ where
         source,
     })?;
 
-    let config: T = serde_yaml::from_str(&contents).map_err(|err| ConfigError::Parse {
-        path: path.to_path_buf(),
-        message: err.to_string(),
-    })?;
+    let config: T = deserialize_yaml(&contents, path)?;
 
     Ok(Some(config))
 }
@@ -169,8 +214,7 @@ where
 {
     for candidate in paths {
         if let Some(config) = try_load_file_toml::<T>(candidate)? {
-            let parent = candidate.parent().map(|p| p.to_path_buf());
-            return Ok((config, parent));
+            return Ok((config, Some(candidate.clone())));
         }
     }
 
@@ -190,26 +234,7 @@ where
         source,
     })?;
 
-    let raw_value: toml::Value = toml::from_str(&contents).map_err(|err| ConfigError::Parse {
-        path: path.to_path_buf(),
-        message: err.to_string(),
-    })?;
-
-    let config: T = if let Some(subtable) = raw_value.get("jupiter") {
-        let nested = toml::to_string(subtable).map_err(|err| ConfigError::Parse {
-            path: path.to_path_buf(),
-            message: err.to_string(),
-        })?;
-        toml::from_str(&nested).map_err(|err| ConfigError::Parse {
-            path: path.to_path_buf(),
-            message: err.to_string(),
-        })?
-    } else {
-        toml::from_str(&contents).map_err(|err| ConfigError::Parse {
-            path: path.to_path_buf(),
-            message: err.to_string(),
-        })?
-    };
+    let config: T = deserialize_toml(&contents, path)?;
 
     Ok(Some(config))
 }
@@ -6,8 +6,11 @@ use crate::engine::DispatchStrategy;
 use serde::Deserialize;
 use serde::de::Deserializer;
 
+pub mod check;
+pub mod interpolate;
 pub mod launch;
 pub mod loader;
+pub mod reload;
 pub mod strategy_loader;
 pub mod types;
 pub mod wallet;
@@ -60,6 +63,22 @@ pub(crate) fn default_auto_unwrap_min_balance_lamports() -> u64 {
     1_000_000_000
 }
 
+pub(crate) fn default_auto_unwrap_check_interval_secs() -> u64 {
+    10
+}
+
+pub(crate) fn default_auto_unwrap_halt_balance_lamports() -> u64 {
+    10_000_000
+}
+
+pub(crate) fn default_treasury_interval_secs() -> u64 {
+    3_600
+}
+
+pub(crate) fn default_treasury_ledger_path() -> String {
+    crate::wallet::treasury::DEFAULT_TREASURY_LEDGER_PATH.to_string()
+}
+
 pub(crate) fn default_repo_owner() -> String {
     "jup-ag".to_string()
 }
@@ -104,6 +123,14 @@ pub(crate) fn default_prometheus_listen() -> String {
     "0.0.0.0:9898".to_string()
 }
 
+pub(crate) fn default_control_api_listen() -> String {
+    "127.0.0.1:9899".to_string()
+}
+
+pub(crate) fn default_hot_reload_watch_interval_ms() -> u64 {
+    2_000
+}
+
 pub(crate) fn default_total_thread_count() -> u16 {
     64
 }
@@ -124,6 +151,91 @@ pub(crate) fn default_flashloan_compute_unit_overhead() -> u32 {
     110_000
 }
 
+pub(crate) fn default_flashloan_liquidity_refresh_secs() -> u64 {
+    10
+}
+
+pub(crate) fn default_kamino_lending_market() -> String {
+    "7u3HeHxYDLhnCoErrtycNokbQYbWGzLs6JSDqGAv5PfF".to_string()
+}
+
+pub(crate) fn default_kamino_reserves() -> Vec<String> {
+    vec![
+        // SOL
+        "d4A2prbA2whesmvHaL88BH6Ewn5N4bTSU2Ze8P6Bc4Q".to_string(),
+        // USDC
+        "D6q6wuQSrifJKZYpR1M8R4YawnLDtDsMmWM1NbBmgJ59".to_string(),
+    ]
+}
+
+pub(crate) fn default_kamino_compute_unit_overhead() -> u32 {
+    80_000
+}
+
+pub(crate) fn default_cu_tuning_window() -> usize {
+    64
+}
+
+pub(crate) fn default_cu_tuning_percentile() -> f64 {
+    95.0
+}
+
+pub(crate) fn default_cu_tuning_headroom_ratio() -> f64 {
+    0.1
+}
+
+pub(crate) fn default_cu_tuning_min_samples() -> usize {
+    5
+}
+
+pub(crate) fn default_cu_tuning_min_limit() -> u32 {
+    60_000
+}
+
+pub(crate) fn default_cu_tuning_max_limit() -> u32 {
+    1_400_000
+}
+
+pub(crate) fn default_cu_tuning_max_routes() -> usize {
+    4_096
+}
+
+pub(crate) fn default_cu_tuning_landed_probe_delay_ms() -> u64 {
+    3_000
+}
+
+pub(crate) fn default_alt_max_tables() -> usize {
+    4
+}
+
+pub(crate) fn default_alt_refresh_interval_secs() -> u64 {
+    60
+}
+
+pub(crate) fn default_alt_min_hits() -> u32 {
+    3
+}
+
+pub(crate) fn default_alt_max_accounts_per_sync() -> usize {
+    64
+}
+
+pub(crate) fn default_nonce_pool_size() -> usize {
+    4
+}
+
+pub(crate) fn default_nonce_seed_prefix() -> String {
+    "galileo-nonce".to_string()
+}
+
+pub(crate) fn default_nonce_refresh_delay_ms() -> u64 {
+    1_500
+}
+
+pub(crate) fn default_trade_ledger_path() -> String {
+    crate::monitoring::ledger::DEFAULT_LEDGER_PATH.to_string()
+}
+
 pub(crate) fn default_graceful_shutdown_timeout_ms() -> u64 {
     5_000
 }
@@ -202,7 +314,9 @@ impl Default for cfg::GalileoConfig {
             intermedium: cfg::IntermediumConfig::default(),
             wallet_keys: Vec::new(),
             auto_unwrap: cfg::AutoUnwrapConfig::default(),
+            treasury: cfg::TreasuryConfig::default(),
             private_key: String::new(),
+            pool_private_keys: Vec::new(),
             bot: cfg::BotConfig::default(),
             flashloan: cfg::FlashloanConfig::default(),
             blind_strategy: cfg::BlindStrategyConfig::default(),
@@ -320,6 +434,22 @@ impl Default for cfg::AutoUnwrapConfig {
             unwrap_amount_lamports: default_auto_unwrap_amount_lamports(),
             min_sol_balance_lamports: default_auto_unwrap_min_balance_lamports(),
             compute_unit_price_micro_lamports: 0,
+            check_interval_secs: default_auto_unwrap_check_interval_secs(),
+            halt_balance_lamports: default_auto_unwrap_halt_balance_lamports(),
+        }
+    }
+}
+
+impl Default for cfg::TreasuryConfig {
+    fn default() -> Self {
+        Self {
+            enable: false,
+            cold_wallet: String::new(),
+            dry_run: false,
+            interval_secs: default_treasury_interval_secs(),
+            compute_unit_price_micro_lamports: 0,
+            ledger_path: default_treasury_ledger_path(),
+            mints: Vec::new(),
         }
     }
 }
@@ -349,10 +479,13 @@ impl Default for cfg::IntermediumConfig {
     fn default() -> Self {
         Self {
             load_mints_from_files: Vec::new(),
-            load_mints_from_url: String::new(),
+            load_mints_from_url: Vec::new(),
             max_tokens_limit: default_max_tokens_limit(),
             mints: Vec::new(),
             disable_mints: Vec::new(),
+            refresh_interval_secs: 0,
+            configured_mints: Vec::new(),
+            base_dir: None,
         }
     }
 }
@@ -372,6 +505,13 @@ impl Default for cfg::BotConfig {
             engines: cfg::EngineToggleSet::default(),
             flashloan: cfg::BotFlashloanToggle::default(),
             light_house: cfg::LightHouseBotConfig::default(),
+            compute_unit_tuning: cfg::ComputeUnitTuningConfig::default(),
+            alt_manager: cfg::AltManagerConfig::default(),
+            durable_nonce: cfg::DurableNonceConfig::default(),
+            ledger: cfg::TradeLedgerConfig::default(),
+            control_api: cfg::ControlApiConfig::default(),
+            hot_reload: cfg::HotReloadConfig::default(),
+            wallet_pool: cfg::WalletPoolConfig::default(),
         }
     }
 }
@@ -380,6 +520,8 @@ impl Default for cfg::FlashloanConfig {
     fn default() -> Self {
         Self {
             marginfi: cfg::FlashloanMarginfiConfig::default(),
+            kamino: cfg::FlashloanKaminoConfig::default(),
+            liquidity_refresh_secs: default_flashloan_liquidity_refresh_secs(),
         }
     }
 }
@@ -418,6 +560,35 @@ impl Default for cfg::PrometheusConfig {
     }
 }
 
+impl Default for cfg::ControlApiConfig {
+    fn default() -> Self {
+        Self {
+            enable: false,
+            listen: default_control_api_listen(),
+            token: None,
+        }
+    }
+}
+
+impl Default for cfg::HotReloadConfig {
+    fn default() -> Self {
+        Self {
+            enable: false,
+            watch_interval_ms: default_hot_reload_watch_interval_ms(),
+        }
+    }
+}
+
+impl Default for cfg::WalletPoolConfig {
+    fn default() -> Self {
+        Self {
+            enable: false,
+            wallets: Vec::new(),
+            selection: cfg::WalletSelection::default(),
+        }
+    }
+}
+
 impl Default for cfg::LanderConfig {
     fn default() -> Self {
         Self {
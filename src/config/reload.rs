@@ -0,0 +1,415 @@
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+use once_cell::sync::Lazy;
+use serde_yaml::{Mapping, Value};
+use tokio::sync::watch;
+use tracing::{debug, info, warn};
+
+use super::check::{read_jupiter_toml, read_yaml};
+use super::loader::{ConfigError, ConfigSources, load_config_sources};
+use super::{AppConfig, StrategyToggle};
+
+/// 可在运行中直接替换的配置路径（前缀匹配，`*` 匹配任意一段，`[*]` 匹配任意下标）。
+const LIVE_PATHS: &[&str] = &[
+    "lander",
+    "blind_strategy.enable_landers",
+    "blind_strategy.auto_scale_to_ip",
+    "blind_strategy.base_mints[*].lanes",
+    "blind_strategy.base_mints[*].min_quote_profit",
+    "pure_blind_strategy.enable_landers",
+    "pure_blind_strategy.cu_multiplier",
+    "pure_blind_strategy.overrides",
+    "pure_blind_strategy.price_guards",
+    "pure_blind_strategy.assets.base_mints[*].lanes",
+    "pure_blind_strategy.assets.base_mints[*].min_profit",
+    "engine.*.quote_config.cadence",
+    "engine.*.*.quote_config.cadence",
+    "engine.multi_leg.quote_cadence",
+];
+
+/// 虽然位于可热更新的段内，但仍只在启动时构建的路径。
+const RESTART_PATHS: &[(&str, &str)] = &[(
+    "lander.jito.multi_ips_setting",
+    "Jito 多 IP 小费钱包池仅在启动时构建",
+)];
+
+static RELOADS: Lazy<watch::Sender<Option<Arc<AppConfig>>>> = Lazy::new(|| watch::channel(None).0);
+
+/// 订阅热重载后的完整配置；只有全部变更都可在线生效时才会推送。
+pub fn subscribe_reloads() -> watch::Receiver<Option<Arc<AppConfig>>> {
+    RELOADS.subscribe()
+}
+
+/// 按 `bot.hot_reload` 启动配置监听：收到 SIGHUP 或检测到文件修改时重新加载并比对。
+pub fn spawn_config_reloader(
+    path: Option<PathBuf>,
+    running: &AppConfig,
+) -> Result<(), ConfigError> {
+    let settings = &running.galileo.bot.hot_reload;
+    if !settings.enable {
+        return Ok(());
+    }
+    let poll_interval = Duration::from_millis(settings.watch_interval_ms);
+    let mut reloader = ConfigReloader::new(path, running.clone())?;
+    info!(
+        target: "config::reload",
+        watch_interval_ms = settings.watch_interval_ms,
+        files = reloader.watched.len(),
+        "配置热重载已启用，发送 SIGHUP 或修改配置文件即可触发"
+    );
+    tokio::spawn(async move {
+        reloader.run(poll_interval).await;
+    });
+    Ok(())
+}
+
+struct ConfigReloader {
+    path: Option<PathBuf>,
+    config: AppConfig,
+    document: Value,
+    watched: Vec<PathBuf>,
+    mtimes: Vec<Option<SystemTime>>,
+}
+
+impl ConfigReloader {
+    fn new(path: Option<PathBuf>, config: AppConfig) -> Result<Self, ConfigError> {
+        let (_, sources) = load_config_sources(path.clone())?;
+        let document = build_document(&sources)?;
+        let watched = watched_paths(&sources);
+        let mtimes = snapshot_mtimes(&watched);
+        Ok(Self {
+            path,
+            config,
+            document,
+            watched,
+            mtimes,
+        })
+    }
+
+    async fn run(&mut self, poll_interval: Duration) {
+        let poll_enabled = !poll_interval.is_zero();
+        let mut hangup = HangupSignal::new();
+        loop {
+            tokio::select! {
+                _ = hangup.recv() => self.reload("sighup"),
+                _ = tokio::time::sleep(poll_interval), if poll_enabled => {
+                    if snapshot_mtimes(&self.watched) != self.mtimes {
+                        self.reload("file_watch");
+                    }
+                }
+            }
+        }
+    }
+
+    fn reload(&mut self, trigger: &'static str) {
+        let loaded = load_config_sources(self.path.clone()).and_then(|(config, sources)| {
+            let document = build_document(&sources)?;
+            Ok((config, sources, document))
+        });
+        let (mut config, sources, document) = match loaded {
+            Ok(loaded) => loaded,
+            Err(err) => {
+                self.mtimes = snapshot_mtimes(&self.watched);
+                warn!(
+                    target: "config::reload",
+                    trigger,
+                    error = %err,
+                    "重新加载配置失败，继续使用当前配置"
+                );
+                return;
+            }
+        };
+        self.watched = watched_paths(&sources);
+        self.mtimes = snapshot_mtimes(&self.watched);
+
+        let mut changes = Vec::new();
+        diff_values(&self.document, &document, "", &mut changes);
+        let intermedium = &self.config.galileo.intermedium;
+        // URL 源与定时刷新由 intermedium 刷新任务负责，此处只比较文件源合并后的结果。
+        let refreshed_elsewhere =
+            !intermedium.load_mints_from_url.is_empty() || intermedium.refresh_interval_secs > 0;
+        if !refreshed_elsewhere
+            && config.galileo.intermedium.mints != intermedium.mints
+            && !changes.iter().any(|path| path.starts_with("intermedium"))
+        {
+            // load_mints_from_files 引入的变化不体现在主配置文档中。
+            changes.push("intermedium.mints".to_string());
+        }
+        if changes.is_empty() {
+            debug!(target: "config::reload", trigger, "配置内容无变化");
+            return;
+        }
+
+        let copy_running = self
+            .config
+            .galileo
+            .bot
+            .strategy_enabled(StrategyToggle::CopyStrategy);
+        let plan = classify_changes(&changes, copy_running);
+        if !plan.restart.is_empty() {
+            for (path, reason) in &plan.restart {
+                warn!(
+                    target: "config::reload",
+                    path = %path,
+                    reason = %reason,
+                    "配置项需要重启才能生效"
+                );
+            }
+            warn!(
+                target: "config::reload",
+                trigger,
+                restart = plan.restart.len(),
+                live = plan.live.len(),
+                "本次变更包含需要重启的配置项，已整体拒绝，运行中的配置保持不变"
+            );
+            return;
+        }
+
+        config.galileo.private_key = self.config.galileo.private_key.clone();
+        self.config = config;
+        self.document = document;
+        info!(
+            target: "config::reload",
+            trigger,
+            changes = ?plan.live,
+            "配置热重载已生效"
+        );
+        RELOADS.send_replace(Some(Arc::new(self.config.clone())));
+    }
+}
+
+#[cfg(unix)]
+struct HangupSignal(Option<tokio::signal::unix::Signal>);
+
+#[cfg(unix)]
+impl HangupSignal {
+    fn new() -> Self {
+        use tokio::signal::unix::{SignalKind, signal};
+        match signal(SignalKind::hangup()) {
+            Ok(signal) => Self(Some(signal)),
+            Err(err) => {
+                warn!(
+                    target: "config::reload",
+                    error = %err,
+                    "注册 SIGHUP 失败，仅依赖文件轮询触发热重载"
+                );
+                Self(None)
+            }
+        }
+    }
+
+    async fn recv(&mut self) {
+        if let Some(signal) = self.0.as_mut() {
+            if signal.recv().await.is_some() {
+                return;
+            }
+            self.0 = None;
+        }
+        std::future::pending::<()>().await
+    }
+}
+
+#[cfg(not(unix))]
+struct HangupSignal;
+
+#[cfg(not(unix))]
+impl HangupSignal {
+    fn new() -> Self {
+        Self
+    }
+
+    async fn recv(&mut self) {
+        std::future::pending::<()>().await
+    }
+}
+
+/// 合并后的原始配置文档：主配置 + 外部策略文件 + `lander` + `jupiter`，用于逐字段比对。
+fn build_document(sources: &ConfigSources) -> Result<Value, ConfigError> {
+    let mut document = match sources.galileo.as_deref() {
+        Some(path) => match read_yaml(path)? {
+            Value::Mapping(map) => map,
+            _ => Mapping::new(),
+        },
+        None => Mapping::new(),
+    };
+    for (section, path) in &sources.strategies {
+        document.insert(Value::from(*section), read_yaml(path)?);
+    }
+    if let Some(path) = sources.lander.as_deref() {
+        let raw = read_yaml(path)?;
+        let lander = raw.get("lander").cloned().unwrap_or(Value::Null);
+        document.insert(Value::from("lander"), lander);
+    }
+    if let Some(path) = sources.jupiter.as_deref() {
+        document.insert(Value::from("jupiter"), read_jupiter_toml(path)?);
+    }
+    Ok(Value::Mapping(document))
+}
+
+fn watched_paths(sources: &ConfigSources) -> Vec<PathBuf> {
+    let mut paths: Vec<PathBuf> = sources.galileo.iter().cloned().collect();
+    paths.extend(sources.strategies.iter().map(|(_, path)| path.clone()));
+    paths.extend(sources.lander.iter().cloned());
+    paths
+}
+
+fn snapshot_mtimes(paths: &[PathBuf]) -> Vec<Option<SystemTime>> {
+    paths.iter().map(|path| modified_at(path)).collect()
+}
+
+fn modified_at(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path)
+        .and_then(|meta| meta.modified())
+        .ok()
+}
+
+/// 记录两份文档间发生变化的叶子路径；等长序列逐项比对，其余差异记在当前层级。
+fn diff_values(old: &Value, new: &Value, path: &str, out: &mut Vec<String>) {
+    if old == new {
+        return;
+    }
+    match (old, new) {
+        (Value::Mapping(old_map), Value::Mapping(new_map)) => {
+            let added = new_map.keys().filter(|key| !old_map.contains_key(*key));
+            for key in old_map.keys().chain(added) {
+                let child = match key.as_str() {
+                    Some(name) if path.is_empty() => name.to_string(),
+                    Some(name) => format!("{path}.{name}"),
+                    None => format!("{path}.{key:?}"),
+                };
+                diff_values(
+                    old_map.get(key).unwrap_or(&Value::Null),
+                    new_map.get(key).unwrap_or(&Value::Null),
+                    &child,
+                    out,
+                );
+            }
+        }
+        (Value::Sequence(old_seq), Value::Sequence(new_seq)) if old_seq.len() == new_seq.len() => {
+            for (idx, (old_item, new_item)) in old_seq.iter().zip(new_seq).enumerate() {
+                diff_values(old_item, new_item, &format!("{path}[{idx}]"), out);
+            }
+        }
+        _ => out.push(path.to_string()),
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+struct ReloadPlan {
+    live: Vec<String>,
+    restart: Vec<(String, &'static str)>,
+}
+
+fn classify_changes(changes: &[String], copy_running: bool) -> ReloadPlan {
+    let mut plan = ReloadPlan::default();
+    for path in changes {
+        let normalized = normalize_indices(path);
+        let restricted = RESTART_PATHS
+            .iter()
+            .find(|(pattern, _)| matches_pattern(&normalized, pattern))
+            .map(|(_, reason)| *reason);
+        let reason = if let Some(reason) = restricted {
+            Some(reason)
+        } else if normalized.starts_with("copy_strategy") {
+            Some("copy 策略不支持热重载")
+        } else if copy_running && matches_pattern(&normalized, "lander") {
+            Some("copy 策略运行中，其落地器仅在启动时构建")
+        } else if LIVE_PATHS
+            .iter()
+            .any(|pattern| matches_pattern(&normalized, pattern))
+        {
+            None
+        } else {
+            Some("该配置项仅在启动时生效")
+        };
+        match reason {
+            Some(reason) => plan.restart.push((path.clone(), reason)),
+            None => plan.live.push(path.clone()),
+        }
+    }
+    plan
+}
+
+/// `a.b[3].c` → `a.b[*].c`
+fn normalize_indices(path: &str) -> String {
+    let mut normalized = String::with_capacity(path.len());
+    let mut in_index = false;
+    for ch in path.chars() {
+        match ch {
+            '[' => {
+                in_index = true;
+                normalized.push_str("[*]");
+            }
+            ']' => in_index = false,
+            _ if in_index => {}
+            _ => normalized.push(ch),
+        }
+    }
+    normalized
+}
+
+fn matches_pattern(path: &str, pattern: &str) -> bool {
+    let mut segments = path.split('.');
+    pattern.split('.').all(|expected| match segments.next() {
+        Some(actual) => expected == "*" || expected == actual,
+        None => false,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn yaml(text: &str) -> Value {
+        serde_yaml::from_str(text).expect("yaml")
+    }
+
+    #[test]
+    fn diff_reports_leaf_paths() {
+        let old = yaml(
+            "blind_strategy:\n  base_mints:\n    - mint: A\n      lanes: [{min: 1, max: 2, count: 1}]\nlander:\n  jito:\n    fixed_tip: 1000\n",
+        );
+        let new = yaml(
+            "blind_strategy:\n  base_mints:\n    - mint: A\n      lanes: [{min: 1, max: 5, count: 1}]\nlander:\n  jito:\n    fixed_tip: 2000\n  staked: {endpoints: [x]}\n",
+        );
+        let mut changes = Vec::new();
+        diff_values(&old, &new, "", &mut changes);
+        assert_eq!(
+            changes,
+            vec![
+                "blind_strategy.base_mints[0].lanes[0].max".to_string(),
+                "lander.jito.fixed_tip".to_string(),
+                "lander.staked".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn classify_separates_live_and_restart_changes() {
+        let changes = vec![
+            "lander.jito.fixed_tip".to_string(),
+            "engine.dflow.quote_config.cadence.default.cycle_cooldown_ms".to_string(),
+            "pure_blind_strategy.assets.base_mints[2].min_profit".to_string(),
+            "blind_strategy.base_mints[0].mint".to_string(),
+            "lander.jito.multi_ips_setting.tips_wallet".to_string(),
+        ];
+        let plan = classify_changes(&changes, false);
+        assert_eq!(plan.live, changes[..3].to_vec());
+        assert_eq!(
+            plan.restart
+                .iter()
+                .map(|(path, _)| path.as_str())
+                .collect::<Vec<_>>(),
+            vec![
+                "blind_strategy.base_mints[0].mint",
+                "lander.jito.multi_ips_setting.tips_wallet"
+            ]
+        );
+
+        let plan = classify_changes(&changes[..1], true);
+        assert!(plan.live.is_empty());
+        assert_eq!(plan.restart.len(), 1);
+    }
+}
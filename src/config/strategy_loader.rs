@@ -4,6 +4,7 @@ use std::path::{Path, PathBuf};
 use serde::de::DeserializeOwned;
 use tracing::{debug, info, warn};
 
+use super::interpolate::deserialize_yaml;
 use super::loader::ConfigError;
 use super::types::{
     BackRunStrategyConfig, BlindStrategyConfig, CopyStrategyConfig, GalileoConfig,
@@ -16,10 +17,12 @@ use super::types::{
 /// 1. 如果主配置文件中已经配置了策略（非默认值），则使用主配置
 /// 2. 否则尝试从外部文件加载
 /// 3. 如果外部文件也不存在，使用默认配置
+///
+/// 返回实际从外部文件加载的策略段及其路径。
 pub fn load_strategy_configs(
     config: &mut GalileoConfig,
     config_dir: Option<&Path>,
-) -> Result<(), ConfigError> {
+) -> Result<Vec<(&'static str, PathBuf)>, ConfigError> {
     let strategy_dir = resolve_strategy_dir(&config.bot.strategies.config_dir, config_dir);
 
     info!(
@@ -30,6 +33,7 @@ pub fn load_strategy_configs(
         "开始加载策略配置"
     );
 
+    let mut loaded = Vec::new();
     for strategy in &config.bot.strategies.enabled {
         let profile_override = config
             .bot
@@ -38,45 +42,49 @@ pub fn load_strategy_configs(
             .map(|s| s.to_string());
         match strategy {
             StrategyToggle::BlindStrategy => {
-                load_if_needed(
+                let path = load_if_needed(
                     &mut config.blind_strategy,
                     &strategy_dir,
                     "blind_strategy.yaml",
                     "blind_strategy",
                     profile_override.as_deref(),
                 )?;
+                loaded.extend(path.map(|path| ("blind_strategy", path)));
             }
             StrategyToggle::PureBlindStrategy => {
-                load_if_needed(
+                let path = load_if_needed(
                     &mut config.pure_blind_strategy,
                     &strategy_dir,
                     "pure_blind_strategy.yaml",
                     "pure_blind_strategy",
                     profile_override.as_deref(),
                 )?;
+                loaded.extend(path.map(|path| ("pure_blind_strategy", path)));
             }
             StrategyToggle::CopyStrategy => {
-                load_if_needed(
+                let path = load_if_needed(
                     &mut config.copy_strategy,
                     &strategy_dir,
                     "copy_strategy.yaml",
                     "copy_strategy",
                     profile_override.as_deref(),
                 )?;
+                loaded.extend(path.map(|path| ("copy_strategy", path)));
             }
             StrategyToggle::BackRunStrategy => {
-                load_if_needed(
+                let path = load_if_needed(
                     &mut config.back_run_strategy,
                     &strategy_dir,
                     "back_run_strategy.yaml",
                     "back_run_strategy",
                     profile_override.as_deref(),
                 )?;
+                loaded.extend(path.map(|path| ("back_run_strategy", path)));
             }
         }
     }
 
-    Ok(())
+    Ok(loaded)
 }
 
 /// 解析策略配置目录路径
@@ -96,14 +104,14 @@ fn resolve_strategy_dir(config_dir: &str, base_dir: Option<&Path>) -> PathBuf {
     }
 }
 
-/// 如果策略配置是默认值，则尝试从外部文件加载
+/// 如果策略配置是默认值，则尝试从外部文件加载，返回加载成功的文件路径
 fn load_if_needed<T>(
     current: &mut T,
     strategy_dir: &Path,
     filename: &str,
     strategy_name: &str,
     override_profile: Option<&str>,
-) -> Result<(), ConfigError>
+) -> Result<Option<PathBuf>, ConfigError>
 where
     T: DeserializeOwned + Default + IsDefault,
 {
@@ -114,7 +122,7 @@ where
             strategy = strategy_name,
             "使用主配置文件中的策略配置"
         );
-        return Ok(());
+        return Ok(None);
     }
 
     // 尝试从外部文件加载
@@ -137,7 +145,7 @@ where
             path = %strategy_path.display(),
             "策略配置文件不存在，使用默认配置"
         );
-        return Ok(());
+        return Ok(None);
     }
 
     match load_strategy_file::<T>(&strategy_path) {
@@ -150,7 +158,7 @@ where
                 path = %strategy_path.display(),
                 "已从外部文件加载策略配置"
             );
-            Ok(())
+            Ok(Some(strategy_path))
         }
         Err(err) => {
             if custom_profile {
@@ -164,7 +172,7 @@ where
                 "加载策略配置文件失败，使用默认配置"
             );
             // 加载失败不中断程序，继续使用默认配置
-            Ok(())
+            Ok(None)
         }
     }
 }
@@ -188,12 +196,7 @@ where
         source,
     })?;
 
-    let config: T = serde_yaml::from_str(&contents).map_err(|err| ConfigError::Parse {
-        path: path.to_path_buf(),
-        message: err.to_string(),
-    })?;
-
-    Ok(config)
+    deserialize_yaml(&contents, path)
 }
 
 /// Trait 用于判断配置是否为默认值
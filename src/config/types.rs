@@ -22,8 +22,9 @@ pub struct AppConfig {
     pub jupiter: JupiterConfig,
 }
 
+/// `Debug` 在 `interpolate.rs` 中手动实现，输出时隐藏私钥与插值解析出的敏感值。
 #[serde_as]
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Clone, Deserialize)]
 pub struct GalileoConfig {
     #[serde(default)]
     pub global: GlobalConfig,
@@ -35,9 +36,14 @@ pub struct GalileoConfig {
     pub wallet_keys: Vec<WalletKeyEntry>,
     #[serde(default)]
     pub auto_unwrap: AutoUnwrapConfig,
+    #[serde(default)]
+    pub treasury: TreasuryConfig,
     /// 解密后的私钥字符串（运行时填充，配置文件中不需要）
     #[serde(skip)]
     pub private_key: String,
+    /// `bot.wallet_pool` 启用时额外解密的私钥（不含 private_key），运行时填充
+    #[serde(skip)]
+    pub pool_private_keys: Vec<String>,
     #[serde(default)]
     pub bot: BotConfig,
     #[serde(default)]
@@ -267,6 +273,41 @@ mod tests {
         );
     }
 
+    #[test]
+    fn wallet_entries_round_trip_with_and_without_pubkey() {
+        #[derive(Deserialize)]
+        struct Wallets {
+            #[serde(deserialize_with = "deserialize_wallet_entries")]
+            wallet_keys: Vec<WalletKeyEntry>,
+        }
+
+        let entries = vec![
+            WalletKeyEntry {
+                remark: "legacy".to_string(),
+                encrypted: "AAAA".to_string(),
+                pubkey: None,
+            },
+            WalletKeyEntry {
+                remark: "main".to_string(),
+                encrypted: "BBBB".to_string(),
+                pubkey: Some("11111111111111111111111111111111".to_string()),
+            },
+        ];
+        let yaml = format!(
+            "wallet_keys:\n{}",
+            serde_yaml::to_string(&entries).expect("serialize")
+        );
+        let parsed: Wallets = serde_yaml::from_str(&yaml).expect("parse yaml");
+        assert_eq!(parsed.wallet_keys.len(), 2);
+        assert_eq!(parsed.wallet_keys[0].remark, "legacy");
+        assert_eq!(parsed.wallet_keys[0].pubkey, None);
+        assert_eq!(parsed.wallet_keys[1].encrypted, "BBBB");
+        assert_eq!(
+            parsed.wallet_keys[1].pubkey.as_deref(),
+            Some("11111111111111111111111111111111")
+        );
+    }
+
     #[test]
     fn deserialize_multiple_rpc_urls_dedup() {
         let yaml =
@@ -292,12 +333,59 @@ pub struct AutoUnwrapConfig {
     pub min_sol_balance_lamports: u64,
     #[serde(default)]
     pub compute_unit_price_micro_lamports: u64,
+    /// 余额巡检间隔（秒）。
+    #[serde(default = "super::default_auto_unwrap_check_interval_secs")]
+    pub check_interval_secs: u64,
+    /// SOL 与 WSOL 均低于该值时视为资金耗尽，暂停全部策略直至余额恢复。
+    #[serde(default = "super::default_auto_unwrap_halt_balance_lamports")]
+    pub halt_balance_lamports: u64,
+}
+
+/// 利润归集：定时把主钱包中超出工作余额的部分转入冷钱包。
+#[derive(Debug, Clone, Deserialize)]
+pub struct TreasuryConfig {
+    #[serde(default)]
+    pub enable: bool,
+    /// 冷钱包地址；SPL 代币转入其关联账户，不存在时自动创建。
+    #[serde(default)]
+    pub cold_wallet: String,
+    /// 只计算并记账，不提交转账。
+    #[serde(default)]
+    pub dry_run: bool,
+    /// 归集检查间隔（秒）。
+    #[serde(default = "super::default_treasury_interval_secs")]
+    pub interval_secs: u64,
+    #[serde(default)]
+    pub compute_unit_price_micro_lamports: u64,
+    /// 归集账本路径（JSON Lines），每次归集追加一行。
+    #[serde(default = "super::default_treasury_ledger_path")]
+    pub ledger_path: String,
+    #[serde(default)]
+    pub mints: Vec<TreasuryMintConfig>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct TreasuryMintConfig {
+    /// mint 地址；`SOL` 表示原生 SOL，WSOL 需填写 WSOL mint。
+    pub mint: String,
+    /// 保留在热钱包中的工作余额（最小单位）。
+    #[serde(default)]
+    pub float: u64,
+    /// 超出部分低于该值时不转账，避免为零头支付手续费。
+    #[serde(default)]
+    pub min_sweep: u64,
+    /// 每个 UTC 自然日累计归集上限，0 表示不限。
+    #[serde(default)]
+    pub max_per_day: u64,
 }
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct WalletKeyEntry {
     pub remark: String,
     pub encrypted: String,
+    /// 加密时记录的公钥，`galileo wallet verify` 据此核对解密结果
+    #[serde(default)]
+    pub pubkey: Option<String>,
 }
 
 impl Serialize for WalletKeyEntry {
@@ -305,8 +393,16 @@ impl Serialize for WalletKeyEntry {
     where
         S: Serializer,
     {
-        let mut map = serializer.serialize_map(Some(1))?;
-        map.serialize_entry(&self.remark, &self.encrypted)?;
+        // 未记录公钥的条目保持 `remark: encrypted` 的旧格式
+        let Some(pubkey) = &self.pubkey else {
+            let mut map = serializer.serialize_map(Some(1))?;
+            map.serialize_entry(&self.remark, &self.encrypted)?;
+            return map.end();
+        };
+        let mut map = serializer.serialize_map(Some(3))?;
+        map.serialize_entry("remark", &self.remark)?;
+        map.serialize_entry("encrypted", &self.encrypted)?;
+        map.serialize_entry("pubkey", pubkey)?;
         map.end()
     }
 }
@@ -344,7 +440,7 @@ pub struct EngineConfig {
 }
 
 #[serde_as]
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Clone, Deserialize, Default)]
 pub struct JupiterConfig {
     /// `[jupiter.binary]`：GitHub Release 下载来源及安装目录。
     #[serde(default)]
@@ -699,7 +795,11 @@ where
                 RawEntry::Structured(entry) => entries.push(entry),
                 RawEntry::LegacyMap(map) => {
                     for (remark, encrypted) in map {
-                        entries.push(WalletKeyEntry { remark, encrypted });
+                        entries.push(WalletKeyEntry {
+                            remark,
+                            encrypted,
+                            pubkey: None,
+                        });
                     }
                 }
             }
@@ -1258,14 +1358,72 @@ pub struct LoggingConfig {
 pub struct IntermediumConfig {
     #[serde(default)]
     pub load_mints_from_files: Vec<MintFileSource>,
-    #[serde(default)]
-    pub load_mints_from_url: String,
+    /// 远程 mint 源：单个 URL 字符串或结构化列表（HTTP JSON，支持 selector）
+    #[serde(default, deserialize_with = "deserialize_mint_url_sources")]
+    pub load_mints_from_url: Vec<MintUrlSource>,
     #[serde(default = "super::default_max_tokens_limit")]
     pub max_tokens_limit: u32,
     #[serde(default)]
     pub mints: Vec<String>,
     #[serde(default)]
     pub disable_mints: Vec<String>,
+    /// 定时重新加载文件与 URL 源并更新盲发交易对的间隔（秒），0 表示只在启动时加载
+    #[serde(default)]
+    pub refresh_interval_secs: u64,
+    /// 配置文件中直接列出的 mints（运行时填充，刷新时与各来源重新合并）
+    #[serde(skip)]
+    pub configured_mints: Vec<String>,
+    /// 解析相对路径的基准目录（运行时填充）
+    #[serde(skip)]
+    pub base_dir: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct MintUrlSource {
+    pub url: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub selector: Option<JsonMintSelector>,
+}
+
+fn deserialize_mint_url_sources<'de, D>(deserializer: D) -> Result<Vec<MintUrlSource>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Entry {
+        Url(String),
+        Detailed(MintUrlSource),
+    }
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Raw {
+        One(Entry),
+        Many(Vec<Entry>),
+    }
+
+    let entries = match Option::<Raw>::deserialize(deserializer)? {
+        None => Vec::new(),
+        Some(Raw::One(entry)) => vec![entry],
+        Some(Raw::Many(entries)) => entries,
+    };
+    Ok(entries
+        .into_iter()
+        .filter_map(|entry| match entry {
+            Entry::Url(url) => {
+                let url = url.trim();
+                (!url.is_empty()).then(|| MintUrlSource {
+                    url: url.to_string(),
+                    name: None,
+                    selector: None,
+                })
+            }
+            Entry::Detailed(source) => Some(source),
+        })
+        .collect())
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -1340,6 +1498,20 @@ pub struct BotConfig {
     pub flashloan: BotFlashloanToggle,
     #[serde(default, alias = "profit_guard")]
     pub light_house: LightHouseBotConfig,
+    #[serde(default, alias = "cu_tuning")]
+    pub compute_unit_tuning: ComputeUnitTuningConfig,
+    #[serde(default, alias = "lookup_tables")]
+    pub alt_manager: AltManagerConfig,
+    #[serde(default, alias = "nonce")]
+    pub durable_nonce: DurableNonceConfig,
+    #[serde(default, alias = "trade_ledger")]
+    pub ledger: TradeLedgerConfig,
+    #[serde(default, alias = "control")]
+    pub control_api: ControlApiConfig,
+    #[serde(default, alias = "reload")]
+    pub hot_reload: HotReloadConfig,
+    #[serde(default)]
+    pub wallet_pool: WalletPoolConfig,
 }
 
 impl BotConfig {
@@ -1485,6 +1657,140 @@ pub struct LightHouseBotConfig {
     pub sol_price_feed: Option<PriceFeedConfig>,
     #[serde(default)]
     pub memory_slots: Option<u8>,
+    /// 额外断言 payer 的 SOL 余额：守护窗口内只允许支出 tip。
+    #[serde(default)]
+    pub native_sol_guard: bool,
+    /// base mint → 利润结算位置（token 账户地址或 `native`），用于起始账户与结算账户不同的路线。
+    #[serde(default)]
+    pub settle_accounts: BTreeMap<String, String>,
+}
+
+/// 按路线自动调整 compute unit limit：基于模拟与落地交易的实际消耗取滚动高分位。
+#[derive(Debug, Clone, Deserialize)]
+pub struct ComputeUnitTuningConfig {
+    #[serde(default)]
+    pub enable: bool,
+    /// 每条路线保留的最近样本数量
+    #[serde(default = "super::default_cu_tuning_window")]
+    pub window: usize,
+    /// 取样本的分位数（0-100）
+    #[serde(default = "super::default_cu_tuning_percentile")]
+    pub percentile: f64,
+    /// 在分位数基础上额外预留的比例，例如 0.1 表示 +10%
+    #[serde(default = "super::default_cu_tuning_headroom_ratio")]
+    pub headroom_ratio: f64,
+    /// 样本数达到该值前沿用静态预算
+    #[serde(default = "super::default_cu_tuning_min_samples")]
+    pub min_samples: usize,
+    #[serde(default = "super::default_cu_tuning_min_limit")]
+    pub min_limit: u32,
+    #[serde(default = "super::default_cu_tuning_max_limit")]
+    pub max_limit: u32,
+    /// 同时跟踪的路线上限，超出后新路线不再采样
+    #[serde(default = "super::default_cu_tuning_max_routes")]
+    pub max_routes: usize,
+    /// 落地后查询 computeUnitsConsumed 的延迟；为 0 时只使用模拟结果
+    #[serde(default = "super::default_cu_tuning_landed_probe_delay_ms")]
+    pub landed_probe_delay_ms: u64,
+}
+
+impl Default for ComputeUnitTuningConfig {
+    fn default() -> Self {
+        Self {
+            enable: false,
+            window: super::default_cu_tuning_window(),
+            percentile: super::default_cu_tuning_percentile(),
+            headroom_ratio: super::default_cu_tuning_headroom_ratio(),
+            min_samples: super::default_cu_tuning_min_samples(),
+            min_limit: super::default_cu_tuning_min_limit(),
+            max_limit: super::default_cu_tuning_max_limit(),
+            max_routes: super::default_cu_tuning_max_routes(),
+            landed_probe_delay_ms: super::default_cu_tuning_landed_probe_delay_ms(),
+        }
+    }
+}
+
+/// 自有地址查找表管理：把热点路线账户写入钱包持有的 ALT。
+#[derive(Debug, Clone, Deserialize)]
+pub struct AltManagerConfig {
+    #[serde(default)]
+    pub enable: bool,
+    /// 最多同时持有的激活 ALT 数量
+    #[serde(default = "super::default_alt_max_tables")]
+    pub max_tables: usize,
+    #[serde(default = "super::default_alt_refresh_interval_secs")]
+    pub refresh_interval_secs: u64,
+    /// 账户在一个同步周期内至少出现的次数
+    #[serde(default = "super::default_alt_min_hits")]
+    pub min_hits: u32,
+    /// 单次同步最多写入的账户数
+    #[serde(default = "super::default_alt_max_accounts_per_sync")]
+    pub max_accounts_per_sync: usize,
+    #[serde(default)]
+    pub compute_unit_price_micro_lamports: u64,
+}
+
+impl Default for AltManagerConfig {
+    fn default() -> Self {
+        Self {
+            enable: false,
+            max_tables: super::default_alt_max_tables(),
+            refresh_interval_secs: super::default_alt_refresh_interval_secs(),
+            min_hits: super::default_alt_min_hits(),
+            max_accounts_per_sync: super::default_alt_max_accounts_per_sync(),
+            compute_unit_price_micro_lamports: 0,
+        }
+    }
+}
+
+/// durable nonce 模式：同一机会的所有变体共享一个 nonce，最多只有一笔能落地。
+#[derive(Debug, Clone, Deserialize)]
+pub struct DurableNonceConfig {
+    #[serde(default)]
+    pub enable: bool,
+    /// nonce 账户数量，决定可同时在途的机会数
+    #[serde(default = "super::default_nonce_pool_size")]
+    pub pool_size: usize,
+    /// 账户由钱包按 `{seed_prefix}-{index}` 派生
+    #[serde(default = "super::default_nonce_seed_prefix")]
+    pub seed_prefix: String,
+    /// 启动时自动创建缺失的 nonce 账户（每个需支付租金）
+    #[serde(default)]
+    pub auto_create: bool,
+    /// 提交结束后等待多久再回查最新 nonce
+    #[serde(default = "super::default_nonce_refresh_delay_ms")]
+    pub refresh_delay_ms: u64,
+}
+
+impl Default for DurableNonceConfig {
+    fn default() -> Self {
+        Self {
+            enable: false,
+            pool_size: super::default_nonce_pool_size(),
+            seed_prefix: super::default_nonce_seed_prefix(),
+            auto_create: false,
+            refresh_delay_ms: super::default_nonce_refresh_delay_ms(),
+        }
+    }
+}
+
+/// 交易账本：每笔提交按签名追加一行 JSONL，落地后回填链上实际余额变化。
+#[derive(Debug, Clone, Deserialize)]
+pub struct TradeLedgerConfig {
+    #[serde(default)]
+    pub enable: bool,
+    /// 账本文件路径，`galileo report` 默认读取同一文件
+    #[serde(default = "super::default_trade_ledger_path")]
+    pub path: String,
+}
+
+impl Default for TradeLedgerConfig {
+    fn default() -> Self {
+        Self {
+            enable: false,
+            path: super::default_trade_ledger_path(),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Default)]
@@ -1811,12 +2117,18 @@ impl BotFlashloanToggle {
 #[serde(rename_all = "snake_case")]
 pub enum FlashloanProduct {
     Marginfi,
+    Kamino,
 }
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct FlashloanConfig {
     #[serde(default)]
     pub marginfi: FlashloanMarginfiConfig,
+    #[serde(default)]
+    pub kamino: FlashloanKaminoConfig,
+    /// 后台刷新各协议可借流动性的间隔，用于挑选有余额的闪电贷来源
+    #[serde(default = "super::default_flashloan_liquidity_refresh_secs")]
+    pub liquidity_refresh_secs: u64,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -1827,6 +2139,32 @@ pub struct FlashloanMarginfiConfig {
     pub compute_unit_overhead: u32,
 }
 
+/// Kamino Lend 闪电贷：无需开户，直接从 reserve 借出并在同一交易内归还。
+#[derive(Debug, Clone, Deserialize)]
+pub struct FlashloanKaminoConfig {
+    #[serde(default = "super::default_kamino_lending_market")]
+    pub lending_market: String,
+    /// 可借的 reserve 地址，mint、金库等账户启动时从链上解析
+    #[serde(default = "super::default_kamino_reserves")]
+    pub reserves: Vec<String>,
+    /// 闪电贷手续费（基点），需与 reserve 配置一致，计入 guard 预算
+    #[serde(default)]
+    pub fee_bps: u64,
+    #[serde(default = "super::default_kamino_compute_unit_overhead")]
+    pub compute_unit_overhead: u32,
+}
+
+impl Default for FlashloanKaminoConfig {
+    fn default() -> Self {
+        Self {
+            lending_market: super::default_kamino_lending_market(),
+            reserves: super::default_kamino_reserves(),
+            fee_bps: 0,
+            compute_unit_overhead: super::default_kamino_compute_unit_overhead(),
+        }
+    }
+}
+
 impl Default for FlashloanMarginfiConfig {
     fn default() -> Self {
         Self {
@@ -1932,6 +2270,36 @@ pub struct PureBlindStrategyConfig {
     /// 池子/路线持久化与缓存相关配置。
     #[serde(default)]
     pub cache: PureBlindCacheConfig,
+    /// swap 之前断言池子或预言机价格落在区间内，路线过期时在交易开头廉价失败。
+    #[serde(default)]
+    pub price_guards: Vec<PureBlindPriceGuardConfig>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PureBlindPriceGuardConfig {
+    /// 价格所在账户（Pyth PriceUpdateV2、Switchboard PullFeed 或池子账户）。
+    pub account: String,
+    /// `pyth`、`switchboard` 或 `raw`；`raw` 需同时配置 `offset` 与 `value_type`。
+    #[serde(default = "default_price_guard_source")]
+    pub source: String,
+    #[serde(default)]
+    pub offset: Option<u64>,
+    /// `u64`、`i64`、`u128` 或 `i128`。
+    #[serde(default)]
+    pub value_type: Option<String>,
+    /// 价格下限（账户中的原始整数，含边界）。
+    #[serde(default)]
+    pub min: Option<i128>,
+    /// 价格上限（账户中的原始整数，含边界）。
+    #[serde(default)]
+    pub max: Option<i128>,
+    /// 仅对经过这些市场的路线生效；为空时作用于全部纯盲发路线。
+    #[serde(default)]
+    pub markets: Vec<String>,
+}
+
+fn default_price_guard_source() -> String {
+    "pyth".to_string()
 }
 
 #[derive(Debug, Clone, Deserialize, Default)]
@@ -2159,6 +2527,9 @@ pub struct CopyStrategyConfig {
     pub copy_dispatch: CopyDispatchConfig,
     #[serde(default)]
     pub wallets: Vec<CopyWalletConfig>,
+    /// 复制持仓的持久化文件，启用 `exits` 的钱包共享；为空时使用默认路径。
+    #[serde(default)]
+    pub positions_file: Option<String>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -2170,6 +2541,103 @@ pub struct CopyWalletConfig {
     pub cu_limit_multiplier: f64,
     #[serde(default)]
     pub use_source_tip: bool,
+    /// 复制规模策略，默认按源交易金额复制（受余额限制）。
+    #[serde(default)]
+    pub sizing: CopySizingConfig,
+    /// 单钱包风控限制：单笔/每小时名义金额上限、每日亏损止损与 mint 黑白名单。
+    #[serde(default)]
+    pub risk: CopyRiskConfig,
+    /// 持仓跟踪与卖出跟随。
+    #[serde(default)]
+    pub exits: CopyExitConfig,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CopyExitConfig {
+    /// 记录复制买入的持仓；源钱包减仓时按相同比例卖出。
+    #[serde(default)]
+    pub enable: bool,
+    /// 持仓超过该秒数后主动卖出，0 表示不限制。
+    #[serde(default)]
+    pub max_hold_secs: u64,
+    /// 报价估值低于成本该百分比时止损卖出，0 表示关闭。
+    #[serde(default)]
+    pub stop_loss_pct: f64,
+    /// 定时/止损检查间隔（秒）。
+    #[serde(default = "default_copy_exit_check_interval_secs")]
+    pub check_interval_secs: u64,
+    /// 主动卖出使用的 Jupiter 兼容 `/quote` 地址。
+    #[serde(default)]
+    pub api_quote_base: Option<String>,
+    /// 主动卖出使用的 Jupiter 兼容 `/swap-instructions` 地址，缺省与 `api_quote_base` 相同。
+    #[serde(default)]
+    pub api_swap_base: Option<String>,
+    #[serde(default = "default_copy_exit_slippage_bps")]
+    pub slippage_bps: u16,
+}
+
+impl Default for CopyExitConfig {
+    fn default() -> Self {
+        Self {
+            enable: false,
+            max_hold_secs: 0,
+            stop_loss_pct: 0.0,
+            check_interval_secs: default_copy_exit_check_interval_secs(),
+            api_quote_base: None,
+            api_swap_base: None,
+            slippage_bps: default_copy_exit_slippage_bps(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CopySizingMode {
+    /// 与源交易相同的 in_amount。
+    Mirror,
+    /// 按 base mint 配置的固定金额。
+    Fixed,
+    /// 源交易 in_amount 的百分比。
+    SourcePercent,
+    /// 自身 base mint 余额的百分比。
+    BalancePercent,
+}
+
+impl Default for CopySizingMode {
+    fn default() -> Self {
+        Self::Mirror
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct CopySizingConfig {
+    #[serde(default)]
+    pub mode: CopySizingMode,
+    /// `source_percent` / `balance_percent` 使用的百分比（0-100]。
+    #[serde(default)]
+    pub percent: f64,
+    /// `fixed` 模式下按 base mint 配置的金额（最小单位），未配置的 mint 不复制。
+    #[serde(default)]
+    pub fixed_amounts: BTreeMap<String, u64>,
+}
+
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct CopyRiskConfig {
+    /// 单笔 in_amount 上限（按 base mint，最小单位）。
+    #[serde(default)]
+    pub max_per_trade: BTreeMap<String, u64>,
+    /// 滚动一小时内累计 in_amount 上限（按 base mint，最小单位）。
+    #[serde(default)]
+    pub max_per_hour: BTreeMap<String, u64>,
+    /// 当日（UTC）已实现亏损达到该值后停止复制该 base mint（最小单位）。
+    #[serde(default)]
+    pub daily_loss_limit: BTreeMap<String, u64>,
+    /// 非空时，源交易涉及的所有 mint 都必须在名单内（包括 base mint）。
+    #[serde(default)]
+    pub allow_mints: Vec<String>,
+    /// 源交易涉及任一名单内 mint 时跳过复制。
+    #[serde(default)]
+    pub deny_mints: Vec<String>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -2239,6 +2707,14 @@ pub struct CopyGrpcConfig {
     pub include_program_ids: Vec<String>,
     #[serde(default)]
     pub exclude_program_ids: Vec<String>,
+    /// 断线重连的初始退避（毫秒），每次失败翻倍直至 `reconnect_max_ms`。
+    #[serde(default = "default_copy_grpc_reconnect_initial_ms")]
+    pub reconnect_initial_ms: u64,
+    #[serde(default = "default_copy_grpc_reconnect_max_ms")]
+    pub reconnect_max_ms: u64,
+    /// 重连或 slot 跳变后每个钱包通过 RPC 回补的签名数量上限，0 表示不回补。
+    #[serde(default = "default_copy_grpc_backfill_limit")]
+    pub backfill_limit: usize,
 }
 
 impl Default for CopyGrpcConfig {
@@ -2248,6 +2724,9 @@ impl Default for CopyGrpcConfig {
             yellowstone_grpc_token: String::new(),
             include_program_ids: Vec::new(),
             exclude_program_ids: Vec::new(),
+            reconnect_initial_ms: default_copy_grpc_reconnect_initial_ms(),
+            reconnect_max_ms: default_copy_grpc_reconnect_max_ms(),
+            backfill_limit: default_copy_grpc_backfill_limit(),
         }
     }
 }
@@ -2330,6 +2809,26 @@ const fn default_copy_cu_limit_multiplier() -> f64 {
     1.0
 }
 
+const fn default_copy_grpc_reconnect_initial_ms() -> u64 {
+    500
+}
+
+const fn default_copy_grpc_reconnect_max_ms() -> u64 {
+    30_000
+}
+
+const fn default_copy_grpc_backfill_limit() -> usize {
+    100
+}
+
+const fn default_copy_exit_check_interval_secs() -> u64 {
+    30
+}
+
+const fn default_copy_exit_slippage_bps() -> u16 {
+    300
+}
+
 const fn default_copy_pull_interval_minutes() -> u64 {
     10
 }
@@ -2345,6 +2844,54 @@ pub struct PrometheusConfig {
     pub listen: String,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+pub struct ControlApiConfig {
+    #[serde(default)]
+    pub enable: bool,
+    #[serde(default = "super::default_control_api_listen")]
+    pub listen: String,
+    /// 非空时所有请求需携带 `Authorization: Bearer <token>`
+    #[serde(default)]
+    pub token: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct HotReloadConfig {
+    /// 启用后收到 SIGHUP 时重新加载 lander.yaml 与策略配置
+    #[serde(default)]
+    pub enable: bool,
+    /// 轮询配置文件修改时间的间隔，0 表示只响应 SIGHUP
+    #[serde(default = "super::default_hot_reload_watch_interval_ms")]
+    pub watch_interval_ms: u64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct WalletPoolConfig {
+    /// 启用后额外解锁 `wallets` 中的钱包，盲发/纯盲发机会在这些签名钱包之间分摊
+    #[serde(default)]
+    pub enable: bool,
+    /// 参与分摊的 wallet_keys remark，为空表示全部 wallet_keys
+    #[serde(default)]
+    pub wallets: Vec<String>,
+    #[serde(default)]
+    pub selection: WalletSelection,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum WalletSelection {
+    /// 按机会依次轮换钱包
+    RoundRobin,
+    /// 同一路线固定落在同一钱包，避免同一路线的并发交易争用账户锁
+    Route,
+}
+
+impl Default for WalletSelection {
+    fn default() -> Self {
+        Self::RoundRobin
+    }
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct HealthCheckConfig {
     #[serde(default = "super::default_health_check_interval_secs")]
@@ -2355,7 +2902,7 @@ pub struct HealthCheckConfig {
     pub retry_count: u32,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Clone, Deserialize)]
 #[allow(dead_code)]
 pub struct LanderConfig {
     #[serde(default)]
@@ -1,3 +1,4 @@
+use std::collections::HashSet;
 use std::fs;
 use std::io::{self, IsTerminal, Write};
 use std::path::{Path, PathBuf};
@@ -16,7 +17,7 @@ use crossterm::{
     terminal,
 };
 use serde_json;
-use solana_sdk::signature::Keypair;
+use solana_sdk::signature::{Keypair, Signer};
 use tracing::{info, warn};
 use zeroize::{Zeroize, Zeroizing};
 
@@ -62,7 +63,7 @@ pub fn process_wallet_keys(
         })?;
 
     let mut attempts = 0usize;
-    loop {
+    let password = loop {
         attempts += 1;
         let password = obtain_existing_password().map_err(|message| ConfigError::Parse {
             path: config_path
@@ -74,7 +75,7 @@ pub fn process_wallet_keys(
         match decrypt_wallet_bytes(&cipher, password.as_ref()) {
             Ok(decrypted) => {
                 config.private_key = decrypted;
-                break;
+                break password;
             }
             Err(message) => {
                 let remaining = MAX_PASSWORD_ATTEMPTS.saturating_sub(attempts);
@@ -96,6 +97,20 @@ pub fn process_wallet_keys(
                 println!("密码错误，还有 {} 次机会。", remaining);
             }
         }
+    };
+
+    if config.bot.wallet_pool.enable {
+        config.pool_private_keys = unlock_wallet_pool(config, selected, password.as_ref())
+            .map_err(|message| ConfigError::Parse {
+                path: config_path
+                    .map(Path::to_path_buf)
+                    .unwrap_or_else(default_wallet_path),
+                message,
+            })?;
+        println!(
+            "🔓 钱包池已解锁 {} 个额外钱包",
+            config.pool_private_keys.len()
+        );
     }
 
     Ok(WalletProcessingResult {
@@ -104,6 +119,96 @@ pub fn process_wallet_keys(
     })
 }
 
+/// 解密 `bot.wallet_pool` 中除主钱包外的其余钱包：优先复用主钱包密码，不匹配时单独提示输入。
+fn unlock_wallet_pool(
+    config: &GalileoConfig,
+    primary: usize,
+    primary_password: &str,
+) -> Result<Vec<String>, String> {
+    let pool = &config.bot.wallet_pool;
+    let targets: Vec<usize> = if pool.wallets.is_empty() {
+        (0..config.wallet_keys.len()).collect()
+    } else {
+        pool.wallets
+            .iter()
+            .map(|remark| {
+                config
+                    .wallet_keys
+                    .iter()
+                    .position(|entry| entry.remark == remark.trim())
+                    .ok_or_else(|| {
+                        format!("wallet_pool.wallets 中的 `{remark}` 不在 wallet_keys 中")
+                    })
+            })
+            .collect::<Result<_, _>>()?
+    };
+
+    let mut seen = HashSet::from([primary]);
+    let mut keys = Vec::new();
+    for index in targets {
+        if !seen.insert(index) {
+            continue;
+        }
+        keys.push(decrypt_entry_or_prompt(
+            &config.wallet_keys[index],
+            primary_password,
+        )?);
+    }
+    Ok(keys)
+}
+
+/// 先用已输入的密码解密条目，不匹配时针对该条目单独提示密码。
+pub(crate) fn decrypt_entry_or_prompt(
+    entry: &WalletKeyEntry,
+    password: &str,
+) -> Result<String, String> {
+    let cipher = general_purpose::STANDARD
+        .decode(entry.encrypted.trim().as_bytes())
+        .map_err(|err| format!("钱包 [{}] Base64 解码失败: {err}", entry.remark))?;
+    match decrypt_wallet_bytes(&cipher, password) {
+        Ok(decrypted) => Ok(decrypted),
+        Err(_) => {
+            println!("钱包 [{}] 使用了不同的密码。", entry.remark);
+            decrypt_with_prompt(&cipher, &entry.remark)
+        }
+    }
+}
+
+/// 以新密码加密私钥并生成记录公钥的条目，每次加密都使用新的 Argon2 salt。
+pub(crate) fn encrypt_wallet_entry(
+    remark: &str,
+    private_key: &str,
+    password: &str,
+) -> Result<WalletKeyEntry, String> {
+    let keypair =
+        parse_keypair_string(private_key).map_err(|err| format!("解析私钥失败: {err}"))?;
+    let encrypted = encrypt_wallet_key(private_key.as_bytes(), password)?;
+    Ok(WalletKeyEntry {
+        remark: remark.to_string(),
+        encrypted: general_purpose::STANDARD.encode(encrypted),
+        pubkey: Some(keypair.pubkey().to_string()),
+    })
+}
+
+fn decrypt_with_prompt(cipher: &[u8], remark: &str) -> Result<String, String> {
+    for attempt in 1..=MAX_PASSWORD_ATTEMPTS {
+        let password = obtain_existing_password()?;
+        match decrypt_wallet_bytes(cipher, password.as_ref()) {
+            Ok(decrypted) => return Ok(decrypted),
+            Err(message) => {
+                let remaining = MAX_PASSWORD_ATTEMPTS - attempt;
+                warn!(target: "config", remark, remaining, "钱包解密失败: {message}");
+                if remaining > 0 {
+                    println!("密码错误，还有 {} 次机会。", remaining);
+                }
+            }
+        }
+    }
+    Err(format!(
+        "钱包 [{remark}] 连续 {MAX_PASSWORD_ATTEMPTS} 次解密失败，已终止尝试"
+    ))
+}
+
 fn interactive_add_wallet_entry_to_config(
     config: &mut GalileoConfig,
     config_path: Option<&Path>,
@@ -138,8 +243,8 @@ fn interactive_add_wallet_entry_to_config(
             .unwrap_or_else(default_wallet_path),
         message,
     })?;
-    let encrypted =
-        encrypt_wallet_key(private_key.as_bytes(), password.as_ref()).map_err(|message| {
+    let entry =
+        encrypt_wallet_entry(&remark, &private_key, password.as_ref()).map_err(|message| {
             ConfigError::Parse {
                 path: config_path
                     .map(Path::to_path_buf)
@@ -147,13 +252,9 @@ fn interactive_add_wallet_entry_to_config(
                 message,
             }
         })?;
-    let encoded = general_purpose::STANDARD.encode(encrypted);
 
     config.private_key = private_key.clone();
-    config.wallet_keys.push(WalletKeyEntry {
-        remark: remark.clone(),
-        encrypted: encoded.clone(),
-    });
+    config.wallet_keys.push(entry);
 
     if let Some(path) = config_path {
         persist_wallet_keys(path, &config.wallet_keys)?;
@@ -201,7 +302,10 @@ pub fn parse_keypair_string(raw: &str) -> Result<Keypair, anyhow::Error> {
     }
 }
 
-fn persist_wallet_keys(path: &Path, entries: &[WalletKeyEntry]) -> Result<(), ConfigError> {
+pub(crate) fn persist_wallet_keys(
+    path: &Path,
+    entries: &[WalletKeyEntry],
+) -> Result<(), ConfigError> {
     let contents = fs::read_to_string(path).map_err(|source| ConfigError::Io {
         path: path.to_path_buf(),
         source,
@@ -606,11 +710,11 @@ fn default_wallet_path() -> PathBuf {
     PathBuf::from("<config>")
 }
 
-fn obtain_new_password() -> Result<Zeroizing<String>, String> {
+pub(crate) fn obtain_new_password() -> Result<Zeroizing<String>, String> {
     prompt_new_password_interactive()
 }
 
-fn obtain_existing_password() -> Result<Zeroizing<String>, String> {
+pub(crate) fn obtain_existing_password() -> Result<Zeroizing<String>, String> {
     prompt_existing_password_interactive()
 }
 
@@ -0,0 +1,197 @@
+use std::time::{Duration, Instant};
+
+use futures::future::join_all;
+use serde::Serialize;
+use tokio::net::TcpStream;
+use url::Url;
+
+use crate::monitoring::events::endpoint_label;
+
+use super::state::runtime_control;
+
+const PROBE_TIMEOUT: Duration = Duration::from_secs(3);
+/// 订阅超过该时长没有任何推送（含 slot 心跳）视为不健康。
+const STREAM_STALE_AFTER: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Serialize)]
+pub struct HealthReport {
+    pub healthy: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rpc: Option<ProbeResult>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aggregator: Option<ProbeResult>,
+    pub yellowstone: Vec<StreamReport>,
+    pub landers: Vec<ProbeResult>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ProbeResult {
+    pub name: String,
+    pub endpoint: String,
+    pub ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latency_ms: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slot: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// 已通过控制接口禁用的落地器不计入整体健康状态。
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub disabled: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct StreamReport {
+    pub stream: String,
+    pub endpoint: String,
+    pub ok: bool,
+    pub connected: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_update_ms: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// 并发探测 RPC、聚合器、Yellowstone 订阅与各落地端点。
+pub async fn health_report() -> HealthReport {
+    let control = runtime_control();
+
+    let rpc_probe = async {
+        let rpc = control.rpc()?;
+        let endpoint = endpoint_label(Some(&rpc.url()));
+        let started = Instant::now();
+        let result = tokio::time::timeout(PROBE_TIMEOUT, rpc.get_slot()).await;
+        let latency_ms = Some(started.elapsed().as_millis() as u64);
+        Some(match result {
+            Ok(Ok(slot)) => ProbeResult {
+                slot: Some(slot),
+                ..ProbeResult::ok("rpc", endpoint, latency_ms)
+            },
+            Ok(Err(err)) => ProbeResult::failed("rpc", endpoint, err.to_string()),
+            Err(_) => ProbeResult::failed("rpc", endpoint, "timeout".to_string()),
+        })
+    };
+    let aggregator_probe = async {
+        let (name, url) = control.aggregator()?;
+        Some(tcp_probe(name, &url).await)
+    };
+    let lander_probes = join_all(control.landers().into_iter().flat_map(|(name, urls)| {
+        urls.into_iter().map(move |url| async move {
+            let mut probe = tcp_probe(name, &url).await;
+            probe.disabled = runtime_control().is_lander_disabled(name);
+            probe
+        })
+    }));
+    let (rpc, aggregator, landers) = tokio::join!(rpc_probe, aggregator_probe, lander_probes);
+
+    let now = Instant::now();
+    let yellowstone: Vec<StreamReport> = control
+        .streams()
+        .into_iter()
+        .map(|(stream, health)| {
+            let since_update = health
+                .last_update
+                .map(|at| now.saturating_duration_since(at));
+            let fresh = since_update.is_none_or(|elapsed| elapsed <= STREAM_STALE_AFTER);
+            StreamReport {
+                stream,
+                endpoint: endpoint_label(Some(&health.endpoint)),
+                ok: health.connected && fresh,
+                connected: health.connected,
+                last_update_ms: since_update.map(|elapsed| elapsed.as_millis() as u64),
+                error: health.last_error,
+            }
+        })
+        .collect();
+
+    let healthy = rpc.as_ref().is_none_or(|probe| probe.ok)
+        && aggregator.as_ref().is_none_or(|probe| probe.ok)
+        && yellowstone.iter().all(|stream| stream.ok)
+        && landers.iter().all(|probe| probe.ok || probe.disabled);
+
+    HealthReport {
+        healthy,
+        rpc,
+        aggregator,
+        yellowstone,
+        landers,
+    }
+}
+
+impl ProbeResult {
+    fn ok(name: &str, endpoint: String, latency_ms: Option<u64>) -> Self {
+        Self {
+            name: name.to_string(),
+            endpoint,
+            ok: true,
+            latency_ms,
+            slot: None,
+            error: None,
+            disabled: false,
+        }
+    }
+
+    fn failed(name: &str, endpoint: String, error: String) -> Self {
+        Self {
+            name: name.to_string(),
+            endpoint,
+            ok: false,
+            latency_ms: None,
+            slot: None,
+            error: Some(error),
+            disabled: false,
+        }
+    }
+}
+
+/// 以 TCP 建连判断端点可达，与 Jupiter 启动时的健康检查一致，不发送业务请求。
+async fn tcp_probe(name: &str, url: &str) -> ProbeResult {
+    let endpoint = endpoint_label(Some(url));
+    let Some((host, port)) = socket_target(url) else {
+        return ProbeResult::failed(name, endpoint, "无法解析端点地址".to_string());
+    };
+    let started = Instant::now();
+    match tokio::time::timeout(PROBE_TIMEOUT, TcpStream::connect((host.as_str(), port))).await {
+        Ok(Ok(_)) => ProbeResult::ok(name, endpoint, Some(started.elapsed().as_millis() as u64)),
+        Ok(Err(err)) => ProbeResult::failed(name, endpoint, err.to_string()),
+        Err(_) => ProbeResult::failed(name, endpoint, "timeout".to_string()),
+    }
+}
+
+fn socket_target(url: &str) -> Option<(String, u16)> {
+    if let Ok(parsed) = Url::parse(url.trim()) {
+        if let (Some(host), Some(port)) = (parsed.host_str(), parsed.port_or_known_default()) {
+            let host = host.trim_start_matches('[').trim_end_matches(']');
+            return Some((host.to_string(), port));
+        }
+    }
+    // 未带 scheme 的 `host:port` 形式（常见于 gRPC 地址）。
+    let authority = endpoint_label(Some(url));
+    match authority.rsplit_once(':') {
+        Some((host, port)) => port.parse().ok().map(|port| (host.to_string(), port)),
+        None if authority != "default" => Some((authority, 443)),
+        None => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn socket_target_handles_urls_and_bare_authorities() {
+        assert_eq!(
+            socket_target("https://mainnet.block-engine.jito.wtf/api/v1/bundles?uuid=secret"),
+            Some(("mainnet.block-engine.jito.wtf".to_string(), 443))
+        );
+        assert_eq!(
+            socket_target("http://127.0.0.1:18080"),
+            Some(("127.0.0.1".to_string(), 18080))
+        );
+        assert_eq!(
+            socket_target("grpc.example.com:10000"),
+            Some(("grpc.example.com".to_string(), 10000))
+        );
+        assert_eq!(socket_target(""), None);
+    }
+}
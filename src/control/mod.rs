@@ -0,0 +1,9 @@
+//! `galileo run` 运行期间的本地 HTTP 控制与健康检查接口。
+
+mod health;
+mod server;
+mod state;
+mod status;
+
+pub use server::spawn_control_api;
+pub use state::{ControlOutcome, lander_disabled, runtime_control, strategy_paused};
@@ -0,0 +1,172 @@
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use axum::Router;
+use axum::extract::{Path, Request, State};
+use axum::http::{HeaderValue, StatusCode, header};
+use axum::middleware::{self, Next};
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
+use serde::Serialize;
+use serde_json::json;
+use tokio::net::TcpListener;
+use tracing::{info, warn};
+
+use crate::strategy::pure_blind::observer::RouteKey;
+
+use super::health::health_report;
+use super::state::{ControlOutcome, runtime_control};
+use super::status::status_report;
+
+#[derive(Clone)]
+struct ApiState {
+    token: Option<Arc<str>>,
+}
+
+/// 绑定监听地址并在后台运行控制接口；绑定失败直接返回错误，避免静默缺失控制面。
+pub async fn spawn_control_api(listen: &str, token: Option<String>) -> Result<()> {
+    let addr: SocketAddr = listen
+        .parse()
+        .with_context(|| format!("invalid control api listen address: {listen}"))?;
+    let listener = TcpListener::bind(addr)
+        .await
+        .with_context(|| format!("failed to bind control api on {addr}"))?;
+    if token.is_none() && !addr.ip().is_loopback() {
+        warn!(
+            target: "control",
+            listen = %addr,
+            "控制接口监听非本机地址且未配置 token，任何可访问该端口的主机都能暂停策略"
+        );
+    }
+
+    let state = ApiState {
+        token: token.map(Arc::from),
+    };
+    let app = router(state);
+    info!(target: "control", listen = %addr, "控制接口已启动");
+    tokio::spawn(async move {
+        if let Err(err) = axum::serve(listener, app).await {
+            warn!(target: "control", error = %err, "控制接口退出");
+        }
+    });
+    Ok(())
+}
+
+fn router(state: ApiState) -> Router {
+    Router::new()
+        .route("/health", get(health))
+        .route("/status", get(status))
+        .route("/dashboard", get(dashboard))
+        .route("/control/strategies/{name}/pause", post(pause_strategy))
+        .route("/control/strategies/{name}/resume", post(resume_strategy))
+        .route("/control/landers/{name}/disable", post(disable_lander))
+        .route("/control/landers/{name}/enable", post(enable_lander))
+        .route("/control/routes/{route}/retire", post(retire_route))
+        .layer(middleware::from_fn_with_state(state.clone(), authorize))
+        .with_state(state)
+}
+
+async fn authorize(State(state): State<ApiState>, request: Request, next: Next) -> Response {
+    if let Some(token) = state.token.as_deref() {
+        let expected = format!("Bearer {token}");
+        let provided = request
+            .headers()
+            .get(header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok());
+        if provided != Some(expected.as_str()) {
+            return json_response(StatusCode::UNAUTHORIZED, &json!({"error": "unauthorized"}));
+        }
+    }
+    next.run(request).await
+}
+
+async fn health() -> Response {
+    let report = health_report().await;
+    let status = if report.healthy {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    json_response(status, &report)
+}
+
+async fn status() -> Response {
+    json_response(StatusCode::OK, &status_report())
+}
+
+async fn dashboard() -> Response {
+    json_response(
+        StatusCode::OK,
+        &crate::monitoring::dashboard::feed().snapshot(),
+    )
+}
+
+async fn pause_strategy(Path(name): Path<String>) -> Response {
+    control_response("strategy", &name, runtime_control().pause_strategy(&name))
+}
+
+async fn resume_strategy(Path(name): Path<String>) -> Response {
+    control_response("strategy", &name, runtime_control().resume_strategy(&name))
+}
+
+async fn disable_lander(Path(name): Path<String>) -> Response {
+    control_response("lander", &name, runtime_control().disable_lander(&name))
+}
+
+async fn enable_lander(Path(name): Path<String>) -> Response {
+    control_response("lander", &name, runtime_control().enable_lander(&name))
+}
+
+async fn retire_route(Path(route): Path<String>) -> Response {
+    let Some(catalog) = runtime_control().route_catalog() else {
+        return json_response(
+            StatusCode::NOT_FOUND,
+            &json!({"error": "pure_blind 动态路线未启用"}),
+        );
+    };
+    let key: RouteKey = match route.parse() {
+        Ok(key) => key,
+        Err(err) => return json_response(StatusCode::BAD_REQUEST, &json!({"error": err})),
+    };
+    let outcome = match catalog.retire(&key) {
+        Some(true) => ControlOutcome::Changed,
+        Some(false) => ControlOutcome::Unchanged,
+        None => ControlOutcome::NotFound,
+    };
+    if outcome == ControlOutcome::Changed {
+        info!(target: "control", route = %key, "路线已通过控制接口下线");
+    }
+    control_response("route", &key.to_string(), outcome)
+}
+
+fn control_response(kind: &str, name: &str, outcome: ControlOutcome) -> Response {
+    match outcome {
+        ControlOutcome::Changed | ControlOutcome::Unchanged => json_response(
+            StatusCode::OK,
+            &json!({
+                kind: name,
+                "changed": outcome == ControlOutcome::Changed,
+            }),
+        ),
+        ControlOutcome::NotFound => json_response(
+            StatusCode::NOT_FOUND,
+            &json!({"error": format!("未找到 {kind} `{name}`")}),
+        ),
+    }
+}
+
+fn json_response<T: Serialize>(status: StatusCode, body: &T) -> Response {
+    match serde_json::to_vec(body) {
+        Ok(bytes) => (
+            status,
+            [(
+                header::CONTENT_TYPE,
+                HeaderValue::from_static("application/json"),
+            )],
+            bytes,
+        )
+            .into_response(),
+        Err(err) => (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()).into_response(),
+    }
+}
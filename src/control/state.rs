@@ -0,0 +1,285 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Arc;
+use std::time::Instant;
+
+use dashmap::mapref::one::RefMut;
+use dashmap::{DashMap, DashSet};
+use once_cell::sync::Lazy;
+use parking_lot::RwLock;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use tracing::{info, warn};
+
+use crate::lander::LanderStack;
+use crate::network::IpAllocator;
+use crate::strategy::pure_blind::observer::RouteCatalog;
+
+static CONTROL: Lazy<RuntimeControl> = Lazy::new(RuntimeControl::default);
+
+/// 进程内唯一的运行时控制状态，策略与落地器在热路径上查询暂停/禁用标记。
+pub fn runtime_control() -> &'static RuntimeControl {
+    &CONTROL
+}
+
+/// 策略是否已通过控制接口暂停，或因钱包资金耗尽被整体暂停。
+pub fn strategy_paused(name: &str) -> bool {
+    CONTROL.is_strategy_paused(name) || CONTROL.is_trading_halted()
+}
+
+/// 落地器是否已通过控制接口禁用。
+pub fn lander_disabled(name: &str) -> bool {
+    CONTROL.is_lander_disabled(name)
+}
+
+/// Yellowstone 订阅的最近状态。
+#[derive(Debug, Clone)]
+pub struct StreamHealth {
+    pub endpoint: String,
+    pub connected: bool,
+    pub last_update: Option<Instant>,
+    pub last_error: Option<String>,
+}
+
+/// 控制接口操作的结果。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ControlOutcome {
+    Changed,
+    Unchanged,
+    NotFound,
+}
+
+#[derive(Default)]
+pub struct RuntimeControl {
+    strategies: RwLock<BTreeSet<&'static str>>,
+    paused_strategies: DashSet<String>,
+    landers: RwLock<BTreeMap<&'static str, BTreeSet<String>>>,
+    disabled_landers: DashSet<String>,
+    route_catalog: RwLock<Option<Arc<RouteCatalog>>>,
+    ip_allocator: RwLock<Option<Arc<IpAllocator>>>,
+    rpc: RwLock<Option<Arc<RpcClient>>>,
+    aggregator: RwLock<Option<(&'static str, String)>>,
+    streams: DashMap<String, StreamHealth>,
+    trading_halt: RwLock<Option<String>>,
+}
+
+impl RuntimeControl {
+    pub fn register_strategy(&self, name: &'static str) {
+        self.strategies.write().insert(name);
+    }
+
+    /// 登记落地器及其端点；copy 策略每个钱包各自构建 stack，端点按名称合并。
+    pub fn register_landers(&self, stack: &LanderStack) {
+        let mut landers = self.landers.write();
+        for variant in stack.variants() {
+            landers
+                .entry(variant.name())
+                .or_default()
+                .extend(variant.endpoint_urls());
+        }
+    }
+
+    pub fn register_route_catalog(&self, catalog: Arc<RouteCatalog>) {
+        *self.route_catalog.write() = Some(catalog);
+    }
+
+    pub fn register_ip_allocator(&self, allocator: Arc<IpAllocator>) {
+        *self.ip_allocator.write() = Some(allocator);
+    }
+
+    pub fn register_rpc(&self, rpc: Arc<RpcClient>) {
+        *self.rpc.write() = Some(rpc);
+    }
+
+    pub fn register_aggregator(&self, name: &'static str, endpoint: String) {
+        *self.aggregator.write() = Some((name, endpoint));
+    }
+
+    pub fn strategies(&self) -> Vec<&'static str> {
+        self.strategies.read().iter().copied().collect()
+    }
+
+    pub fn landers(&self) -> Vec<(&'static str, Vec<String>)> {
+        self.landers
+            .read()
+            .iter()
+            .map(|(name, endpoints)| (*name, endpoints.iter().cloned().collect()))
+            .collect()
+    }
+
+    pub fn route_catalog(&self) -> Option<Arc<RouteCatalog>> {
+        self.route_catalog.read().clone()
+    }
+
+    pub fn ip_allocator(&self) -> Option<Arc<IpAllocator>> {
+        self.ip_allocator.read().clone()
+    }
+
+    pub fn rpc(&self) -> Option<Arc<RpcClient>> {
+        self.rpc.read().clone()
+    }
+
+    pub fn aggregator(&self) -> Option<(&'static str, String)> {
+        self.aggregator.read().clone()
+    }
+
+    pub fn streams(&self) -> Vec<(String, StreamHealth)> {
+        let mut streams: Vec<(String, StreamHealth)> = self
+            .streams
+            .iter()
+            .map(|entry| (entry.key().clone(), entry.value().clone()))
+            .collect();
+        streams.sort_by(|a, b| a.0.cmp(&b.0));
+        streams
+    }
+
+    pub fn is_strategy_paused(&self, name: &str) -> bool {
+        self.paused_strategies.contains(name)
+    }
+
+    pub fn is_lander_disabled(&self, name: &str) -> bool {
+        self.disabled_landers.contains(name)
+    }
+
+    pub fn is_trading_halted(&self) -> bool {
+        self.trading_halt.read().is_some()
+    }
+
+    pub fn trading_halt(&self) -> Option<String> {
+        self.trading_halt.read().clone()
+    }
+
+    /// 钱包守护任务在资金耗尽时调用，独立于手动暂停，恢复时不影响控制接口设置的暂停。
+    pub fn halt_trading(&self, reason: impl Into<String>) -> ControlOutcome {
+        let mut halt = self.trading_halt.write();
+        if halt.is_some() {
+            return ControlOutcome::Unchanged;
+        }
+        let reason = reason.into();
+        warn!(target: "control", reason = %reason, "资金耗尽，全部策略已暂停");
+        *halt = Some(reason);
+        ControlOutcome::Changed
+    }
+
+    pub fn resume_trading(&self) -> ControlOutcome {
+        if self.trading_halt.write().take().is_none() {
+            return ControlOutcome::Unchanged;
+        }
+        info!(target: "control", "钱包余额已恢复，策略解除资金暂停");
+        ControlOutcome::Changed
+    }
+
+    pub fn pause_strategy(&self, name: &str) -> ControlOutcome {
+        if !self.strategies.read().contains(name) {
+            return ControlOutcome::NotFound;
+        }
+        if !self.paused_strategies.insert(name.to_string()) {
+            return ControlOutcome::Unchanged;
+        }
+        info!(target: "control", strategy = name, "策略已通过控制接口暂停");
+        ControlOutcome::Changed
+    }
+
+    pub fn resume_strategy(&self, name: &str) -> ControlOutcome {
+        if !self.strategies.read().contains(name) {
+            return ControlOutcome::NotFound;
+        }
+        if self.paused_strategies.remove(name).is_none() {
+            return ControlOutcome::Unchanged;
+        }
+        info!(target: "control", strategy = name, "策略已通过控制接口恢复");
+        ControlOutcome::Changed
+    }
+
+    pub fn disable_lander(&self, name: &str) -> ControlOutcome {
+        if !self.landers.read().contains_key(name) {
+            return ControlOutcome::NotFound;
+        }
+        if !self.disabled_landers.insert(name.to_string()) {
+            return ControlOutcome::Unchanged;
+        }
+        info!(target: "control", lander = name, "落地器已通过控制接口禁用");
+        ControlOutcome::Changed
+    }
+
+    pub fn enable_lander(&self, name: &str) -> ControlOutcome {
+        if !self.landers.read().contains_key(name) {
+            return ControlOutcome::NotFound;
+        }
+        if self.disabled_landers.remove(name).is_none() {
+            return ControlOutcome::Unchanged;
+        }
+        info!(target: "control", lander = name, "落地器已通过控制接口恢复");
+        ControlOutcome::Changed
+    }
+
+    pub fn stream_connected(&self, label: &str, endpoint: &str) {
+        let mut entry = self.stream_entry(label, endpoint);
+        entry.connected = true;
+        entry.last_error = None;
+    }
+
+    pub fn stream_update(&self, label: &str) {
+        if let Some(mut entry) = self.streams.get_mut(label) {
+            entry.last_update = Some(Instant::now());
+        }
+    }
+
+    /// 首次连接失败的订阅同样登记，避免健康检查遗漏从未连上的订阅。
+    pub fn stream_disconnected(&self, label: &str, endpoint: &str, error: &str) {
+        let mut entry = self.stream_entry(label, endpoint);
+        entry.connected = false;
+        entry.last_error = Some(error.to_string());
+    }
+
+    fn stream_entry(&self, label: &str, endpoint: &str) -> RefMut<'_, String, StreamHealth> {
+        let mut entry = self
+            .streams
+            .entry(label.to_string())
+            .or_insert_with(|| StreamHealth {
+                endpoint: String::new(),
+                connected: false,
+                last_update: None,
+                last_error: None,
+            });
+        if entry.endpoint != endpoint {
+            entry.endpoint = endpoint.to_string();
+        }
+        entry
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pause_requires_registered_strategy() {
+        let control = RuntimeControl::default();
+        assert_eq!(control.pause_strategy("blind"), ControlOutcome::NotFound);
+
+        control.register_strategy("blind");
+        assert!(!control.is_strategy_paused("blind"));
+        assert_eq!(control.pause_strategy("blind"), ControlOutcome::Changed);
+        assert_eq!(control.pause_strategy("blind"), ControlOutcome::Unchanged);
+        assert!(control.is_strategy_paused("blind"));
+
+        assert_eq!(control.resume_strategy("blind"), ControlOutcome::Changed);
+        assert_eq!(control.resume_strategy("blind"), ControlOutcome::Unchanged);
+        assert!(!control.is_strategy_paused("blind"));
+    }
+
+    #[test]
+    fn stream_health_tracks_updates_and_errors() {
+        let control = RuntimeControl::default();
+        control.stream_update("copy@grpc");
+        assert!(control.streams().is_empty());
+
+        control.stream_connected("copy@grpc", "https://grpc.example");
+        control.stream_update("copy@grpc");
+        control.stream_disconnected("copy@grpc", "https://grpc.example", "stream closed");
+        let (label, health) = control.streams().remove(0);
+        assert_eq!(label, "copy@grpc");
+        assert!(!health.connected);
+        assert!(health.last_update.is_some());
+        assert_eq!(health.last_error.as_deref(), Some("stream closed"));
+    }
+}
@@ -0,0 +1,150 @@
+use serde::Serialize;
+
+use super::state::runtime_control;
+
+/// `/status` 中最多列出的激活路线数，按得分降序截断。
+const MAX_LISTED_ROUTES: usize = 200;
+
+#[derive(Debug, Serialize)]
+pub struct StatusReport {
+    /// 钱包守护任务因资金耗尽暂停全部策略时的原因。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trading_halt: Option<String>,
+    pub strategies: Vec<StrategyStatus>,
+    pub landers: Vec<LanderStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub routes: Option<RouteStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip_allocator: Option<IpAllocatorStatus>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct StrategyStatus {
+    pub name: &'static str,
+    pub paused: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct LanderStatus {
+    pub name: &'static str,
+    pub disabled: bool,
+    pub endpoints: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RouteStatus {
+    pub active: usize,
+    pub items: Vec<RouteEntry>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RouteEntry {
+    /// 逗号连接的池子地址，可直接用于 `/control/routes/{route}/retire`。
+    pub route: String,
+    pub legs: usize,
+    pub observations: u64,
+    pub last_seen_slot: Option<u64>,
+    pub estimated_profit_total: String,
+    pub score: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct IpAllocatorStatus {
+    pub total_slots: usize,
+    pub per_ip_inflight_limit: Option<usize>,
+    pub source: String,
+    pub slots: Vec<IpSlotEntry>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct IpSlotEntry {
+    pub ip: String,
+    pub kind: &'static str,
+    pub state: &'static str,
+    pub inflight: usize,
+    pub cooldown_remaining_ms: Option<u64>,
+    pub requests: u64,
+    pub rate_limited: u64,
+    pub timeouts: u64,
+    pub network_errors: u64,
+}
+
+pub fn status_report() -> StatusReport {
+    let control = runtime_control();
+
+    let strategies = control
+        .strategies()
+        .into_iter()
+        .map(|name| StrategyStatus {
+            name,
+            paused: control.is_strategy_paused(name),
+        })
+        .collect();
+
+    let landers = control
+        .landers()
+        .into_iter()
+        .map(|(name, endpoints)| LanderStatus {
+            name,
+            disabled: control.is_lander_disabled(name),
+            endpoints: endpoints.len(),
+        })
+        .collect();
+
+    let routes = control.route_catalog().map(|catalog| {
+        let mut active = catalog.active_routes();
+        active.sort_by(|a, b| b.score.total_cmp(&a.score));
+        let total = active.len();
+        let items = active
+            .into_iter()
+            .take(MAX_LISTED_ROUTES)
+            .map(|route| RouteEntry {
+                route: route.profile.key.to_string(),
+                legs: route.profile.markets().len(),
+                observations: route.stats.observations,
+                last_seen_slot: route.stats.last_seen_slot,
+                // i128 在 JSON 中可能超出 f64 精度，以字符串输出。
+                estimated_profit_total: route.stats.estimated_profit_total.to_string(),
+                score: route.score,
+            })
+            .collect();
+        RouteStatus {
+            active: total,
+            items,
+        }
+    });
+
+    let ip_allocator = control.ip_allocator().map(|allocator| {
+        let summary = allocator.summary();
+        IpAllocatorStatus {
+            total_slots: summary.total_slots,
+            per_ip_inflight_limit: summary.per_ip_inflight_limit,
+            source: format!("{:?}", summary.source).to_ascii_lowercase(),
+            slots: allocator
+                .slot_statuses()
+                .into_iter()
+                .map(|slot| IpSlotEntry {
+                    ip: slot.ip.to_string(),
+                    kind: slot.kind,
+                    state: slot.state.as_str(),
+                    inflight: slot.inflight,
+                    cooldown_remaining_ms: slot
+                        .cooldown_remaining
+                        .map(|remaining| remaining.as_millis() as u64),
+                    requests: slot.stats.total_requests,
+                    rate_limited: slot.stats.rate_limited,
+                    timeouts: slot.stats.timeouts,
+                    network_errors: slot.stats.network_errors,
+                })
+                .collect(),
+        }
+    });
+
+    StatusReport {
+        trading_halt: control.trading_halt(),
+        strategies,
+        landers,
+        routes,
+        ip_allocator,
+    }
+}
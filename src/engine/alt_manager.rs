@@ -0,0 +1,534 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{Context, Result, anyhow};
+use dashmap::DashMap;
+use parking_lot::RwLock;
+use solana_account_decoder::UiAccountEncoding;
+use solana_address_lookup_table_interface::instruction::{
+    close_lookup_table, create_lookup_table, deactivate_lookup_table, extend_lookup_table,
+};
+use solana_address_lookup_table_interface::program::ID as ALT_PROGRAM_ID;
+use solana_address_lookup_table_interface::state::{
+    AddressLookupTable, LOOKUP_TABLE_MAX_ADDRESSES, estimate_last_valid_slot,
+};
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_client::rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig};
+use solana_client::rpc_filter::{Memcmp, RpcFilterType};
+use solana_commitment_config::CommitmentConfig;
+use solana_compute_budget_interface::ComputeBudgetInstruction;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::message::AddressLookupTableAccount;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use tokio::task::JoinHandle;
+use tracing::{debug, info, warn};
+
+use crate::config::AltManagerConfig;
+use crate::engine::EngineIdentity;
+use crate::tools::sol::send_transaction;
+
+/// ALT 账户中 authority 公钥的偏移：4 字节枚举标签 + 8 + 8 + 1 + 1 字节 Option 标签。
+const AUTHORITY_OFFSET: usize = 22;
+/// 单笔 extend 交易写入的地址数量，避免超出交易体积上限。
+const EXTEND_BATCH: usize = 20;
+
+/// 由我们钱包创建并持有的 ALT 集合，`TransactionBuilder` 编译消息时优先使用。
+#[derive(Clone, Default)]
+pub struct OwnedLookupTables {
+    inner: Arc<RwLock<Vec<AddressLookupTableAccount>>>,
+}
+
+impl OwnedLookupTables {
+    pub fn snapshot(&self) -> Vec<AddressLookupTableAccount> {
+        self.inner.read().clone()
+    }
+
+    pub fn replace(&self, tables: Vec<AddressLookupTableAccount>) {
+        *self.inner.write() = tables;
+    }
+
+    pub fn contains_address(&self, address: &Pubkey) -> bool {
+        self.inner
+            .read()
+            .iter()
+            .any(|table| table.addresses.contains(address))
+    }
+
+    /// 将自有 ALT 排在前面，`V0Message::try_compile` 会优先从靠前的表中查找账户。
+    pub fn prepend_to(
+        &self,
+        tables: Vec<AddressLookupTableAccount>,
+    ) -> Vec<AddressLookupTableAccount> {
+        let owned = self.inner.read();
+        if owned.is_empty() {
+            return tables;
+        }
+        let mut merged = Vec::with_capacity(owned.len() + tables.len());
+        merged.extend(owned.iter().cloned());
+        for table in tables {
+            if !merged.iter().any(|existing| existing.key == table.key) {
+                merged.push(table);
+            }
+        }
+        merged
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct OwnedTableInfo {
+    pub address: Pubkey,
+    pub addresses: Vec<Pubkey>,
+    pub deactivation_slot: Option<u64>,
+    pub last_extended_slot: u64,
+    pub lamports: u64,
+}
+
+impl OwnedTableInfo {
+    pub fn is_active(&self) -> bool {
+        self.deactivation_slot.is_none()
+    }
+
+    pub fn is_closable(&self, current_slot: u64) -> bool {
+        self.deactivation_slot
+            .is_some_and(|slot| current_slot > estimate_last_valid_slot(slot))
+    }
+
+    pub fn remaining_capacity(&self) -> usize {
+        LOOKUP_TABLE_MAX_ADDRESSES.saturating_sub(self.addresses.len())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AltGcAction {
+    Close,
+    Deactivate,
+    Wait { remaining_slots: u64 },
+}
+
+#[derive(Debug, Clone)]
+pub struct AltGcEntry {
+    pub address: Pubkey,
+    pub action: AltGcAction,
+    pub signature: Option<Signature>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct AltSyncOutcome {
+    pub created: Vec<Pubkey>,
+    pub extended_accounts: usize,
+}
+
+/// 管理自有 ALT：统计热点账户、创建/扩展/停用/关闭查找表，并维护共享注册表。
+#[derive(Clone)]
+pub struct AltManager {
+    rpc: Arc<RpcClient>,
+    identity: EngineIdentity,
+    config: AltManagerConfig,
+    tables: OwnedLookupTables,
+    heat: Arc<DashMap<Pubkey, u32>>,
+}
+
+impl AltManager {
+    pub fn new(rpc: Arc<RpcClient>, identity: EngineIdentity, config: AltManagerConfig) -> Self {
+        Self {
+            rpc,
+            identity,
+            config,
+            tables: OwnedLookupTables::default(),
+            heat: Arc::new(DashMap::new()),
+        }
+    }
+
+    pub fn tables(&self) -> OwnedLookupTables {
+        self.tables.clone()
+    }
+
+    /// 记录一笔已构建交易涉及的账户热度；被调用的程序与签名者无法放入 ALT，直接跳过。
+    pub fn observe(&self, instructions: &[Instruction]) {
+        let programs: HashSet<Pubkey> = instructions.iter().map(|ix| ix.program_id).collect();
+        for ix in instructions {
+            for meta in ix.accounts.iter().filter(|meta| !meta.is_signer) {
+                if programs.contains(&meta.pubkey) || self.tables.contains_address(&meta.pubkey) {
+                    continue;
+                }
+                *self.heat.entry(meta.pubkey).or_insert(0) += 1;
+            }
+        }
+    }
+
+    /// 通过 getProgramAccounts 按 authority 查询钱包持有的全部 ALT。
+    pub async fn list_owned(&self) -> Result<Vec<OwnedTableInfo>> {
+        let config = RpcProgramAccountsConfig {
+            filters: Some(vec![RpcFilterType::Memcmp(Memcmp::new_raw_bytes(
+                AUTHORITY_OFFSET,
+                self.identity.pubkey.to_bytes().to_vec(),
+            ))]),
+            account_config: RpcAccountInfoConfig {
+                encoding: Some(UiAccountEncoding::Base64),
+                data_slice: None,
+                commitment: Some(CommitmentConfig::confirmed()),
+                min_context_slot: None,
+            },
+            with_context: Some(false),
+            sort_results: None,
+        };
+        let accounts = self
+            .rpc
+            .get_program_accounts_with_config(&ALT_PROGRAM_ID, config)
+            .await
+            .map_err(|err| anyhow!("查询自有 ALT 失败: {err}"))?;
+
+        let mut tables = Vec::with_capacity(accounts.len());
+        for (address, account) in accounts {
+            let table = match AddressLookupTable::deserialize(&account.data) {
+                Ok(table) => table,
+                Err(err) => {
+                    warn!(
+                        target: "engine::alt_manager",
+                        address = %address,
+                        error = %err,
+                        "解析自有 ALT 失败，已跳过"
+                    );
+                    continue;
+                }
+            };
+            if table.meta.authority != Some(self.identity.pubkey) {
+                continue;
+            }
+            let deactivation_slot =
+                (table.meta.deactivation_slot != u64::MAX).then_some(table.meta.deactivation_slot);
+            tables.push(OwnedTableInfo {
+                address,
+                addresses: table.addresses.to_vec(),
+                deactivation_slot,
+                last_extended_slot: table.meta.last_extended_slot,
+                lamports: account.lamports,
+            });
+        }
+        tables.sort_by_key(|table| table.address);
+        Ok(tables)
+    }
+
+    /// 重新拉取自有 ALT 并把仍处于激活状态的表写入注册表。
+    pub async fn refresh(&self) -> Result<Vec<OwnedTableInfo>> {
+        let tables = self.list_owned().await?;
+        let active = tables
+            .iter()
+            .filter(|table| table.is_active() && !table.addresses.is_empty())
+            .map(|table| AddressLookupTableAccount {
+                key: table.address,
+                addresses: table.addresses.clone(),
+            })
+            .collect();
+        self.tables.replace(active);
+        Ok(tables)
+    }
+
+    /// 将热点账户写入自有 ALT，必要时创建新表。
+    pub async fn sync_once(&self) -> Result<AltSyncOutcome> {
+        let mut tables = self.refresh().await?;
+        let mut outcome = AltSyncOutcome::default();
+
+        let known: HashSet<Pubkey> = tables
+            .iter()
+            .filter(|table| table.is_active())
+            .flat_map(|table| table.addresses.iter().copied())
+            .collect();
+        let mut candidates: Vec<(Pubkey, u32)> = self
+            .heat
+            .iter()
+            .filter(|entry| *entry.value() >= self.config.min_hits && !known.contains(entry.key()))
+            .map(|entry| (*entry.key(), *entry.value()))
+            .collect();
+        candidates.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        candidates.truncate(self.config.max_accounts_per_sync);
+        let mut pending: Vec<Pubkey> = candidates.into_iter().map(|(key, _)| key).collect();
+
+        while !pending.is_empty() {
+            let target = tables
+                .iter_mut()
+                .find(|table| table.is_active() && table.remaining_capacity() > 0);
+            let target = match target {
+                Some(table) => table,
+                None => {
+                    let active = tables.iter().filter(|table| table.is_active()).count();
+                    if active >= self.config.max_tables {
+                        warn!(
+                            target: "engine::alt_manager",
+                            active,
+                            pending = pending.len(),
+                            "自有 ALT 数量已达上限，剩余热点账户暂不写入"
+                        );
+                        break;
+                    }
+                    let (address, _) = self.create_table().await?;
+                    outcome.created.push(address);
+                    tables.push(OwnedTableInfo {
+                        address,
+                        addresses: Vec::new(),
+                        deactivation_slot: None,
+                        last_extended_slot: 0,
+                        lamports: 0,
+                    });
+                    tables.last_mut().expect("table just pushed")
+                }
+            };
+
+            let take = pending
+                .len()
+                .min(target.remaining_capacity())
+                .min(EXTEND_BATCH);
+            let batch: Vec<Pubkey> = pending.drain(..take).collect();
+            self.extend_table(&target.address, batch.clone()).await?;
+            outcome.extended_accounts += batch.len();
+            target.addresses.extend(batch);
+        }
+
+        self.decay_heat();
+        if !outcome.created.is_empty() || outcome.extended_accounts > 0 {
+            self.refresh().await?;
+        }
+        Ok(outcome)
+    }
+
+    pub async fn create_table(&self) -> Result<(Pubkey, Signature)> {
+        let recent_slot = self
+            .rpc
+            .get_slot_with_commitment(CommitmentConfig::finalized())
+            .await
+            .map_err(|err| anyhow!("获取 slot 失败: {err}"))?;
+        let (ix, address) =
+            create_lookup_table(self.identity.pubkey, self.identity.pubkey, recent_slot);
+        let signature = self.send(vec![ix]).await.context("创建 ALT 失败")?;
+        info!(
+            target: "engine::alt_manager",
+            address = %address,
+            signature = %signature,
+            "已创建自有 ALT"
+        );
+        Ok((address, signature))
+    }
+
+    pub async fn extend_table(&self, table: &Pubkey, addresses: Vec<Pubkey>) -> Result<Signature> {
+        let count = addresses.len();
+        let ix = extend_lookup_table(
+            *table,
+            self.identity.pubkey,
+            Some(self.identity.pubkey),
+            addresses,
+        );
+        let signature = self.send(vec![ix]).await.context("扩展 ALT 失败")?;
+        info!(
+            target: "engine::alt_manager",
+            address = %table,
+            count,
+            signature = %signature,
+            "已扩展自有 ALT"
+        );
+        Ok(signature)
+    }
+
+    pub async fn deactivate_table(&self, table: &Pubkey) -> Result<Signature> {
+        let ix = deactivate_lookup_table(*table, self.identity.pubkey);
+        let signature = self.send(vec![ix]).await.context("停用 ALT 失败")?;
+        info!(
+            target: "engine::alt_manager",
+            address = %table,
+            signature = %signature,
+            "已停用自有 ALT"
+        );
+        Ok(signature)
+    }
+
+    pub async fn close_table(&self, table: &Pubkey) -> Result<Signature> {
+        let ix = close_lookup_table(*table, self.identity.pubkey, self.identity.pubkey);
+        let signature = self.send(vec![ix]).await.context("关闭 ALT 失败")?;
+        info!(
+            target: "engine::alt_manager",
+            address = %table,
+            signature = %signature,
+            "已关闭自有 ALT 并回收租金"
+        );
+        Ok(signature)
+    }
+
+    /// 回收：关闭冷却期已过的停用表，停用从未写入地址的空表。
+    pub async fn garbage_collect(&self, dry_run: bool) -> Result<Vec<AltGcEntry>> {
+        let tables = self.list_owned().await?;
+        let current_slot = self
+            .rpc
+            .get_slot()
+            .await
+            .map_err(|err| anyhow!("获取 slot 失败: {err}"))?;
+
+        let mut report = Vec::new();
+        for table in tables {
+            let action = match table.deactivation_slot {
+                Some(_) if table.is_closable(current_slot) => AltGcAction::Close,
+                Some(slot) => AltGcAction::Wait {
+                    remaining_slots: estimate_last_valid_slot(slot)
+                        .saturating_sub(current_slot)
+                        .saturating_add(1),
+                },
+                None if table.addresses.is_empty() => AltGcAction::Deactivate,
+                None => continue,
+            };
+            let signature = if dry_run {
+                None
+            } else {
+                match action {
+                    AltGcAction::Close => Some(self.close_table(&table.address).await?),
+                    AltGcAction::Deactivate => Some(self.deactivate_table(&table.address).await?),
+                    AltGcAction::Wait { .. } => None,
+                }
+            };
+            report.push(AltGcEntry {
+                address: table.address,
+                action,
+                signature,
+            });
+        }
+        Ok(report)
+    }
+
+    /// 后台周期同步：刷新注册表并写入新的热点账户。
+    pub fn spawn(self) -> JoinHandle<()> {
+        let interval = Duration::from_secs(self.config.refresh_interval_secs.max(5));
+        tokio::spawn(async move {
+            loop {
+                match self.sync_once().await {
+                    Ok(outcome) => debug!(
+                        target: "engine::alt_manager",
+                        created = outcome.created.len(),
+                        extended = outcome.extended_accounts,
+                        tables = self.tables.snapshot().len(),
+                        "自有 ALT 同步完成"
+                    ),
+                    Err(err) => warn!(
+                        target: "engine::alt_manager",
+                        error = %err,
+                        "自有 ALT 同步失败"
+                    ),
+                }
+                tokio::time::sleep(interval).await;
+            }
+        })
+    }
+
+    fn decay_heat(&self) {
+        self.heat.retain(|_, hits| {
+            *hits /= 2;
+            *hits > 0
+        });
+    }
+
+    async fn send(&self, mut instructions: Vec<Instruction>) -> Result<Signature> {
+        if self.config.compute_unit_price_micro_lamports > 0 {
+            instructions.insert(
+                0,
+                ComputeBudgetInstruction::set_compute_unit_price(
+                    self.config.compute_unit_price_micro_lamports,
+                ),
+            );
+        }
+        send_transaction(&self.rpc, &self.identity, &instructions, &[]).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::instruction::AccountMeta;
+
+    fn table(key: Pubkey, addresses: Vec<Pubkey>) -> AddressLookupTableAccount {
+        AddressLookupTableAccount { key, addresses }
+    }
+
+    #[test]
+    fn prepend_places_owned_tables_first_without_duplicates() {
+        let owned_key = Pubkey::new_unique();
+        let other_key = Pubkey::new_unique();
+        let registry = OwnedLookupTables::default();
+        registry.replace(vec![table(owned_key, vec![Pubkey::new_unique()])]);
+
+        let merged = registry.prepend_to(vec![
+            table(other_key, Vec::new()),
+            table(owned_key, Vec::new()),
+        ]);
+        let keys: Vec<_> = merged.iter().map(|table| table.key).collect();
+        assert_eq!(keys, vec![owned_key, other_key]);
+        assert_eq!(merged[0].addresses.len(), 1);
+    }
+
+    #[test]
+    fn closable_only_after_deactivation_cooldown() {
+        let info = OwnedTableInfo {
+            address: Pubkey::new_unique(),
+            addresses: Vec::new(),
+            deactivation_slot: Some(1_000),
+            last_extended_slot: 0,
+            lamports: 0,
+        };
+        assert!(!info.is_closable(1_000));
+        assert!(info.is_closable(estimate_last_valid_slot(1_000) + 1));
+
+        let active = OwnedTableInfo {
+            deactivation_slot: None,
+            ..info
+        };
+        assert!(!active.is_closable(u64::MAX));
+        assert_eq!(active.remaining_capacity(), LOOKUP_TABLE_MAX_ADDRESSES);
+    }
+
+    #[tokio::test]
+    async fn observe_skips_signers_programs_and_registered_accounts() {
+        let rpc = Arc::new(RpcClient::new_mock("succeeds".to_string()));
+        let keypair = solana_sdk::signature::Keypair::new();
+        let private_key = format!(
+            "[{}]",
+            keypair
+                .to_bytes()
+                .iter()
+                .map(|byte| byte.to_string())
+                .collect::<Vec<_>>()
+                .join(",")
+        );
+        let identity = EngineIdentity::from_private_key(&private_key).expect("identity");
+        let manager = AltManager::new(rpc, identity, AltManagerConfig::default());
+
+        let pool = Pubkey::new_unique();
+        let registered = Pubkey::new_unique();
+        let downstream_program = Pubkey::new_unique();
+        manager
+            .tables
+            .replace(vec![table(Pubkey::new_unique(), vec![registered])]);
+
+        let swap_program = Pubkey::new_unique();
+        let ix = Instruction {
+            program_id: swap_program,
+            accounts: vec![
+                AccountMeta::new(Pubkey::new_unique(), true),
+                AccountMeta::new(pool, false),
+                AccountMeta::new_readonly(registered, false),
+                AccountMeta::new_readonly(downstream_program, false),
+            ],
+            data: Vec::new(),
+        };
+        let cpi = Instruction {
+            program_id: downstream_program,
+            accounts: Vec::new(),
+            data: Vec::new(),
+        };
+        manager.observe(&[ix.clone(), cpi.clone()]);
+        manager.observe(&[ix, cpi]);
+
+        assert_eq!(manager.heat.get(&pool).map(|hits| *hits), Some(2));
+        assert!(manager.heat.get(&registered).is_none());
+        assert!(manager.heat.get(&downstream_program).is_none());
+
+        manager.decay_heat();
+        assert_eq!(manager.heat.get(&pool).map(|hits| *hits), Some(1));
+    }
+}
@@ -5,7 +5,7 @@ use solana_sdk::message::AddressLookupTableAccount;
 use solana_sdk::pubkey::Pubkey;
 
 use crate::instructions::compute_budget::{COMPUTE_BUDGET_PROGRAM_ID, compute_budget_sequence};
-use crate::instructions::guards::lighthouse::TokenAmountGuard;
+use crate::instructions::guards::lighthouse::ProfitGuard;
 
 /// 描述交易指令不同阶段的组合。
 #[derive(Debug, Clone, Default)]
@@ -41,10 +41,10 @@ impl InstructionBundle {
         }
     }
 
-    /// 将利润守护指令插入到预备/收尾阶段。
-    pub fn insert_profit_guard(&mut self, guard: TokenAmountGuard) {
-        self.pre.insert(0, guard.memory_write);
-        self.post.push(guard.assert_delta);
+    /// 将利润守护指令插入到预备/收尾阶段：快照保持原顺序置于最前，断言追加到最后。
+    pub fn insert_profit_guard(&mut self, guard: ProfitGuard) {
+        self.pre.splice(0..0, guard.memory_writes);
+        self.post.extend(guard.assertions);
     }
 
     /// 替换当前 bundle 的指令序列，同时保持现有的 ALT 元数据。
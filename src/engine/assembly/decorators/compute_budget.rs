@@ -1,10 +1,11 @@
 use async_trait::async_trait;
 
-use crate::engine::EngineResult;
+use crate::engine::{EngineResult, RouteKey};
 use crate::instructions::compute_budget::{COMPUTE_BUDGET_PROGRAM_ID, compute_budget_sequence};
 
 use super::{AssemblyContext, InstructionDecorator};
 use crate::engine::assembly::bundle::InstructionBundle;
+use crate::monitoring::events;
 
 pub struct ComputeBudgetDecorator;
 
@@ -15,6 +16,8 @@ impl InstructionDecorator for ComputeBudgetDecorator {
         bundle: &mut InstructionBundle,
         context: &mut AssemblyContext<'_>,
     ) -> EngineResult<()> {
+        apply_tuned_limit(bundle, context);
+
         let target_limit = context.compute_unit_limit;
         let target_price = context.compute_unit_price.unwrap_or(0);
 
@@ -99,3 +102,36 @@ fn is_preserved_compute_budget(ix: &solana_sdk::instruction::Instruction) -> boo
         _ => true,
     }
 }
+
+/// 路线样本充足时以历史消耗替换静态预算，并同步重算优先费。
+fn apply_tuned_limit(bundle: &InstructionBundle, context: &mut AssemblyContext<'_>) {
+    let Some(estimator) = context.compute_unit_estimator else {
+        return;
+    };
+    let Some(key) = RouteKey::from_instructions(
+        bundle
+            .pre
+            .iter()
+            .chain(bundle.main.iter())
+            .chain(bundle.post.iter()),
+    ) else {
+        return;
+    };
+    let Some(tuned) = estimator.estimate(key) else {
+        return;
+    };
+    let static_limit = context.compute_unit_limit;
+    if tuned == static_limit {
+        return;
+    }
+
+    if let Some(price) = context.compute_unit_price.filter(|price| *price > 0) {
+        let fee = (price as u128)
+            .saturating_mul(tuned as u128)
+            .checked_div(1_000_000u128)
+            .unwrap_or(0);
+        context.prioritization_fee = fee.min(u64::MAX as u128) as u64;
+    }
+    context.compute_unit_limit = tuned;
+    events::compute_unit_limit_tuned(&key.to_string(), static_limit, tuned);
+}
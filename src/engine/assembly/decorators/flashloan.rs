@@ -5,6 +5,7 @@ use crate::engine::EngineResult;
 use super::{AssemblyContext, InstructionDecorator};
 use crate::engine::assembly::bundle::InstructionBundle;
 
+/// 按 base mint 从注册表挑选闪电贷来源，并用 borrow/repay 包裹指令。
 pub struct FlashloanDecorator;
 
 #[async_trait]
@@ -14,7 +15,7 @@ impl InstructionDecorator for FlashloanDecorator {
         bundle: &mut InstructionBundle,
         context: &mut AssemblyContext<'_>,
     ) -> EngineResult<()> {
-        let Some(manager) = context.flashloan_manager else {
+        let Some(registry) = context.flashloan else {
             return Ok(());
         };
         let Some(opportunity) = context.opportunity else {
@@ -23,17 +24,21 @@ impl InstructionDecorator for FlashloanDecorator {
         let Some(variant) = context.variant.as_deref() else {
             return Ok(());
         };
+        let Some(provider) = registry.select(&opportunity.pair.input_pubkey, opportunity.amount_in)
+        else {
+            return Ok(());
+        };
 
-        let outcome = manager
+        let outcome = provider
             .assemble(context.identity, opportunity, variant)
             .await?;
 
         if let Some(metadata) = outcome.metadata {
-            context.flashloan_metadata = Some(metadata);
-            let overhead = manager.compute_unit_overhead();
+            let overhead = provider.compute_unit_overhead();
             if overhead > 0 {
                 context.compute_unit_limit = context.compute_unit_limit.saturating_add(overhead);
             }
+            context.flashloan_metadata = Some(metadata);
         }
 
         bundle.replace_instructions(outcome.instructions);
@@ -3,10 +3,10 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use solana_sdk::pubkey::Pubkey;
 
-use crate::engine::plugins::flashloan::{FlashloanMetadata, marginfi::MarginfiFlashloanManager};
+use crate::engine::plugins::flashloan::{FlashloanMetadata, FlashloanRegistry};
 use crate::engine::{
-    EngineIdentity, EngineResult, JitoTipPlan, LighthouseRuntime, SwapInstructionsVariant,
-    SwapOpportunity,
+    ComputeUnitEstimator, EngineIdentity, EngineResult, JitoTipPlan, LighthouseRuntime,
+    SwapInstructionsVariant, SwapOpportunity,
 };
 use crate::monitoring::events;
 
@@ -45,8 +45,9 @@ pub struct AssemblyContext<'a> {
     pub jito_tip_plan: Option<JitoTipPlan>,
     pub variant: Option<&'a mut SwapInstructionsVariant>,
     pub opportunity: Option<&'a SwapOpportunity>,
-    pub flashloan_manager: Option<&'a MarginfiFlashloanManager>,
+    pub flashloan: Option<&'a FlashloanRegistry>,
     pub flashloan_metadata: Option<FlashloanMetadata>,
+    pub compute_unit_estimator: Option<&'a ComputeUnitEstimator>,
     lighthouse: Option<&'a mut LighthouseRuntime>,
 }
 
@@ -65,8 +66,9 @@ impl<'a> AssemblyContext<'a> {
             jito_tip_plan: None,
             variant: None,
             opportunity: None,
-            flashloan_manager: None,
+            flashloan: None,
             flashloan_metadata: None,
+            compute_unit_estimator: None,
             lighthouse: None,
         }
     }
@@ -79,7 +81,8 @@ mod tests {
     use crate::engine::aggregator::MultiLegInstructions;
     use crate::engine::assembly::bundle::InstructionBundle;
     use crate::engine::plugins::flashloan::{
-        MarginfiAccountRegistry, MarginfiFlashloanManager, MarginfiFlashloanPreparation,
+        FlashloanPreparation, FlashloanProvider, FlashloanRegistry, MarginfiAccountRegistry,
+        MarginfiFlashloanManager,
     };
     use crate::engine::types::{JitoTipPlan, SwapOpportunity};
     use crate::engine::{EngineIdentity, LighthouseSettings};
@@ -248,6 +251,9 @@ mod tests {
             memory_slots: Some(4),
             existing_memory_ids: Vec::new(),
             sol_price_feed: None,
+            native_sol_guard: false,
+            settlements: Default::default(),
+            token_programs: Default::default(),
         };
         let mut lighthouse = LighthouseRuntime::new(&settings, 4);
         super::attach_lighthouse_internal(&mut ctx, &mut lighthouse);
@@ -293,10 +299,13 @@ mod tests {
         let registry = MarginfiAccountRegistry::new(Some(marginfi_account));
         let mut manager =
             MarginfiFlashloanManager::new(&cfg, true, false, Arc::clone(&rpc), registry);
-        manager.adopt_preparation(MarginfiFlashloanPreparation {
+        manager.adopt_preparation(&FlashloanPreparation {
+            protocol: FlashloanProtocol::Marginfi,
             account: marginfi_account,
             created: false,
         });
+        let mut flashloan = FlashloanRegistry::new();
+        flashloan.register(Box::new(manager));
 
         let base_mint = solana_sdk::pubkey!("So11111111111111111111111111111111111111112");
         let opportunity = SwapOpportunity {
@@ -313,7 +322,7 @@ mod tests {
         ctx.compute_unit_limit = 200_000;
         ctx.variant = Some(&mut variant);
         ctx.opportunity = Some(&opportunity);
-        ctx.flashloan_manager = Some(&manager);
+        ctx.flashloan = Some(&flashloan);
 
         FlashloanDecorator
             .apply(&mut bundle, &mut ctx)
@@ -322,7 +331,7 @@ mod tests {
 
         ctx.variant = None;
         ctx.opportunity = None;
-        ctx.flashloan_manager = None;
+        ctx.flashloan = None;
 
         assert!(ctx.flashloan_metadata.is_some());
         let metadata = ctx.flashloan_metadata.clone().unwrap();
@@ -1,5 +1,6 @@
 use crate::engine::EngineResult;
-use crate::instructions::guards::lighthouse::token_amount_guard;
+use crate::engine::runtime::lighthouse::LighthouseRuntime;
+use crate::instructions::guards::lighthouse::build_profit_guard;
 use async_trait::async_trait;
 
 use super::{AssemblyContext, InstructionDecorator};
@@ -15,7 +16,13 @@ impl InstructionDecorator for ProfitGuardDecorator {
         context: &mut AssemblyContext<'_>,
     ) -> EngineResult<()> {
         let guard_required = context.guard_required;
-        if guard_required == 0 {
+        // 闪电贷手续费按 base mint 计价，不能与 lamports 阈值一起换算
+        let flashloan_fee = context
+            .flashloan_metadata
+            .as_ref()
+            .map(|metadata| metadata.fee)
+            .unwrap_or(0);
+        if guard_required == 0 && flashloan_fee == 0 {
             return Ok(());
         }
 
@@ -28,7 +35,7 @@ impl InstructionDecorator for ProfitGuardDecorator {
         };
 
         let Some(required_amount) = lighthouse
-            .guard_amount_for(base_mint, guard_required)
+            .guard_amount_for(base_mint, guard_required, flashloan_fee)
             .await?
         else {
             return Ok(());
@@ -38,11 +45,29 @@ impl InstructionDecorator for ProfitGuardDecorator {
         }
 
         let payer = context.identity.pubkey;
-        let token_account =
-            spl_associated_token_account::get_associated_token_address(&payer, base_mint);
-        let memory_id = lighthouse.next_memory_id();
-        let guard = token_amount_guard(payer, token_account, memory_id, required_amount);
-        bundle.insert_profit_guard(guard);
+        let amount_in = context.opportunity.map(|opportunity| opportunity.amount_in);
+        let tip_lamports = context
+            .jito_tip_plan
+            .as_ref()
+            .map(|plan| plan.lamports)
+            .unwrap_or(0);
+        let rent_lamports = LighthouseRuntime::expected_rent_lamports(
+            &payer,
+            bundle.pre.iter().chain(&bundle.main).chain(&bundle.post),
+        );
+        let targets = lighthouse.guard_targets(
+            &payer,
+            base_mint,
+            required_amount,
+            amount_in,
+            tip_lamports,
+            rent_lamports,
+        );
+        if targets.is_empty() {
+            return Ok(());
+        }
+        let memory_ids = lighthouse.allocate_memory_ids(targets.len());
+        bundle.insert_profit_guard(build_profit_guard(payer, &targets, &memory_ids));
         Ok(())
     }
 }
@@ -1,12 +1,12 @@
 use std::net::IpAddr;
 use std::sync::Arc;
+use std::time::Instant;
 
 use anyhow::anyhow;
 use solana_client::client_error::{ClientError, ClientErrorKind};
 use solana_client::nonblocking::rpc_client::RpcClient;
 use solana_sdk::hash::Hash;
 use solana_sdk::instruction::Instruction;
-use solana_sdk::message::v0::Message as V0Message;
 use solana_sdk::message::{AddressLookupTableAccount, VersionedMessage};
 use solana_sdk::pubkey::Pubkey;
 use solana_sdk::signature::Keypair;
@@ -15,6 +15,7 @@ use tracing::{debug, info, warn};
 use yellowstone_grpc_proto::geyser::CommitmentLevel;
 
 use crate::cache::AltCache;
+use crate::monitoring::events;
 use crate::network::{
     IpAllocator, IpBoundClientPool, IpLeaseMode, IpLeaseOutcome, IpTaskKind, RpcClientFactoryFn,
 };
@@ -23,8 +24,12 @@ use crate::rpc::yellowstone::YellowstoneBlockhashClient;
 
 use super::COMPUTE_BUDGET_PROGRAM_ID;
 use super::aggregator::SwapInstructionsVariant;
+use super::alt_manager::OwnedLookupTables;
+use super::compute_units::MEMO_PROGRAM_ID;
 use super::error::{EngineError, EngineResult};
 use super::identity::EngineIdentity;
+use super::nonce::NonceLease;
+use super::packing::{PackError, PackFallback, PackOutcome, pack_message};
 use super::types::JitoTipPlan;
 use crate::engine::assembly::decorators::GuardStrategy;
 
@@ -98,6 +103,21 @@ pub struct PreparedTransaction {
     pub instructions: Vec<Instruction>,
     pub lookup_accounts: Vec<AddressLookupTableAccount>,
     pub jito_tip_plan: Option<JitoTipPlan>,
+    /// 超限拆分后的第二笔交易，需与主交易一起作为 Jito bundle 提交。
+    pub bundle_tail: Option<VersionedTransaction>,
+    /// durable nonce 模式下使用的 nonce，`blockhash` 字段即为其 nonce 值。
+    pub durable_nonce: Option<NonceLease>,
+    /// 获取 blockhash 的时刻，用于统计提交时的 blockhash 时效；durable nonce 为 None。
+    pub blockhash_fetched_at: Option<Instant>,
+}
+
+/// 单次构建的附加选项。
+#[derive(Clone, Debug, Default)]
+pub struct SequenceOptions {
+    /// 超限时允许拆分为两笔 Jito bundle 交易
+    pub allow_bundle_split: bool,
+    /// 使用 durable nonce 代替最新 blockhash
+    pub durable_nonce: Option<NonceLease>,
 }
 
 #[derive(Clone)]
@@ -109,6 +129,8 @@ pub struct TransactionBuilder {
     ip_allocator: Arc<IpAllocator>,
     rpc_pool: Option<Arc<IpBoundClientPool<RpcClientFactoryFn>>>,
     force_rpc_blockhash: bool,
+    owned_tables: Option<OwnedLookupTables>,
+    flashloan_programs: Vec<Pubkey>,
 }
 
 impl TransactionBuilder {
@@ -157,9 +179,23 @@ impl TransactionBuilder {
             ip_allocator,
             rpc_pool,
             force_rpc_blockhash,
+            owned_tables: None,
+            flashloan_programs: Vec::new(),
         }
     }
 
+    /// 注册自有 ALT，编译消息时优先于路线自带的查找表。
+    pub fn with_owned_lookup_tables(mut self, tables: OwnedLookupTables) -> Self {
+        self.owned_tables = Some(tables);
+        self
+    }
+
+    /// 登记已启用闪电贷来源的程序，bundle 拆分时不会把借与还分进两笔交易。
+    pub fn with_flashloan_programs(mut self, programs: Vec<Pubkey>) -> Self {
+        self.flashloan_programs = programs;
+        self
+    }
+
     pub async fn build_with_sequence(
         &self,
         identity: &EngineIdentity,
@@ -167,6 +203,7 @@ impl TransactionBuilder {
         sequence: Vec<Instruction>,
         tip_lamports: u64,
         jito_tip_plan: Option<JitoTipPlan>,
+        options: SequenceOptions,
     ) -> EngineResult<PreparedTransaction> {
         self.build_with_options(
             identity,
@@ -174,6 +211,7 @@ impl TransactionBuilder {
             Some(sequence),
             tip_lamports,
             jito_tip_plan,
+            options,
         )
         .await
     }
@@ -185,6 +223,7 @@ impl TransactionBuilder {
         override_sequence: Option<Vec<Instruction>>,
         tip_lamports: u64,
         jito_tip_plan: Option<JitoTipPlan>,
+        options: SequenceOptions,
     ) -> EngineResult<PreparedTransaction> {
         let lease = self
             .ip_allocator
@@ -203,6 +242,7 @@ impl TransactionBuilder {
                 override_sequence,
                 tip_lamports,
                 jito_tip_plan.clone(),
+                options,
                 &rpc,
             )
             .await;
@@ -218,6 +258,7 @@ impl TransactionBuilder {
         result
     }
 
+    #[allow(clippy::too_many_arguments)]
     async fn build_internal(
         &self,
         identity: &EngineIdentity,
@@ -225,6 +266,7 @@ impl TransactionBuilder {
         override_sequence: Option<Vec<Instruction>>,
         tip_lamports: u64,
         jito_tip_plan: Option<JitoTipPlan>,
+        options: SequenceOptions,
         rpc: &Arc<RpcClient>,
     ) -> EngineResult<PreparedTransaction> {
         let lookup_accounts = if let Some(resolved) = Self::resolved_tables(instructions) {
@@ -233,8 +275,18 @@ impl TransactionBuilder {
             self.load_lookup_tables(rpc, instructions.address_lookup_table_addresses())
                 .await?
         };
+        let lookup_accounts = match &self.owned_tables {
+            Some(owned) => owned.prepend_to(lookup_accounts),
+            None => lookup_accounts,
+        };
 
-        let snapshot = if !self.force_rpc_blockhash {
+        let snapshot = if let Some(nonce) = &options.durable_nonce {
+            BlockhashSnapshot {
+                blockhash: nonce.nonce,
+                slot: None,
+                last_valid_block_height: None,
+            }
+        } else if !self.force_rpc_blockhash {
             if let Some(meta) = instructions.blockhash_with_metadata() {
                 BlockhashSnapshot {
                     blockhash: meta.blockhash,
@@ -247,6 +299,7 @@ impl TransactionBuilder {
         } else {
             self.latest_blockhash(rpc).await?
         };
+        let blockhash_fetched_at = options.durable_nonce.is_none().then(Instant::now);
         let blockhash = snapshot.blockhash;
         let slot = snapshot
             .slot
@@ -266,6 +319,12 @@ impl TransactionBuilder {
             instructions.push(Self::build_memo_instruction(memo));
         }
 
+        if let Some(nonce) = &options.durable_nonce {
+            instructions.insert(0, nonce.advance_instruction());
+            // 闪电贷指令记录的是交易内绝对下标，前插 advance 指令后需要整体后移
+            crate::instructions::flashloan::shift_instruction_indices(&mut instructions, 1);
+        }
+
         if tip_lamports > 0 && !self.force_rpc_blockhash {
             debug!(
                 target: "engine::builder",
@@ -274,8 +333,30 @@ impl TransactionBuilder {
             );
         }
 
-        let message =
-            compile_message(&identity.pubkey, &instructions, &lookup_accounts, blockhash)?;
+        let packed = pack_message(
+            &identity.pubkey,
+            instructions,
+            &lookup_accounts,
+            blockhash,
+            // 拆出的第二笔没有 AdvanceNonceAccount，无法复用 nonce
+            options.allow_bundle_split && options.durable_nonce.is_none(),
+            &self.flashloan_programs,
+        )
+        .map_err(|err| {
+            if let PackError::Oversized {
+                footprint,
+                fallbacks,
+            } = &err
+            {
+                events::transaction_oversized(footprint.as_ref(), fallbacks);
+            }
+            EngineError::Transaction(anyhow!(err))
+        })?;
+        report_pack_fallbacks(&packed);
+        let PackOutcome { head, tail, .. } = packed;
+        let instructions = head.instructions;
+        let lookup_accounts = head.lookup_accounts;
+        let message = head.message;
         let signer_slice: Vec<_> = message
             .account_keys
             .iter()
@@ -293,6 +374,15 @@ impl TransactionBuilder {
         let signer = identity.signer.clone();
         let tx = VersionedTransaction::try_new(versioned, &[signer.as_ref()])
             .map_err(|err| EngineError::Transaction(anyhow!(err)))?;
+        let bundle_tail = tail
+            .map(|tail| {
+                VersionedTransaction::try_new(
+                    VersionedMessage::V0(tail.message),
+                    &[signer.as_ref()],
+                )
+                .map_err(|err| EngineError::Transaction(anyhow!(err)))
+            })
+            .transpose()?;
 
         Ok(PreparedTransaction {
             transaction: tx,
@@ -309,6 +399,9 @@ impl TransactionBuilder {
             instructions,
             lookup_accounts,
             jito_tip_plan,
+            bundle_tail,
+            durable_nonce: options.durable_nonce,
+            blockhash_fetched_at,
         })
     }
 
@@ -336,7 +429,7 @@ impl TransactionBuilder {
     }
     fn build_memo_instruction(memo: &str) -> Instruction {
         Instruction {
-            program_id: solana_sdk::pubkey!("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr"),
+            program_id: MEMO_PROGRAM_ID,
             accounts: Vec::new(),
             data: memo.as_bytes().to_vec(),
         }
@@ -404,14 +497,29 @@ impl TransactionBuilder {
     }
 }
 
-fn compile_message(
-    payer: &Pubkey,
-    instructions: &[Instruction],
-    tables: &[AddressLookupTableAccount],
-    blockhash: Hash,
-) -> EngineResult<V0Message> {
-    V0Message::try_compile(payer, instructions, tables, blockhash)
-        .map_err(|err| EngineError::Transaction(anyhow!(err)))
+fn report_pack_fallbacks(outcome: &PackOutcome) {
+    for fallback in &outcome.fallbacks {
+        match fallback {
+            PackFallback::AltCover => events::transaction_alt_cover_applied(
+                outcome.original.as_ref(),
+                &outcome.head.footprint,
+                outcome.head.lookup_accounts.len(),
+            ),
+            PackFallback::StripRedundant => events::transaction_redundant_stripped(
+                outcome.original.as_ref(),
+                &outcome.head.footprint,
+            ),
+            PackFallback::BundleSplit => {
+                if let Some(tail) = &outcome.tail {
+                    events::transaction_bundle_split(
+                        outcome.original.as_ref(),
+                        &outcome.head.footprint,
+                        &tail.footprint,
+                    );
+                }
+            }
+        }
+    }
 }
 
 fn classify_builder_error(err: &EngineError) -> Option<IpLeaseOutcome> {
@@ -0,0 +1,323 @@
+use std::collections::VecDeque;
+use std::collections::hash_map::DefaultHasher;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+use std::time::Duration;
+
+use dashmap::DashMap;
+use serde_json::{Value, json};
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_client::rpc_request::RpcRequest;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use tracing::debug;
+
+use crate::config::ComputeUnitTuningConfig;
+use crate::instructions::compute_budget::COMPUTE_BUDGET_PROGRAM_ID;
+use crate::instructions::guards::lighthouse::program::LIGHTHOUSE_PROGRAM_ID;
+use crate::monitoring::events;
+
+pub(crate) const MEMO_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr");
+const LANDED_PROBE_ATTEMPTS: usize = 3;
+
+/// 路线指纹：由参与交换的程序与非签名账户哈希得到，忽略 CU、tip、memo、守护等附加指令。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RouteKey(u64);
+
+impl RouteKey {
+    pub fn from_instructions<'a, I>(instructions: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = &'a Instruction>,
+    {
+        let mut hasher = DefaultHasher::new();
+        let mut matched = false;
+        for ix in instructions {
+            if is_auxiliary_program(&ix.program_id) {
+                continue;
+            }
+            matched = true;
+            ix.program_id.hash(&mut hasher);
+            for meta in ix.accounts.iter().filter(|meta| !meta.is_signer) {
+                meta.pubkey.hash(&mut hasher);
+            }
+        }
+        matched.then(|| Self(hasher.finish()))
+    }
+}
+
+impl fmt::Display for RouteKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:016x}", self.0)
+    }
+}
+
+fn is_auxiliary_program(program_id: &Pubkey) -> bool {
+    *program_id == COMPUTE_BUDGET_PROGRAM_ID
+        || *program_id == MEMO_PROGRAM_ID
+        || *program_id == LIGHTHOUSE_PROGRAM_ID
+        || *program_id == solana_system_interface::program::ID
+        || *program_id == spl_associated_token_account::id()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComputeUnitSampleSource {
+    Simulation,
+    Landed,
+}
+
+impl ComputeUnitSampleSource {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ComputeUnitSampleSource::Simulation => "simulation",
+            ComputeUnitSampleSource::Landed => "landed",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct TuningParams {
+    window: usize,
+    percentile: f64,
+    headroom_ratio: f64,
+    min_samples: usize,
+    min_limit: u32,
+    max_limit: u32,
+    max_routes: usize,
+    landed_probe_delay: Duration,
+}
+
+impl TuningParams {
+    fn from_config(config: &ComputeUnitTuningConfig) -> Self {
+        let min_limit = config.min_limit.max(1);
+        Self {
+            window: config.window.max(1),
+            percentile: config.percentile.clamp(0.0, 100.0),
+            headroom_ratio: config.headroom_ratio.max(0.0),
+            min_samples: config.min_samples.max(1),
+            min_limit,
+            max_limit: config.max_limit.max(min_limit),
+            max_routes: config.max_routes.max(1),
+            landed_probe_delay: Duration::from_millis(config.landed_probe_delay_ms),
+        }
+    }
+}
+
+/// 按路线记录实际 CU 消耗，并给出滚动高分位 + 余量的 compute unit limit。
+#[derive(Clone)]
+pub struct ComputeUnitEstimator {
+    params: Arc<TuningParams>,
+    samples: Arc<DashMap<RouteKey, VecDeque<u32>>>,
+    rpc: Option<Arc<RpcClient>>,
+}
+
+impl ComputeUnitEstimator {
+    pub fn new(config: &ComputeUnitTuningConfig) -> Self {
+        Self {
+            params: Arc::new(TuningParams::from_config(config)),
+            samples: Arc::new(DashMap::new()),
+            rpc: None,
+        }
+    }
+
+    /// 提供 RPC 后，真实提交的交易会在延迟后回查 `computeUnitsConsumed`。
+    pub fn with_landed_probe(mut self, rpc: Arc<RpcClient>) -> Self {
+        if !self.params.landed_probe_delay.is_zero() {
+            self.rpc = Some(rpc);
+        }
+        self
+    }
+
+    pub fn record(&self, key: RouteKey, units: u64, source: ComputeUnitSampleSource) {
+        if units == 0 {
+            return;
+        }
+        let units = units.min(u32::MAX as u64) as u32;
+        if !self.samples.contains_key(&key) && self.samples.len() >= self.params.max_routes {
+            return;
+        }
+        let mut entry = self.samples.entry(key).or_default();
+        if entry.len() >= self.params.window {
+            entry.pop_front();
+        }
+        entry.push_back(units);
+        let count = entry.len();
+        drop(entry);
+        events::compute_unit_sample_recorded(&key.to_string(), source.as_str(), units, count);
+    }
+
+    /// 样本不足时返回 None，调用方应继续使用静态预算。
+    pub fn estimate(&self, key: RouteKey) -> Option<u32> {
+        let entry = self.samples.get(&key)?;
+        if entry.len() < self.params.min_samples {
+            return None;
+        }
+        let mut sorted: Vec<u32> = entry.iter().copied().collect();
+        drop(entry);
+        sorted.sort_unstable();
+        let observed = percentile(&sorted, self.params.percentile)?;
+        let padded = (observed as f64 * (1.0 + self.params.headroom_ratio)).ceil();
+        let limit = padded.min(u32::MAX as f64) as u32;
+        Some(limit.clamp(self.params.min_limit, self.params.max_limit))
+    }
+
+    /// 后台等待交易确认后读取实际消耗；查询失败或交易报错时静默放弃。
+    pub fn track_landed(&self, signature: Signature, key: RouteKey) {
+        let Some(rpc) = self.rpc.clone() else {
+            return;
+        };
+        let estimator = self.clone();
+        let delay = self.params.landed_probe_delay;
+        tokio::spawn(async move {
+            for _ in 0..LANDED_PROBE_ATTEMPTS {
+                tokio::time::sleep(delay).await;
+                match fetch_landed_units(&rpc, &signature).await {
+                    Ok(LandedUnits::Consumed(units)) => {
+                        estimator.record(key, units, ComputeUnitSampleSource::Landed);
+                        return;
+                    }
+                    // 失败交易的消耗不代表成功路径，不计入样本且不再重试
+                    Ok(LandedUnits::Failed) => return,
+                    Ok(LandedUnits::Pending) => continue,
+                    Err(err) => {
+                        debug!(
+                            target: "engine::compute_units",
+                            signature = %signature,
+                            error = %err,
+                            "查询落地交易 CU 消耗失败"
+                        );
+                        return;
+                    }
+                }
+            }
+        });
+    }
+}
+
+/// 落地交易的 CU 查询结果。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LandedUnits {
+    Consumed(u64),
+    /// 交易执行报错
+    Failed,
+    /// 尚未查询到交易或缺少消耗字段
+    Pending,
+}
+
+async fn fetch_landed_units(
+    rpc: &RpcClient,
+    signature: &Signature,
+) -> Result<LandedUnits, solana_client::client_error::ClientError> {
+    let params = json!([
+        signature.to_string(),
+        {
+            "encoding": "base64",
+            "commitment": "confirmed",
+            "maxSupportedTransactionVersion": 0,
+        }
+    ]);
+    let value: Value = rpc.send(RpcRequest::GetTransaction, params).await?;
+    let Some(meta) = value.get("meta") else {
+        return Ok(LandedUnits::Pending);
+    };
+    if meta.get("err").is_some_and(|err| !err.is_null()) {
+        return Ok(LandedUnits::Failed);
+    }
+    Ok(meta
+        .get("computeUnitsConsumed")
+        .and_then(Value::as_u64)
+        .map_or(LandedUnits::Pending, LandedUnits::Consumed))
+}
+
+fn percentile(sorted: &[u32], pct: f64) -> Option<u32> {
+    if sorted.is_empty() {
+        return None;
+    }
+    let rank = ((pct / 100.0) * (sorted.len() - 1) as f64).ceil() as usize;
+    sorted.get(rank.min(sorted.len() - 1)).copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instructions::compute_budget::compute_unit_limit_instruction;
+    use solana_sdk::instruction::AccountMeta;
+
+    fn swap_ix(pool: Pubkey) -> Instruction {
+        Instruction {
+            program_id: Pubkey::new_unique(),
+            accounts: vec![
+                AccountMeta::new(Pubkey::new_unique(), true),
+                AccountMeta::new(pool, false),
+            ],
+            data: vec![1, 2, 3],
+        }
+    }
+
+    fn config() -> ComputeUnitTuningConfig {
+        ComputeUnitTuningConfig {
+            enable: true,
+            window: 4,
+            percentile: 100.0,
+            headroom_ratio: 0.1,
+            min_samples: 2,
+            min_limit: 1_000,
+            max_limit: 500_000,
+            ..ComputeUnitTuningConfig::default()
+        }
+    }
+
+    #[test]
+    fn route_key_ignores_compute_budget_and_signers() {
+        let swap = swap_ix(Pubkey::new_unique());
+        let mut other_signer = swap.clone();
+        other_signer.accounts[0].pubkey = Pubkey::new_unique();
+
+        let plain = RouteKey::from_instructions([&swap]).unwrap();
+        let with_budget =
+            RouteKey::from_instructions([&compute_unit_limit_instruction(200_000), &swap]).unwrap();
+        let resigned = RouteKey::from_instructions([&other_signer]).unwrap();
+
+        assert_eq!(plain, with_budget);
+        assert_eq!(plain, resigned);
+        assert!(RouteKey::from_instructions([&compute_unit_limit_instruction(1)]).is_none());
+    }
+
+    #[test]
+    fn estimate_requires_min_samples_and_applies_headroom() {
+        let estimator = ComputeUnitEstimator::new(&config());
+        let key = RouteKey(7);
+        estimator.record(key, 100_000, ComputeUnitSampleSource::Simulation);
+        assert_eq!(estimator.estimate(key), None);
+
+        estimator.record(key, 120_000, ComputeUnitSampleSource::Landed);
+        assert_eq!(estimator.estimate(key), Some(132_000));
+    }
+
+    #[test]
+    fn estimate_uses_rolling_window_and_clamps() {
+        let estimator = ComputeUnitEstimator::new(&config());
+        let key = RouteKey(9);
+        estimator.record(key, 900_000, ComputeUnitSampleSource::Simulation);
+        for _ in 0..4 {
+            estimator.record(key, 10_000, ComputeUnitSampleSource::Simulation);
+        }
+        assert_eq!(estimator.estimate(key), Some(11_000));
+
+        let capped = RouteKey(10);
+        estimator.record(capped, 800_000, ComputeUnitSampleSource::Simulation);
+        estimator.record(capped, 800_000, ComputeUnitSampleSource::Simulation);
+        assert_eq!(estimator.estimate(capped), Some(500_000));
+    }
+
+    #[test]
+    fn percentile_picks_upper_rank() {
+        let sorted = [10, 20, 30, 40, 50];
+        assert_eq!(percentile(&sorted, 0.0), Some(10));
+        assert_eq!(percentile(&sorted, 90.0), Some(50));
+        assert_eq!(percentile(&sorted, 50.0), Some(30));
+        assert_eq!(percentile(&[], 95.0), None);
+    }
+}
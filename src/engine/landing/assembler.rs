@@ -12,8 +12,11 @@ use crate::engine::assembly::{
 use crate::engine::builder::PreparedTransaction;
 use crate::engine::landing::execution_plan::ExecutionPlan;
 use crate::engine::landing::profile::{GuardBudgetKind, LanderKind, LandingProfile, TipStrategy};
-use crate::engine::plugins::flashloan::{FlashloanMetadata, MarginfiFlashloanManager};
-use crate::engine::{EngineError, LighthouseRuntime, TransactionBuilder};
+use crate::engine::plugins::flashloan::{FlashloanMetadata, FlashloanRegistry};
+use crate::engine::{
+    ComputeUnitEstimator, EngineError, LighthouseRuntime, NonceLease, SequenceOptions,
+    TransactionBuilder,
+};
 
 #[derive(Debug)]
 pub enum LandingAssemblyError {
@@ -36,6 +39,9 @@ impl From<EngineError> for LandingAssemblyError {
 
 pub type LandingAssemblyResult<T> = Result<T, LandingAssemblyError>;
 
+/// 超限拆分出的第二笔 bundle 交易额外支付的签名费。
+const BUNDLE_TAIL_SIGNATURE_FEE_LAMPORTS: u64 = 5_000;
+
 #[derive(Debug, Clone, Copy)]
 pub struct TipComputation {
     pub kind: TipComputationKind,
@@ -67,15 +73,17 @@ pub struct LandingPlanEntry {
 pub struct LandingAssemblyContext<'a> {
     pub identity: &'a crate::engine::EngineIdentity,
     pub tx_builder: &'a TransactionBuilder,
-    pub flashloan: Option<&'a MarginfiFlashloanManager>,
+    pub flashloan: Option<&'a FlashloanRegistry>,
     pub lighthouse: &'a mut LighthouseRuntime,
+    pub compute_units: Option<&'a ComputeUnitEstimator>,
+    pub durable_nonce: Option<&'a NonceLease>,
 }
 
 impl<'a> LandingAssemblyContext<'a> {
     pub fn new(
         identity: &'a crate::engine::EngineIdentity,
         tx_builder: &'a TransactionBuilder,
-        flashloan: Option<&'a MarginfiFlashloanManager>,
+        flashloan: Option<&'a FlashloanRegistry>,
         lighthouse: &'a mut LighthouseRuntime,
     ) -> Self {
         Self {
@@ -83,8 +91,20 @@ impl<'a> LandingAssemblyContext<'a> {
             tx_builder,
             flashloan,
             lighthouse,
+            compute_units: None,
+            durable_nonce: None,
         }
     }
+
+    pub fn with_compute_units(mut self, estimator: Option<&'a ComputeUnitEstimator>) -> Self {
+        self.compute_units = estimator;
+        self
+    }
+
+    pub fn with_durable_nonce(mut self, nonce: Option<&'a NonceLease>) -> Self {
+        self.durable_nonce = nonce;
+        self
+    }
 }
 
 #[async_trait]
@@ -116,15 +136,13 @@ impl DefaultLandingAssembler {
             }
         }
     }
-}
 
-#[async_trait]
-impl LandingAssembler for DefaultLandingAssembler {
-    async fn assemble_landing(
+    async fn assemble_with_guard(
         &self,
         ctx: &mut LandingAssemblyContext<'_>,
         profile: &LandingProfile,
         plan: &ExecutionPlan,
+        extra_guard_lamports: u64,
     ) -> LandingAssemblyResult<LandingPlanEntry> {
         let mut variant = plan.swap_variant.clone();
 
@@ -179,7 +197,9 @@ impl LandingAssembler for DefaultLandingAssembler {
         assembly_ctx.base_mint = Some(&plan.base_mint);
         assembly_ctx.compute_unit_limit = plan.compute_unit_limit;
         assembly_ctx.compute_unit_price = compute_unit_price;
-        assembly_ctx.guard_required = plan.base_guard_lamports;
+        assembly_ctx.guard_required = plan
+            .base_guard_lamports
+            .saturating_add(extra_guard_lamports);
         assembly_ctx.guard_strategy = guard_strategy;
         assembly_ctx.prioritization_fee = prioritization_fee;
         assembly_ctx.tip_lamports = tip.lamports;
@@ -191,7 +211,8 @@ impl LandingAssembler for DefaultLandingAssembler {
         assembly_ctx.jito_tip_plan = tip_plan.clone();
         assembly_ctx.variant = Some(&mut variant);
         assembly_ctx.opportunity = Some(&plan.opportunity);
-        assembly_ctx.flashloan_manager = ctx.flashloan;
+        assembly_ctx.flashloan = ctx.flashloan;
+        assembly_ctx.compute_unit_estimator = ctx.compute_units;
 
         attach_lighthouse(&mut assembly_ctx, ctx.lighthouse);
 
@@ -208,6 +229,7 @@ impl LandingAssembler for DefaultLandingAssembler {
             .map_err(LandingAssemblyError::Engine)?;
 
         let guard_lamports = assembly_ctx.guard_required;
+        let prioritization_fee = assembly_ctx.prioritization_fee;
         let flashloan_metadata = assembly_ctx.flashloan_metadata.clone();
 
         drop(assembly_ctx);
@@ -222,6 +244,10 @@ impl LandingAssembler for DefaultLandingAssembler {
                 final_instructions,
                 tip.lamports,
                 tip_plan.clone(),
+                SequenceOptions {
+                    allow_bundle_split: matches!(profile.lander_kind, LanderKind::Jito),
+                    durable_nonce: ctx.durable_nonce.cloned(),
+                },
             )
             .await
             .map_err(LandingAssemblyError::Engine)?;
@@ -251,6 +277,24 @@ impl LandingAssembler for DefaultLandingAssembler {
     }
 }
 
+#[async_trait]
+impl LandingAssembler for DefaultLandingAssembler {
+    async fn assemble_landing(
+        &self,
+        ctx: &mut LandingAssemblyContext<'_>,
+        profile: &LandingProfile,
+        plan: &ExecutionPlan,
+    ) -> LandingAssemblyResult<LandingPlanEntry> {
+        let entry = self.assemble_with_guard(ctx, profile, plan, 0).await?;
+        if entry.prepared.bundle_tail.is_none() {
+            return Ok(entry);
+        }
+        // 拆分后第二笔交易还要支付一次签名费，按新的守护预算重新装配
+        self.assemble_with_guard(ctx, profile, plan, BUNDLE_TAIL_SIGNATURE_FEE_LAMPORTS)
+            .await
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
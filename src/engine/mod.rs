@@ -1,11 +1,15 @@
 mod aggregator;
+mod alt_manager;
 pub mod assembly;
 mod builder;
+mod compute_units;
 mod context;
 mod error;
 mod identity;
 pub mod landing;
 pub mod multi_leg;
+mod nonce;
+mod packing;
 mod planner;
 pub mod plugins;
 mod precheck;
@@ -22,10 +26,16 @@ pub mod ultra;
 
 pub use crate::instructions::compute_budget::COMPUTE_BUDGET_PROGRAM_ID;
 pub use aggregator::{MultiLegInstructions, SwapInstructionsVariant};
-pub use builder::{BuilderConfig, TransactionBuilder};
+pub use alt_manager::{
+    AltGcAction, AltGcEntry, AltManager, AltSyncOutcome, OwnedLookupTables, OwnedTableInfo,
+};
+pub use builder::{BuilderConfig, SequenceOptions, TransactionBuilder};
+pub use compute_units::{ComputeUnitEstimator, ComputeUnitSampleSource, RouteKey};
 pub use context::{Action, StrategyContext, StrategyDecision};
 pub use error::{EngineError, EngineResult};
 pub use identity::EngineIdentity;
+pub use nonce::{NonceLease, NoncePool};
+pub use packing::{MessageFootprint, PackFallback};
 pub use planner::{DispatchPlan, DispatchStrategy, TxVariant, TxVariantPlanner, VariantId};
 pub use precheck::AccountPrechecker;
 pub use profit::{ProfitConfig, ProfitEvaluator, TipConfig};
@@ -36,9 +46,10 @@ pub(crate) use runtime::LighthouseRuntime;
 pub use runtime::MultiLegEngineContext;
 pub(crate) use runtime::strategy::MintSchedule;
 pub use runtime::strategy::{
-    ConsoleSummarySettings, EngineSettings, LighthouseSettings, SolPriceFeedSettings,
-    StrategyEngine,
+    BlindPriceGuard, ConsoleSummarySettings, EngineReload, EngineSettings, GuardSettlement,
+    LighthouseSettings, SolPriceFeedSettings, StrategyEngine,
 };
+pub use runtime::wallets::PoolWallet;
 pub use scheduler::Scheduler;
 pub use swap_preparer::{ComputeUnitPriceMode, SwapPreparer};
 #[allow(unused_imports)]
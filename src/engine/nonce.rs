@@ -0,0 +1,347 @@
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{Context, Result, anyhow};
+use parking_lot::Mutex;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::hash::Hash;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_system_interface::instruction as system_instruction;
+use tracing::{info, warn};
+
+use crate::config::DurableNonceConfig;
+use crate::engine::EngineIdentity;
+use crate::monitoring::events;
+use crate::tools::sol::send_transaction;
+
+/// nonce 账户数据长度：4 字节版本 + 4 字节状态 + authority + durable nonce + fee calculator。
+pub const NONCE_ACCOUNT_LENGTH: usize = 80;
+const AUTHORITY_OFFSET: usize = 8;
+const NONCE_OFFSET: usize = 40;
+const STATE_INITIALIZED: u32 = 1;
+const REFRESH_ATTEMPTS: usize = 3;
+
+/// 从池中借出的一个 nonce 账户，同一机会的所有变体共享它。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NonceLease {
+    pub account: Pubkey,
+    pub authority: Pubkey,
+    pub nonce: Hash,
+}
+
+impl NonceLease {
+    /// `AdvanceNonceAccount` 必须是交易的第一条指令。
+    pub fn advance_instruction(&self) -> Instruction {
+        system_instruction::advance_nonce_account(&self.account, &self.authority)
+    }
+}
+
+/// 解析 nonce 账户，返回 (authority, 当前 nonce)；未初始化或数据不完整时返回 None。
+pub fn parse_nonce_account(data: &[u8]) -> Option<(Pubkey, Hash)> {
+    if data.len() < NONCE_ACCOUNT_LENGTH {
+        return None;
+    }
+    let state = u32::from_le_bytes(data[4..8].try_into().ok()?);
+    if state != STATE_INITIALIZED {
+        return None;
+    }
+    let authority = Pubkey::try_from(&data[AUTHORITY_OFFSET..AUTHORITY_OFFSET + 32]).ok()?;
+    let nonce = Hash::new_from_array(data[NONCE_OFFSET..NONCE_OFFSET + 32].try_into().ok()?);
+    Some((authority, nonce))
+}
+
+/// 由钱包派生的 nonce 账户地址，种子为 `{seed_prefix}-{index}`。
+pub fn derive_nonce_address(base: &Pubkey, seed_prefix: &str, index: usize) -> Result<Pubkey> {
+    let seed = nonce_seed(seed_prefix, index);
+    Pubkey::create_with_seed(base, &seed, &solana_system_interface::program::ID)
+        .map_err(|err| anyhow!("派生 nonce 账户失败 seed={seed}: {err}"))
+}
+
+fn nonce_seed(seed_prefix: &str, index: usize) -> String {
+    format!("{seed_prefix}-{index}")
+}
+
+/// durable nonce 池：每个机会借出一个 nonce，提交结束后回查链上最新值再放回。
+#[derive(Clone)]
+pub struct NoncePool {
+    rpc: Arc<RpcClient>,
+    available: Arc<Mutex<VecDeque<NonceLease>>>,
+    capacity: usize,
+    refresh_delay: Duration,
+}
+
+impl NoncePool {
+    fn from_leases(rpc: Arc<RpcClient>, leases: Vec<NonceLease>, refresh_delay: Duration) -> Self {
+        Self {
+            rpc,
+            capacity: leases.len(),
+            available: Arc::new(Mutex::new(leases.into())),
+            refresh_delay,
+        }
+    }
+
+    /// 加载（必要时创建）配置的 nonce 账户，authority 必须是当前钱包。
+    pub async fn initialize(
+        rpc: Arc<RpcClient>,
+        identity: &EngineIdentity,
+        config: &DurableNonceConfig,
+    ) -> Result<Self> {
+        let addresses = (0..config.pool_size)
+            .map(|index| derive_nonce_address(&identity.pubkey, &config.seed_prefix, index))
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut missing = Vec::new();
+        let mut leases = Vec::with_capacity(addresses.len());
+        let accounts = rpc
+            .get_multiple_accounts(&addresses)
+            .await
+            .map_err(|err| anyhow!("获取 nonce 账户失败: {err}"))?;
+        for (index, (address, account)) in addresses.iter().zip(accounts).enumerate() {
+            match account.and_then(|account| parse_nonce_account(&account.data)) {
+                Some((authority, nonce)) if authority == identity.pubkey => {
+                    leases.push(NonceLease {
+                        account: *address,
+                        authority,
+                        nonce,
+                    });
+                }
+                Some((authority, _)) => {
+                    warn!(
+                        target: "engine::nonce",
+                        account = %address,
+                        authority = %authority,
+                        "nonce 账户 authority 与钱包不一致，已忽略"
+                    );
+                }
+                None => missing.push(index),
+            }
+        }
+
+        if !missing.is_empty() {
+            if !config.auto_create {
+                warn!(
+                    target: "engine::nonce",
+                    missing = missing.len(),
+                    "部分 nonce 账户不存在，auto_create 未开启，跳过创建"
+                );
+            } else {
+                let rent = rpc
+                    .get_minimum_balance_for_rent_exemption(NONCE_ACCOUNT_LENGTH)
+                    .await
+                    .map_err(|err| anyhow!("获取 nonce 账户租金失败: {err}"))?;
+                for index in missing {
+                    let lease = create_nonce_account(&rpc, identity, config, index, rent).await?;
+                    leases.push(lease);
+                }
+            }
+        }
+
+        if leases.is_empty() {
+            return Err(anyhow!("durable nonce 已启用，但没有可用的 nonce 账户"));
+        }
+
+        info!(
+            target: "engine::nonce",
+            accounts = leases.len(),
+            "durable nonce 池已就绪"
+        );
+        Ok(Self::from_leases(
+            rpc,
+            leases,
+            Duration::from_millis(config.refresh_delay_ms),
+        ))
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn available(&self) -> usize {
+        self.available.lock().len()
+    }
+
+    /// 借出一个 nonce；池为空时返回 None，调用方应放弃本次机会以免多笔变体同时落地。
+    pub fn checkout(&self) -> Option<NonceLease> {
+        let lease = self.available.lock().pop_front();
+        if lease.is_none() {
+            events::durable_nonce_exhausted(self.capacity);
+        }
+        lease
+    }
+
+    /// 交易未发出（构建失败等）时直接归还，nonce 值保持不变。
+    pub fn restore(&self, lease: NonceLease) {
+        self.available.lock().push_back(lease);
+    }
+
+    /// 交易已提交：等待确认窗口后读取链上最新 nonce 再归还。
+    pub fn release(&self, lease: NonceLease) {
+        let pool = self.clone();
+        tokio::spawn(async move {
+            let refreshed = pool.refresh(&lease).await;
+            pool.available.lock().push_back(refreshed);
+        });
+    }
+
+    async fn refresh(&self, lease: &NonceLease) -> NonceLease {
+        for attempt in 0..REFRESH_ATTEMPTS {
+            tokio::time::sleep(self.refresh_delay * (attempt as u32 + 1)).await;
+            match fetch_nonce(&self.rpc, &lease.account).await {
+                Ok(nonce) => {
+                    let advanced = nonce != lease.nonce;
+                    events::durable_nonce_refreshed(&lease.account, advanced);
+                    return NonceLease {
+                        nonce,
+                        ..lease.clone()
+                    };
+                }
+                Err(err) => {
+                    warn!(
+                        target: "engine::nonce",
+                        account = %lease.account,
+                        attempt = attempt + 1,
+                        error = %err,
+                        "刷新 nonce 失败"
+                    );
+                }
+            }
+        }
+        // 多次失败仍归还旧值：若已被推进，下一次使用会失败并再次触发刷新
+        lease.clone()
+    }
+}
+
+async fn fetch_nonce(rpc: &RpcClient, account: &Pubkey) -> Result<Hash> {
+    let data = rpc
+        .get_account_data(account)
+        .await
+        .map_err(|err| anyhow!("读取 nonce 账户失败: {err}"))?;
+    parse_nonce_account(&data)
+        .map(|(_, nonce)| nonce)
+        .ok_or_else(|| anyhow!("nonce 账户 {account} 未初始化"))
+}
+
+async fn create_nonce_account(
+    rpc: &Arc<RpcClient>,
+    identity: &EngineIdentity,
+    config: &DurableNonceConfig,
+    index: usize,
+    rent: u64,
+) -> Result<NonceLease> {
+    let seed = nonce_seed(&config.seed_prefix, index);
+    let address = derive_nonce_address(&identity.pubkey, &config.seed_prefix, index)?;
+    let instructions = system_instruction::create_nonce_account_with_seed(
+        &identity.pubkey,
+        &address,
+        &identity.pubkey,
+        &seed,
+        &identity.pubkey,
+        rent,
+    );
+    let signature = send_transaction(rpc, identity, &instructions, &[])
+        .await
+        .with_context(|| format!("创建 nonce 账户 {address} 失败"))?;
+    info!(
+        target: "engine::nonce",
+        account = %address,
+        signature = %signature,
+        "已创建 nonce 账户"
+    );
+    let nonce = fetch_nonce(rpc, &address).await?;
+    Ok(NonceLease {
+        account: address,
+        authority: identity.pubkey,
+        nonce,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encode_nonce(state: u32, authority: &Pubkey, nonce: &Hash) -> Vec<u8> {
+        let mut data = Vec::with_capacity(NONCE_ACCOUNT_LENGTH);
+        data.extend_from_slice(&1u32.to_le_bytes());
+        data.extend_from_slice(&state.to_le_bytes());
+        data.extend_from_slice(authority.as_ref());
+        data.extend_from_slice(nonce.as_ref());
+        data.extend_from_slice(&5_000u64.to_le_bytes());
+        data
+    }
+
+    fn pool_with(count: usize) -> NoncePool {
+        let rpc = Arc::new(RpcClient::new_mock("http://localhost:8899".to_string()));
+        let authority = Pubkey::new_unique();
+        let leases = (0..count)
+            .map(|_| NonceLease {
+                account: Pubkey::new_unique(),
+                authority,
+                nonce: Hash::new_unique(),
+            })
+            .collect();
+        NoncePool::from_leases(rpc, leases, Duration::from_millis(1))
+    }
+
+    #[test]
+    fn parse_initialized_nonce_account() {
+        let authority = Pubkey::new_unique();
+        let nonce = Hash::new_unique();
+        let data = encode_nonce(STATE_INITIALIZED, &authority, &nonce);
+        assert_eq!(parse_nonce_account(&data), Some((authority, nonce)));
+    }
+
+    #[test]
+    fn parse_rejects_uninitialized_or_short_data() {
+        let data = encode_nonce(0, &Pubkey::new_unique(), &Hash::new_unique());
+        assert_eq!(parse_nonce_account(&data), None);
+        assert_eq!(parse_nonce_account(&data[..40]), None);
+    }
+
+    #[test]
+    fn derived_addresses_are_stable_per_index() {
+        let base = Pubkey::new_unique();
+        let first = derive_nonce_address(&base, "galileo-nonce", 0).unwrap();
+        assert_eq!(
+            first,
+            derive_nonce_address(&base, "galileo-nonce", 0).unwrap()
+        );
+        assert_ne!(
+            first,
+            derive_nonce_address(&base, "galileo-nonce", 1).unwrap()
+        );
+    }
+
+    #[test]
+    fn advance_instruction_targets_lease_account() {
+        let lease = NonceLease {
+            account: Pubkey::new_unique(),
+            authority: Pubkey::new_unique(),
+            nonce: Hash::new_unique(),
+        };
+        let ix = lease.advance_instruction();
+        assert_eq!(ix.program_id, solana_system_interface::program::ID);
+        assert_eq!(ix.accounts[0].pubkey, lease.account);
+        assert!(ix.accounts[0].is_writable);
+        assert!(
+            ix.accounts
+                .iter()
+                .any(|meta| meta.pubkey == lease.authority && meta.is_signer)
+        );
+    }
+
+    #[test]
+    fn checkout_and_restore_cycle_leases() {
+        let pool = pool_with(2);
+        let first = pool.checkout().unwrap();
+        let second = pool.checkout().unwrap();
+        assert_ne!(first.account, second.account);
+        assert!(pool.checkout().is_none());
+
+        pool.restore(first.clone());
+        assert_eq!(pool.available(), 1);
+        assert_eq!(pool.checkout(), Some(first));
+        assert_eq!(pool.capacity(), 2);
+    }
+}
@@ -0,0 +1,493 @@
+use std::collections::HashSet;
+use std::fmt;
+
+use solana_sdk::hash::Hash;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::message::v0::Message as V0Message;
+use solana_sdk::message::{AddressLookupTableAccount, CompileError};
+use solana_sdk::pubkey::Pubkey;
+
+use super::compute_units::MEMO_PROGRAM_ID;
+use crate::instructions::compute_budget::{is_compute_budget, is_compute_unit_price};
+use crate::instructions::flashloan::remap_instruction_indices;
+
+/// 单笔交易序列化后的最大字节数（PACKET_DATA_SIZE）。
+pub const MAX_TRANSACTION_BYTES: usize = 1232;
+/// 主网当前生效的单笔交易账户锁上限。
+pub const MAX_ACCOUNT_LOCKS: usize = 64;
+/// 拆分为 bundle 时为首笔交易预留的 Jito tip 转账空间。
+const TIP_RESERVE_BYTES: usize = 96;
+
+/// 打包过程中依次尝试的降级手段，每种都会单独上报事件。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PackFallback {
+    /// 仅保留覆盖所需账户的最少 ALT
+    AltCover,
+    /// 去掉 memo 与重复的 ATA 创建指令
+    StripRedundant,
+    /// 拆分为两笔交易，通过 Jito bundle 原子提交
+    BundleSplit,
+}
+
+impl PackFallback {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PackFallback::AltCover => "alt_cover",
+            PackFallback::StripRedundant => "strip_redundant",
+            PackFallback::BundleSplit => "bundle_split",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MessageFootprint {
+    pub bytes: usize,
+    pub account_locks: usize,
+}
+
+impl MessageFootprint {
+    fn of(message: &V0Message) -> Self {
+        let signatures = message.header.num_required_signatures as usize;
+        let bytes = short_vec_len(signatures) + signatures * 64 + message.serialize().len();
+        let account_locks = message.account_keys.len()
+            + message
+                .address_table_lookups
+                .iter()
+                .map(|lookup| lookup.writable_indexes.len() + lookup.readonly_indexes.len())
+                .sum::<usize>();
+        Self {
+            bytes,
+            account_locks,
+        }
+    }
+
+    fn fits(&self, max_bytes: usize) -> bool {
+        self.bytes <= max_bytes && self.account_locks <= MAX_ACCOUNT_LOCKS
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PackedMessage {
+    pub message: V0Message,
+    pub instructions: Vec<Instruction>,
+    pub lookup_accounts: Vec<AddressLookupTableAccount>,
+    pub footprint: MessageFootprint,
+}
+
+#[derive(Debug, Clone)]
+pub struct PackOutcome {
+    pub head: PackedMessage,
+    /// 拆分后的第二笔交易，仅在允许 bundle 拆分时出现
+    pub tail: Option<PackedMessage>,
+    pub fallbacks: Vec<PackFallback>,
+    /// 未做任何处理时的原始占用，编译失败时为 None
+    pub original: Option<MessageFootprint>,
+}
+
+#[derive(Debug)]
+pub enum PackError {
+    Oversized {
+        footprint: Option<MessageFootprint>,
+        fallbacks: Vec<PackFallback>,
+    },
+    Compile(String),
+}
+
+impl fmt::Display for PackError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PackError::Oversized {
+                footprint: Some(footprint),
+                ..
+            } => write!(
+                f,
+                "交易超出限制: {} 字节 / {} 个账户锁（上限 {} / {}）",
+                footprint.bytes, footprint.account_locks, MAX_TRANSACTION_BYTES, MAX_ACCOUNT_LOCKS
+            ),
+            PackError::Oversized {
+                footprint: None, ..
+            } => write!(f, "交易账户过多，无法编译消息"),
+            PackError::Compile(err) => write!(f, "编译交易消息失败: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for PackError {}
+
+/// 在编译前把指令装进体积与账户锁限制之内：依次尝试最小 ALT 覆盖、去冗余指令、bundle 拆分。
+/// `unsplittable_programs` 为闪电贷等借还必须位于同一笔交易的程序，含这些指令时不做拆分。
+pub fn pack_message(
+    payer: &Pubkey,
+    instructions: Vec<Instruction>,
+    tables: &[AddressLookupTableAccount],
+    blockhash: Hash,
+    allow_bundle_split: bool,
+    unsplittable_programs: &[Pubkey],
+) -> Result<PackOutcome, PackError> {
+    let original = match compile(payer, &instructions, tables, blockhash) {
+        Ok(packed) if packed.footprint.fits(MAX_TRANSACTION_BYTES) => {
+            return Ok(PackOutcome {
+                head: packed,
+                tail: None,
+                fallbacks: Vec::new(),
+                original: None,
+            });
+        }
+        Ok(packed) => Some(packed.footprint),
+        Err(CompileFailure::TooManyAccounts) => None,
+        Err(CompileFailure::Other(err)) => return Err(PackError::Compile(err)),
+    };
+    let finish = |head, tail, fallbacks| PackOutcome {
+        head,
+        tail,
+        fallbacks,
+        original,
+    };
+
+    let mut fallbacks = Vec::new();
+    let mut last = original;
+
+    let cover = minimal_cover(payer, &instructions, tables);
+    if cover.len() < tables.len() {
+        fallbacks.push(PackFallback::AltCover);
+        match compile(payer, &instructions, &cover, blockhash) {
+            Ok(packed) if packed.footprint.fits(MAX_TRANSACTION_BYTES) => {
+                return Ok(finish(packed, None, fallbacks));
+            }
+            Ok(packed) => last = Some(packed.footprint),
+            Err(CompileFailure::TooManyAccounts) => {}
+            Err(CompileFailure::Other(err)) => return Err(PackError::Compile(err)),
+        }
+    }
+
+    let stripped = strip_redundant(&instructions);
+    let instructions = if stripped.len() < instructions.len() {
+        fallbacks.push(PackFallback::StripRedundant);
+        let cover = minimal_cover(payer, &stripped, tables);
+        match compile(payer, &stripped, &cover, blockhash) {
+            Ok(packed) if packed.footprint.fits(MAX_TRANSACTION_BYTES) => {
+                return Ok(finish(packed, None, fallbacks));
+            }
+            Ok(packed) => last = Some(packed.footprint),
+            Err(CompileFailure::TooManyAccounts) => {}
+            Err(CompileFailure::Other(err)) => return Err(PackError::Compile(err)),
+        }
+        stripped
+    } else {
+        instructions
+    };
+
+    if allow_bundle_split {
+        if let Some((head, tail)) = split_in_two(
+            payer,
+            &instructions,
+            tables,
+            blockhash,
+            unsplittable_programs,
+        ) {
+            fallbacks.push(PackFallback::BundleSplit);
+            return Ok(finish(head, Some(tail), fallbacks));
+        }
+    }
+
+    Err(PackError::Oversized {
+        footprint: last,
+        fallbacks,
+    })
+}
+
+enum CompileFailure {
+    TooManyAccounts,
+    Other(String),
+}
+
+fn compile(
+    payer: &Pubkey,
+    instructions: &[Instruction],
+    tables: &[AddressLookupTableAccount],
+    blockhash: Hash,
+) -> Result<PackedMessage, CompileFailure> {
+    let message = V0Message::try_compile(payer, instructions, tables, blockhash).map_err(
+        |err| match err {
+            CompileError::AccountIndexOverflow | CompileError::AddressTableLookupIndexOverflow => {
+                CompileFailure::TooManyAccounts
+            }
+            other => CompileFailure::Other(other.to_string()),
+        },
+    )?;
+    let footprint = MessageFootprint::of(&message);
+    Ok(PackedMessage {
+        message,
+        instructions: instructions.to_vec(),
+        lookup_accounts: tables.to_vec(),
+        footprint,
+    })
+}
+
+/// 贪心求最小集合覆盖：每轮选择覆盖剩余可查找账户最多的 ALT。
+fn minimal_cover(
+    payer: &Pubkey,
+    instructions: &[Instruction],
+    tables: &[AddressLookupTableAccount],
+) -> Vec<AddressLookupTableAccount> {
+    let programs: HashSet<Pubkey> = instructions.iter().map(|ix| ix.program_id).collect();
+    let mut uncovered: HashSet<Pubkey> = instructions
+        .iter()
+        .flat_map(|ix| ix.accounts.iter())
+        .filter(|meta| !meta.is_signer && meta.pubkey != *payer && !programs.contains(&meta.pubkey))
+        .map(|meta| meta.pubkey)
+        .collect();
+
+    let mut remaining: Vec<&AddressLookupTableAccount> = tables.iter().collect();
+    let mut selected = Vec::new();
+    while !uncovered.is_empty() {
+        let best = remaining
+            .iter()
+            .enumerate()
+            .map(|(idx, table)| {
+                let hits = table
+                    .addresses
+                    .iter()
+                    .filter(|address| uncovered.contains(address))
+                    .count();
+                (idx, hits)
+            })
+            .max_by(|a, b| a.1.cmp(&b.1).then_with(|| b.0.cmp(&a.0)));
+        let Some((idx, hits)) = best else {
+            break;
+        };
+        if hits == 0 {
+            break;
+        }
+        let table = remaining.remove(idx);
+        for address in &table.addresses {
+            uncovered.remove(address);
+        }
+        selected.push(table.clone());
+    }
+    selected
+}
+
+/// 去掉 memo 以及针对同一 ATA 的重复创建指令，保留首次出现的顺序。
+/// 闪电贷指令记录的是交易内绝对下标，删除指令后按新位置同步修正。
+fn strip_redundant(instructions: &[Instruction]) -> Vec<Instruction> {
+    let mut created = HashSet::new();
+    let mut positions = Vec::with_capacity(instructions.len());
+    let mut kept = Vec::with_capacity(instructions.len());
+    for ix in instructions {
+        let keep = if ix.program_id == MEMO_PROGRAM_ID {
+            false
+        } else if ix.program_id == spl_associated_token_account::ID {
+            ix.accounts
+                .get(1)
+                .is_none_or(|ata| created.insert(ata.pubkey))
+        } else {
+            true
+        };
+        positions.push(keep.then_some(kept.len()));
+        if keep {
+            kept.push(ix.clone());
+        }
+    }
+    if kept.len() < instructions.len() {
+        remap_instruction_indices(&mut kept, |index| positions.get(index).copied().flatten());
+    }
+    kept
+}
+
+/// 在尽量均衡的位置把指令拆成两笔。compute unit limit 在两笔中各保留一份，price 只留在首笔，
+/// 避免整条路线的优先费被重复支付。
+fn split_in_two(
+    payer: &Pubkey,
+    instructions: &[Instruction],
+    tables: &[AddressLookupTableAccount],
+    blockhash: Hash,
+    unsplittable_programs: &[Pubkey],
+) -> Option<(PackedMessage, PackedMessage)> {
+    if instructions
+        .iter()
+        .any(|ix| unsplittable_programs.contains(&ix.program_id))
+    {
+        return None;
+    }
+    let (budget, body): (Vec<Instruction>, Vec<Instruction>) =
+        instructions.iter().cloned().partition(is_compute_budget);
+    if body.len() < 2 {
+        return None;
+    }
+
+    let tail_budget: Vec<Instruction> = budget
+        .iter()
+        .filter(|ix| !is_compute_unit_price(ix))
+        .cloned()
+        .collect();
+
+    let middle = body.len() / 2;
+    let mut candidates: Vec<usize> = (1..body.len()).collect();
+    candidates.sort_by_key(|idx| idx.abs_diff(middle));
+
+    for idx in candidates {
+        let head_ixs: Vec<Instruction> = budget.iter().chain(&body[..idx]).cloned().collect();
+        let tail_ixs: Vec<Instruction> = tail_budget.iter().chain(&body[idx..]).cloned().collect();
+        let head_cover = minimal_cover(payer, &head_ixs, tables);
+        let tail_cover = minimal_cover(payer, &tail_ixs, tables);
+        let Ok(head) = compile(payer, &head_ixs, &head_cover, blockhash) else {
+            continue;
+        };
+        if !head
+            .footprint
+            .fits(MAX_TRANSACTION_BYTES - TIP_RESERVE_BYTES)
+        {
+            continue;
+        }
+        let Ok(tail) = compile(payer, &tail_ixs, &tail_cover, blockhash) else {
+            continue;
+        };
+        if tail.footprint.fits(MAX_TRANSACTION_BYTES) {
+            return Some((head, tail));
+        }
+    }
+    None
+}
+
+fn short_vec_len(value: usize) -> usize {
+    match value {
+        0..=0x7f => 1,
+        0x80..=0x3fff => 2,
+        _ => 3,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instructions::compute_budget::{
+        compute_unit_limit_instruction, compute_unit_price_instruction,
+    };
+    use solana_sdk::instruction::AccountMeta;
+
+    fn wide_ix(payer: &Pubkey, accounts: &[Pubkey]) -> Instruction {
+        let mut metas = vec![AccountMeta::new(*payer, true)];
+        metas.extend(accounts.iter().map(|key| AccountMeta::new(*key, false)));
+        Instruction {
+            program_id: Pubkey::new_unique(),
+            accounts: metas,
+            data: vec![0; 16],
+        }
+    }
+
+    fn keys(count: usize) -> Vec<Pubkey> {
+        (0..count).map(|_| Pubkey::new_unique()).collect()
+    }
+
+    #[test]
+    fn small_message_passes_through() {
+        let payer = Pubkey::new_unique();
+        let ix = wide_ix(&payer, &keys(4));
+        let outcome =
+            pack_message(&payer, vec![ix], &[], Hash::default(), false, &[]).expect("pack");
+        assert!(outcome.fallbacks.is_empty());
+        assert!(outcome.tail.is_none());
+        assert!(outcome.head.footprint.bytes <= MAX_TRANSACTION_BYTES);
+    }
+
+    #[test]
+    fn minimal_cover_drops_unused_and_redundant_tables() {
+        let payer = Pubkey::new_unique();
+        let accounts = keys(6);
+        let ix = wide_ix(&payer, &accounts);
+        let full = AddressLookupTableAccount {
+            key: Pubkey::new_unique(),
+            addresses: accounts.clone(),
+        };
+        let partial = AddressLookupTableAccount {
+            key: Pubkey::new_unique(),
+            addresses: accounts[..3].to_vec(),
+        };
+        let unrelated = AddressLookupTableAccount {
+            key: Pubkey::new_unique(),
+            addresses: keys(10),
+        };
+        let cover = minimal_cover(&payer, &[ix], &[partial, unrelated, full.clone()]);
+        assert_eq!(cover.len(), 1);
+        assert_eq!(cover[0].key, full.key);
+    }
+
+    #[test]
+    fn strip_removes_memo_and_duplicate_ata_creates() {
+        let payer = Pubkey::new_unique();
+        let ata = Pubkey::new_unique();
+        let create = Instruction {
+            program_id: spl_associated_token_account::ID,
+            accounts: vec![AccountMeta::new(payer, true), AccountMeta::new(ata, false)],
+            data: vec![1],
+        };
+        let memo = Instruction {
+            program_id: MEMO_PROGRAM_ID,
+            accounts: Vec::new(),
+            data: b"galileo".to_vec(),
+        };
+        let swap = wide_ix(&payer, &keys(2));
+        let stripped = strip_redundant(&[create.clone(), swap.clone(), create, memo]);
+        assert_eq!(stripped.len(), 2);
+        assert_eq!(stripped[1].program_id, swap.program_id);
+    }
+
+    #[test]
+    fn strip_remaps_flashloan_end_index() {
+        use crate::engine::plugins::flashloan::marginfi::{BEGIN_DISCRIMINATOR, PROGRAM_ID};
+
+        let payer = Pubkey::new_unique();
+        let memo = Instruction {
+            program_id: MEMO_PROGRAM_ID,
+            accounts: Vec::new(),
+            data: b"galileo".to_vec(),
+        };
+        let mut data = BEGIN_DISCRIMINATOR.to_vec();
+        data.extend_from_slice(&3u64.to_le_bytes());
+        let begin = Instruction {
+            program_id: *PROGRAM_ID,
+            accounts: Vec::new(),
+            data,
+        };
+        let swap = wide_ix(&payer, &keys(2));
+        let end = wide_ix(&payer, &keys(1));
+
+        let stripped = strip_redundant(&[memo, begin, swap, end]);
+        assert_eq!(stripped.len(), 3);
+        assert_eq!(stripped[0].data[8..16], 2u64.to_le_bytes());
+    }
+
+    #[test]
+    fn oversized_message_splits_only_when_allowed() {
+        let payer = Pubkey::new_unique();
+        let legs: Vec<Instruction> = (0..2).map(|_| wide_ix(&payer, &keys(24))).collect();
+        let mut instructions = vec![
+            compute_unit_limit_instruction(400_000),
+            compute_unit_price_instruction(1_000),
+        ];
+        instructions.extend(legs);
+
+        let err = pack_message(
+            &payer,
+            instructions.clone(),
+            &[],
+            Hash::default(),
+            false,
+            &[],
+        )
+        .expect_err("oversized without split");
+        assert!(matches!(err, PackError::Oversized { .. }));
+
+        let outcome = pack_message(&payer, instructions, &[], Hash::default(), true, &[])
+            .expect("split bundle");
+        assert_eq!(outcome.fallbacks, vec![PackFallback::BundleSplit]);
+        let tail = outcome.tail.expect("tail transaction");
+        assert!(is_compute_budget(&outcome.head.instructions[0]));
+        assert!(is_compute_budget(&tail.instructions[0]));
+        assert_eq!(outcome.head.instructions.len(), 3);
+        assert_eq!(tail.instructions.len(), 2);
+        assert!(outcome.head.instructions.iter().any(is_compute_unit_price));
+        assert!(!tail.instructions.iter().any(is_compute_unit_price));
+    }
+}
@@ -1,6 +1,7 @@
 use std::fmt;
 
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use serde::de::{Error as DeError, Visitor};
 use serde::{Deserialize, Deserializer};
@@ -15,6 +16,7 @@ use tracing::warn;
 
 use super::COMPUTE_BUDGET_PROGRAM_ID;
 use super::builder::PreparedTransaction;
+use super::nonce::NonceLease;
 use super::types::JitoTipPlan;
 
 pub type VariantId = u32;
@@ -96,6 +98,9 @@ pub struct TxVariant {
     compute_unit_price_strategy_label: &'static str,
     prioritization_fee_lamports: u64,
     compute_unit_price_micro_lamports: Option<u64>,
+    bundle_tail: Option<VersionedTransaction>,
+    durable_nonce: Option<NonceLease>,
+    blockhash_fetched_at: Option<Instant>,
 }
 
 impl TxVariant {
@@ -128,9 +133,27 @@ impl TxVariant {
             compute_unit_price_strategy_label,
             prioritization_fee_lamports,
             compute_unit_price_micro_lamports,
+            bundle_tail: None,
+            durable_nonce: None,
+            blockhash_fetched_at: None,
         }
     }
 
+    pub fn with_bundle_tail(mut self, tail: Option<VersionedTransaction>) -> Self {
+        self.bundle_tail = tail;
+        self
+    }
+
+    pub fn with_durable_nonce(mut self, nonce: Option<NonceLease>) -> Self {
+        self.durable_nonce = nonce;
+        self
+    }
+
+    pub fn with_blockhash_fetched_at(mut self, fetched_at: Option<Instant>) -> Self {
+        self.blockhash_fetched_at = fetched_at;
+        self
+    }
+
     pub fn id(&self) -> VariantId {
         self.id
     }
@@ -186,9 +209,24 @@ impl TxVariant {
         self.prioritization_fee_lamports
     }
 
+    /// 拆分为两笔交易时的第二笔，只能通过 Jito bundle 提交。
+    pub fn bundle_tail(&self) -> Option<&VersionedTransaction> {
+        self.bundle_tail.as_ref()
+    }
+
+    /// durable nonce 模式下 `blockhash()` 返回的是 nonce 值，不能用于构建额外的独立交易。
+    pub fn durable_nonce(&self) -> Option<&NonceLease> {
+        self.durable_nonce.as_ref()
+    }
+
     pub fn compute_unit_price_micro_lamports(&self) -> Option<u64> {
         self.compute_unit_price_micro_lamports
     }
+
+    /// 距 blockhash 获取的时长；durable nonce 或未知来源时为 None。
+    pub fn blockhash_age(&self) -> Option<Duration> {
+        self.blockhash_fetched_at.map(|at| at.elapsed())
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -228,6 +266,13 @@ impl DispatchPlan {
             .flat_map(|variants| variants.iter())
             .next()
     }
+
+    pub fn variant(&self, id: VariantId) -> Option<&TxVariant> {
+        self.lander_variants
+            .iter()
+            .flat_map(|variants| variants.iter())
+            .find(|variant| variant.id() == id)
+    }
 }
 
 #[derive(Default)]
@@ -287,7 +332,10 @@ impl TxVariantPlanner {
                     prepared_entry.compute_unit_price_strategy_label,
                     prepared_entry.prioritization_fee_lamports,
                     prepared_entry.compute_unit_price_micro_lamports,
-                );
+                )
+                .with_bundle_tail(prepared_entry.bundle_tail.clone())
+                .with_durable_nonce(prepared_entry.durable_nonce.clone())
+                .with_blockhash_fetched_at(prepared_entry.blockhash_fetched_at);
                 variants.push(variant);
                 next_id = next_id.saturating_add(1);
             }
@@ -412,6 +460,9 @@ mod tests {
             instructions: Vec::new(),
             lookup_accounts: Vec::new(),
             jito_tip_plan: None,
+            bundle_tail: None,
+            durable_nonce: None,
+            blockhash_fetched_at: None,
         }
     }
 
@@ -456,4 +507,28 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn planner_shares_durable_nonce_across_variants() {
+        let planner = TxVariantPlanner::new();
+        let mut prepared = build_prepared();
+        let nonce = NonceLease {
+            account: solana_sdk::pubkey::Pubkey::new_unique(),
+            authority: prepared.signer.pubkey(),
+            nonce: Hash::new_unique(),
+        };
+        prepared.blockhash = nonce.nonce;
+        prepared.durable_nonce = Some(nonce.clone());
+
+        let plan = planner.plan(
+            DispatchStrategy::AllAtOnce,
+            &[prepared.clone(), prepared],
+            &[1, 1],
+        );
+        for index in 0..2 {
+            let variant = &plan.variants_for_lander(index)[0];
+            assert_eq!(variant.durable_nonce(), Some(&nonce));
+            assert_eq!(variant.blockhash(), nonce.nonce);
+        }
+    }
 }
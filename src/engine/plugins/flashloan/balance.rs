@@ -0,0 +1,101 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use dashmap::DashMap;
+use solana_client::client_error::{ClientError, ClientErrorKind};
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_client::rpc_request::RpcError;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::cache::{Cache, InMemoryBackend};
+use crate::engine::plugins::flashloan::{FlashloanError, FlashloanResult};
+
+const BALANCE_STATIC_TTL: Duration = Duration::from_secs(60 * 60 * 24); // effectively static
+
+/// 钱包代币余额缓存：`prefer_wallet_balance` 时判断是否可以不借款直接交易。
+pub(crate) struct WalletBalances {
+    rpc: Arc<RpcClient>,
+    cache: Cache<InMemoryBackend<Pubkey, u64>>,
+}
+
+impl WalletBalances {
+    pub(crate) fn new(rpc: Arc<RpcClient>) -> Self {
+        Self {
+            rpc,
+            cache: Cache::new(InMemoryBackend::default()),
+        }
+    }
+
+    pub(crate) async fn balance_of(&self, token_account: &Pubkey) -> FlashloanResult<u64> {
+        if let Some(entry) = self.cache.get(token_account).await {
+            return Ok(*entry);
+        }
+
+        let amount = match self.rpc.get_token_account_balance(token_account).await {
+            Ok(balance) => balance.amount.parse::<u64>().unwrap_or(0),
+            Err(err) => {
+                if is_account_not_found(&err) {
+                    0
+                } else {
+                    return Err(FlashloanError::Rpc(err));
+                }
+            }
+        };
+
+        self.cache
+            .insert(*token_account, amount, Some(BALANCE_STATIC_TTL))
+            .await;
+
+        Ok(amount)
+    }
+}
+
+/// 各协议按 mint 记录的可借流动性，由后台任务定期刷新；未知时视为可借。
+#[derive(Clone, Default)]
+pub(crate) struct LiquiditySnapshot {
+    inner: Arc<DashMap<Pubkey, u64>>,
+}
+
+impl LiquiditySnapshot {
+    pub(crate) fn update(&self, mint: Pubkey, available: u64) {
+        self.inner.insert(mint, available);
+    }
+
+    pub(crate) fn available(&self, mint: &Pubkey) -> Option<u64> {
+        self.inner.get(mint).map(|entry| *entry)
+    }
+
+    pub(crate) fn can_cover(&self, mint: &Pubkey, amount: u64) -> bool {
+        self.available(mint)
+            .map(|available| available >= amount)
+            .unwrap_or(true)
+    }
+}
+
+/// SPL Token 账户中 amount 字段的偏移（mint + owner 之后）。
+const TOKEN_ACCOUNT_AMOUNT_OFFSET: usize = 64;
+
+pub(crate) fn token_account_amount(data: &[u8]) -> Option<u64> {
+    let bytes = data
+        .get(TOKEN_ACCOUNT_AMOUNT_OFFSET..TOKEN_ACCOUNT_AMOUNT_OFFSET + 8)?
+        .try_into()
+        .ok()?;
+    Some(u64::from_le_bytes(bytes))
+}
+
+fn is_account_not_found(err: &ClientError) -> bool {
+    match err.kind() {
+        ClientErrorKind::RpcError(RpcError::RpcResponseError { message, .. }) => {
+            is_account_missing_message(message)
+        }
+        ClientErrorKind::RpcError(RpcError::ForUser(message)) => {
+            is_account_missing_message(message)
+        }
+        _ => false,
+    }
+}
+
+fn is_account_missing_message(message: &str) -> bool {
+    let normalized = message.to_ascii_lowercase();
+    normalized.contains("could not find account") || normalized.contains("account does not exist")
+}
@@ -0,0 +1,288 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use tokio::task::JoinHandle;
+use tracing::{info, warn};
+
+use crate::cache::cached_associated_token_address;
+use crate::config::FlashloanKaminoConfig;
+use crate::engine::plugins::flashloan::balance::{LiquiditySnapshot, WalletBalances};
+use crate::engine::plugins::flashloan::{
+    FlashloanError, FlashloanOutcome, FlashloanPreparation, FlashloanProtocol, FlashloanProvider,
+    FlashloanResult,
+};
+use crate::engine::{EngineIdentity, SwapInstructionsVariant, SwapOpportunity};
+use crate::instructions::flashloan::kamino::{
+    KaminoFlashloan, KaminoReserve, parse_available_liquidity,
+};
+
+/// Kamino Lend 闪电贷：按 base mint 选择 reserve，在同一交易内 borrow/repay。
+pub struct KaminoFlashloanManager {
+    rpc: Arc<RpcClient>,
+    enabled: bool,
+    prefer_wallet_balance: bool,
+    lending_market: Pubkey,
+    reserve_addresses: Vec<Pubkey>,
+    reserves: HashMap<Pubkey, KaminoReserve>,
+    flashloan: KaminoFlashloan,
+    balances: WalletBalances,
+    liquidity: LiquiditySnapshot,
+    compute_unit_overhead: u32,
+}
+
+impl KaminoFlashloanManager {
+    pub fn new(
+        cfg: &FlashloanKaminoConfig,
+        enabled: bool,
+        prefer_wallet_balance: bool,
+        rpc: Arc<RpcClient>,
+    ) -> FlashloanResult<Self> {
+        let lending_market = Pubkey::from_str(cfg.lending_market.trim()).map_err(|err| {
+            FlashloanError::InvalidConfigDetail(format!(
+                "flashloan.kamino.lending_market 无效: {err}"
+            ))
+        })?;
+        let reserve_addresses = cfg
+            .reserves
+            .iter()
+            .map(|raw| {
+                Pubkey::from_str(raw.trim()).map_err(|err| {
+                    FlashloanError::InvalidConfigDetail(format!(
+                        "flashloan.kamino.reserves 包含无效地址 {raw}: {err}"
+                    ))
+                })
+            })
+            .collect::<FlashloanResult<Vec<_>>>()?;
+
+        Ok(Self {
+            balances: WalletBalances::new(Arc::clone(&rpc)),
+            rpc,
+            enabled,
+            prefer_wallet_balance,
+            lending_market,
+            reserve_addresses,
+            reserves: HashMap::new(),
+            flashloan: KaminoFlashloan::new(cfg.fee_bps),
+            liquidity: LiquiditySnapshot::default(),
+            compute_unit_overhead: cfg.compute_unit_overhead,
+        })
+    }
+
+    /// 从链上解析配置的 reserve，得到 mint、金库与 token program；不属于配置市场的 reserve 会被忽略。
+    async fn resolve_reserves(&mut self) -> FlashloanResult<usize> {
+        if !self.enabled || self.reserve_addresses.is_empty() {
+            return Ok(0);
+        }
+
+        let accounts = self
+            .rpc
+            .get_multiple_accounts(&self.reserve_addresses)
+            .await?;
+        let mut parsed = Vec::with_capacity(accounts.len());
+        for (address, account) in self.reserve_addresses.iter().zip(accounts) {
+            let Some(account) = account else {
+                warn!(
+                    target: "flashloan::kamino",
+                    reserve = %address,
+                    "Kamino reserve 不存在，已跳过"
+                );
+                continue;
+            };
+            match KaminoReserve::parse(*address, &account.data, Pubkey::default()) {
+                Some((reserve, available)) if reserve.lending_market == self.lending_market => {
+                    parsed.push((reserve, available));
+                }
+                Some((reserve, _)) => warn!(
+                    target: "flashloan::kamino",
+                    reserve = %address,
+                    market = %reserve.lending_market,
+                    "Kamino reserve 不属于配置的 lending market，已跳过"
+                ),
+                None => warn!(
+                    target: "flashloan::kamino",
+                    reserve = %address,
+                    "Kamino reserve 数据解析失败，已跳过"
+                ),
+            }
+        }
+
+        let mints: Vec<Pubkey> = parsed.iter().map(|(reserve, _)| reserve.mint).collect();
+        let mint_accounts = self.rpc.get_multiple_accounts(&mints).await?;
+        for ((mut reserve, available), mint_account) in parsed.into_iter().zip(mint_accounts) {
+            let Some(mint_account) = mint_account else {
+                continue;
+            };
+            reserve.token_program = mint_account.owner;
+            self.liquidity.update(reserve.mint, available);
+            info!(
+                target: "flashloan::kamino",
+                reserve = %reserve.address,
+                mint = %reserve.mint,
+                available,
+                "Kamino reserve 已就绪"
+            );
+            self.reserves.insert(reserve.mint, reserve);
+        }
+
+        Ok(self.reserves.len())
+    }
+
+    #[cfg(test)]
+    pub(crate) fn insert_reserve(&mut self, reserve: KaminoReserve, available: u64) {
+        self.liquidity.update(reserve.mint, available);
+        self.reserves.insert(reserve.mint, reserve);
+    }
+}
+
+#[async_trait]
+impl FlashloanProvider for KaminoFlashloanManager {
+    fn protocol(&self) -> FlashloanProtocol {
+        FlashloanProtocol::Kamino
+    }
+
+    fn program_id(&self) -> Pubkey {
+        crate::instructions::flashloan::kamino::PROGRAM_ID
+    }
+
+    /// Kamino 闪电贷无需钱包侧账户，只解析 reserve。
+    async fn prepare(
+        &mut self,
+        _identity: &EngineIdentity,
+    ) -> FlashloanResult<Option<FlashloanPreparation>> {
+        let reserves = self.resolve_reserves().await?;
+        info!(
+            target: "flashloan::kamino",
+            reserves,
+            "Kamino 闪电贷 reserve 解析完成"
+        );
+        Ok(None)
+    }
+
+    fn supported_mints(&self) -> Vec<Pubkey> {
+        self.reserves.keys().copied().collect()
+    }
+
+    fn supports_mint(&self, mint: &Pubkey) -> bool {
+        self.reserves.contains_key(mint)
+    }
+
+    fn fee(&self, _mint: &Pubkey, amount: u64) -> u64 {
+        self.flashloan.fee(amount)
+    }
+
+    fn compute_unit_overhead(&self) -> u32 {
+        self.compute_unit_overhead
+    }
+
+    /// 存在该 mint 的 reserve 且最近一次刷新的可借余额足以覆盖借款。
+    fn can_serve(&self, mint: &Pubkey, amount: u64) -> bool {
+        self.reserves.contains_key(mint) && self.liquidity.can_cover(mint, amount)
+    }
+
+    fn available_liquidity(&self, mint: &Pubkey) -> Option<u64> {
+        self.liquidity.available(mint)
+    }
+
+    /// 定期读取 reserve 的 available_amount。
+    fn spawn_liquidity_refresh(&self, interval: Duration) -> JoinHandle<()> {
+        let rpc = Arc::clone(&self.rpc);
+        let liquidity = self.liquidity.clone();
+        let reserves: Vec<(Pubkey, Pubkey)> = self
+            .reserves
+            .values()
+            .map(|reserve| (reserve.mint, reserve.address))
+            .collect();
+        tokio::spawn(async move {
+            let addresses: Vec<Pubkey> = reserves.iter().map(|(_, address)| *address).collect();
+            loop {
+                match rpc.get_multiple_accounts(&addresses).await {
+                    Ok(accounts) => {
+                        for ((mint, _), account) in reserves.iter().zip(accounts) {
+                            let available = account
+                                .and_then(|account| parse_available_liquidity(&account.data))
+                                .unwrap_or(0);
+                            liquidity.update(*mint, available);
+                        }
+                    }
+                    Err(err) => warn!(
+                        target: "flashloan::kamino",
+                        error = %err,
+                        "刷新 Kamino 流动性失败"
+                    ),
+                }
+                tokio::time::sleep(interval).await;
+            }
+        })
+    }
+
+    async fn assemble(
+        &self,
+        identity: &EngineIdentity,
+        opportunity: &SwapOpportunity,
+        response: &SwapInstructionsVariant,
+    ) -> FlashloanResult<FlashloanOutcome> {
+        let mut flattened = response.flatten_instructions();
+        if flattened.is_empty() {
+            return Ok(FlashloanOutcome {
+                instructions: flattened,
+                metadata: None,
+            });
+        }
+
+        let prefix_len = response
+            .compute_budget_instructions()
+            .len()
+            .min(flattened.len());
+        let body = flattened.split_off(prefix_len);
+        let prefix = flattened;
+
+        let base_mint = opportunity.pair.input_pubkey;
+        let Some(reserve) = self.reserves.get(&base_mint) else {
+            return Err(FlashloanError::UnsupportedAsset(base_mint.to_string()));
+        };
+        let user_token_account =
+            cached_associated_token_address(&identity.pubkey, &base_mint, &reserve.token_program);
+
+        if self.prefer_wallet_balance {
+            let wallet_balance = self.balances.balance_of(&user_token_account).await?;
+            if opportunity.amount_in <= wallet_balance {
+                return Ok(FlashloanOutcome {
+                    instructions: combine(prefix, body),
+                    metadata: None,
+                });
+            }
+        }
+
+        self.flashloan.wrap(
+            &identity.pubkey,
+            reserve,
+            user_token_account,
+            prefix,
+            body,
+            opportunity.amount_in,
+        )
+    }
+}
+
+impl fmt::Debug for KaminoFlashloanManager {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KaminoFlashloanManager")
+            .field("enabled", &self.enabled)
+            .field("prefer_wallet_balance", &self.prefer_wallet_balance)
+            .field("lending_market", &self.lending_market)
+            .field("reserves", &self.reserves.len())
+            .finish()
+    }
+}
+
+fn combine(mut prefix: Vec<Instruction>, mut body: Vec<Instruction>) -> Vec<Instruction> {
+    prefix.append(&mut body);
+    prefix
+}
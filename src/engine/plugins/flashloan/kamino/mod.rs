@@ -0,0 +1,6 @@
+mod manager;
+
+pub use manager::KaminoFlashloanManager;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,188 @@
+use std::sync::Arc;
+
+use serde_json::Value;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+
+use crate::api::dflow::SwapInstructionsResponse as DflowSwapInstructionsResponse;
+use crate::api::dflow::swap_instructions::BlockhashWithMetadata;
+use crate::config::FlashloanKaminoConfig;
+use crate::engine::plugins::flashloan::FlashloanProvider;
+use crate::engine::{EngineIdentity, SwapInstructionsVariant, SwapOpportunity};
+use crate::instructions::flashloan::kamino::{
+    FLASH_BORROW_DISCRIMINATOR, FLASH_REPAY_DISCRIMINATOR, KaminoReserve, PROGRAM_ID,
+    RESERVE_DISCRIMINATOR, flashloan_fee, parse_available_liquidity,
+};
+use crate::instructions::flashloan::shift_instruction_indices;
+use crate::instructions::flashloan::types::FlashloanProtocol;
+use crate::strategy::types::TradePair;
+
+use super::KaminoFlashloanManager;
+
+const WSOL: Pubkey = solana_sdk::pubkey!("So11111111111111111111111111111111111111112");
+
+fn make_identity() -> EngineIdentity {
+    let signer = Keypair::new();
+    let private_key =
+        serde_json::to_string(&signer.to_bytes().to_vec()).expect("serialize keypair");
+    EngineIdentity::from_private_key(&private_key).expect("build engine identity")
+}
+
+fn make_instruction(tag: u8) -> Instruction {
+    Instruction {
+        program_id: Pubkey::new_unique(),
+        accounts: vec![],
+        data: vec![tag],
+    }
+}
+
+fn sample_swap_response() -> DflowSwapInstructionsResponse {
+    DflowSwapInstructionsResponse {
+        raw: Value::Null,
+        compute_budget_instructions: vec![make_instruction(1)],
+        setup_instructions: vec![make_instruction(2)],
+        swap_instruction: make_instruction(3),
+        cleanup_instructions: vec![make_instruction(4)],
+        other_instructions: vec![make_instruction(5)],
+        address_lookup_table_addresses: vec![],
+        blockhash_with_metadata: BlockhashWithMetadata {
+            blockhash: solana_sdk::hash::Hash::default(),
+            last_valid_block_height: 0,
+        },
+        prioritization_fee_lamports: None,
+        compute_unit_limit: 0,
+        prioritization_type: None,
+    }
+}
+
+fn sample_opportunity(amount_in: u64) -> SwapOpportunity {
+    SwapOpportunity {
+        pair: TradePair::from_pubkeys(WSOL, Pubkey::new_unique()),
+        amount_in,
+        profit_lamports: 0,
+        tip_lamports: 0,
+        merged_quote: None,
+        ultra_legs: None,
+    }
+}
+
+fn sample_reserve() -> KaminoReserve {
+    KaminoReserve {
+        address: Pubkey::new_unique(),
+        lending_market: Pubkey::new_unique(),
+        mint: WSOL,
+        supply_vault: Pubkey::new_unique(),
+        fee_vault: Pubkey::new_unique(),
+        token_program: spl_token::id(),
+    }
+}
+
+fn make_manager(fee_bps: u64) -> KaminoFlashloanManager {
+    let config = FlashloanKaminoConfig {
+        fee_bps,
+        reserves: Vec::new(),
+        ..FlashloanKaminoConfig::default()
+    };
+    let rpc = Arc::new(RpcClient::new_mock("mock://kamino".to_string()));
+    KaminoFlashloanManager::new(&config, true, false, rpc).expect("kamino manager")
+}
+
+#[tokio::test]
+async fn kamino_wraps_instructions_with_borrow_index() {
+    let identity = make_identity();
+    let mut manager = make_manager(9);
+    manager.insert_reserve(sample_reserve(), 10_000_000);
+
+    let response = SwapInstructionsVariant::Dflow(sample_swap_response());
+    let opportunity = sample_opportunity(1_000_000);
+    let outcome = manager
+        .assemble(&identity, &opportunity, &response)
+        .await
+        .expect("assemble instructions");
+
+    let metadata = outcome.metadata.as_ref().expect("flashloan metadata");
+    assert_eq!(metadata.protocol, FlashloanProtocol::Kamino);
+    assert_eq!(metadata.inner_instruction_count, 4);
+    assert_eq!(metadata.fee, 900);
+
+    // compute budget + borrow + body(4) + repay
+    assert_eq!(outcome.instructions.len(), 7);
+    let borrow = &outcome.instructions[1];
+    assert_eq!(borrow.program_id, PROGRAM_ID);
+    assert_eq!(borrow.data[..8], FLASH_BORROW_DISCRIMINATOR);
+    let repay = outcome.instructions.last().unwrap();
+    assert_eq!(repay.data[..8], FLASH_REPAY_DISCRIMINATOR);
+    assert_eq!(repay.data[16], 1);
+    assert_eq!(borrow.accounts, repay.accounts);
+}
+
+#[tokio::test]
+async fn kamino_rejects_unknown_mint() {
+    let identity = make_identity();
+    let manager = make_manager(0);
+    let response = SwapInstructionsVariant::Dflow(sample_swap_response());
+    let result = manager
+        .assemble(&identity, &sample_opportunity(1_000), &response)
+        .await;
+    assert!(result.is_err());
+}
+
+#[test]
+fn can_serve_respects_liquidity() {
+    let mut manager = make_manager(0);
+    assert!(!manager.can_serve(&WSOL, 1));
+    manager.insert_reserve(sample_reserve(), 5_000);
+    assert!(manager.can_serve(&WSOL, 5_000));
+    assert!(!manager.can_serve(&WSOL, 5_001));
+}
+
+#[test]
+fn parse_reserve_layout() {
+    let mut data = vec![0u8; 256];
+    data[..8].copy_from_slice(&RESERVE_DISCRIMINATOR);
+    let market = Pubkey::new_unique();
+    let vault = Pubkey::new_unique();
+    let fee_vault = Pubkey::new_unique();
+    data[32..64].copy_from_slice(market.as_ref());
+    data[128..160].copy_from_slice(WSOL.as_ref());
+    data[160..192].copy_from_slice(vault.as_ref());
+    data[192..224].copy_from_slice(fee_vault.as_ref());
+    data[224..232].copy_from_slice(&42u64.to_le_bytes());
+
+    let address = Pubkey::new_unique();
+    let (reserve, available) =
+        KaminoReserve::parse(address, &data, spl_token::id()).expect("parse reserve");
+    assert_eq!(reserve.lending_market, market);
+    assert_eq!(reserve.mint, WSOL);
+    assert_eq!(reserve.supply_vault, vault);
+    assert_eq!(reserve.fee_vault, fee_vault);
+    assert_eq!(available, 42);
+    assert_eq!(parse_available_liquidity(&data), Some(42));
+
+    data[0] = 0;
+    assert!(KaminoReserve::parse(address, &data, spl_token::id()).is_none());
+}
+
+#[test]
+fn fee_rounds_up() {
+    assert_eq!(flashloan_fee(1_000_000, 0), 0);
+    assert_eq!(flashloan_fee(1, 1), 1);
+    assert_eq!(flashloan_fee(20_000, 5), 10);
+}
+
+#[tokio::test]
+async fn shifting_indices_updates_repay() {
+    let identity = make_identity();
+    let mut manager = make_manager(0);
+    manager.insert_reserve(sample_reserve(), u64::MAX);
+    let response = SwapInstructionsVariant::Dflow(sample_swap_response());
+    let mut outcome = manager
+        .assemble(&identity, &sample_opportunity(10), &response)
+        .await
+        .expect("assemble instructions");
+
+    shift_instruction_indices(&mut outcome.instructions, 1);
+    assert_eq!(outcome.instructions.last().unwrap().data[16], 2);
+}
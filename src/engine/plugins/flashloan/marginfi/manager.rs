@@ -2,22 +2,27 @@ use std::fmt;
 use std::sync::Arc;
 use std::time::Duration;
 
-use crate::cache::{Cache, InMemoryBackend};
-use solana_client::client_error::{ClientError, ClientErrorKind};
+use async_trait::async_trait;
 use solana_client::nonblocking::rpc_client::RpcClient;
-use solana_client::rpc_request::RpcError;
 use solana_sdk::instruction::Instruction;
 use solana_sdk::pubkey::Pubkey;
+use tokio::task::JoinHandle;
+use tracing::warn;
 
 use crate::config::FlashloanMarginfiConfig;
-use crate::engine::plugins::flashloan::{FlashloanError, FlashloanOutcome, FlashloanResult};
+use crate::engine::plugins::flashloan::balance::{
+    LiquiditySnapshot, WalletBalances, token_account_amount,
+};
+use crate::engine::plugins::flashloan::{
+    FlashloanOutcome, FlashloanPreparation, FlashloanProtocol, FlashloanProvider, FlashloanResult,
+};
 use crate::engine::{EngineIdentity, SwapInstructionsVariant, SwapOpportunity};
 
 use super::account::{MarginfiAccountEnsure, ensure_marginfi_account};
 use super::compute_associated_token_address;
-use crate::instructions::flashloan::marginfi::MarginfiFlashloan;
-
-const BALANCE_STATIC_TTL: Duration = Duration::from_secs(60 * 60 * 24); // effectively static
+use crate::instructions::flashloan::marginfi::{
+    MarginfiFlashloan, liquidity_vaults, supports_mint,
+};
 
 #[derive(Debug, Clone, Default)]
 pub struct MarginfiAccountRegistry {
@@ -38,19 +43,14 @@ impl MarginfiAccountRegistry {
     }
 }
 
-#[derive(Debug, Clone)]
-pub struct MarginfiFlashloanPreparation {
-    pub account: Pubkey,
-    pub created: bool,
-}
-
 pub struct MarginfiFlashloanManager {
     rpc: Arc<RpcClient>,
     enabled: bool,
     prefer_wallet_balance: bool,
     configured_default: Option<Pubkey>,
     fallback_marginfi: Option<MarginfiFlashloan>,
-    balance_cache: Cache<InMemoryBackend<Pubkey, u64>>,
+    balances: WalletBalances,
+    liquidity: LiquiditySnapshot,
     compute_unit_overhead: u32,
 }
 
@@ -65,45 +65,115 @@ impl MarginfiFlashloanManager {
         let configured_default = accounts.configured_default();
         let fallback_marginfi = configured_default.map(MarginfiFlashloan::new);
         Self {
+            balances: WalletBalances::new(Arc::clone(&rpc)),
             rpc,
             enabled,
             prefer_wallet_balance,
             configured_default,
             fallback_marginfi,
-            balance_cache: Cache::new(InMemoryBackend::default()),
+            liquidity: LiquiditySnapshot::default(),
             compute_unit_overhead: cfg.compute_unit_overhead,
         }
     }
 
+    #[allow(dead_code)]
     pub fn is_enabled(&self) -> bool {
         self.enabled
     }
 
-    pub fn try_into_enabled(self) -> Option<Self> {
-        if self.enabled { Some(self) } else { None }
+    async fn wallet_balance(&self, owner: &Pubkey, mint: &Pubkey) -> FlashloanResult<u64> {
+        let ata = compute_associated_token_address(owner, mint);
+        self.balances.balance_of(&ata).await
     }
+}
 
-    pub fn compute_unit_overhead(&self) -> u32 {
+#[async_trait]
+impl FlashloanProvider for MarginfiFlashloanManager {
+    fn protocol(&self) -> FlashloanProtocol {
+        FlashloanProtocol::Marginfi
+    }
+
+    fn program_id(&self) -> Pubkey {
+        *super::PROGRAM_ID
+    }
+
+    fn supported_mints(&self) -> Vec<Pubkey> {
+        liquidity_vaults()
+            .into_iter()
+            .map(|(mint, _)| mint)
+            .collect()
+    }
+
+    fn supports_mint(&self, mint: &Pubkey) -> bool {
+        supports_mint(mint)
+    }
+
+    /// Marginfi 闪电贷不收取手续费。
+    fn fee(&self, _mint: &Pubkey, _amount: u64) -> u64 {
+        0
+    }
+
+    fn compute_unit_overhead(&self) -> u32 {
         self.compute_unit_overhead
     }
 
-    pub fn adopt_preparation(&mut self, prep: MarginfiFlashloanPreparation) {
-        if self.enabled {
+    /// bank 支持该 mint 且最近一次刷新的金库余额足以覆盖借款。
+    fn can_serve(&self, mint: &Pubkey, amount: u64) -> bool {
+        self.fallback_marginfi.is_some()
+            && supports_mint(mint)
+            && self.liquidity.can_cover(mint, amount)
+    }
+
+    fn available_liquidity(&self, mint: &Pubkey) -> Option<u64> {
+        self.liquidity.available(mint)
+    }
+
+    /// 定期读取各 bank 流动性金库余额，金库被借空时让出给其他闪电贷来源。
+    fn spawn_liquidity_refresh(&self, interval: Duration) -> JoinHandle<()> {
+        let rpc = Arc::clone(&self.rpc);
+        let liquidity = self.liquidity.clone();
+        let vaults = liquidity_vaults();
+        tokio::spawn(async move {
+            let addresses: Vec<Pubkey> = vaults.iter().map(|(_, vault)| *vault).collect();
+            loop {
+                match rpc.get_multiple_accounts(&addresses).await {
+                    Ok(accounts) => {
+                        for ((mint, _), account) in vaults.iter().zip(accounts) {
+                            let available = account
+                                .and_then(|account| token_account_amount(&account.data))
+                                .unwrap_or(0);
+                            liquidity.update(*mint, available);
+                        }
+                    }
+                    Err(err) => warn!(
+                        target: "flashloan::marginfi",
+                        error = %err,
+                        "刷新 Marginfi 流动性失败"
+                    ),
+                }
+                tokio::time::sleep(interval).await;
+            }
+        })
+    }
+
+    fn adopt_preparation(&mut self, prep: &FlashloanPreparation) {
+        if self.enabled && prep.protocol == FlashloanProtocol::Marginfi {
             self.fallback_marginfi = Some(MarginfiFlashloan::new(prep.account));
             self.configured_default = Some(prep.account);
         }
     }
 
-    pub async fn prepare(
+    async fn prepare(
         &mut self,
         identity: &EngineIdentity,
-    ) -> FlashloanResult<Option<MarginfiFlashloanPreparation>> {
+    ) -> FlashloanResult<Option<FlashloanPreparation>> {
         if !self.enabled {
             return Ok(None);
         }
 
         if let Some(existing) = &self.fallback_marginfi {
-            return Ok(Some(MarginfiFlashloanPreparation {
+            return Ok(Some(FlashloanPreparation {
+                protocol: FlashloanProtocol::Marginfi,
                 account: existing.account(),
                 created: false,
             }));
@@ -111,11 +181,14 @@ impl MarginfiFlashloanManager {
         let MarginfiAccountEnsure { account, created } =
             ensure_marginfi_account(&self.rpc, identity, self.configured_default).await?;
         self.fallback_marginfi = Some(MarginfiFlashloan::new(account));
-        Ok(Some(MarginfiFlashloanPreparation { account, created }))
+        Ok(Some(FlashloanPreparation {
+            protocol: FlashloanProtocol::Marginfi,
+            account,
+            created,
+        }))
     }
 
-    #[cfg_attr(feature = "hotpath", hotpath::measure)]
-    pub async fn assemble(
+    async fn assemble(
         &self,
         identity: &EngineIdentity,
         opportunity: &SwapOpportunity,
@@ -165,30 +238,6 @@ impl MarginfiFlashloanManager {
             opportunity.amount_in,
         )
     }
-
-    async fn wallet_balance(&self, owner: &Pubkey, mint: &Pubkey) -> FlashloanResult<u64> {
-        let ata = compute_associated_token_address(owner, mint);
-        if let Some(entry) = self.balance_cache.get(&ata).await {
-            return Ok(*entry);
-        }
-
-        let amount = match self.rpc.get_token_account_balance(&ata).await {
-            Ok(balance) => balance.amount.parse::<u64>().unwrap_or(0),
-            Err(err) => {
-                if is_account_not_found(&err) {
-                    0
-                } else {
-                    return Err(FlashloanError::Rpc(err));
-                }
-            }
-        };
-
-        self.balance_cache
-            .insert(ata, amount, Some(BALANCE_STATIC_TTL))
-            .await;
-
-        Ok(amount)
-    }
 }
 
 impl fmt::Debug for MarginfiFlashloanManager {
@@ -206,20 +255,3 @@ fn combine(mut prefix: Vec<Instruction>, mut body: Vec<Instruction>) -> Vec<Inst
     prefix.append(&mut body);
     prefix
 }
-
-fn is_account_not_found(err: &ClientError) -> bool {
-    match err.kind() {
-        ClientErrorKind::RpcError(RpcError::RpcResponseError { message, .. }) => {
-            is_account_missing_message(message)
-        }
-        ClientErrorKind::RpcError(RpcError::ForUser(message)) => {
-            is_account_missing_message(message)
-        }
-        _ => false,
-    }
-}
-
-fn is_account_missing_message(message: &str) -> bool {
-    let normalized = message.to_ascii_lowercase();
-    normalized.contains("could not find account") || normalized.contains("account does not exist")
-}
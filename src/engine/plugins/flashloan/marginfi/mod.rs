@@ -2,9 +2,7 @@ mod account;
 mod manager;
 
 pub use account::{find_marginfi_account_by_authority, marginfi_account_matches_authority};
-pub use manager::{
-    MarginfiAccountRegistry, MarginfiFlashloanManager, MarginfiFlashloanPreparation,
-};
+pub use manager::{MarginfiAccountRegistry, MarginfiFlashloanManager};
 
 use once_cell::sync::Lazy;
 use solana_sdk::pubkey::Pubkey;
@@ -12,9 +12,8 @@ use crate::config::FlashloanMarginfiConfig;
 use crate::engine::{EngineIdentity, SwapInstructionsVariant, SwapOpportunity};
 use crate::strategy::types::TradePair;
 
-use super::{
-    MarginfiAccountRegistry, MarginfiFlashloanManager, MarginfiFlashloanPreparation, PROGRAM_ID,
-};
+use super::{MarginfiAccountRegistry, MarginfiFlashloanManager, PROGRAM_ID};
+use crate::engine::plugins::flashloan::{FlashloanPreparation, FlashloanProvider};
 use crate::instructions::flashloan::marginfi::build_close_instruction;
 use crate::instructions::flashloan::types::FlashloanProtocol;
 
@@ -77,7 +76,8 @@ async fn marginfi_wraps_instructions() {
     let rpc = Arc::new(RpcClient::new_mock("mock://marginfi".to_string()));
     let registry = MarginfiAccountRegistry::new(None);
     let mut manager = MarginfiFlashloanManager::new(&config, true, false, rpc, registry);
-    manager.adopt_preparation(MarginfiFlashloanPreparation {
+    manager.adopt_preparation(&FlashloanPreparation {
+        protocol: FlashloanProtocol::Marginfi,
         account: marginfi_account,
         created: false,
     });
@@ -1,9 +1,17 @@
+mod balance;
+pub mod kamino;
 pub mod marginfi;
+mod provider;
+mod registry;
 
+pub use crate::instructions::flashloan::types::FlashloanProtocol;
 pub use crate::instructions::flashloan::{
     FlashloanError, FlashloanMetadata, FlashloanOutcome, FlashloanResult,
 };
+pub use kamino::KaminoFlashloanManager;
 pub use marginfi::{
-    MarginfiAccountRegistry, MarginfiFlashloanManager, MarginfiFlashloanPreparation,
-    find_marginfi_account_by_authority, marginfi_account_matches_authority,
+    MarginfiAccountRegistry, MarginfiFlashloanManager, find_marginfi_account_by_authority,
+    marginfi_account_matches_authority,
 };
+pub use provider::{FlashloanPreparation, FlashloanProvider};
+pub use registry::FlashloanRegistry;
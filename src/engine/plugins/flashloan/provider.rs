@@ -0,0 +1,66 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use solana_sdk::pubkey::Pubkey;
+use tokio::task::JoinHandle;
+
+use crate::engine::plugins::flashloan::{FlashloanOutcome, FlashloanResult};
+use crate::engine::{EngineIdentity, SwapInstructionsVariant, SwapOpportunity};
+use crate::instructions::flashloan::types::FlashloanProtocol;
+
+/// 启动阶段为闪电贷准备的钱包侧账户（如 Marginfi account）。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FlashloanPreparation {
+    pub protocol: FlashloanProtocol,
+    pub account: Pubkey,
+    pub created: bool,
+}
+
+/// 闪电贷来源：负责可借资产发现、流动性与费用报价，以及用 borrow/repay 包裹主体指令。
+#[async_trait]
+pub trait FlashloanProvider: Send + Sync {
+    fn protocol(&self) -> FlashloanProtocol;
+
+    /// 借还指令所属程序；这类指令互相记录绝对下标，必须留在同一笔交易内。
+    fn program_id(&self) -> Pubkey;
+
+    /// 启动时发现可借资产并准备所需账户；不需要钱包侧账户的来源返回 None。
+    async fn prepare(
+        &mut self,
+        identity: &EngineIdentity,
+    ) -> FlashloanResult<Option<FlashloanPreparation>>;
+
+    /// 采用账户预检阶段已经准备好的账户，协议不匹配时忽略。
+    fn adopt_preparation(&mut self, _preparation: &FlashloanPreparation) {}
+
+    fn supported_mints(&self) -> Vec<Pubkey>;
+
+    fn supports_mint(&self, mint: &Pubkey) -> bool {
+        self.supported_mints().contains(mint)
+    }
+
+    /// 最近一次刷新的可借余额，尚未刷新时为 None。
+    fn available_liquidity(&self, mint: &Pubkey) -> Option<u64>;
+
+    /// 借出 `amount` 需支付的手续费（base mint 最小单位）。
+    fn fee(&self, mint: &Pubkey, amount: u64) -> u64;
+
+    fn compute_unit_overhead(&self) -> u32;
+
+    /// 支持该 mint 且已知流动性足以覆盖借款（未知视为可用）。
+    fn can_serve(&self, mint: &Pubkey, amount: u64) -> bool {
+        self.supports_mint(mint)
+            && self
+                .available_liquidity(mint)
+                .is_none_or(|available| available >= amount)
+    }
+
+    fn spawn_liquidity_refresh(&self, interval: Duration) -> JoinHandle<()>;
+
+    async fn assemble(
+        &self,
+        identity: &EngineIdentity,
+        opportunity: &SwapOpportunity,
+        variant: &SwapInstructionsVariant,
+    ) -> FlashloanResult<FlashloanOutcome>;
+}
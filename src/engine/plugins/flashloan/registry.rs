@@ -0,0 +1,221 @@
+use std::cmp::Reverse;
+use std::fmt;
+use std::time::Duration;
+
+use solana_sdk::pubkey::Pubkey;
+use tokio::task::JoinHandle;
+
+use super::provider::FlashloanProvider;
+
+/// 已启用的闪电贷来源，按配置顺序注册；每次借款按 mint 挑选手续费最低、流动性最充足的来源。
+#[derive(Default)]
+pub struct FlashloanRegistry {
+    providers: Vec<Box<dyn FlashloanProvider>>,
+}
+
+impl FlashloanRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register(&mut self, provider: Box<dyn FlashloanProvider>) {
+        self.providers.push(provider);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.providers.is_empty()
+    }
+
+    pub fn providers(&self) -> impl Iterator<Item = &dyn FlashloanProvider> {
+        self.providers.iter().map(|provider| provider.as_ref())
+    }
+
+    /// 能覆盖本次借款的来源，手续费低者优先，其次可借余额多者优先，其余按配置顺序。
+    pub fn rank(&self, mint: &Pubkey, amount: u64) -> Vec<&dyn FlashloanProvider> {
+        let mut candidates: Vec<&dyn FlashloanProvider> = self
+            .providers()
+            .filter(|provider| provider.can_serve(mint, amount))
+            .collect();
+        candidates.sort_by_key(|provider| {
+            (
+                provider.fee(mint, amount),
+                Reverse(provider.available_liquidity(mint).unwrap_or(0)),
+            )
+        });
+        candidates
+    }
+
+    /// 选出本次借款的来源；流动性快照显示都不够时退回第一个支持该 mint 的来源，由链上结果兜底。
+    pub fn select(&self, mint: &Pubkey, amount: u64) -> Option<&dyn FlashloanProvider> {
+        self.rank(mint, amount).into_iter().next().or_else(|| {
+            self.providers()
+                .find(|provider| provider.supports_mint(mint))
+        })
+    }
+
+    /// 已注册来源的闪电贷程序，打包时含这些程序的交易不允许拆分。
+    pub fn program_ids(&self) -> Vec<Pubkey> {
+        self.providers()
+            .map(|provider| provider.program_id())
+            .collect()
+    }
+
+    pub fn spawn_liquidity_refresh(&self, interval: Duration) -> Vec<JoinHandle<()>> {
+        self.providers()
+            .map(|provider| provider.spawn_liquidity_refresh(interval))
+            .collect()
+    }
+}
+
+impl fmt::Debug for FlashloanRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(
+                self.providers()
+                    .map(|provider| provider.protocol().as_str()),
+            )
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use async_trait::async_trait;
+
+    use super::*;
+    use crate::engine::plugins::flashloan::{
+        FlashloanOutcome, FlashloanPreparation, FlashloanResult,
+    };
+    use crate::engine::{EngineIdentity, SwapInstructionsVariant, SwapOpportunity};
+    use crate::instructions::flashloan::types::FlashloanProtocol;
+
+    struct StubProvider {
+        protocol: FlashloanProtocol,
+        fee_bps: u64,
+        liquidity: HashMap<Pubkey, Option<u64>>,
+    }
+
+    impl StubProvider {
+        fn new(
+            protocol: FlashloanProtocol,
+            fee_bps: u64,
+            liquidity: &[(Pubkey, Option<u64>)],
+        ) -> Self {
+            Self {
+                protocol,
+                fee_bps,
+                liquidity: liquidity.iter().copied().collect(),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl FlashloanProvider for StubProvider {
+        fn protocol(&self) -> FlashloanProtocol {
+            self.protocol
+        }
+
+        fn program_id(&self) -> Pubkey {
+            Pubkey::default()
+        }
+
+        async fn prepare(
+            &mut self,
+            _identity: &EngineIdentity,
+        ) -> FlashloanResult<Option<FlashloanPreparation>> {
+            Ok(None)
+        }
+
+        fn supported_mints(&self) -> Vec<Pubkey> {
+            self.liquidity.keys().copied().collect()
+        }
+
+        fn available_liquidity(&self, mint: &Pubkey) -> Option<u64> {
+            self.liquidity.get(mint).copied().flatten()
+        }
+
+        fn fee(&self, _mint: &Pubkey, amount: u64) -> u64 {
+            amount * self.fee_bps / 10_000
+        }
+
+        fn compute_unit_overhead(&self) -> u32 {
+            0
+        }
+
+        fn spawn_liquidity_refresh(&self, _interval: Duration) -> JoinHandle<()> {
+            tokio::spawn(async {})
+        }
+
+        async fn assemble(
+            &self,
+            _identity: &EngineIdentity,
+            _opportunity: &SwapOpportunity,
+            variant: &SwapInstructionsVariant,
+        ) -> FlashloanResult<FlashloanOutcome> {
+            Ok(FlashloanOutcome {
+                instructions: variant.flatten_instructions(),
+                metadata: None,
+            })
+        }
+    }
+
+    fn registry(providers: Vec<StubProvider>) -> FlashloanRegistry {
+        let mut registry = FlashloanRegistry::new();
+        for provider in providers {
+            registry.register(Box::new(provider));
+        }
+        registry
+    }
+
+    #[test]
+    fn select_prefers_lower_fee() {
+        let mint = Pubkey::new_unique();
+        let registry = registry(vec![
+            StubProvider::new(FlashloanProtocol::Kamino, 9, &[(mint, Some(1_000_000))]),
+            StubProvider::new(FlashloanProtocol::Marginfi, 0, &[(mint, Some(1_000_000))]),
+        ]);
+        let selected = registry.select(&mint, 10_000).expect("provider");
+        assert_eq!(selected.protocol(), FlashloanProtocol::Marginfi);
+    }
+
+    #[test]
+    fn select_prefers_deeper_liquidity_on_equal_fee() {
+        let mint = Pubkey::new_unique();
+        let registry = registry(vec![
+            StubProvider::new(FlashloanProtocol::Marginfi, 0, &[(mint, Some(20_000))]),
+            StubProvider::new(FlashloanProtocol::Kamino, 0, &[(mint, Some(5_000_000))]),
+        ]);
+        let selected = registry.select(&mint, 10_000).expect("provider");
+        assert_eq!(selected.protocol(), FlashloanProtocol::Kamino);
+    }
+
+    #[test]
+    fn rank_skips_drained_or_unsupported_providers() {
+        let mint = Pubkey::new_unique();
+        let other = Pubkey::new_unique();
+        let registry = registry(vec![
+            StubProvider::new(FlashloanProtocol::Marginfi, 0, &[(mint, Some(100))]),
+            StubProvider::new(FlashloanProtocol::Kamino, 9, &[(mint, None)]),
+        ]);
+        let ranked: Vec<_> = registry
+            .rank(&mint, 10_000)
+            .into_iter()
+            .map(|provider| provider.protocol())
+            .collect();
+        assert_eq!(ranked, vec![FlashloanProtocol::Kamino]);
+        assert!(registry.select(&other, 10_000).is_none());
+    }
+
+    #[test]
+    fn select_falls_back_to_first_supporting_provider() {
+        let mint = Pubkey::new_unique();
+        let registry = registry(vec![
+            StubProvider::new(FlashloanProtocol::Marginfi, 0, &[(mint, Some(10))]),
+            StubProvider::new(FlashloanProtocol::Kamino, 0, &[(mint, Some(20))]),
+        ]);
+        let selected = registry.select(&mint, 10_000).expect("provider");
+        assert_eq!(selected.protocol(), FlashloanProtocol::Marginfi);
+    }
+}
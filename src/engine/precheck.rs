@@ -14,7 +14,7 @@ use tracing::{info, warn};
 use super::{EngineError, EngineIdentity, EngineResult};
 use crate::cache::cached_associated_token_address;
 use crate::engine::plugins::flashloan::{
-    FlashloanError, MarginfiAccountRegistry, MarginfiFlashloanPreparation,
+    FlashloanError, FlashloanPreparation, FlashloanProtocol, MarginfiAccountRegistry,
     find_marginfi_account_by_authority, marginfi_account_matches_authority,
 };
 use crate::instructions::flashloan::marginfi::build_initialize_instruction;
@@ -108,7 +108,7 @@ impl AccountPrechecker {
         identity: &EngineIdentity,
         trade_pairs: &[TradePair],
         flashloan_enabled: bool,
-    ) -> EngineResult<(PrecheckSummary, Option<MarginfiFlashloanPreparation>)> {
+    ) -> EngineResult<(PrecheckSummary, Option<FlashloanPreparation>)> {
         let candidates = self.collect_candidates(identity, trade_pairs)?;
         let (states, skipped) = self.classify_account_states(&candidates).await?;
 
@@ -355,10 +355,7 @@ impl AccountPrechecker {
         &self,
         identity: &EngineIdentity,
         instructions: &mut Vec<Instruction>,
-    ) -> EngineResult<(
-        Option<MarginfiCreationPlan>,
-        Option<MarginfiFlashloanPreparation>,
-    )> {
+    ) -> EngineResult<(Option<MarginfiCreationPlan>, Option<FlashloanPreparation>)> {
         if let Some(configured) = self.marginfi_accounts.default() {
             self.verify_marginfi_account(
                 identity,
@@ -368,7 +365,8 @@ impl AccountPrechecker {
             .await?;
             return Ok((
                 None,
-                Some(MarginfiFlashloanPreparation {
+                Some(FlashloanPreparation {
+                    protocol: FlashloanProtocol::Marginfi,
                     account: configured,
                     created: false,
                 }),
@@ -379,7 +377,8 @@ impl AccountPrechecker {
         match lookup {
             Ok(Some(account)) => Ok((
                 None,
-                Some(MarginfiFlashloanPreparation {
+                Some(FlashloanPreparation {
+                    protocol: FlashloanProtocol::Marginfi,
                     account,
                     created: false,
                 }),
@@ -389,7 +388,8 @@ impl AccountPrechecker {
                 let instruction = build_initialize_instruction(keypair.pubkey(), &identity.pubkey)
                     .map_err(EngineError::from)?;
                 instructions.insert(0, instruction);
-                let preparation = MarginfiFlashloanPreparation {
+                let preparation = FlashloanPreparation {
+                    protocol: FlashloanProtocol::Marginfi,
                     account: keypair.pubkey(),
                     created: true,
                 };
@@ -405,7 +405,8 @@ impl AccountPrechecker {
                 let instruction = build_initialize_instruction(keypair.pubkey(), &identity.pubkey)
                     .map_err(EngineError::from)?;
                 instructions.insert(0, instruction);
-                let preparation = MarginfiFlashloanPreparation {
+                let preparation = FlashloanPreparation {
+                    protocol: FlashloanProtocol::Marginfi,
                     account: keypair.pubkey(),
                     created: true,
                 };
@@ -140,6 +140,17 @@ impl QuoteExecutor {
         }
     }
 
+    /// 指标中使用的聚合器标签。
+    pub fn aggregator_label(&self) -> &'static str {
+        match &self.backend {
+            QuoteBackend::Jupiter { .. } => "jupiter",
+            QuoteBackend::Dflow { .. } => "dflow",
+            QuoteBackend::Kamino { .. } => "kamino",
+            QuoteBackend::Ultra { .. } => "ultra",
+            QuoteBackend::Disabled => "disabled",
+        }
+    }
+
     #[cfg_attr(feature = "hotpath", hotpath::measure)]
     pub async fn quote_once(
         &self,
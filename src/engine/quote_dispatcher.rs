@@ -393,40 +393,25 @@ impl QuoteDispatcher {
             },
         };
 
-        match &result {
-            Ok(Some(_)) => {
-                events::quote_end(
-                    strategy_label.as_str(),
-                    &task,
-                    true,
-                    started.elapsed(),
-                    Some(batch.batch_id),
-                    forward_ip,
-                );
-            }
-            Ok(None) => {
-                events::quote_end(
-                    strategy_label.as_str(),
-                    &task,
-                    false,
-                    started.elapsed(),
-                    Some(batch.batch_id),
-                    forward_ip,
-                );
-            }
-            Err(err) => {
-                events::quote_end(
-                    strategy_label.as_str(),
-                    &task,
-                    false,
-                    started.elapsed(),
-                    Some(batch.batch_id),
-                    forward_ip,
-                );
-                if let Some(handle) = forward_handle.take() {
-                    if let Some(outcome) = classify_ip_outcome(err) {
-                        handle.mark_outcome(outcome);
-                    }
+        let aggregator = quote_executor.aggregator_label();
+        let quote_result = match &result {
+            Ok(Some(_)) => "success",
+            Ok(None) => "empty",
+            Err(_) => "error",
+        };
+        events::quote_end(
+            strategy_label.as_str(),
+            aggregator,
+            &task,
+            quote_result,
+            started.elapsed(),
+            Some(batch.batch_id),
+            forward_ip,
+        );
+        if let Err(err) = &result {
+            if let Some(handle) = forward_handle.take() {
+                if let Some(outcome) = classify_ip_outcome(err) {
+                    handle.mark_outcome(outcome);
                 }
             }
         }
@@ -2,16 +2,25 @@ use std::collections::{HashMap, HashSet};
 use std::time::{Duration, Instant};
 
 use reqwest::Client;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::instruction::Instruction;
 use solana_sdk::pubkey::Pubkey;
 use tracing::{debug, warn};
 
-use crate::engine::{EngineResult, LighthouseSettings, SolPriceFeedSettings};
+use crate::cache::cached_associated_token_address;
+use crate::engine::{EngineResult, GuardSettlement, LighthouseSettings, SolPriceFeedSettings};
+use crate::instructions::guards::lighthouse::{GuardAccount, GuardTarget};
 
 const MIN_LIGHTHOUSE_MEMORY_SLOTS: usize = 1;
 const MAX_LIGHTHOUSE_MEMORY_SLOTS: usize = 128;
 const LAMPORTS_PER_SOL: u64 = 1_000_000_000;
 const WSOL_MINT: Pubkey = solana_sdk::pubkey!("So11111111111111111111111111111111111111112");
 const USDC_MINT: Pubkey = solana_sdk::pubkey!("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v");
+const SPL_TOKEN_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+/// Token 账户租金豁免下限：SPL Token 165 字节；Token-2022 ATA 默认带 ImmutableOwner 扩展，为 170 字节。
+const TOKEN_ACCOUNT_RENT_LAMPORTS: u64 = 2_039_280;
+const TOKEN_2022_ACCOUNT_RENT_LAMPORTS: u64 = 2_074_080;
 
 pub(crate) struct LighthouseRuntime {
     pub(super) enabled: bool,
@@ -20,6 +29,31 @@ pub(crate) struct LighthouseRuntime {
     pub(super) available_ids: Vec<u8>,
     pub(super) cursor: usize,
     sol_price_feed: Option<SolPriceFeed>,
+    native_sol_guard: bool,
+    settlements: HashMap<Pubkey, GuardSettlement>,
+}
+
+impl LighthouseSettings {
+    /// 查询守护 mint 的所有者程序：Token-2022 mint 的 ATA 必须按其 program 派生。
+    pub async fn resolve_token_programs(&mut self, rpc: &RpcClient) -> EngineResult<()> {
+        if !self.enable || self.profit_guard_mints.is_empty() {
+            return Ok(());
+        }
+        let accounts = rpc.get_multiple_accounts(&self.profit_guard_mints).await?;
+        for (mint, account) in self.profit_guard_mints.iter().zip(accounts) {
+            match account {
+                Some(account) => {
+                    self.token_programs.insert(*mint, account.owner);
+                }
+                None => warn!(
+                    target: "engine::lighthouse",
+                    mint = %mint,
+                    "profit guard mint 账户不存在，按 SPL Token 派生 ATA"
+                ),
+            }
+        }
+        Ok(())
+    }
 }
 
 impl LighthouseRuntime {
@@ -47,7 +81,12 @@ impl LighthouseRuntime {
 
         let mut guard_assets = HashMap::new();
         for mint in &guard_mints {
-            if let Some(config) = GuardAssetConfig::infer_from_mint(*mint) {
+            let token_program = settings
+                .token_programs
+                .get(mint)
+                .copied()
+                .unwrap_or(SPL_TOKEN_PROGRAM_ID);
+            if let Some(config) = GuardAssetConfig::infer_from_mint(*mint, token_program) {
                 guard_assets.insert(*mint, config);
             } else {
                 warn!(
@@ -60,6 +99,7 @@ impl LighthouseRuntime {
                     GuardAssetConfig {
                         decimals: 9,
                         denomination: GuardDenomination::Native,
+                        token_program,
                     },
                 );
             }
@@ -77,6 +117,8 @@ impl LighthouseRuntime {
             available_ids,
             cursor: 0,
             sol_price_feed,
+            native_sol_guard: settings.native_sol_guard,
+            settlements: settings.settlements.clone(),
         }
     }
 
@@ -84,36 +126,145 @@ impl LighthouseRuntime {
         self.enabled && self.guard_assets.contains_key(mint)
     }
 
-    pub(crate) fn next_memory_id(&mut self) -> u8 {
-        if !self.enabled {
-            return 0;
+    /// 为一笔交易分配 `count` 个互不相同的 memory id；已有 id 不足时补充最小的未用 id。
+    pub(crate) fn allocate_memory_ids(&mut self, count: usize) -> Vec<u8> {
+        if !self.enabled || count == 0 {
+            return Vec::new();
         }
-        if self.available_ids.is_empty() {
-            let id = 0u8;
-            if self.memory_slots > 1 {
+        let count = count.min(MAX_LIGHTHOUSE_MEMORY_SLOTS);
+        if self.available_ids.len() < count {
+            if self.memory_slots > self.available_ids.len() {
                 self.available_ids
-                    .reserve(self.memory_slots.saturating_sub(1));
+                    .reserve(self.memory_slots - self.available_ids.len());
+            }
+            let mut candidate = 0u8;
+            while self.available_ids.len() < count {
+                if !self.available_ids.contains(&candidate) {
+                    self.available_ids.push(candidate);
+                }
+                candidate = candidate.saturating_add(1);
             }
-            self.available_ids.push(id);
-            return id;
         }
 
-        let idx = if self.available_ids.len() == 1 {
-            0
-        } else {
-            let current = self.cursor % self.available_ids.len();
-            self.cursor = (self.cursor + 1) % self.available_ids.len();
-            current
+        let len = self.available_ids.len();
+        let start = self.cursor % len;
+        self.cursor = (start + count) % len;
+        (0..count)
+            .map(|offset| self.available_ids[(start + offset) % len])
+            .collect()
+    }
+
+    /// 生成本次路线的守护目标。
+    ///
+    /// 未配置结算位置时断言 base mint ATA 的净增量不低于 `required`；结算位置不同时，起始账户只允许
+    /// 支出 `amount_in`，结算位置需收到 `amount_in + required`。原生 SOL 结算仅适用于 WSOL 路线，
+    /// 并扣除 payer 支付的 tip 与租金。首个目标始终是利润断言，落地阶段据此追加缓冲。
+    pub(crate) fn guard_targets(
+        &self,
+        payer: &Pubkey,
+        mint: &Pubkey,
+        required: u64,
+        amount_in: Option<u64>,
+        tip_lamports: u64,
+        rent_lamports: u64,
+    ) -> Vec<GuardTarget> {
+        let Some(config) = self.guard_assets.get(mint) else {
+            return Vec::new();
         };
-        self.available_ids[idx]
+        let start_account = cached_associated_token_address(payer, mint, &config.token_program);
+        let start = GuardAccount::TokenAmount(start_account);
+        let required = i128::from(required);
+        let spent = i128::from(tip_lamports) + i128::from(rent_lamports);
+
+        let settle = match self.settlements.get(mint) {
+            Some(GuardSettlement::TokenAccount(account)) if *account != start_account => {
+                Some((GuardAccount::TokenAmount(*account), required))
+            }
+            Some(GuardSettlement::Native) if *mint == WSOL_MINT => {
+                Some((GuardAccount::Lamports(*payer), required - spent))
+            }
+            _ => None,
+        };
+
+        let mut targets = Vec::with_capacity(3);
+        match settle {
+            None => targets.push(GuardTarget {
+                account: start,
+                min_delta: required,
+            }),
+            Some((account, min_delta)) => match amount_in {
+                Some(amount_in) => {
+                    let amount_in = i128::from(amount_in);
+                    targets.push(GuardTarget {
+                        account,
+                        min_delta: amount_in + min_delta,
+                    });
+                    targets.push(GuardTarget {
+                        account: start,
+                        min_delta: -amount_in,
+                    });
+                }
+                None => targets.push(GuardTarget { account, min_delta }),
+            },
+        }
+
+        let payer_lamports = GuardAccount::Lamports(*payer);
+        if self.native_sol_guard
+            && targets
+                .iter()
+                .all(|target| target.account != payer_lamports)
+        {
+            targets.push(GuardTarget {
+                account: payer_lamports,
+                min_delta: -spent,
+            });
+        }
+        targets
     }
 
+    /// 估算路线中由 payer 支付的租金：ATA 创建（幂等创建按需要付租金计）与 System CreateAccount。
+    pub(crate) fn expected_rent_lamports<'a>(
+        payer: &Pubkey,
+        instructions: impl IntoIterator<Item = &'a Instruction>,
+    ) -> u64 {
+        instructions
+            .into_iter()
+            .map(|ix| {
+                if ix.program_id == spl_associated_token_account::ID {
+                    let creates = matches!(ix.data.first(), None | Some(0) | Some(1));
+                    if !creates || ix.accounts.first().map(|meta| meta.pubkey) != Some(*payer) {
+                        return 0;
+                    }
+                    return match ix.accounts.get(5) {
+                        Some(meta) if meta.pubkey == spl_token_2022::ID => {
+                            TOKEN_2022_ACCOUNT_RENT_LAMPORTS
+                        }
+                        _ => TOKEN_ACCOUNT_RENT_LAMPORTS,
+                    };
+                }
+                if ix.program_id == solana_system_interface::program::ID
+                    && ix.data.len() >= 12
+                    && ix.data[..4] == 0u32.to_le_bytes()
+                    && ix.accounts.first().map(|meta| meta.pubkey) == Some(*payer)
+                {
+                    let mut lamports = [0u8; 8];
+                    lamports.copy_from_slice(&ix.data[4..12]);
+                    return u64::from_le_bytes(lamports);
+                }
+                0
+            })
+            .fold(0u64, u64::saturating_add)
+    }
+
+    /// 计算 base mint 计价的守护阈值：`lamports_required` 按 SOL 价格换算，`base_units` 已是 base mint
+    /// 最小单位（如闪电贷手续费），换算后直接叠加。
     pub(crate) async fn guard_amount_for(
         &mut self,
         mint: &Pubkey,
         lamports_required: u64,
+        base_units: u64,
     ) -> EngineResult<Option<u64>> {
-        if lamports_required == 0 || !self.should_guard(mint) {
+        if (lamports_required == 0 && base_units == 0) || !self.should_guard(mint) {
             return Ok(None);
         }
         let Some(config) = self.guard_assets.get(mint).copied() else {
@@ -135,7 +286,7 @@ impl LighthouseRuntime {
             }
         };
 
-        Ok(Some(amount))
+        Ok(Some(amount.saturating_add(base_units)))
     }
 }
 
@@ -143,6 +294,7 @@ impl LighthouseRuntime {
 struct GuardAssetConfig {
     decimals: u8,
     denomination: GuardDenomination,
+    token_program: Pubkey,
 }
 
 #[derive(Clone, Copy)]
@@ -152,16 +304,18 @@ enum GuardDenomination {
 }
 
 impl GuardAssetConfig {
-    fn infer_from_mint(mint: Pubkey) -> Option<Self> {
+    fn infer_from_mint(mint: Pubkey, token_program: Pubkey) -> Option<Self> {
         if mint == WSOL_MINT {
             Some(Self {
                 decimals: 9,
                 denomination: GuardDenomination::Native,
+                token_program,
             })
         } else if mint == USDC_MINT {
             Some(Self {
                 decimals: 6,
                 denomination: GuardDenomination::SolEquivalent,
+                token_program,
             })
         } else {
             None
@@ -190,6 +344,12 @@ impl SolPriceFeed {
         }
     }
 
+    #[cfg(test)]
+    fn seed(&mut self, price: SolUsdPrice) {
+        self.last_price = Some(price);
+        self.last_updated = Some(Instant::now());
+    }
+
     async fn latest(&mut self) -> EngineResult<SolUsdPrice> {
         let should_refresh = match self.last_updated {
             Some(instant) => instant.elapsed() >= self.refresh,
@@ -302,3 +462,165 @@ fn convert_lamports_to_token(lamports: u64, token_decimals: u8, price: &SolUsdPr
 
     amount.min(u64::MAX as i128) as u64
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn runtime(settings: LighthouseSettings) -> LighthouseRuntime {
+        LighthouseRuntime::new(&settings, 4)
+    }
+
+    fn wsol_settings() -> LighthouseSettings {
+        LighthouseSettings {
+            enable: true,
+            profit_guard_mints: vec![WSOL_MINT],
+            memory_slots: Some(4),
+            ..LighthouseSettings::default()
+        }
+    }
+
+    #[test]
+    fn allocate_memory_ids_returns_distinct_rotating_ids() {
+        let mut settings = wsol_settings();
+        settings.existing_memory_ids = vec![3, 7];
+        let mut lighthouse = runtime(settings);
+
+        assert_eq!(lighthouse.allocate_memory_ids(1), vec![3]);
+        assert_eq!(lighthouse.allocate_memory_ids(1), vec![7]);
+        let ids = lighthouse.allocate_memory_ids(3);
+        assert_eq!(ids.len(), 3);
+        let unique: HashSet<u8> = ids.iter().copied().collect();
+        assert_eq!(unique.len(), 3);
+        assert!(unique.contains(&0));
+    }
+
+    #[test]
+    fn guard_targets_default_to_start_account() {
+        let lighthouse = runtime(wsol_settings());
+        let payer = Pubkey::new_unique();
+        let targets =
+            lighthouse.guard_targets(&payer, &WSOL_MINT, 5_000, Some(1_000_000), 2_000, 0);
+        let ata = cached_associated_token_address(&payer, &WSOL_MINT, &SPL_TOKEN_PROGRAM_ID);
+        assert_eq!(
+            targets,
+            vec![GuardTarget {
+                account: GuardAccount::TokenAmount(ata),
+                min_delta: 5_000,
+            }]
+        );
+    }
+
+    #[test]
+    fn guard_targets_follow_native_settlement() {
+        let mut settings = wsol_settings();
+        settings.native_sol_guard = true;
+        settings
+            .settlements
+            .insert(WSOL_MINT, GuardSettlement::Native);
+        let lighthouse = runtime(settings);
+        let payer = Pubkey::new_unique();
+
+        let targets =
+            lighthouse.guard_targets(&payer, &WSOL_MINT, 5_000, Some(1_000_000), 2_000, 0);
+        assert_eq!(targets.len(), 2);
+        assert_eq!(targets[0].account, GuardAccount::Lamports(payer));
+        assert_eq!(targets[0].min_delta, 1_003_000);
+        assert_eq!(targets[1].min_delta, -1_000_000);
+    }
+
+    #[test]
+    fn payer_guard_allows_route_rent() {
+        let mut settings = wsol_settings();
+        settings.native_sol_guard = true;
+        let lighthouse = runtime(settings);
+        let payer = Pubkey::new_unique();
+        let create_ata =
+            spl_associated_token_account::instruction::create_associated_token_account_idempotent(
+                &payer,
+                &payer,
+                &Pubkey::new_unique(),
+                &SPL_TOKEN_PROGRAM_ID,
+            );
+        let rent = LighthouseRuntime::expected_rent_lamports(&payer, [&create_ata]);
+        assert_eq!(rent, TOKEN_ACCOUNT_RENT_LAMPORTS);
+
+        let targets =
+            lighthouse.guard_targets(&payer, &WSOL_MINT, 5_000, Some(1_000_000), 2_000, rent);
+        assert_eq!(
+            targets.last(),
+            Some(&GuardTarget {
+                account: GuardAccount::Lamports(payer),
+                min_delta: -(2_000 + i128::from(TOKEN_ACCOUNT_RENT_LAMPORTS)),
+            })
+        );
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn guard_amount_adds_base_units_after_conversion() {
+        let settings = LighthouseSettings {
+            enable: true,
+            profit_guard_mints: vec![USDC_MINT],
+            sol_price_feed: Some(SolPriceFeedSettings {
+                refresh: Duration::from_secs(3_600),
+                ..SolPriceFeedSettings::default()
+            }),
+            ..LighthouseSettings::default()
+        };
+        let mut lighthouse = runtime(settings);
+        lighthouse
+            .sol_price_feed
+            .as_mut()
+            .expect("price feed")
+            .seed(SolUsdPrice {
+                price: 15_000_000_000,
+                expo: -8,
+            });
+
+        // 0.001 SOL 按 150 USD 换算为 0.15 USDC，再加 padding 与 300 个 USDC 最小单位的手续费
+        let amount = lighthouse
+            .guard_amount_for(&USDC_MINT, 1_000_000, 300)
+            .await
+            .expect("guard amount");
+        assert_eq!(amount, Some(150_000 + 200 + 300));
+    }
+
+    #[test]
+    fn guard_targets_use_token_2022_ata() {
+        let token_2022 = solana_sdk::pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+        let mint = Pubkey::new_unique();
+        let settle = Pubkey::new_unique();
+        let mut settings = LighthouseSettings {
+            enable: true,
+            profit_guard_mints: vec![mint],
+            ..LighthouseSettings::default()
+        };
+        settings.token_programs.insert(mint, token_2022);
+        settings
+            .settlements
+            .insert(mint, GuardSettlement::TokenAccount(settle));
+        settings.native_sol_guard = true;
+        let lighthouse = runtime(settings);
+        let payer = Pubkey::new_unique();
+
+        let targets = lighthouse.guard_targets(&payer, &mint, 10, Some(100), 2_000, 0);
+        let ata = cached_associated_token_address(&payer, &mint, &token_2022);
+        assert_eq!(
+            targets,
+            vec![
+                GuardTarget {
+                    account: GuardAccount::TokenAmount(settle),
+                    min_delta: 110,
+                },
+                GuardTarget {
+                    account: GuardAccount::TokenAmount(ata),
+                    min_delta: -100,
+                },
+                GuardTarget {
+                    account: GuardAccount::Lamports(payer),
+                    min_delta: -2_000,
+                },
+            ]
+        );
+    }
+}
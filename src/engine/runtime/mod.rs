@@ -1,6 +1,7 @@
 pub mod lighthouse;
 pub mod multi_leg;
 pub mod strategy;
+pub mod wallets;
 
 pub(crate) use lighthouse::LighthouseRuntime;
 pub use multi_leg::MultiLegEngineContext;
@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 use std::time::Instant;
 
-use solana_sdk::instruction::AccountMeta;
+use solana_sdk::instruction::{AccountMeta, Instruction};
 use solana_sdk::pubkey::Pubkey;
 use tracing::warn;
 
@@ -37,6 +37,9 @@ use crate::engine::FALLBACK_CU_LIMIT;
 use super::BASE_TX_FEE_LAMPORTS;
 use super::StrategyEngine;
 
+/// 单条 Lighthouse AccountData 断言的 CU 预算。
+const PRICE_GUARD_CU_PER_ASSERTION: u32 = 2_000;
+
 impl<S> StrategyEngine<S>
 where
     S: Strategy<Event = StrategyEvent>,
@@ -81,9 +84,13 @@ where
 
         ensure_route_pair(first_step, last_step)?;
 
-        let mut ata_resolver = AtaResolver::new(self.identity.pubkey);
+        let markets: Vec<Pubkey> = order.steps.iter().map(|step| step.market).collect();
+        let wallet = self.wallets.select(&markets);
+        let payer = self.wallets.get(wallet).identity.pubkey;
+        let mut ata_resolver = AtaResolver::new(payer);
 
-        let (route_plan, remaining_accounts) = self.build_route_plan(order, &mut ata_resolver)?;
+        let (route_plan, remaining_accounts) =
+            self.build_route_plan(order, payer, &mut ata_resolver)?;
 
         let source_mint = first_step.input.mint;
         let source_program = first_step.input.token_program;
@@ -93,7 +100,7 @@ where
         let destination_account = ata_resolver.get(destination_mint, destination_program);
 
         let mut accounts = RouteV2Accounts::with_defaults(
-            self.identity.pubkey,
+            payer,
             source_account,
             destination_account,
             source_mint,
@@ -118,7 +125,19 @@ where
         .build()
         .map_err(|err| EngineError::Transaction(err.into()))?;
 
-        let compute_unit_limit = self.estimate_cu_limit(order);
+        let mut instructions: Vec<Instruction> = self
+            .settings
+            .price_guards
+            .iter()
+            .filter(|guard| guard.applies_to(&order.steps))
+            .flat_map(|guard| guard.band.instructions())
+            .collect();
+        let price_guard_count = instructions.len();
+        instructions.push(instruction);
+
+        let compute_unit_limit = self
+            .estimate_cu_limit(order)
+            .saturating_add(PRICE_GUARD_CU_PER_ASSERTION.saturating_mul(price_guard_count as u32));
         let compute_unit_price = if self.landers.has_jito() && !self.landers.has_non_jito() {
             0
         } else {
@@ -135,7 +154,7 @@ where
 
         let bundle = MultiLegInstructions::new(
             compute_budget_instructions,
-            vec![instruction],
+            instructions,
             lookup_table_addresses,
             lookup_table_accounts,
             None,
@@ -178,7 +197,8 @@ where
             deadline,
         );
 
-        self.dispatch_execution_plan(execution_plan, None).await
+        self.dispatch_execution_plan(execution_plan, None, wallet)
+            .await
     }
 
     fn estimate_cu_limit(&self, order: &BlindOrder) -> u32 {
@@ -203,6 +223,7 @@ where
     fn build_route_plan(
         &self,
         order: &BlindOrder,
+        payer: Pubkey,
         resolver: &mut AtaResolver,
     ) -> EngineResult<(Vec<RoutePlanStepV2>, Vec<AccountMeta>)> {
         if order.steps.is_empty() {
@@ -354,7 +375,7 @@ where
 
             let ctx = SwapAccountsContext {
                 market: step.market,
-                payer: self.identity.pubkey,
+                payer,
                 user_base,
                 user_quote,
                 flow: step.flow,
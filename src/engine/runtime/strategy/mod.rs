@@ -4,35 +4,42 @@ mod quote;
 mod swap;
 mod titan_driver;
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
+use std::str::FromStr;
 use std::sync::Arc;
 use std::time::Duration;
 
 use solana_sdk::pubkey::Pubkey;
 use tokio::pin;
 use tokio::sync::mpsc::{self, error::TryRecvError};
-use tracing::{debug, error, info, trace};
+use tracing::{debug, error, info, trace, warn};
 
 use self::{
     multi_leg::MultiLegDispatchResult,
     titan_driver::{TitanEventConfig, spawn_titan_event_driver},
 };
+use crate::config::WalletSelection;
 use crate::engine::context::{Action, StrategyContext, StrategyDecision, StrategyResources};
 use crate::engine::planner::{DispatchStrategy, TxVariantPlanner};
-use crate::engine::plugins::flashloan::MarginfiFlashloanManager;
+use crate::engine::plugins::flashloan::FlashloanRegistry;
+use crate::engine::runtime::wallets::{EngineWallet, PoolWallet, WalletPool};
 use crate::engine::runtime::{LighthouseRuntime, multi_leg::MultiLegEngineContext};
 use crate::engine::titan::subscription::{TitanSubscriptionPlan, TitanSubscriptionPlanner};
 use crate::engine::{
-    ComputeUnitPriceMode, EngineError, EngineIdentity, EngineResult, ProfitEvaluator, QuoteCadence,
-    QuoteConfig, QuoteDispatcher, QuoteExecutor, QuoteTask, Scheduler, StrategyTick, SwapPreparer,
-    TradeProfile, TransactionBuilder,
+    AltManager, ComputeUnitEstimator, ComputeUnitPriceMode, EngineError, EngineIdentity,
+    EngineResult, NoncePool, ProfitEvaluator, QuoteCadence, QuoteConfig, QuoteDispatcher,
+    QuoteExecutor, QuoteTask, Scheduler, StrategyTick, SwapPreparer, TradeProfile,
+    TransactionBuilder,
 };
+use crate::instructions::guards::price_band::PriceBand;
 use crate::lander::LanderStack;
 use crate::network::IpAllocator;
-use crate::strategy::types::TradePair;
+use crate::strategy::types::{BlindStep, TradePair};
 use crate::strategy::{Strategy, StrategyEvent};
 
 pub(super) const BASE_TX_FEE_LAMPORTS: u64 = 5_000;
+/// 策略被控制接口暂停时检查恢复的间隔。
+const PAUSED_POLL_INTERVAL: Duration = Duration::from_millis(500);
 
 #[derive(Clone)]
 pub(crate) struct MintSchedule {
@@ -62,6 +69,35 @@ pub struct LighthouseSettings {
     pub memory_slots: Option<u8>,
     pub existing_memory_ids: Vec<u8>,
     pub sol_price_feed: Option<SolPriceFeedSettings>,
+    /// 额外断言 payer 的 lamports 变化，窗口内只允许支出 tip
+    pub native_sol_guard: bool,
+    /// 起始账户与结算账户不同的路线：base mint → 利润落入的位置
+    pub settlements: HashMap<Pubkey, GuardSettlement>,
+    /// 守护 mint 所属的 token program，缺省按 SPL Token 处理
+    pub token_programs: HashMap<Pubkey, Pubkey>,
+}
+
+/// 路线利润最终落入的位置。
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GuardSettlement {
+    /// 指定的 token 账户（非 ATA 或其他 token program 下的账户）
+    TokenAccount(Pubkey),
+    /// payer 的原生 SOL（路线末尾解包 WSOL）
+    Native,
+}
+
+impl FromStr for GuardSettlement {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let trimmed = value.trim();
+        if trimmed.eq_ignore_ascii_case("native") {
+            return Ok(GuardSettlement::Native);
+        }
+        Pubkey::from_str(trimmed)
+            .map(GuardSettlement::TokenAccount)
+            .map_err(|err| format!("无效的结算账户 `{trimmed}`: {err}"))
+    }
 }
 
 #[derive(Clone)]
@@ -81,6 +117,19 @@ impl Default for SolPriceFeedSettings {
     }
 }
 
+/// 纯盲发路线的价格区间守护；`markets` 为空时作用于所有路线。
+#[derive(Clone, Debug)]
+pub struct BlindPriceGuard {
+    pub band: PriceBand,
+    pub markets: Vec<Pubkey>,
+}
+
+impl BlindPriceGuard {
+    pub fn applies_to(&self, steps: &[BlindStep]) -> bool {
+        self.markets.is_empty() || steps.iter().any(|step| self.markets.contains(&step.market))
+    }
+}
+
 #[derive(Clone, Default)]
 pub struct ConsoleSummarySettings {
     pub enable: bool,
@@ -98,6 +147,10 @@ pub struct EngineSettings {
     pub quote_cadence: QuoteCadence,
     pub lighthouse: LighthouseSettings,
     pub console_summary: ConsoleSummarySettings,
+    pub compute_units: Option<ComputeUnitEstimator>,
+    pub alt_manager: Option<AltManager>,
+    pub nonce_pool: Option<NoncePool>,
+    pub price_guards: Vec<BlindPriceGuard>,
 }
 
 impl EngineSettings {
@@ -112,6 +165,10 @@ impl EngineSettings {
             quote_cadence: QuoteCadence::default(),
             lighthouse: LighthouseSettings::default(),
             console_summary: ConsoleSummarySettings::default(),
+            compute_units: None,
+            alt_manager: None,
+            nonce_pool: None,
+            price_guards: Vec::new(),
         }
     }
 
@@ -155,6 +212,26 @@ impl EngineSettings {
         self
     }
 
+    pub fn with_compute_units(mut self, estimator: Option<ComputeUnitEstimator>) -> Self {
+        self.compute_units = estimator;
+        self
+    }
+
+    pub fn with_alt_manager(mut self, manager: Option<AltManager>) -> Self {
+        self.alt_manager = manager;
+        self
+    }
+
+    pub fn with_nonce_pool(mut self, pool: Option<NoncePool>) -> Self {
+        self.nonce_pool = pool;
+        self
+    }
+
+    pub fn with_price_guards(mut self, guards: Vec<BlindPriceGuard>) -> Self {
+        self.price_guards = guards;
+        self
+    }
+
     pub fn sample_compute_unit_price(&self) -> Option<u64> {
         self.compute_unit_price_mode
             .as_ref()
@@ -162,13 +239,27 @@ impl EngineSettings {
     }
 }
 
+/// 配置热重载后需要替换的引擎组件，在下一个策略 tick 开始前生效。
+pub struct EngineReload<S> {
+    pub landers: Arc<LanderStack>,
+    pub compute_unit_price_mode: Option<ComputeUnitPriceMode>,
+    pub dispatch_strategy: DispatchStrategy,
+    pub cu_multiplier: f64,
+    pub quote_cadence: QuoteCadence,
+    pub price_guards: Vec<BlindPriceGuard>,
+    pub profit_evaluator: ProfitEvaluator,
+    pub trade_profiles: BTreeMap<Pubkey, TradeProfile>,
+    /// 策略自身需要更新的状态（例如纯盲发路由）
+    pub strategy: Option<Box<dyn FnOnce(&mut S) + Send>>,
+}
+
 pub struct StrategyEngine<S>
 where
     S: Strategy,
 {
     strategy: S,
     landers: Arc<LanderStack>,
-    identity: EngineIdentity,
+    wallets: WalletPool,
     ip_allocator: Arc<IpAllocator>,
     quote_dispatcher: QuoteDispatcher,
     quote_executor: QuoteExecutor,
@@ -176,17 +267,24 @@ where
     swap_preparer: SwapPreparer,
     tx_builder: TransactionBuilder,
     scheduler: Scheduler,
-    flashloan: Option<MarginfiFlashloanManager>,
     settings: EngineSettings,
     trade_pairs: Vec<TradePair>,
     trade_profiles: BTreeMap<Pubkey, MintSchedule>,
     variant_planner: TxVariantPlanner,
     next_batch_id: u64,
     multi_leg: Option<Arc<MultiLegEngineContext>>,
-    lighthouse: LighthouseRuntime,
     titan_plan: Option<TitanSubscriptionPlan>,
     titan_bootstrapped: bool,
     titan_event_rx: Option<mpsc::Receiver<MultiLegDispatchResult>>,
+    reload_rx: Option<mpsc::Receiver<EngineReload<S>>>,
+    pair_update_rx: Option<mpsc::Receiver<Vec<TradePair>>>,
+}
+
+/// Lighthouse memory 槽位的默认规模：与可并发的 IP 租约数一致。
+fn ip_capacity_hint(ip_allocator: &IpAllocator) -> usize {
+    let summary = ip_allocator.summary();
+    let per_ip_capacity = summary.per_ip_inflight_limit.unwrap_or(1).max(1);
+    summary.total_slots.max(1).saturating_mul(per_ip_capacity)
 }
 
 impl<S> StrategyEngine<S>
@@ -204,7 +302,7 @@ where
         swap_preparer: SwapPreparer,
         tx_builder: TransactionBuilder,
         scheduler: Scheduler,
-        flashloan: Option<MarginfiFlashloanManager>,
+        flashloan: Option<FlashloanRegistry>,
         settings: EngineSettings,
         trade_pairs: Vec<TradePair>,
         trade_profiles: BTreeMap<Pubkey, TradeProfile>,
@@ -212,12 +310,6 @@ where
     ) -> Self {
         let quote_dispatcher =
             QuoteDispatcher::new(Arc::clone(&ip_allocator), settings.quote_cadence.clone());
-        let allocator_summary = ip_allocator.summary();
-        let per_ip_capacity = allocator_summary.per_ip_inflight_limit.unwrap_or(1).max(1);
-        let ip_capacity_hint = allocator_summary
-            .total_slots
-            .max(1)
-            .saturating_mul(per_ip_capacity);
         let trade_profiles: BTreeMap<Pubkey, MintSchedule> = trade_profiles
             .into_iter()
             .map(|(mint, profile)| (mint, MintSchedule::from_profile(profile)))
@@ -240,12 +332,18 @@ where
                 Some(plan)
             }
         });
-        let lighthouse_runtime = LighthouseRuntime::new(&settings.lighthouse, ip_capacity_hint);
+        let lighthouse_runtime =
+            LighthouseRuntime::new(&settings.lighthouse, ip_capacity_hint(&ip_allocator));
+        let wallets = WalletPool::single(EngineWallet {
+            identity,
+            flashloan,
+            lighthouse: lighthouse_runtime,
+        });
 
         Self {
             strategy,
             landers,
-            identity,
+            wallets,
             ip_allocator,
             quote_dispatcher,
             quote_executor,
@@ -253,20 +351,57 @@ where
             swap_preparer,
             tx_builder,
             scheduler,
-            flashloan,
             settings,
             trade_pairs,
             trade_profiles,
             variant_planner: TxVariantPlanner::new(),
             next_batch_id: 1,
             multi_leg: multi_leg.map(Arc::new),
-            lighthouse: lighthouse_runtime,
             titan_plan,
             titan_bootstrapped: false,
             titan_event_rx: None,
+            reload_rx: None,
+            pair_update_rx: None,
         }
     }
 
+    pub fn with_reload(mut self, reload_rx: mpsc::Receiver<EngineReload<S>>) -> Self {
+        self.reload_rx = Some(reload_rx);
+        self
+    }
+
+    /// 主钱包之外的签名钱包，与主钱包一起按 `selection` 分摊机会；多腿与 Titan 推流仍使用主钱包。
+    pub fn with_wallet_pool(
+        mut self,
+        wallets: Vec<PoolWallet>,
+        selection: WalletSelection,
+    ) -> Self {
+        let capacity_hint = ip_capacity_hint(&self.ip_allocator);
+        let wallets = wallets
+            .into_iter()
+            .map(|wallet| {
+                let mut lighthouse = self.settings.lighthouse.clone();
+                lighthouse.existing_memory_ids = wallet.lighthouse_memory_ids;
+                EngineWallet {
+                    identity: wallet.identity,
+                    flashloan: wallet.flashloan,
+                    lighthouse: LighthouseRuntime::new(&lighthouse, capacity_hint),
+                }
+            })
+            .collect();
+        self.wallets.extend(wallets, selection);
+        self
+    }
+
+    /// 运行中替换交易对（intermedium mint 集合刷新），新交易对需已完成账户预检。
+    pub fn with_trade_pair_updates(
+        mut self,
+        pair_update_rx: mpsc::Receiver<Vec<TradePair>>,
+    ) -> Self {
+        self.pair_update_rx = Some(pair_update_rx);
+        self
+    }
+
     #[cfg_attr(feature = "hotpath", hotpath::measure)]
     pub async fn run(mut self) -> EngineResult<()> {
         if self.landers.is_empty() {
@@ -279,12 +414,18 @@ where
             None
         };
 
+        let control = crate::control::runtime_control();
+        control.register_strategy(self.strategy.name());
+        control.register_landers(&self.landers);
+        control.register_ip_allocator(Arc::clone(&self.ip_allocator));
+
         self.bootstrap_titan_streams().await?;
         self.launch_titan_event_driver().await?;
 
         info!(
             target: "engine",
             strategy = self.strategy.name(),
+            wallets = self.wallets.len(),
             "策略引擎启动"
         );
 
@@ -355,7 +496,7 @@ where
         let (tx, rx) = mpsc::channel(128);
         spawn_titan_event_driver(TitanEventConfig {
             context: ctx,
-            payer: self.identity.pubkey,
+            payer: self.wallets.primary().identity.pubkey,
             compute_unit_price,
             dex_whitelist: self.settings.quote.dex_whitelist.clone(),
             dex_blacklist: self.settings.quote.dex_blacklist.clone(),
@@ -386,6 +527,11 @@ where
     }
 
     async fn process_strategy_tick(&mut self) -> EngineResult<Duration> {
+        self.apply_pending_reload();
+        self.apply_trade_pair_updates();
+        if crate::control::strategy_paused(self.strategy.name()) {
+            return Ok(PAUSED_POLL_INTERVAL);
+        }
         let tick = StrategyTick::now();
         trace!(target: "engine::tick", started_at = ?tick.at);
         let event = StrategyEvent::Tick(tick);
@@ -416,6 +562,121 @@ where
         Ok(strategy_wait.max(cadence_wait))
     }
 
+    fn apply_pending_reload(&mut self) {
+        let mut latest = None;
+        if let Some(rx) = self.reload_rx.as_mut() {
+            loop {
+                match rx.try_recv() {
+                    Ok(reload) => latest = Some(reload),
+                    Err(TryRecvError::Empty) => break,
+                    Err(TryRecvError::Disconnected) => {
+                        self.reload_rx = None;
+                        break;
+                    }
+                }
+            }
+        }
+        let Some(reload) = latest else {
+            return;
+        };
+
+        let EngineReload {
+            landers,
+            compute_unit_price_mode,
+            dispatch_strategy,
+            cu_multiplier,
+            quote_cadence,
+            price_guards,
+            profit_evaluator,
+            trade_profiles,
+            strategy,
+        } = reload;
+
+        let same_mints = trade_profiles.len() == self.trade_profiles.len()
+            && trade_profiles
+                .keys()
+                .all(|mint| self.trade_profiles.contains_key(mint));
+        if same_mints {
+            self.trade_profiles = trade_profiles
+                .into_iter()
+                .map(|(mint, profile)| (mint, MintSchedule::from_profile(profile)))
+                .collect();
+        } else {
+            warn!(
+                target: "engine::reload",
+                "交易规模涉及的 base mint 集合发生变化，需重启后生效，保留原有交易规模"
+            );
+        }
+
+        if landers.is_empty() {
+            warn!(
+                target: "engine::reload",
+                "新的落地器配置为空，保留原有落地器"
+            );
+        } else {
+            crate::control::runtime_control().register_landers(&landers);
+            self.landers = landers;
+        }
+        self.settings.compute_unit_price_mode = compute_unit_price_mode;
+        self.settings.dispatch_strategy = dispatch_strategy;
+        self.settings.cu_multiplier = cu_multiplier;
+        self.settings.price_guards = price_guards;
+        self.quote_dispatcher =
+            QuoteDispatcher::new(Arc::clone(&self.ip_allocator), quote_cadence.clone());
+        self.settings.quote_cadence = quote_cadence;
+        self.profit_evaluator = profit_evaluator;
+        if let Some(update) = strategy {
+            update(&mut self.strategy);
+        }
+
+        info!(
+            target: "engine::reload",
+            strategy = self.strategy.name(),
+            landers = self.landers.count(),
+            "热重载配置已应用"
+        );
+    }
+
+    fn apply_trade_pair_updates(&mut self) {
+        let mut latest = None;
+        if let Some(rx) = self.pair_update_rx.as_mut() {
+            loop {
+                match rx.try_recv() {
+                    Ok(pairs) => latest = Some(pairs),
+                    Err(TryRecvError::Empty) => break,
+                    Err(TryRecvError::Disconnected) => {
+                        self.pair_update_rx = None;
+                        break;
+                    }
+                }
+            }
+        }
+        let Some(pairs) = latest else {
+            return;
+        };
+        if pairs.is_empty() {
+            warn!(
+                target: "engine::reload",
+                "刷新后的交易对为空，保留原有交易对"
+            );
+            return;
+        }
+        if self.titan_plan.is_some() {
+            warn!(
+                target: "engine::reload",
+                "Titan 推流订阅仅在启动时生成，新增交易对需重启后才会订阅"
+            );
+        }
+        info!(
+            target: "engine::reload",
+            strategy = self.strategy.name(),
+            previous = self.trade_pairs.len(),
+            current = pairs.len(),
+            "交易对已更新"
+        );
+        self.trade_pairs = pairs;
+    }
+
     async fn wait_with_titan(&mut self, delay: Duration) -> EngineResult<()> {
         if self.titan_event_rx.is_none() {
             self.scheduler.wait(delay).await;
@@ -476,6 +737,9 @@ where
         &mut self,
         dispatch: MultiLegDispatchResult,
     ) -> EngineResult<()> {
+        if crate::control::strategy_paused(self.strategy.name()) {
+            return Ok(());
+        }
         let Some(ctx) = self.multi_leg.as_ref() else {
             return Ok(());
         };
@@ -501,7 +501,8 @@ where
             deadline,
         );
 
-        self.dispatch_execution_plan(execution_plan, None).await
+        // 多腿指令按主钱包请求，落地同样使用主钱包。
+        self.dispatch_execution_plan(execution_plan, None, 0).await
     }
 }
 
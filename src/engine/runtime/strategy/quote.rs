@@ -225,7 +225,7 @@ where
             };
             let handler = Arc::new(MultiLegBatchHandler::new(
                 (*context.as_ref()).clone(),
-                self.identity.pubkey,
+                self.wallets.primary().identity.pubkey,
                 compute_unit_price,
                 self.settings.quote.dex_whitelist.clone(),
                 self.settings.quote.dex_blacklist.clone(),
@@ -12,9 +12,10 @@ use crate::engine::landing::assembler::{
 use crate::engine::landing::{ExecutionPlan, LandingProfileBuilder};
 use crate::engine::quote_dispatcher;
 use crate::engine::types::SwapOpportunity;
-use crate::engine::{EngineError, EngineResult};
+use crate::engine::{ComputeUnitSampleSource, EngineError, EngineResult, RouteKey};
 use crate::lander::Deadline;
 use crate::monitoring::events;
+use crate::monitoring::ledger::{self, TradeDraft};
 use crate::network::{IpLeaseMode, IpTaskKind};
 use crate::strategy::{Strategy, StrategyEvent};
 
@@ -69,9 +70,14 @@ where
         let swap_ip = Some(swap_handle.ip());
         drop(swap_lease);
 
+        let wallet = self.wallets.select(&opportunity.pair);
         let swap_variant = match self
             .swap_preparer
-            .prepare(&opportunity, &self.identity, &swap_handle)
+            .prepare(
+                &opportunity,
+                &self.wallets.get(wallet).identity,
+                &swap_handle,
+            )
             .await
         {
             Ok(variant) => {
@@ -168,7 +174,8 @@ where
             deadline,
         );
 
-        self.dispatch_execution_plan(execution_plan, swap_ip).await
+        self.dispatch_execution_plan(execution_plan, swap_ip, wallet)
+            .await
     }
 }
 
@@ -180,6 +187,7 @@ where
         &mut self,
         execution_plan: ExecutionPlan,
         swap_ip: Option<std::net::IpAddr>,
+        wallet: usize,
     ) -> EngineResult<()> {
         let strategy_name = self.strategy.name();
 
@@ -195,23 +203,45 @@ where
             profiles.push(builder.build_for_variant(variant, sampled_compute_unit_price));
         }
 
+        let nonce_pool = self.settings.nonce_pool.clone();
+        let nonce_lease = match &nonce_pool {
+            Some(pool) => match pool.checkout() {
+                Some(lease) => Some(lease),
+                None => return Ok(()),
+            },
+            None => None,
+        };
+
         let assembler = DefaultLandingAssembler::new();
         let mut entries = Vec::with_capacity(profiles.len());
         for profile in profiles {
+            let signer = self.wallets.get_mut(wallet);
             let mut context = LandingAssemblyContext::new(
-                &self.identity,
+                &signer.identity,
                 &self.tx_builder,
-                self.flashloan.as_ref(),
-                &mut self.lighthouse,
-            );
-            let entry = assembler
+                signer.flashloan.as_ref(),
+                &mut signer.lighthouse,
+            )
+            .with_compute_units(self.settings.compute_units.as_ref())
+            .with_durable_nonce(nonce_lease.as_ref());
+            match assembler
                 .assemble_landing(&mut context, &profile, &execution_plan)
                 .await
-                .map_err(|err| EngineError::Landing(err.to_string()))?;
-            entries.push(entry);
+            {
+                Ok(entry) => entries.push(entry),
+                Err(err) => {
+                    if let (Some(pool), Some(lease)) = (&nonce_pool, nonce_lease) {
+                        pool.restore(lease);
+                    }
+                    return Err(EngineError::Landing(err.to_string()));
+                }
+            }
         }
 
         if entries.is_empty() {
+            if let (Some(pool), Some(lease)) = (&nonce_pool, nonce_lease) {
+                pool.restore(lease);
+            }
             return Err(EngineError::Landing("no landing entries".into()));
         }
 
@@ -246,6 +276,7 @@ where
 
             events::transaction_built(
                 strategy_name,
+                entry.profile.lander_kind.label(),
                 &execution_plan.opportunity,
                 entry.prepared.slot,
                 &entry.prepared.blockhash.to_string(),
@@ -277,6 +308,9 @@ where
         }
 
         let prepared: Vec<_> = entries.iter().map(|entry| entry.prepared.clone()).collect();
+        if let (Some(alt_manager), Some(first)) = (&self.settings.alt_manager, prepared.first()) {
+            alt_manager.observe(&first.instructions);
+        }
 
         let dispatch_strategy = self.settings.dispatch_strategy;
         let variant_layout = self.landers.variant_layout(dispatch_strategy);
@@ -293,13 +327,45 @@ where
         let lander_stack = Arc::clone(&self.landers);
         let strategy_label = strategy_name.to_string();
         let tx_signature_for_log = tx_signature.clone();
+        let compute_units = self.settings.compute_units.clone();
+        let route_key = prepared
+            .first()
+            .and_then(|tx| RouteKey::from_instructions(tx.instructions.iter()));
+        let ledger_draft = ledger::ledger_enabled().then(|| {
+            TradeDraft::from_plan(strategy_name, &execution_plan, entries.first())
+                .with_route(route_key)
+                .with_signature(tx_signature.clone())
+        });
 
         task::spawn(async move {
-            match lander_stack
+            let outcome = lander_stack
                 .submit_plan(plan.as_ref(), deadline, &strategy_label)
-                .await
-            {
-                Ok(_receipt) => {}
+                .await;
+            if let Some(draft) = ledger_draft {
+                ledger::record_dispatch(draft, &outcome);
+            }
+            if let (Some(pool), Some(lease)) = (nonce_pool, nonce_lease) {
+                pool.release(lease);
+            }
+            match outcome {
+                Ok(receipt) => {
+                    if let (Some(estimator), Some(key)) = (compute_units, route_key) {
+                        match receipt.compute_units_consumed {
+                            Some(units) => {
+                                estimator.record(key, units, ComputeUnitSampleSource::Simulation)
+                            }
+                            None => {
+                                let signature =
+                                    plan.variant(receipt.variant_id).and_then(|variant| {
+                                        variant.transaction().signatures.first().copied()
+                                    });
+                                if let Some(signature) = signature {
+                                    estimator.track_landed(signature, key);
+                                }
+                            }
+                        }
+                    }
+                }
                 Err(err) => {
                     let sig = tx_signature_for_log.as_deref().unwrap_or("");
                     warn!(
@@ -0,0 +1,108 @@
+//! 多钱包签名池：机会按轮换或路线分摊到不同 payer，各钱包持有独立的闪电贷账户与 Lighthouse memory。
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use crate::config::WalletSelection;
+use crate::engine::EngineIdentity;
+use crate::engine::plugins::flashloan::FlashloanRegistry;
+
+use super::LighthouseRuntime;
+
+/// 主钱包之外加入签名池的钱包，账户需已完成预检。
+pub struct PoolWallet {
+    pub identity: EngineIdentity,
+    pub flashloan: Option<FlashloanRegistry>,
+    /// 该钱包已存在的 Lighthouse memory 账户 id
+    pub lighthouse_memory_ids: Vec<u8>,
+}
+
+pub(crate) struct EngineWallet {
+    pub(crate) identity: EngineIdentity,
+    pub(crate) flashloan: Option<FlashloanRegistry>,
+    pub(crate) lighthouse: LighthouseRuntime,
+}
+
+/// 首个钱包为主钱包，多腿与 Titan 推流等按 payer 预先订阅的路径固定使用它。
+pub(crate) struct WalletPool {
+    wallets: Vec<EngineWallet>,
+    selection: WalletSelection,
+    cursor: usize,
+}
+
+impl WalletPool {
+    pub(crate) fn single(wallet: EngineWallet) -> Self {
+        Self {
+            wallets: vec![wallet],
+            selection: WalletSelection::default(),
+            cursor: 0,
+        }
+    }
+
+    pub(crate) fn extend(&mut self, wallets: Vec<EngineWallet>, selection: WalletSelection) {
+        self.wallets.extend(wallets);
+        self.selection = selection;
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.wallets.len()
+    }
+
+    pub(crate) fn primary(&self) -> &EngineWallet {
+        &self.wallets[0]
+    }
+
+    pub(crate) fn get(&self, index: usize) -> &EngineWallet {
+        &self.wallets[index]
+    }
+
+    pub(crate) fn get_mut(&mut self, index: usize) -> &mut EngineWallet {
+        &mut self.wallets[index]
+    }
+
+    /// 为一次机会挑选钱包；`route` 仅在按路线分配时参与计算。
+    pub(crate) fn select(&mut self, route: impl Hash) -> usize {
+        pick(self.selection, self.wallets.len(), &mut self.cursor, route)
+    }
+}
+
+fn pick(selection: WalletSelection, len: usize, cursor: &mut usize, route: impl Hash) -> usize {
+    if len <= 1 {
+        return 0;
+    }
+    match selection {
+        WalletSelection::RoundRobin => {
+            let index = *cursor % len;
+            *cursor = cursor.wrapping_add(1);
+            index
+        }
+        WalletSelection::Route => {
+            let mut hasher = DefaultHasher::new();
+            route.hash(&mut hasher);
+            (hasher.finish() % len as u64) as usize
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_robin_rotates_and_route_is_sticky() {
+        let mut cursor = 0;
+        let picked: Vec<usize> = (0..4)
+            .map(|_| pick(WalletSelection::RoundRobin, 3, &mut cursor, ()))
+            .collect();
+        assert_eq!(picked, vec![0, 1, 2, 0]);
+
+        let first = pick(WalletSelection::Route, 3, &mut cursor, "route-a");
+        for _ in 0..8 {
+            assert_eq!(
+                pick(WalletSelection::Route, 3, &mut cursor, "route-a"),
+                first
+            );
+        }
+        assert_eq!(pick(WalletSelection::Route, 1, &mut cursor, "route-a"), 0);
+    }
+}
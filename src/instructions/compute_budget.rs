@@ -92,3 +92,7 @@ pub fn compute_budget_sequence(
 pub fn is_compute_budget(ix: &Instruction) -> bool {
     ix.program_id == COMPUTE_BUDGET_PROGRAM_ID
 }
+
+pub fn is_compute_unit_price(ix: &Instruction) -> bool {
+    is_compute_budget(ix) && ix.data.first() == Some(&3)
+}
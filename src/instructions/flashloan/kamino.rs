@@ -0,0 +1,206 @@
+use solana_sdk::instruction::{AccountMeta, Instruction};
+use solana_sdk::pubkey::Pubkey;
+
+use super::types::{FlashloanMetadata, FlashloanOutcome, FlashloanProtocol};
+use crate::instructions::flashloan::{FlashloanError, FlashloanResult};
+
+pub const PROGRAM_ID: Pubkey = solana_sdk::pubkey!("KLend2g3cP87fffoy8q1mQqGKjrxjC8boSyAYavgmjD");
+pub const MAIN_LENDING_MARKET: Pubkey =
+    solana_sdk::pubkey!("7u3HeHxYDLhnCoErrtycNokbQYbWGzLs6JSDqGAv5PfF");
+
+pub(crate) const FLASH_BORROW_DISCRIMINATOR: [u8; 8] = [135, 231, 52, 167, 7, 52, 212, 193];
+pub(crate) const FLASH_REPAY_DISCRIMINATOR: [u8; 8] = [185, 117, 0, 203, 96, 245, 180, 186];
+pub(crate) const RESERVE_DISCRIMINATOR: [u8; 8] = [43, 242, 204, 202, 26, 247, 59, 127];
+
+/// Reserve 账户布局：discriminator(8) + version(8) + last_update(16) 之后依次为 lending_market、
+/// farm_collateral、farm_debt，再进入 ReserveLiquidity（mint、supply_vault、fee_vault、available_amount）。
+const LENDING_MARKET_OFFSET: usize = 32;
+const LIQUIDITY_MINT_OFFSET: usize = 128;
+const SUPPLY_VAULT_OFFSET: usize = 160;
+const FEE_VAULT_OFFSET: usize = 192;
+const AVAILABLE_AMOUNT_OFFSET: usize = 224;
+const RESERVE_MIN_LEN: usize = AVAILABLE_AMOUNT_OFFSET + 8;
+
+/// 闪电贷所需的 reserve 账户信息。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KaminoReserve {
+    pub address: Pubkey,
+    pub lending_market: Pubkey,
+    pub mint: Pubkey,
+    pub supply_vault: Pubkey,
+    pub fee_vault: Pubkey,
+    pub token_program: Pubkey,
+}
+
+impl KaminoReserve {
+    /// 解析 reserve 账户数据，返回 reserve 信息与当前可借流动性；token program 由调用方根据 mint 所有者填写。
+    pub fn parse(address: Pubkey, data: &[u8], token_program: Pubkey) -> Option<(Self, u64)> {
+        if data.len() < RESERVE_MIN_LEN || data[..8] != RESERVE_DISCRIMINATOR {
+            return None;
+        }
+        let reserve = Self {
+            address,
+            lending_market: read_pubkey(data, LENDING_MARKET_OFFSET)?,
+            mint: read_pubkey(data, LIQUIDITY_MINT_OFFSET)?,
+            supply_vault: read_pubkey(data, SUPPLY_VAULT_OFFSET)?,
+            fee_vault: read_pubkey(data, FEE_VAULT_OFFSET)?,
+            token_program,
+        };
+        Some((reserve, parse_available_liquidity(data)?))
+    }
+}
+
+/// 仅读取 reserve 的可借流动性。
+pub fn parse_available_liquidity(data: &[u8]) -> Option<u64> {
+    if data.len() < RESERVE_MIN_LEN || data[..8] != RESERVE_DISCRIMINATOR {
+        return None;
+    }
+    let bytes = data[AVAILABLE_AMOUNT_OFFSET..AVAILABLE_AMOUNT_OFFSET + 8]
+        .try_into()
+        .ok()?;
+    Some(u64::from_le_bytes(bytes))
+}
+
+/// reserve 的闪电贷手续费（向上取整），`fee_bps` 需与链上 reserve 配置一致。
+pub fn flashloan_fee(amount: u64, fee_bps: u64) -> u64 {
+    if fee_bps == 0 || amount == 0 {
+        return 0;
+    }
+    let fee = (amount as u128 * fee_bps as u128).div_ceil(10_000);
+    fee.min(u64::MAX as u128) as u64
+}
+
+pub fn lending_market_authority(lending_market: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"lma", lending_market.as_ref()], &PROGRAM_ID).0
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct KaminoFlashloan {
+    fee_bps: u64,
+}
+
+impl KaminoFlashloan {
+    pub fn new(fee_bps: u64) -> Self {
+        Self { fee_bps }
+    }
+
+    pub fn fee(&self, amount: u64) -> u64 {
+        flashloan_fee(amount, self.fee_bps)
+    }
+
+    /// 以 `flash_borrow → body → flash_repay` 包裹主体指令；repay 需要 borrow 在整笔交易中的下标。
+    #[cfg_attr(feature = "hotpath", hotpath::measure)]
+    pub fn wrap(
+        &self,
+        identity_pubkey: &Pubkey,
+        reserve: &KaminoReserve,
+        user_token_account: Pubkey,
+        mut prefix: Vec<Instruction>,
+        mut body: Vec<Instruction>,
+        borrow_amount: u64,
+    ) -> FlashloanResult<FlashloanOutcome> {
+        if borrow_amount == 0 {
+            prefix.append(&mut body);
+            return Ok(FlashloanOutcome {
+                instructions: prefix,
+                metadata: None,
+            });
+        }
+
+        let borrow_index = prefix.len();
+        let borrow_index = u8::try_from(borrow_index).map_err(|_| {
+            FlashloanError::InvalidConfigDetail(format!(
+                "Kamino flash borrow 指令下标超出范围: {borrow_index}"
+            ))
+        })?;
+        let accounts = flash_accounts(identity_pubkey, reserve, user_token_account);
+        let borrow_ix = build_flash_borrow(&accounts, borrow_amount);
+        let repay_ix = build_flash_repay(&accounts, borrow_amount, borrow_index);
+
+        let inner_count = body.len();
+        let mut instructions = Vec::with_capacity(prefix.len() + inner_count + 2);
+        instructions.append(&mut prefix);
+        instructions.push(borrow_ix);
+        instructions.append(&mut body);
+        instructions.push(repay_ix);
+
+        Ok(FlashloanOutcome {
+            instructions,
+            metadata: Some(FlashloanMetadata {
+                protocol: FlashloanProtocol::Kamino,
+                mint: reserve.mint,
+                borrow_amount,
+                inner_instruction_count: inner_count,
+                fee: self.fee(borrow_amount),
+            }),
+        })
+    }
+}
+
+/// borrow 与 repay 共用同一组账户，仅 token 流向不同。
+fn flash_accounts(
+    authority: &Pubkey,
+    reserve: &KaminoReserve,
+    user_token_account: Pubkey,
+) -> Vec<AccountMeta> {
+    vec![
+        AccountMeta::new_readonly(*authority, true),
+        AccountMeta::new_readonly(lending_market_authority(&reserve.lending_market), false),
+        AccountMeta::new_readonly(reserve.lending_market, false),
+        AccountMeta::new(reserve.address, false),
+        AccountMeta::new_readonly(reserve.mint, false),
+        AccountMeta::new(reserve.supply_vault, false),
+        AccountMeta::new(user_token_account, false),
+        AccountMeta::new(reserve.fee_vault, false),
+        // referrer_token_state / referrer_account 为可选账户，未使用时传程序 ID
+        AccountMeta::new_readonly(PROGRAM_ID, false),
+        AccountMeta::new_readonly(PROGRAM_ID, false),
+        AccountMeta::new_readonly(solana_sdk::sysvar::instructions::id(), false),
+        AccountMeta::new_readonly(reserve.token_program, false),
+    ]
+}
+
+fn build_flash_borrow(accounts: &[AccountMeta], amount: u64) -> Instruction {
+    let mut data = Vec::with_capacity(16);
+    data.extend_from_slice(&FLASH_BORROW_DISCRIMINATOR);
+    data.extend_from_slice(&amount.to_le_bytes());
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: accounts.to_vec(),
+        data,
+    }
+}
+
+fn build_flash_repay(accounts: &[AccountMeta], amount: u64, borrow_index: u8) -> Instruction {
+    let mut data = Vec::with_capacity(17);
+    data.extend_from_slice(&FLASH_REPAY_DISCRIMINATOR);
+    data.extend_from_slice(&amount.to_le_bytes());
+    data.push(borrow_index);
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: accounts.to_vec(),
+        data,
+    }
+}
+
+/// 指令增删后按 `remap` 修正 repay 中记录的 borrow 下标。
+pub(crate) fn remap_repay_index(
+    instruction: &mut Instruction,
+    remap: &dyn Fn(usize) -> Option<usize>,
+) -> bool {
+    if instruction.program_id != PROGRAM_ID
+        || instruction.data.len() != 17
+        || instruction.data[..8] != FLASH_REPAY_DISCRIMINATOR
+    {
+        return false;
+    }
+    if let Some(index) = remap(usize::from(instruction.data[16])) {
+        instruction.data[16] = u8::try_from(index).unwrap_or(u8::MAX);
+    }
+    true
+}
+
+fn read_pubkey(data: &[u8], offset: usize) -> Option<Pubkey> {
+    let bytes: [u8; 32] = data.get(offset..offset + 32)?.try_into().ok()?;
+    Some(Pubkey::new_from_array(bytes))
+}
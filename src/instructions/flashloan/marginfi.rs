@@ -129,6 +129,7 @@ impl MarginfiFlashloan {
                 mint: asset.mint,
                 borrow_amount,
                 inner_instruction_count: inner_count,
+                fee: 0,
             }),
         })
     }
@@ -138,6 +139,40 @@ impl MarginfiFlashloan {
     }
 }
 
+/// 支持闪电贷的资产及其 bank 流动性金库，用于刷新可借余额。
+pub fn liquidity_vaults() -> Vec<(Pubkey, Pubkey)> {
+    MARGINFI_ASSETS
+        .iter()
+        .map(|asset| (asset.mint, find_liquidity_vault(&asset.bank)))
+        .collect()
+}
+
+pub fn supports_mint(mint: &Pubkey) -> bool {
+    MARGINFI_ASSETS.iter().any(|asset| asset.mint == *mint)
+}
+
+/// 指令增删后按 `remap` 修正 begin 指令记录的 end 指令下标。
+pub(crate) fn remap_end_index(
+    instruction: &mut Instruction,
+    remap: &dyn Fn(usize) -> Option<usize>,
+) -> bool {
+    if instruction.program_id != *PROGRAM_ID
+        || instruction.data.len() != 16
+        || instruction.data[..8] != BEGIN_DISCRIMINATOR
+    {
+        return false;
+    }
+    let mut buf = [0u8; 8];
+    buf.copy_from_slice(&instruction.data[8..16]);
+    let remapped = usize::try_from(u64::from_le_bytes(buf))
+        .ok()
+        .and_then(remap);
+    if let Some(index) = remapped {
+        instruction.data[8..16].copy_from_slice(&(index as u64).to_le_bytes());
+    }
+    true
+}
+
 pub fn build_initialize_instruction(
     marginfi_account: Pubkey,
     authority: &Pubkey,
@@ -167,7 +202,6 @@ pub fn build_initialize_instruction(
     })
 }
 
-#[cfg_attr(not(test), allow(dead_code))]
 pub fn build_close_instruction(
     marginfi_account: Pubkey,
     authority: &Pubkey,
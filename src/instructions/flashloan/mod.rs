@@ -1,6 +1,30 @@
+use solana_sdk::instruction::Instruction;
+
 pub mod error;
+pub mod kamino;
 pub mod marginfi;
 pub mod types;
 
 pub use error::{FlashloanError, FlashloanResult};
 pub use types::{FlashloanMetadata, FlashloanOutcome};
+
+/// 在交易最前面插入 `by` 条指令后，修正闪电贷指令中记录的绝对下标。
+pub fn shift_instruction_indices(instructions: &mut [Instruction], by: usize) {
+    if by == 0 {
+        return;
+    }
+    remap_instruction_indices(instructions, |index| Some(index + by));
+}
+
+/// 指令增删后按 `remap`（原下标 → 新下标）修正闪电贷指令中记录的绝对下标；映射不到时保持原值。
+pub fn remap_instruction_indices(
+    instructions: &mut [Instruction],
+    remap: impl Fn(usize) -> Option<usize>,
+) {
+    for instruction in instructions.iter_mut() {
+        if marginfi::remap_end_index(instruction, &remap) {
+            continue;
+        }
+        kamino::remap_repay_index(instruction, &remap);
+    }
+}
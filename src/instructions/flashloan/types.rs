@@ -13,17 +13,21 @@ pub struct FlashloanMetadata {
     pub mint: Pubkey,
     pub borrow_amount: u64,
     pub inner_instruction_count: usize,
+    /// 协议收取的闪电贷手续费（base mint 最小单位）
+    pub fee: u64,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum FlashloanProtocol {
     Marginfi,
+    Kamino,
 }
 
 impl FlashloanProtocol {
     pub fn as_str(&self) -> &'static str {
         match self {
             FlashloanProtocol::Marginfi => "marginfi",
+            FlashloanProtocol::Kamino => "kamino",
         }
     }
 }
@@ -0,0 +1,93 @@
+use solana_sdk::instruction::{AccountMeta, Instruction};
+use solana_sdk::pubkey::Pubkey;
+
+use super::program::{IntegerOperator, LIGHTHOUSE_PROGRAM_ID, LogLevel, push_compact_u64};
+
+/// AccountData 断言的比较值，决定读取的字节宽度与符号。
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DataValue {
+    U64(u64),
+    I64(i64),
+    U128(u128),
+    I128(i128),
+}
+
+impl DataValue {
+    /// Lighthouse `DataValueAssertion` 的 variant 下标。
+    fn variant_index(&self) -> u8 {
+        match self {
+            DataValue::U64(_) => 7,
+            DataValue::I64(_) => 8,
+            DataValue::U128(_) => 9,
+            DataValue::I128(_) => 10,
+        }
+    }
+
+    fn push_le_bytes(&self, buffer: &mut Vec<u8>) {
+        match self {
+            DataValue::U64(value) => buffer.extend_from_slice(&value.to_le_bytes()),
+            DataValue::I64(value) => buffer.extend_from_slice(&value.to_le_bytes()),
+            DataValue::U128(value) => buffer.extend_from_slice(&value.to_le_bytes()),
+            DataValue::I128(value) => buffer.extend_from_slice(&value.to_le_bytes()),
+        }
+    }
+}
+
+/// AccountData 指令参数：断言目标账户 `offset` 处的值与 `value` 满足 `operator`。
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct AccountDataParams {
+    pub target_account: Pubkey,
+    pub log_level: LogLevel,
+    pub offset: u64,
+    pub value: DataValue,
+    pub operator: IntegerOperator,
+}
+
+/// 构造 AccountData 指令（对应 Lighthouse `assert_account_data`）。
+pub fn build_account_data_instruction(params: AccountDataParams) -> Instruction {
+    let AccountDataParams {
+        target_account,
+        log_level,
+        offset,
+        value,
+        operator,
+    } = params;
+
+    let mut data = Vec::with_capacity(24);
+    data.push(2); // instruction discriminator for AssertAccountData
+    data.push(log_level as u8);
+    push_compact_u64(&mut data, offset);
+    data.push(value.variant_index());
+    value.push_le_bytes(&mut data);
+    data.push(operator as u8);
+
+    Instruction {
+        program_id: LIGHTHOUSE_PROGRAM_ID,
+        accounts: vec![AccountMeta::new_readonly(target_account, false)],
+        data,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn account_data_encoding_matches_expected_layout() {
+        let target = Pubkey::new_unique();
+        let ix = build_account_data_instruction(AccountDataParams {
+            target_account: target,
+            log_level: LogLevel::FailedPlaintextMessage,
+            offset: 73,
+            value: DataValue::I64(-12_345),
+            operator: IntegerOperator::GreaterThanOrEqual,
+        });
+        let mut expected = vec![2, 4, 73, 8];
+        expected.extend_from_slice(&(-12_345i64).to_le_bytes());
+        expected.push(IntegerOperator::GreaterThanOrEqual as u8);
+        assert_eq!(ix.data, expected);
+        assert_eq!(ix.accounts.len(), 1);
+        assert_eq!(ix.accounts[0].pubkey, target);
+        assert!(!ix.accounts[0].is_writable);
+    }
+}
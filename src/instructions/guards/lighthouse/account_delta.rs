@@ -1,7 +1,9 @@
 use solana_sdk::instruction::{AccountMeta, Instruction};
 use solana_sdk::pubkey::Pubkey;
 
-use super::program::{IntegerOperator, LIGHTHOUSE_PROGRAM_ID, LogLevel, push_compact_u64};
+use super::program::{
+    IntegerOperator, LIGHTHOUSE_PROGRAM_ID, LogLevel, push_compact_u64, read_compact_u64,
+};
 
 /// AccountDelta(Data) 指令参数。
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -49,6 +51,85 @@ pub fn build_account_delta_instruction(params: AccountDeltaParams) -> Instructio
     }
 }
 
+/// AccountDelta(AccountInfo::Lamports) 指令参数，快照来自 lamports MemoryWrite。
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct LamportsDeltaParams {
+    pub memory: Pubkey,
+    pub target_account: Pubkey,
+    pub log_level: LogLevel,
+    pub snapshot_offset: u64,
+    pub expected_delta: i128,
+    pub operator: IntegerOperator,
+}
+
+/// 构造 lamports 变化量断言。
+pub fn build_lamports_delta_instruction(params: LamportsDeltaParams) -> Instruction {
+    let LamportsDeltaParams {
+        memory,
+        target_account,
+        log_level,
+        snapshot_offset,
+        expected_delta,
+        operator,
+    } = params;
+
+    let mut data = Vec::with_capacity(24);
+    data.push(4); // instruction discriminator for AssertAccountDelta
+    data.push(log_level as u8);
+    data.push(0); // AccountDeltaAssertion::AccountInfo variant index
+    push_compact_u64(&mut data, snapshot_offset);
+    data.push(0); // AccountInfoDeltaAssertion::Lamports variant index
+    data.extend_from_slice(&expected_delta.to_le_bytes());
+    data.push(operator as u8);
+
+    Instruction {
+        program_id: LIGHTHOUSE_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(memory, false),
+            AccountMeta::new_readonly(target_account, false),
+        ],
+        data,
+    }
+}
+
+/// 已编码 AccountDelta 指令所断言的对象。
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AccountDeltaKind {
+    Lamports,
+    TokenAmount,
+}
+
+/// 识别 AccountDelta 指令并原地调整 expected_delta；无法识别的布局返回 None 且不修改数据。
+pub fn adjust_expected_delta(data: &mut [u8], adjustment: i128) -> Option<AccountDeltaKind> {
+    if data.first() != Some(&4) {
+        return None;
+    }
+    let (kind, expected_start) = match data.get(2)? {
+        0 => {
+            let (_, cursor) = read_compact_u64(data, 3)?;
+            // AccountInfoDeltaAssertion::Lamports variant index
+            if *data.get(cursor)? != 0 {
+                return None;
+            }
+            (AccountDeltaKind::Lamports, cursor + 1)
+        }
+        1 => {
+            let (_, cursor) = read_compact_u64(data, 3)?;
+            let (_, cursor) = read_compact_u64(data, cursor)?;
+            // DataValueDeltaAssertion::U64 variant index
+            if *data.get(cursor)? != 6 {
+                return None;
+            }
+            (AccountDeltaKind::TokenAmount, cursor + 1)
+        }
+        _ => return None,
+    };
+    let slot = data.get_mut(expected_start..expected_start + 16)?;
+    let current = i128::from_le_bytes(slot.try_into().ok()?);
+    slot.copy_from_slice(&current.saturating_add(adjustment).to_le_bytes());
+    Some(kind)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -70,4 +151,58 @@ mod tests {
         expected.push(IntegerOperator::GreaterThanOrEqual as u8);
         assert_eq!(ix.data, expected, "unexpected account_delta encoding");
     }
+
+    #[test]
+    fn lamports_delta_encoding_matches_expected_layout() {
+        let ix = build_lamports_delta_instruction(LamportsDeltaParams {
+            memory: Pubkey::new_unique(),
+            target_account: Pubkey::new_unique(),
+            log_level: LogLevel::FailedPlaintextMessage,
+            snapshot_offset: 8,
+            expected_delta: -5_000,
+            operator: IntegerOperator::GreaterThanOrEqual,
+        });
+        let mut expected = vec![4, 4, 0, 8, 0];
+        expected.extend_from_slice(&(-5_000i128).to_le_bytes());
+        expected.push(IntegerOperator::GreaterThanOrEqual as u8);
+        assert_eq!(ix.data, expected);
+    }
+
+    #[test]
+    fn adjust_expected_delta_handles_both_layouts() {
+        let mut token = build_account_delta_instruction(AccountDeltaParams {
+            memory: Pubkey::new_unique(),
+            target_account: Pubkey::new_unique(),
+            log_level: LogLevel::FailedPlaintextMessage,
+            snapshot_offset: 200,
+            account_data_offset: 64,
+            expected_delta: 1_000,
+            operator: IntegerOperator::GreaterThanOrEqual,
+        });
+        assert_eq!(
+            adjust_expected_delta(&mut token.data, 500),
+            Some(AccountDeltaKind::TokenAmount)
+        );
+        let start = token.data.len() - 17;
+        let value = i128::from_le_bytes(token.data[start..start + 16].try_into().unwrap());
+        assert_eq!(value, 1_500);
+
+        let mut lamports = build_lamports_delta_instruction(LamportsDeltaParams {
+            memory: Pubkey::new_unique(),
+            target_account: Pubkey::new_unique(),
+            log_level: LogLevel::FailedPlaintextMessage,
+            snapshot_offset: 0,
+            expected_delta: -2_000,
+            operator: IntegerOperator::GreaterThanOrEqual,
+        });
+        assert_eq!(
+            adjust_expected_delta(&mut lamports.data, -300),
+            Some(AccountDeltaKind::Lamports)
+        );
+        let value = i128::from_le_bytes(lamports.data[5..21].try_into().unwrap());
+        assert_eq!(value, -2_300);
+
+        let mut other = vec![0, 1, 2];
+        assert_eq!(adjust_expected_delta(&mut other, 1), None);
+    }
 }
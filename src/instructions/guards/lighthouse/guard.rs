@@ -1,8 +1,14 @@
 use solana_sdk::instruction::Instruction;
 use solana_sdk::pubkey::Pubkey;
 
-use super::account_delta::{AccountDeltaParams, build_account_delta_instruction};
-use super::memory_write::{MemoryWriteParams, build_memory_write_instruction};
+use super::account_delta::{
+    AccountDeltaParams, LamportsDeltaParams, build_account_delta_instruction,
+    build_lamports_delta_instruction,
+};
+use super::memory_write::{
+    LamportsMemoryWriteParams, MemoryWriteParams, build_lamports_memory_write_instruction,
+    build_memory_write_instruction,
+};
 use super::program::{
     IntegerOperator, LIGHTHOUSE_PROGRAM_ID, LogLevel, TOKEN_ACCOUNT_AMOUNT_OFFSET,
     TOKEN_ACCOUNT_AMOUNT_SIZE,
@@ -59,3 +65,157 @@ pub fn build_token_amount_guard(
         memory_bump,
     }
 }
+
+/// 守护对象：token 账户余额（Token 与 Token-2022 的 amount 偏移相同）或账户 lamports。
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum GuardAccount {
+    TokenAmount(Pubkey),
+    Lamports(Pubkey),
+}
+
+impl GuardAccount {
+    pub fn address(&self) -> Pubkey {
+        match self {
+            GuardAccount::TokenAmount(account) | GuardAccount::Lamports(account) => *account,
+        }
+    }
+}
+
+/// 单个账户的变化量下限，可为负数（允许的最大支出）。
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct GuardTarget {
+    pub account: GuardAccount,
+    pub min_delta: i128,
+}
+
+/// 一笔交易的全部守护指令：快照放在最前，断言放在最后。
+#[derive(Clone, Debug, Default)]
+pub struct ProfitGuard {
+    pub memory_writes: Vec<Instruction>,
+    pub assertions: Vec<Instruction>,
+}
+
+/// 为每个守护对象分配独立的 memory 账户，返回 MemoryWrite + AccountDelta 组合。
+///
+/// `memory_ids` 与 `targets` 一一对应，多余的目标会被忽略。
+pub fn build_profit_guard(
+    payer: Pubkey,
+    targets: &[GuardTarget],
+    memory_ids: &[u8],
+) -> ProfitGuard {
+    let mut guard = ProfitGuard::default();
+    for (target, memory_id) in targets.iter().zip(memory_ids.iter().copied()) {
+        let (memory, memory_bump) = Pubkey::find_program_address(
+            &[b"memory", payer.as_ref(), &[memory_id]],
+            &LIGHTHOUSE_PROGRAM_ID,
+        );
+        match target.account {
+            GuardAccount::TokenAmount(account) => {
+                guard
+                    .memory_writes
+                    .push(build_memory_write_instruction(MemoryWriteParams {
+                        payer,
+                        memory,
+                        memory_id,
+                        memory_bump,
+                        source_account: account,
+                        write_offset: 0,
+                        account_data_offset: TOKEN_ACCOUNT_AMOUNT_OFFSET,
+                        account_data_length: TOKEN_ACCOUNT_AMOUNT_SIZE,
+                    }));
+                guard
+                    .assertions
+                    .push(build_account_delta_instruction(AccountDeltaParams {
+                        memory,
+                        target_account: account,
+                        log_level: LogLevel::FailedPlaintextMessage,
+                        snapshot_offset: 0,
+                        account_data_offset: u64::from(TOKEN_ACCOUNT_AMOUNT_OFFSET),
+                        expected_delta: target.min_delta,
+                        operator: IntegerOperator::GreaterThanOrEqual,
+                    }));
+            }
+            GuardAccount::Lamports(account) => {
+                guard
+                    .memory_writes
+                    .push(build_lamports_memory_write_instruction(
+                        LamportsMemoryWriteParams {
+                            payer,
+                            memory,
+                            memory_id,
+                            memory_bump,
+                            source_account: account,
+                            write_offset: 0,
+                        },
+                    ));
+                guard
+                    .assertions
+                    .push(build_lamports_delta_instruction(LamportsDeltaParams {
+                        memory,
+                        target_account: account,
+                        log_level: LogLevel::FailedPlaintextMessage,
+                        snapshot_offset: 0,
+                        expected_delta: target.min_delta,
+                        operator: IntegerOperator::GreaterThanOrEqual,
+                    }));
+            }
+        }
+    }
+    guard
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instructions::guards::lighthouse::account_delta::{
+        AccountDeltaKind, adjust_expected_delta,
+    };
+
+    #[test]
+    fn profit_guard_uses_distinct_memory_per_target() {
+        let payer = Pubkey::new_unique();
+        let token_account = Pubkey::new_unique();
+        let targets = [
+            GuardTarget {
+                account: GuardAccount::TokenAmount(token_account),
+                min_delta: 10_000,
+            },
+            GuardTarget {
+                account: GuardAccount::Lamports(payer),
+                min_delta: -2_000,
+            },
+        ];
+        let guard = build_profit_guard(payer, &targets, &[0, 1]);
+        assert_eq!(guard.memory_writes.len(), 2);
+        assert_eq!(guard.assertions.len(), 2);
+        assert_ne!(
+            guard.memory_writes[0].accounts[3].pubkey,
+            guard.memory_writes[1].accounts[3].pubkey
+        );
+        assert_eq!(guard.assertions[0].accounts[1].pubkey, token_account);
+        assert_eq!(guard.assertions[1].accounts[1].pubkey, payer);
+
+        let mut token = guard.assertions[0].data.clone();
+        assert_eq!(
+            adjust_expected_delta(&mut token, 0),
+            Some(AccountDeltaKind::TokenAmount)
+        );
+        let mut lamports = guard.assertions[1].data.clone();
+        assert_eq!(
+            adjust_expected_delta(&mut lamports, 0),
+            Some(AccountDeltaKind::Lamports)
+        );
+    }
+
+    #[test]
+    fn profit_guard_skips_targets_without_memory_id() {
+        let payer = Pubkey::new_unique();
+        let targets = [GuardTarget {
+            account: GuardAccount::Lamports(payer),
+            min_delta: 0,
+        }];
+        let guard = build_profit_guard(payer, &targets, &[]);
+        assert!(guard.memory_writes.is_empty());
+        assert!(guard.assertions.is_empty());
+    }
+}
@@ -0,0 +1,39 @@
+use solana_sdk::instruction::{AccountMeta, Instruction};
+use solana_sdk::pubkey::Pubkey;
+
+use super::program::LIGHTHOUSE_PROGRAM_ID;
+
+/// 构造 MemoryClose 指令（对应 Lighthouse `memory_close`），租金退回 payer。
+pub fn build_memory_close_instruction(
+    payer: Pubkey,
+    memory: Pubkey,
+    memory_id: u8,
+    memory_bump: u8,
+) -> Instruction {
+    Instruction {
+        program_id: LIGHTHOUSE_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(LIGHTHOUSE_PROGRAM_ID, false),
+            AccountMeta::new(payer, true),
+            AccountMeta::new(memory, false),
+        ],
+        data: vec![1, memory_id, memory_bump], // 1 = MemoryClose discriminator
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn memory_close_encoding_matches_expected_layout() {
+        let payer = Pubkey::new_unique();
+        let memory = Pubkey::new_unique();
+        let ix = build_memory_close_instruction(payer, memory, 5, 253);
+        assert_eq!(ix.program_id, LIGHTHOUSE_PROGRAM_ID);
+        assert_eq!(ix.data, vec![1, 5, 253]);
+        assert_eq!(ix.accounts.len(), 3);
+        assert!(ix.accounts[1].is_signer && ix.accounts[1].is_writable);
+        assert_eq!(ix.accounts[2].pubkey, memory);
+    }
+}
@@ -1,7 +1,9 @@
 use solana_sdk::instruction::{AccountMeta, Instruction};
 use solana_sdk::pubkey::Pubkey;
 
-use super::program::{LIGHTHOUSE_PROGRAM_ID, SYSTEM_PROGRAM_ID, push_compact_u64};
+use super::program::{
+    AccountInfoField, LIGHTHOUSE_PROGRAM_ID, SYSTEM_PROGRAM_ID, push_compact_u64,
+};
 
 /// MemoryWrite 指令参数。
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -16,6 +18,17 @@ pub struct MemoryWriteParams {
     pub account_data_length: u16,
 }
 
+/// 针对账户 lamports 的 MemoryWrite 参数。
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct LamportsMemoryWriteParams {
+    pub payer: Pubkey,
+    pub memory: Pubkey,
+    pub memory_id: u8,
+    pub memory_bump: u8,
+    pub source_account: Pubkey,
+    pub write_offset: u64,
+}
+
 /// 构造 MemoryWrite 指令（对应 Lighthouse `memory_write`）。
 pub fn build_memory_write_instruction(params: MemoryWriteParams) -> Instruction {
     let MemoryWriteParams {
@@ -29,14 +42,59 @@ pub fn build_memory_write_instruction(params: MemoryWriteParams) -> Instruction
         account_data_length,
     } = params;
 
+    let mut write_type = Vec::with_capacity(5);
+    write_type.push(0); // WriteType::AccountData variant index
+    write_type.extend_from_slice(&account_data_offset.to_le_bytes());
+    write_type.extend_from_slice(&account_data_length.to_le_bytes());
+
+    encode_memory_write(
+        payer,
+        memory,
+        memory_id,
+        memory_bump,
+        source_account,
+        write_offset,
+        &write_type,
+    )
+}
+
+/// 构造快照账户 lamports 的 MemoryWrite 指令（`WriteType::AccountInfoField(Lamports)`）。
+pub fn build_lamports_memory_write_instruction(params: LamportsMemoryWriteParams) -> Instruction {
+    let LamportsMemoryWriteParams {
+        payer,
+        memory,
+        memory_id,
+        memory_bump,
+        source_account,
+        write_offset,
+    } = params;
+
+    encode_memory_write(
+        payer,
+        memory,
+        memory_id,
+        memory_bump,
+        source_account,
+        write_offset,
+        &[1, AccountInfoField::Lamports as u8],
+    )
+}
+
+fn encode_memory_write(
+    payer: Pubkey,
+    memory: Pubkey,
+    memory_id: u8,
+    memory_bump: u8,
+    source_account: Pubkey,
+    write_offset: u64,
+    write_type: &[u8],
+) -> Instruction {
     let mut data = Vec::with_capacity(16);
     data.push(0); // instruction discriminator for MemoryWrite
     data.push(memory_id);
     data.push(memory_bump);
     push_compact_u64(&mut data, write_offset);
-    data.push(0); // WriteType::AccountData variant index
-    data.extend_from_slice(&account_data_offset.to_le_bytes());
-    data.extend_from_slice(&account_data_length.to_le_bytes());
+    data.extend_from_slice(write_type);
 
     let accounts = vec![
         AccountMeta::new_readonly(LIGHTHOUSE_PROGRAM_ID, false),
@@ -78,4 +136,18 @@ mod tests {
             "unexpected memory_write encoding"
         );
     }
+
+    #[test]
+    fn lamports_memory_write_encoding_matches_expected_layout() {
+        let ix = build_lamports_memory_write_instruction(LamportsMemoryWriteParams {
+            payer: Pubkey::new_unique(),
+            memory: Pubkey::new_unique(),
+            memory_id: 3,
+            memory_bump: 254,
+            source_account: Pubkey::new_unique(),
+            write_offset: 8,
+        });
+        assert_eq!(ix.data, vec![0, 3, 254, 8, 1, 1]);
+        assert_eq!(ix.accounts.len(), 5);
+    }
 }
@@ -30,6 +30,19 @@ pub enum LogLevel {
     FailedEncodedNoop = 6,
 }
 
+/// Lighthouse `AccountInfoField` 枚举，用于快照/断言账户元信息。
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u8)]
+#[allow(dead_code)]
+pub enum AccountInfoField {
+    Key = 0,
+    Lamports = 1,
+    DataLength = 2,
+    Executable = 3,
+    Owner = 4,
+    RentEpoch = 5,
+}
+
 /// Lighthouse 整数比较运算符。
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 #[repr(u8)]
@@ -58,3 +71,21 @@ pub(crate) fn push_compact_u64(buffer: &mut Vec<u8>, mut value: u64) {
         }
     }
 }
+
+pub(crate) fn read_compact_u64(buffer: &[u8], start: usize) -> Option<(u64, usize)> {
+    let mut value = 0u64;
+    let mut shift = 0u32;
+    let mut cursor = start;
+    loop {
+        let byte = *buffer.get(cursor)?;
+        cursor += 1;
+        value |= u64::from(byte & 0x7F).checked_shl(shift)?;
+        if byte & 0x80 == 0 {
+            return Some((value, cursor));
+        }
+        shift += 7;
+        if shift >= 64 {
+            return None;
+        }
+    }
+}
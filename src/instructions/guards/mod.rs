@@ -1 +1,2 @@
 pub mod lighthouse;
+pub mod price_band;
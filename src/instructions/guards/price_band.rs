@@ -0,0 +1,222 @@
+use std::fmt;
+use std::str::FromStr;
+
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+
+use super::lighthouse::account_data::{
+    AccountDataParams, DataValue, build_account_data_instruction,
+};
+use super::lighthouse::program::{IntegerOperator, LogLevel};
+
+/// Pyth receiver `PriceUpdateV2`（Full 验证级别）中 `price_message.price`（i64）的偏移：
+/// discriminator(8) + write_authority(32) + verification_level(1) + feed_id(32)。
+pub const PYTH_RECEIVER_PRICE_OFFSET: u64 = 73;
+/// Switchboard On-Demand `PullFeedAccountData` 中 `result.value`（i128，18 位小数）的偏移。
+pub const SWITCHBOARD_RESULT_VALUE_OFFSET: u64 = 2264;
+
+/// 价格字段的存储宽度与符号。
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PriceValueType {
+    U64,
+    I64,
+    U128,
+    I128,
+}
+
+impl PriceValueType {
+    fn encode(&self, value: i128) -> Option<DataValue> {
+        Some(match self {
+            PriceValueType::U64 => DataValue::U64(u64::try_from(value).ok()?),
+            PriceValueType::I64 => DataValue::I64(i64::try_from(value).ok()?),
+            PriceValueType::U128 => DataValue::U128(u128::try_from(value).ok()?),
+            PriceValueType::I128 => DataValue::I128(value),
+        })
+    }
+}
+
+impl FromStr for PriceValueType {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "u64" => Ok(PriceValueType::U64),
+            "i64" => Ok(PriceValueType::I64),
+            "u128" => Ok(PriceValueType::U128),
+            "i128" => Ok(PriceValueType::I128),
+            other => Err(format!(
+                "未知的价格字段类型 `{other}`，可选 u64/i64/u128/i128"
+            )),
+        }
+    }
+}
+
+/// 价格来源：预言机预设布局，或池子账户中的任意价格字段。
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PriceSource {
+    PythReceiver,
+    SwitchboardOnDemand,
+    Raw {
+        offset: u64,
+        value_type: PriceValueType,
+    },
+}
+
+impl PriceSource {
+    pub fn offset(&self) -> u64 {
+        match self {
+            PriceSource::PythReceiver => PYTH_RECEIVER_PRICE_OFFSET,
+            PriceSource::SwitchboardOnDemand => SWITCHBOARD_RESULT_VALUE_OFFSET,
+            PriceSource::Raw { offset, .. } => *offset,
+        }
+    }
+
+    pub fn value_type(&self) -> PriceValueType {
+        match self {
+            PriceSource::PythReceiver => PriceValueType::I64,
+            PriceSource::SwitchboardOnDemand => PriceValueType::I128,
+            PriceSource::Raw { value_type, .. } => *value_type,
+        }
+    }
+}
+
+impl fmt::Display for PriceSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PriceSource::PythReceiver => f.write_str("pyth"),
+            PriceSource::SwitchboardOnDemand => f.write_str("switchboard"),
+            PriceSource::Raw { offset, .. } => write!(f, "raw@{offset}"),
+        }
+    }
+}
+
+/// 账户中价格字段的允许区间（原始整数，含边界），交易在 swap 之前断言。
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PriceBand {
+    account: Pubkey,
+    source: PriceSource,
+    min: Option<DataValue>,
+    max: Option<DataValue>,
+}
+
+impl PriceBand {
+    pub fn new(
+        account: Pubkey,
+        source: PriceSource,
+        min: Option<i128>,
+        max: Option<i128>,
+    ) -> Result<Self, String> {
+        if min.is_none() && max.is_none() {
+            return Err("价格区间至少需要 min 或 max 之一".to_string());
+        }
+        if let (Some(min), Some(max)) = (min, max) {
+            if min > max {
+                return Err(format!("价格区间 min({min}) 大于 max({max})"));
+            }
+        }
+        let value_type = source.value_type();
+        let encode = |bound: i128| {
+            value_type
+                .encode(bound)
+                .ok_or_else(|| format!("价格边界 {bound} 超出 {value_type:?} 的取值范围"))
+        };
+        Ok(Self {
+            account,
+            source,
+            min: min.map(encode).transpose()?,
+            max: max.map(encode).transpose()?,
+        })
+    }
+
+    pub fn account(&self) -> &Pubkey {
+        &self.account
+    }
+
+    pub fn source(&self) -> PriceSource {
+        self.source
+    }
+
+    /// 每个边界一条 AccountData 断言。
+    pub fn instructions(&self) -> Vec<Instruction> {
+        let bounds = [
+            (self.min, IntegerOperator::GreaterThanOrEqual),
+            (self.max, IntegerOperator::LessThanOrEqual),
+        ];
+        bounds
+            .into_iter()
+            .filter_map(|(value, operator)| {
+                let value = value?;
+                Some(build_account_data_instruction(AccountDataParams {
+                    target_account: self.account,
+                    log_level: LogLevel::FailedPlaintextMessage,
+                    offset: self.source.offset(),
+                    value,
+                    operator,
+                }))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pyth_band_emits_lower_and_upper_assertions() {
+        let oracle = Pubkey::new_unique();
+        let band = PriceBand::new(
+            oracle,
+            PriceSource::PythReceiver,
+            Some(12_000_000_000),
+            Some(25_000_000_000),
+        )
+        .expect("band");
+        let instructions = band.instructions();
+        assert_eq!(instructions.len(), 2);
+        assert_eq!(instructions[0].accounts[0].pubkey, oracle);
+        assert_eq!(&instructions[0].data[..4], &[2, 4, 73, 8]);
+        assert_eq!(
+            *instructions[0].data.last().unwrap(),
+            IntegerOperator::GreaterThanOrEqual as u8
+        );
+        assert_eq!(
+            *instructions[1].data.last().unwrap(),
+            IntegerOperator::LessThanOrEqual as u8
+        );
+    }
+
+    #[test]
+    fn one_sided_band_emits_single_assertion() {
+        let source = PriceSource::Raw {
+            offset: 253,
+            value_type: PriceValueType::U128,
+        };
+        let band = PriceBand::new(Pubkey::new_unique(), source, None, Some(1 << 80)).unwrap();
+        let instructions = band.instructions();
+        assert_eq!(instructions.len(), 1);
+        // offset 253 需要两个字节的 compact 编码
+        assert_eq!(&instructions[0].data[..5], &[2, 4, 0xFD, 0x01, 9]);
+    }
+
+    #[test]
+    fn invalid_bands_are_rejected() {
+        let account = Pubkey::new_unique();
+        assert!(PriceBand::new(account, PriceSource::PythReceiver, None, None).is_err());
+        assert!(PriceBand::new(account, PriceSource::PythReceiver, Some(10), Some(5)).is_err());
+        let unsigned = PriceSource::Raw {
+            offset: 0,
+            value_type: PriceValueType::U64,
+        };
+        assert!(PriceBand::new(account, unsigned, Some(-1), None).is_err());
+        assert!(
+            PriceBand::new(
+                account,
+                PriceSource::PythReceiver,
+                Some(i128::from(i64::MAX) + 1),
+                None
+            )
+            .is_err()
+        );
+    }
+}
@@ -5,10 +5,11 @@ use std::{
 };
 
 use serde_json::Value;
-use tracing::{info, warn};
+use tracing::{debug, info, warn};
 
 use crate::config::{
-    IntermediumConfig, JsonMintSource, JsonSelectorType, MintFileSource, MintFileSourceKind,
+    IntermediumConfig, JsonMintSelector, JsonMintSource, JsonSelectorType, MintFileSource,
+    MintFileSourceKind, MintUrlSource,
 };
 
 use thiserror::Error;
@@ -27,87 +28,119 @@ pub enum MintSourceError {
     },
     #[error("{path}: {message}")]
     Invalid { path: PathBuf, message: String },
+    #[error("拉取 {url}: {message}")]
+    Remote { url: String, message: String },
 }
 
+/// 启动时合并配置中的 mints 与 `load_mints_from_files`；URL 源需要网络，由
+/// [`resolve_mint_universe`] 在运行时加载。
 pub fn hydrate_mints_from_sources(
     cfg: &mut IntermediumConfig,
     base_dir: Option<&Path>,
 ) -> Result<(), MintSourceError> {
-    let mut seen = HashSet::new();
-    let mut merged = Vec::new();
+    cfg.configured_mints = std::mem::take(&mut cfg.mints);
+    cfg.base_dir = base_dir.map(Path::to_path_buf);
 
-    for mint in std::mem::take(&mut cfg.mints) {
-        let trimmed = mint.trim();
-        if trimmed.is_empty() {
+    let loaded = load_file_sources(cfg)?;
+    if !loaded.is_empty() {
+        info!(
+            target: "intermedium::loader",
+            total = loaded.len(),
+            "intermedium mints 已追加来自文件的条目"
+        );
+    }
+
+    cfg.mints = merge_universe(cfg, loaded);
+    Ok(())
+}
+
+/// 重新读取文件与 URL 源，合并配置中的 mints 后应用 `disable_mints` 与 `max_tokens_limit`。
+pub async fn resolve_mint_universe(
+    cfg: &IntermediumConfig,
+    client: &reqwest::Client,
+) -> Result<Vec<String>, MintSourceError> {
+    let mut loaded = load_file_sources(cfg)?;
+    for source in &cfg.load_mints_from_url {
+        let mints = load_url_source(source, client).await?;
+        if mints.is_empty() {
+            warn!(
+                target: "intermedium::loader",
+                url = %source.url,
+                name = source.name.as_deref().unwrap_or(""),
+                "未从该 URL 读取到 mint，检查 selector 配置是否正确"
+            );
             continue;
         }
-        if seen.insert(trimmed.to_string()) {
-            merged.push(trimmed.to_string());
-        }
+        debug!(
+            target: "intermedium::loader",
+            url = %source.url,
+            name = source.name.as_deref().unwrap_or(""),
+            count = mints.len(),
+            "load_mints_from_url 已加载条目"
+        );
+        loaded.extend(mints);
     }
+    Ok(merge_universe(cfg, loaded))
+}
 
-    let mut total_loaded = 0usize;
-    if !cfg.load_mints_from_files.is_empty() {
-        for source in &cfg.load_mints_from_files {
-            let (path, label, mints) = match source {
-                MintFileSource::TextPath(path) => load_text_source(path, None, base_dir)
-                    .map(|(path, mints)| (path, None, mints))?,
-                MintFileSource::Detailed(kind) => match kind {
-                    MintFileSourceKind::Text(text) => {
-                        let name = text.name.clone();
-                        let (path, mints) =
-                            load_text_source(&text.path, text.name.as_deref(), base_dir)?;
-                        (path, name, mints)
-                    }
-                    MintFileSourceKind::Json(json) => {
-                        let name = json.name.clone();
-                        let (path, mints) = load_json_source(json, base_dir)?;
-                        (path, name, mints)
-                    }
-                },
-            };
-
-            if mints.is_empty() {
-                warn!(
-                    target: "intermedium::loader",
-                    path = %path.display(),
-                    name = label.as_deref().unwrap_or(""),
-                    "未从该源读取到 mint，检查 selector 配置是否正确"
-                );
-                continue;
+fn load_file_sources(cfg: &IntermediumConfig) -> Result<Vec<String>, MintSourceError> {
+    let base_dir = cfg.base_dir.as_deref();
+    let mut loaded = Vec::new();
+    for source in &cfg.load_mints_from_files {
+        let (path, label, mints) = match source {
+            MintFileSource::TextPath(path) => {
+                load_text_source(path, None, base_dir).map(|(path, mints)| (path, None, mints))?
             }
-
-            let mut added = 0usize;
-            for mint in mints {
-                if seen.insert(mint.clone()) {
-                    merged.push(mint);
-                    added += 1;
-                    total_loaded += 1;
+            MintFileSource::Detailed(kind) => match kind {
+                MintFileSourceKind::Text(text) => {
+                    let name = text.name.clone();
+                    let (path, mints) =
+                        load_text_source(&text.path, text.name.as_deref(), base_dir)?;
+                    (path, name, mints)
                 }
-            }
+                MintFileSourceKind::Json(json) => {
+                    let name = json.name.clone();
+                    let (path, mints) = load_json_source(json, base_dir)?;
+                    (path, name, mints)
+                }
+            },
+        };
 
-            info!(
+        if mints.is_empty() {
+            warn!(
                 target: "intermedium::loader",
                 path = %path.display(),
                 name = label.as_deref().unwrap_or(""),
-                added,
-                "load_mints_from_files 已加载条目"
+                "未从该源读取到 mint，检查 selector 配置是否正确"
             );
+            continue;
         }
+        loaded.extend(mints);
     }
+    Ok(loaded)
+}
 
-    if total_loaded > 0 {
-        info!(
-            target: "intermedium::loader",
-            total = total_loaded,
-            "intermedium mints 已追加来自文件的条目"
-        );
+/// 配置中的 mints 优先，随后按来源顺序去重追加，剔除禁用项后截断到上限。
+fn merge_universe(cfg: &IntermediumConfig, loaded: Vec<String>) -> Vec<String> {
+    let disabled: HashSet<&str> = cfg
+        .disable_mints
+        .iter()
+        .map(|mint| mint.trim())
+        .filter(|mint| !mint.is_empty())
+        .collect();
+    let mut seen = HashSet::new();
+    let mut merged = Vec::new();
+    for mint in cfg.configured_mints.iter().cloned().chain(loaded) {
+        let trimmed = mint.trim();
+        if trimmed.is_empty() || disabled.contains(trimmed) {
+            continue;
+        }
+        if seen.insert(trimmed.to_string()) {
+            merged.push(trimmed.to_string());
+        }
     }
-
     apply_limit(cfg.max_tokens_limit, &mut merged);
-
-    cfg.mints = merged;
-    Ok(())
+    merged
 }
 
 fn apply_limit(limit: u32, mints: &mut Vec<String>) {
@@ -171,53 +204,74 @@ fn load_json_source(
         source,
     })?;
 
-    let nodes = if let Some(selector) = spec.selector.as_ref() {
+    let mints = select_mints(&value, spec.selector.as_ref()).map_err(|message| {
+        MintSourceError::Invalid {
+            path: resolved.clone(),
+            message,
+        }
+    })?;
+
+    Ok((resolved, mints))
+}
+
+async fn load_url_source(
+    spec: &MintUrlSource,
+    client: &reqwest::Client,
+) -> Result<Vec<String>, MintSourceError> {
+    let remote_error = |message: String| MintSourceError::Remote {
+        url: spec.url.clone(),
+        message,
+    };
+    let response = client
+        .get(spec.url.trim())
+        .send()
+        .await
+        .and_then(|response| response.error_for_status())
+        .map_err(|err| remote_error(err.to_string()))?;
+    let body = response
+        .text()
+        .await
+        .map_err(|err| remote_error(err.to_string()))?;
+    let value: Value =
+        serde_json::from_str(&body).map_err(|err| remote_error(format!("解析 JSON: {err}")))?;
+    select_mints(&value, spec.selector.as_ref()).map_err(remote_error)
+}
+
+/// 按 selector（JsonPath / JsonPointer）定位节点并收集其中的标量。
+fn select_mints(value: &Value, selector: Option<&JsonMintSelector>) -> Result<Vec<String>, String> {
+    let nodes = if let Some(selector) = selector {
         let expr = selector.expr.trim();
         if expr.is_empty() {
-            return Err(MintSourceError::Invalid {
-                path: resolved.clone(),
-                message: "selector.expr 不能为空".to_string(),
-            });
+            return Err("selector.expr 不能为空".to_string());
         }
         match selector.selector_type {
             JsonSelectorType::JsonPath => {
-                let ops = parse_json_path(expr).map_err(|message| MintSourceError::Invalid {
-                    path: resolved.clone(),
-                    message,
-                })?;
-                apply_json_path(&value, &ops)
+                let ops = parse_json_path(expr)?;
+                apply_json_path(value, &ops)
             }
             JsonSelectorType::JsonPointer => {
                 if expr == "/" {
-                    vec![&value]
+                    vec![value]
                 } else {
-                    value.pointer(expr).map(|node| vec![node]).ok_or_else(|| {
-                        MintSourceError::Invalid {
-                            path: resolved.clone(),
-                            message: format!("pointer {expr} 未命中任何节点"),
-                        }
-                    })?
+                    value
+                        .pointer(expr)
+                        .map(|node| vec![node])
+                        .ok_or_else(|| format!("pointer {expr} 未命中任何节点"))?
                 }
             }
         }
     } else {
-        vec![&value]
+        vec![value]
     };
 
-    let mints = collect_scalar_strings(&nodes, &resolved)?;
-
-    Ok((resolved, mints))
-}
-
-fn collect_scalar_strings(nodes: &[&Value], path: &Path) -> Result<Vec<String>, MintSourceError> {
     let mut result = Vec::new();
     for node in nodes {
-        push_scalar(node, &mut result, path)?;
+        push_scalar(node, &mut result)?;
     }
     Ok(result)
 }
 
-fn push_scalar(node: &Value, output: &mut Vec<String>, path: &Path) -> Result<(), MintSourceError> {
+fn push_scalar(node: &Value, output: &mut Vec<String>) -> Result<(), String> {
     match node {
         Value::String(value) => {
             let trimmed = value.trim();
@@ -228,7 +282,7 @@ fn push_scalar(node: &Value, output: &mut Vec<String>, path: &Path) -> Result<()
         }
         Value::Array(items) => {
             for item in items {
-                push_scalar(item, output, path)?;
+                push_scalar(item, output)?;
             }
             Ok(())
         }
@@ -241,10 +295,7 @@ fn push_scalar(node: &Value, output: &mut Vec<String>, path: &Path) -> Result<()
             Ok(())
         }
         Value::Null => Ok(()),
-        Value::Object(_) => Err(MintSourceError::Invalid {
-            path: path.to_path_buf(),
-            message: "selector 命中对象，请直接指向包含 mint 的字段".to_string(),
-        }),
+        Value::Object(_) => Err("selector 命中对象，请直接指向包含 mint 的字段".to_string()),
     }
 }
 
@@ -370,3 +421,33 @@ fn apply_json_path<'a>(root: &'a Value, ops: &[JsonPathOp]) -> Vec<&'a Value> {
     }
     current
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merge_universe_dedupes_disables_and_limits() {
+        let cfg = IntermediumConfig {
+            configured_mints: vec!["A".into(), " B ".into()],
+            disable_mints: vec!["C".into()],
+            max_tokens_limit: 3,
+            ..IntermediumConfig::default()
+        };
+        let merged = merge_universe(&cfg, vec!["B".into(), "C".into(), "D".into(), "E".into()]);
+        assert_eq!(merged, vec!["A", "B", "D"]);
+    }
+
+    #[test]
+    fn select_mints_supports_json_path() {
+        let value: Value = serde_json::from_str(r#"{"data":[{"mint":"A"},{"mint":"B"}]}"#).unwrap();
+        let selector = JsonMintSelector {
+            selector_type: JsonSelectorType::JsonPath,
+            expr: "$.data[*].mint".to_string(),
+        };
+        assert_eq!(
+            select_mints(&value, Some(&selector)).unwrap(),
+            vec!["A", "B"]
+        );
+    }
+}
@@ -199,6 +199,13 @@ impl JitoLander {
             .collect()
     }
 
+    pub fn endpoint_urls(&self) -> Vec<String> {
+        self.endpoints
+            .iter()
+            .map(|endpoint| endpoint.url.clone())
+            .collect()
+    }
+
     pub fn tip_strategy_label(&self) -> &'static str {
         match self.tip_selector.strategy_kind() {
             crate::config::TipStrategyKind::Fixed => "fixed",
@@ -246,7 +253,14 @@ impl JitoLander {
 
         let base_submission = self.build_base_submission(&variant);
         let base_tip_lamports = base_submission.tip_lamports;
-        let encoded_main = encode_transaction(&base_submission.transaction)?;
+        let mut bundle_transactions = vec![base_submission.transaction.clone()];
+        if let Some(tail) = variant.bundle_tail() {
+            bundle_transactions.push(tail.clone());
+        }
+        let encoded_bundle = bundle_transactions
+            .iter()
+            .map(encode_transaction)
+            .collect::<Result<Vec<_>, _>>()?;
 
         let mut submissions = Vec::new();
         for endpoint in endpoints {
@@ -258,8 +272,8 @@ impl JitoLander {
                             UuidTicketOutcome::Ticket(ticket) => {
                                 if let Some(submission) = self.build_uuid_submission(
                                     endpoint,
-                                    &encoded_main,
-                                    &base_submission.transaction,
+                                    &encoded_bundle,
+                                    &bundle_transactions,
                                     ticket,
                                 ) {
                                     submissions.push(submission);
@@ -283,20 +297,27 @@ impl JitoLander {
                     } else {
                         submissions.push(self.build_forward_submission(
                             endpoint,
-                            &encoded_main,
-                            &base_submission.transaction,
+                            &encoded_bundle,
+                            &bundle_transactions,
                         ));
                     }
                 }
                 LanderJitoStrategyKind::Forward => {
                     submissions.push(self.build_forward_submission(
                         endpoint,
-                        &encoded_main,
-                        &base_submission.transaction,
+                        &encoded_bundle,
+                        &bundle_transactions,
                     ));
                 }
                 LanderJitoStrategyKind::MultiIps => {
-                    if let Some(strategy) = &self.multi_ips {
+                    if variant.bundle_tail().is_some() || variant.durable_nonce().is_some() {
+                        // 拆分 bundle 无法再叠加独立小费交易；nonce 值也不能作为小费交易的 blockhash，均退化为普通转发
+                        submissions.push(self.build_forward_submission(
+                            endpoint,
+                            &encoded_bundle,
+                            &bundle_transactions,
+                        ));
+                    } else if let Some(strategy) = &self.multi_ips {
                         match strategy
                             .build_bundle(&variant, base_tip_lamports, tip_offset(endpoint.index))
                             .await
@@ -459,6 +480,7 @@ impl JitoLander {
                 signature: bundle_id,
                 variant_id,
                 local_ip,
+                compute_units_consumed: None,
             };
 
             if !crate::monitoring::events::summary_only_enabled() {
@@ -514,39 +536,39 @@ impl JitoLander {
     fn build_uuid_submission(
         &self,
         endpoint: &StrategyEndpoint,
-        encoded_main: &str,
-        base_tx: &VersionedTransaction,
+        encoded: &[String],
+        transactions: &[VersionedTransaction],
         ticket: UuidTicket,
     ) -> Option<BundleSubmission> {
         let bundle_hint = ticket.bundle_id.clone();
         let url = prepare_endpoint_url(endpoint, Some(&ticket))?;
-        let payload = build_jsonrpc_payload(vec![encoded_main.to_string()], Some(&ticket));
+        let payload = build_jsonrpc_payload(encoded.to_vec(), Some(&ticket));
         Some(BundleSubmission {
             label: endpoint.label.clone(),
             strategy: endpoint.kind,
             endpoint: url,
             payload,
             bundle_hint: Some(bundle_hint),
-            raw_transactions: vec![base_tx.clone()],
+            raw_transactions: transactions.to_vec(),
         })
     }
 
     fn build_forward_submission(
         &self,
         endpoint: &StrategyEndpoint,
-        encoded_main: &str,
-        base_tx: &VersionedTransaction,
+        encoded: &[String],
+        transactions: &[VersionedTransaction],
     ) -> BundleSubmission {
         let url = prepare_endpoint_url(endpoint, None)
             .unwrap_or_else(|| Url::parse(&endpoint.url).expect("valid endpoint url"));
-        let payload = build_jsonrpc_payload(vec![encoded_main.to_string()], None);
+        let payload = build_jsonrpc_payload(encoded.to_vec(), None);
         BundleSubmission {
             label: endpoint.label.clone(),
             strategy: endpoint.kind,
             endpoint: url,
             payload,
             bundle_hint: None,
-            raw_transactions: vec![base_tx.clone()],
+            raw_transactions: transactions.to_vec(),
         }
     }
 
@@ -93,6 +93,7 @@ impl DryRunFallback {
             signature: Some(joined_signatures),
             variant_id,
             local_ip,
+            compute_units_consumed: None,
         })
     }
 }
@@ -22,6 +22,9 @@ use crate::lander::error::LanderError;
 
 use super::bundle::{encode_transaction, random_tip_wallet, strip_tip_transfer};
 use super::tip::MIN_JITO_TIP_LAMPORTS;
+use crate::instructions::guards::lighthouse::account_delta::{
+    AccountDeltaKind, adjust_expected_delta,
+};
 use crate::instructions::guards::lighthouse::program::LIGHTHOUSE_PROGRAM_ID;
 
 const SURCHARGE_LAMPORTS: u64 = 1_000_000; // 0.001 SOL
@@ -82,7 +85,16 @@ impl MultiIpsStrategy {
             insert_at,
             system_instruction::transfer(&payer, &wallet.pubkey(), deposit),
         );
-        bump_profit_guard_threshold(&mut base_instructions, guard_buffer);
+        let removed_tip = variant
+            .jito_tip_plan()
+            .map(|plan| plan.lamports)
+            .unwrap_or(0);
+        bump_profit_guard_threshold(
+            &mut base_instructions,
+            &payer,
+            guard_buffer,
+            i128::from(removed_tip) - i128::from(deposit),
+        );
         let main_tx = assemble_main_transaction(variant, base_instructions)?;
 
         let reclaim_instruction = if reclaim > 0 {
@@ -175,37 +187,37 @@ fn adjust_tip(base_tip: u64, offset: i64) -> u64 {
     }
 }
 
-fn bump_profit_guard_threshold(instructions: &mut [Instruction], extra: u64) {
-    if extra == 0 {
-        return;
-    }
+/// 利润断言（首个 AccountDelta）追加 `extra`；payer 的 lamports 断言按 tip 转账替换为
+/// 临时钱包充值后的支出差额 `payer_spend_delta` 调整。
+fn bump_profit_guard_threshold(
+    instructions: &mut [Instruction],
+    payer: &Pubkey,
+    extra: u64,
+    payer_spend_delta: i128,
+) {
+    let mut profit_bumped = extra == 0;
     for instruction in instructions.iter_mut() {
         if instruction.program_id != LIGHTHOUSE_PROGRAM_ID {
             continue;
         }
-        if adjust_guard_delta(&mut instruction.data, extra) {
-            break;
+        let payer_lamports = instruction
+            .accounts
+            .get(1)
+            .is_some_and(|meta| meta.pubkey == *payer);
+        let mut adjustment = 0i128;
+        if !profit_bumped {
+            adjustment += i128::from(extra);
+        }
+        let kind = adjust_expected_delta(&mut instruction.data, adjustment);
+        if kind.is_some() {
+            profit_bumped = true;
+        }
+        if kind == Some(AccountDeltaKind::Lamports) && payer_lamports && payer_spend_delta != 0 {
+            adjust_expected_delta(&mut instruction.data, payer_spend_delta);
         }
     }
 }
 
-fn adjust_guard_delta(data: &mut Vec<u8>, extra: u64) -> bool {
-    if data.first() != Some(&4) {
-        return false;
-    }
-    // layout: [4, log_level, 1, snapshot_offset..., account_data_offset..., 6, expected_delta (i128 LE), operator]
-    let expected_start = 6;
-    if data.len() < expected_start + 16 {
-        return false;
-    }
-    let mut buf = [0u8; 16];
-    buf.copy_from_slice(&data[expected_start..expected_start + 16]);
-    let mut value = i128::from_le_bytes(buf);
-    value = value.saturating_add(extra as i128);
-    data[expected_start..expected_start + 16].copy_from_slice(&value.to_le_bytes());
-    true
-}
-
 #[derive(Clone)]
 struct WalletPool {
     store: Arc<Mutex<VecDeque<Arc<Keypair>>>>,
@@ -231,13 +243,17 @@ impl WalletPool {
         };
         if interval_ms > 0 && generate_count > 0 {
             if let Ok(handle) = Handle::try_current() {
-                let store_clone = store.clone();
+                // 仅持有弱引用：热重载重建落地器后旧钱包池释放，补充任务随之退出。
+                let store_weak = Arc::downgrade(&store);
                 let threshold = refill_threshold;
                 handle.spawn(async move {
                     let interval = Duration::from_millis(interval_ms);
                     loop {
                         sleep(interval).await;
-                        let mut guard = store_clone.lock().await;
+                        let Some(store) = store_weak.upgrade() else {
+                            break;
+                        };
+                        let mut guard = store.lock().await;
                         if let Some(limit) = threshold {
                             if guard.len() >= limit {
                                 continue;
@@ -308,4 +324,34 @@ mod tests {
             "positive offset increments tip"
         );
     }
+
+    #[test]
+    fn bump_profit_guard_threshold_adjusts_profit_and_payer_lamports() {
+        use crate::instructions::guards::lighthouse::{
+            GuardAccount, GuardTarget, build_profit_guard,
+        };
+
+        let payer = Pubkey::new_unique();
+        let settle = Pubkey::new_unique();
+        let targets = [
+            GuardTarget {
+                account: GuardAccount::TokenAmount(settle),
+                min_delta: 1_000,
+            },
+            GuardTarget {
+                account: GuardAccount::Lamports(payer),
+                min_delta: -2_000,
+            },
+        ];
+        let mut instructions = build_profit_guard(payer, &targets, &[0, 1]).assertions;
+        bump_profit_guard_threshold(&mut instructions, &payer, 500, -700);
+
+        // expected_delta 位于末尾 operator 字节之前
+        let delta = |ix: &Instruction| {
+            let len = ix.data.len();
+            i128::from_le_bytes(ix.data[len - 17..len - 1].try_into().unwrap())
+        };
+        assert_eq!(delta(&instructions[0]), 1_500);
+        assert_eq!(delta(&instructions[1]), -2_700);
+    }
 }
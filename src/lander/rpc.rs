@@ -45,6 +45,10 @@ impl RpcLander {
         }
     }
 
+    pub fn url(&self) -> String {
+        self.client.url()
+    }
+
     pub async fn submit_variant(
         &self,
         variant: TxVariant,
@@ -107,6 +111,7 @@ impl RpcLander {
             signature: Some(signature.to_string()),
             variant_id,
             local_ip,
+            compute_units_consumed: None,
         })
     }
 
@@ -153,6 +158,12 @@ impl RpcLander {
             signature: None,
             variant_id,
             local_ip: None,
+            compute_units_consumed: result
+                .value
+                .err
+                .is_none()
+                .then_some(result.value.units_consumed)
+                .flatten(),
         })
     }
 }
@@ -9,6 +9,7 @@ use futures::stream::FuturesUnordered;
 use solana_sdk::instruction::Instruction;
 use tracing::{info, warn};
 
+use crate::control;
 use crate::engine::{
     COMPUTE_BUDGET_PROGRAM_ID, DispatchPlan, DispatchStrategy, JitoTipPlan, TxVariant, VariantId,
 };
@@ -42,6 +43,8 @@ pub struct LanderReceipt {
     pub signature: Option<String>,
     pub variant_id: VariantId,
     pub local_ip: Option<IpAddr>,
+    /// 模拟模式下返回的 compute unit 实际消耗，真实提交时为 None。
+    pub compute_units_consumed: Option<u64>,
 }
 
 #[derive(Clone)]
@@ -76,6 +79,15 @@ impl LanderVariant {
         }
     }
 
+    /// 落地器实际请求的 URL，用于健康检查。
+    pub fn endpoint_urls(&self) -> Vec<String> {
+        match self {
+            LanderVariant::Rpc(lander) => vec![lander.url()],
+            LanderVariant::Jito(lander) => lander.endpoint_urls(),
+            LanderVariant::Staked(lander) => lander.endpoint_list(),
+        }
+    }
+
     pub async fn submit_variant(
         &self,
         variant: TxVariant,
@@ -83,6 +95,9 @@ impl LanderVariant {
         endpoint: Option<&str>,
         local_ip: Option<IpAddr>,
     ) -> Result<LanderReceipt, LanderError> {
+        if variant.bundle_tail().is_some() && !matches!(self, LanderVariant::Jito(_)) {
+            return Err(LanderError::fatal("拆分 bundle 仅支持 Jito 落地"));
+        }
         match self {
             LanderVariant::Rpc(lander) => lander.submit_variant(variant, deadline, local_ip).await,
             LanderVariant::Jito(lander) => {
@@ -173,6 +188,13 @@ impl LanderStack {
         if plan.is_empty() {
             return Err(LanderError::fatal("dispatch plan missing variants"));
         }
+        if self
+            .landers
+            .iter()
+            .all(|lander| control::lander_disabled(lander.name()))
+        {
+            return Err(LanderError::fatal("all landers disabled via control api"));
+        }
 
         match plan.strategy() {
             DispatchStrategy::AllAtOnce => {
@@ -205,6 +227,9 @@ impl LanderStack {
                 if deadline.expired() {
                     return Err(LanderError::fatal("deadline expired before submission"));
                 }
+                if control::lander_disabled(lander.name()) {
+                    continue;
+                }
 
                 let variants = plan.variants_for_lander(lander_idx);
                 for variant in variants.iter().cloned() {
@@ -262,7 +287,14 @@ impl LanderStack {
                         if receipt.local_ip.is_none() {
                             receipt.local_ip = local_ip;
                         }
-                        events::lander_success(strategy_name, dispatch_label, attempt, &receipt);
+                        events::lander_success(
+                            strategy_name,
+                            dispatch_label,
+                            attempt,
+                            &receipt,
+                            telemetry.tip,
+                            telemetry.compute_unit_price,
+                        );
                         return Ok(receipt);
                     }
                     Err(err) => {
@@ -404,6 +436,9 @@ impl LanderStack {
             let mut futures = FuturesUnordered::new();
 
             for (lander_idx, lander) in self.landers.iter().enumerate() {
+                if control::lander_disabled(lander.name()) {
+                    continue;
+                }
                 let variants = plan.variants_for_lander(lander_idx);
                 if variants.is_empty() {
                     continue;
@@ -485,7 +520,14 @@ impl LanderStack {
                         if receipt.local_ip.is_none() {
                             receipt.local_ip = local_ip;
                         }
-                        events::lander_success(strategy_name, dispatch_label, attempt, &receipt);
+                        events::lander_success(
+                            strategy_name,
+                            dispatch_label,
+                            attempt,
+                            &receipt,
+                            telemetry.tip,
+                            telemetry.compute_unit_price,
+                        );
                         return Ok(receipt);
                     }
                     Err(err) => {
@@ -732,7 +774,8 @@ async fn submit_with_lease(
         .jito_tip_plan()
         .map(|plan| plan.lamports)
         .unwrap_or_else(|| variant.tip_lamports());
-    let _ = variant.prioritization_fee_lamports();
+    let prioritization_fee_lamports = variant.prioritization_fee_lamports();
+    let blockhash_age = variant.blockhash_age();
     let compute_unit_price_micro = variant
         .compute_unit_price_micro_lamports()
         .or(instruction_price)
@@ -783,10 +826,27 @@ async fn submit_with_lease(
         local_ip,
     );
 
+    if let Some(age) = blockhash_age {
+        events::blockhash_age(lander_name, age);
+    }
+
     let submission_started = Instant::now();
     let mut result = lander
         .submit_variant(variant, deadline, endpoint.as_deref(), local_ip)
         .await;
+    events::lander_endpoint_submission(
+        strategy,
+        lander_name,
+        result
+            .as_ref()
+            .ok()
+            .map(|receipt| receipt.endpoint.as_str())
+            .or(endpoint.as_deref()),
+        result.is_ok(),
+        submission_started.elapsed(),
+        tip_lamports,
+        prioritization_fee_lamports,
+    );
 
     if let Some(handle) = lease_handle.take() {
         if let Err(err) = &result {
@@ -257,11 +257,19 @@ impl StakedLander {
                             .pointer("/result/value/logs")
                             .cloned()
                             .unwrap_or(Value::Null);
+                        let sim_failed = !err.is_null()
+                            || value
+                                .pointer("/result/value/err")
+                                .is_some_and(|inner| !inner.is_null());
+                        let units_consumed = value
+                            .pointer("/result/value/unitsConsumed")
+                            .and_then(Value::as_u64);
                         info!(
                             target: "lander::staked",
                             endpoint = %target,
                             error = ?err,
                             logs = ?logs,
+                            units_consumed = ?units_consumed,
                             "simulateTransaction completed"
                         );
                         return Ok(LanderReceipt {
@@ -272,6 +280,7 @@ impl StakedLander {
                             signature: None,
                             variant_id,
                             local_ip,
+                            compute_units_consumed: units_consumed.filter(|_| !sim_failed),
                         });
                     }
                     Err(err) => {
@@ -340,6 +349,7 @@ impl StakedLander {
             signature,
             variant_id,
             local_ip,
+            compute_units_consumed: None,
         })
     }
 }
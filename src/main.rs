@@ -11,6 +11,7 @@ mod cache;
 mod cli;
 mod concurrency;
 mod config;
+mod control;
 mod dexes;
 mod engine;
 mod instructions;
@@ -135,8 +136,17 @@ fn bootstrap() -> Result<(Cli, AppConfig, RuntimeOptions)> {
         crate::cli::wallet::handle_wallet_command(cmd, cli.config.clone())?;
         std::process::exit(0);
     }
+    if let Command::Config(cmd) = &cli.command {
+        crate::cli::config::handle_config_command(cmd, cli.config.clone())?;
+        std::process::exit(0);
+    }
     let config = load_configuration(cli.config.clone()).map_err(|err| anyhow!(err))?;
-    init_tracing(&config.galileo.global.logging)?;
+    let dashboard = match &cli.command {
+        Command::Run(args) => args.tui,
+        Command::Dashboard(_) => true,
+        _ => false,
+    };
+    init_tracing(&config.galileo.global.logging, dashboard)?;
 
     let runtime_opts = prepare_runtime_options(&config.galileo.bot.cpu_affinity)?;
 
@@ -0,0 +1,617 @@
+//! 运行时仪表盘数据源：汇总 `monitoring::events` 发出的事件，
+//! 供 `galileo run --tui` 本地渲染与控制接口 `/dashboard` 远程拉取。
+
+use std::collections::{BTreeMap, VecDeque};
+use std::io;
+use std::net::IpAddr;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::pubkey::Pubkey;
+use spl_token::solana_program::program_pack::Pack;
+use tokio::task::JoinHandle;
+use tokio::time::MissedTickBehavior;
+use tracing::warn;
+use tracing_subscriber::fmt::MakeWriter;
+
+use super::events;
+use crate::instructions::wsol;
+
+/// 速率统计窗口。
+const RATE_WINDOW_SECS: u64 = 60;
+/// 快照中最多列出的路线数，按近一分钟下单数降序截断。
+const MAX_LISTED_ROUTES: usize = 50;
+/// 本地面板保留的日志行数。
+const LOG_CAPACITY: usize = 500;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static FEED: Lazy<DashboardFeed> = Lazy::new(DashboardFeed::new);
+
+/// 开始汇总事件；未启用时事件函数只做一次原子读取。
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+pub fn feed() -> &'static DashboardFeed {
+    &FEED
+}
+
+/// 按秒分桶的滑动窗口计数。
+#[derive(Debug, Default)]
+struct RateWindow {
+    buckets: VecDeque<(u64, u64)>,
+    total: u64,
+}
+
+impl RateWindow {
+    fn record(&mut self, now_sec: u64, count: u64) {
+        self.total = self.total.saturating_add(count);
+        match self.buckets.back_mut() {
+            Some((sec, value)) if *sec == now_sec => *value = value.saturating_add(count),
+            _ => self.buckets.push_back((now_sec, count)),
+        }
+        self.prune(now_sec);
+    }
+
+    fn prune(&mut self, now_sec: u64) {
+        while let Some((sec, _)) = self.buckets.front() {
+            if sec + RATE_WINDOW_SECS > now_sec {
+                break;
+            }
+            self.buckets.pop_front();
+        }
+    }
+
+    fn recent(&self, now_sec: u64) -> u64 {
+        self.buckets
+            .iter()
+            .filter(|(sec, _)| sec + RATE_WINDOW_SECS > now_sec)
+            .map(|(_, count)| count)
+            .sum()
+    }
+}
+
+#[derive(Debug, Default)]
+struct LanderCounters {
+    attempts: u64,
+    success: u64,
+    failure: u64,
+}
+
+#[derive(Debug, Default)]
+struct IpSlot {
+    inflight: usize,
+    cooldown_until: Option<Instant>,
+    cooldown_reason: Option<String>,
+    cooldowns: u64,
+}
+
+#[derive(Debug)]
+struct RouteState {
+    source: String,
+    legs: usize,
+    orders: RateWindow,
+}
+
+#[derive(Debug)]
+struct WalletState {
+    sol: u64,
+    wsol: u64,
+    updated: Instant,
+}
+
+#[derive(Default)]
+struct FeedState {
+    opportunities: BTreeMap<String, RateWindow>,
+    landers: BTreeMap<String, LanderCounters>,
+    ip_slots: BTreeMap<(IpAddr, String), IpSlot>,
+    routes: BTreeMap<String, RouteState>,
+    tip: Option<FeeSample>,
+    compute_unit_price: Option<FeeSample>,
+    wallets: BTreeMap<String, WalletState>,
+}
+
+/// 事件汇总状态，所有写入都在事件函数内同步完成。
+pub struct DashboardFeed {
+    started: Instant,
+    state: Mutex<FeedState>,
+    logs: Mutex<VecDeque<String>>,
+}
+
+impl DashboardFeed {
+    fn new() -> Self {
+        Self {
+            started: Instant::now(),
+            state: Mutex::new(FeedState::default()),
+            logs: Mutex::new(VecDeque::with_capacity(LOG_CAPACITY)),
+        }
+    }
+
+    fn now_sec(&self) -> u64 {
+        self.started.elapsed().as_secs()
+    }
+
+    pub(crate) fn record_opportunity(&self, strategy: &str) {
+        let now = self.now_sec();
+        let mut state = self.state.lock();
+        state
+            .opportunities
+            .entry(strategy.to_string())
+            .or_default()
+            .record(now, 1);
+    }
+
+    pub(crate) fn record_lander_attempt(&self, lander: &str) {
+        let mut state = self.state.lock();
+        state
+            .landers
+            .entry(lander.to_string())
+            .or_default()
+            .attempts += 1;
+    }
+
+    pub(crate) fn record_lander_result(
+        &self,
+        lander: &str,
+        landed: bool,
+        tip: Option<(&str, u64)>,
+        compute_unit_price: Option<(&str, u64)>,
+    ) {
+        let mut state = self.state.lock();
+        let counters = state.landers.entry(lander.to_string()).or_default();
+        if landed {
+            counters.success += 1;
+        } else {
+            counters.failure += 1;
+        }
+        if let Some((strategy, value)) = tip {
+            state.tip = Some(FeeSample::new(strategy, value));
+        }
+        if let Some((strategy, value)) = compute_unit_price {
+            state.compute_unit_price = Some(FeeSample::new(strategy, value));
+        }
+    }
+
+    pub(crate) fn record_ip_inventory(&self, ip: IpAddr, kind: &str) {
+        let mut state = self.state.lock();
+        state.ip_slots.entry((ip, kind.to_string())).or_default();
+    }
+
+    pub(crate) fn record_ip_inflight(&self, ip: IpAddr, kind: &str, inflight: usize) {
+        let mut state = self.state.lock();
+        state
+            .ip_slots
+            .entry((ip, kind.to_string()))
+            .or_default()
+            .inflight = inflight;
+    }
+
+    /// 冷却事件不区分槽位类型，同一 IP 的全部槽位一起进入冷却。
+    pub(crate) fn record_ip_cooldown(&self, ip: IpAddr, reason: &str, duration: Duration) {
+        let until = Instant::now() + duration;
+        let mut state = self.state.lock();
+        for ((slot_ip, _), slot) in state.ip_slots.iter_mut() {
+            if *slot_ip == ip {
+                slot.cooldown_until = Some(until);
+                slot.cooldown_reason = Some(reason.to_string());
+                slot.cooldowns += 1;
+            }
+        }
+    }
+
+    pub(crate) fn record_route_active(&self, route: &str, legs: usize, source: &str) {
+        let mut state = self.state.lock();
+        let entry = state
+            .routes
+            .entry(route.to_string())
+            .or_insert_with(|| RouteState {
+                source: source.to_string(),
+                legs,
+                orders: RateWindow::default(),
+            });
+        entry.legs = legs;
+        if entry.source != source {
+            entry.source = source.to_string();
+        }
+    }
+
+    pub(crate) fn record_route_retired(&self, route: &str) {
+        self.state.lock().routes.remove(route);
+    }
+
+    pub(crate) fn record_route_orders(&self, route: &str, source: &str, count: usize) {
+        let now = self.now_sec();
+        let mut state = self.state.lock();
+        state
+            .routes
+            .entry(route.to_string())
+            .or_insert_with(|| RouteState {
+                source: source.to_string(),
+                legs: 0,
+                orders: RateWindow::default(),
+            })
+            .orders
+            .record(now, count as u64);
+    }
+
+    pub(crate) fn record_wallet_balance(&self, wallet: &Pubkey, sol: u64, wsol: u64) {
+        let mut state = self.state.lock();
+        state.wallets.insert(
+            wallet.to_string(),
+            WalletState {
+                sol,
+                wsol,
+                updated: Instant::now(),
+            },
+        );
+    }
+
+    pub fn push_log(&self, line: String) {
+        let mut logs = self.logs.lock();
+        if logs.len() >= LOG_CAPACITY {
+            logs.pop_front();
+        }
+        logs.push_back(line);
+    }
+
+    pub fn recent_logs(&self, max_lines: usize) -> Vec<String> {
+        let logs = self.logs.lock();
+        let start = logs.len().saturating_sub(max_lines);
+        logs.iter().skip(start).cloned().collect()
+    }
+
+    pub fn snapshot(&self) -> DashboardSnapshot {
+        let now_sec = self.now_sec();
+        let now = Instant::now();
+        let state = self.state.lock();
+
+        let strategies = state
+            .opportunities
+            .iter()
+            .map(|(name, window)| StrategyRow {
+                name: name.clone(),
+                opportunities_total: window.total,
+                opportunities_per_minute: window.recent(now_sec),
+            })
+            .collect();
+
+        let landers = state
+            .landers
+            .iter()
+            .map(|(name, counters)| LanderRow {
+                name: name.clone(),
+                attempts: counters.attempts,
+                success: counters.success,
+                failure: counters.failure,
+            })
+            .collect();
+
+        let ip_slots = state
+            .ip_slots
+            .iter()
+            .map(|((ip, kind), slot)| {
+                let cooldown_remaining = slot
+                    .cooldown_until
+                    .and_then(|until| until.checked_duration_since(now))
+                    .filter(|remaining| !remaining.is_zero());
+                let slot_state = if cooldown_remaining.is_some() {
+                    IpSlotState::Cooling
+                } else if slot.inflight > 0 {
+                    IpSlotState::Busy
+                } else {
+                    IpSlotState::Idle
+                };
+                IpSlotRow {
+                    ip: ip.to_string(),
+                    kind: kind.clone(),
+                    state: slot_state,
+                    inflight: slot.inflight,
+                    cooldown_remaining_ms: cooldown_remaining
+                        .map(|remaining| remaining.as_millis() as u64),
+                    cooldown_reason: cooldown_remaining.and(slot.cooldown_reason.clone()),
+                    cooldowns: slot.cooldowns,
+                }
+            })
+            .collect();
+
+        let mut routes: Vec<RouteRow> = state
+            .routes
+            .iter()
+            .map(|(route, entry)| RouteRow {
+                route: route.clone(),
+                source: entry.source.clone(),
+                legs: entry.legs,
+                orders_total: entry.orders.total,
+                orders_per_minute: entry.orders.recent(now_sec),
+            })
+            .collect();
+        routes.sort_by(|a, b| {
+            b.orders_per_minute
+                .cmp(&a.orders_per_minute)
+                .then_with(|| b.orders_total.cmp(&a.orders_total))
+        });
+        let routes_active = routes.len();
+        routes.truncate(MAX_LISTED_ROUTES);
+
+        let wallets = state
+            .wallets
+            .iter()
+            .map(|(wallet, entry)| WalletRow {
+                wallet: wallet.clone(),
+                sol_lamports: entry.sol,
+                wsol_lamports: entry.wsol,
+                age_secs: now.duration_since(entry.updated).as_secs(),
+            })
+            .collect();
+
+        DashboardSnapshot {
+            uptime_secs: now_sec,
+            trading_halt: crate::control::runtime_control().trading_halt(),
+            strategies,
+            landers,
+            ip_slots,
+            routes_active,
+            routes,
+            tip: state.tip.clone(),
+            compute_unit_price: state.compute_unit_price.clone(),
+            wallets,
+        }
+    }
+}
+
+/// 仪表盘快照，本地渲染与 `/dashboard` 共用同一结构。
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DashboardSnapshot {
+    pub uptime_secs: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trading_halt: Option<String>,
+    pub strategies: Vec<StrategyRow>,
+    pub landers: Vec<LanderRow>,
+    pub ip_slots: Vec<IpSlotRow>,
+    pub routes_active: usize,
+    pub routes: Vec<RouteRow>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tip: Option<FeeSample>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub compute_unit_price: Option<FeeSample>,
+    pub wallets: Vec<WalletRow>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StrategyRow {
+    pub name: String,
+    pub opportunities_total: u64,
+    /// 最近 60 秒发现的机会数。
+    pub opportunities_per_minute: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LanderRow {
+    pub name: String,
+    pub attempts: u64,
+    pub success: u64,
+    pub failure: u64,
+}
+
+impl LanderRow {
+    /// 已完成提交中的成功占比。
+    pub fn success_rate(&self) -> Option<f64> {
+        let finished = self.success + self.failure;
+        (finished > 0).then(|| self.success as f64 / finished as f64)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IpSlotState {
+    Idle,
+    Busy,
+    Cooling,
+}
+
+impl IpSlotState {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Idle => "idle",
+            Self::Busy => "busy",
+            Self::Cooling => "cooling",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IpSlotRow {
+    pub ip: String,
+    pub kind: String,
+    pub state: IpSlotState,
+    pub inflight: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cooldown_remaining_ms: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cooldown_reason: Option<String>,
+    pub cooldowns: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RouteRow {
+    pub route: String,
+    pub source: String,
+    pub legs: usize,
+    pub orders_total: u64,
+    pub orders_per_minute: u64,
+}
+
+/// 最近一次提交使用的小费或 compute unit price，`strategy` 为对应的计算策略。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeeSample {
+    pub strategy: String,
+    pub value: u64,
+}
+
+impl FeeSample {
+    fn new(strategy: &str, value: u64) -> Self {
+        Self {
+            strategy: strategy.to_string(),
+            value,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WalletRow {
+    pub wallet: String,
+    pub sol_lamports: u64,
+    pub wsol_lamports: u64,
+    pub age_secs: u64,
+}
+
+/// `galileo run --tui` 时替代标准输出的日志目标，日志行写入面板的日志区。
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DashboardLogWriter;
+
+impl<'a> MakeWriter<'a> for DashboardLogWriter {
+    type Writer = DashboardLogLine;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        DashboardLogLine(Vec::with_capacity(256))
+    }
+}
+
+pub struct DashboardLogLine(Vec<u8>);
+
+impl io::Write for DashboardLogLine {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Drop for DashboardLogLine {
+    fn drop(&mut self) {
+        let text = String::from_utf8_lossy(&self.0);
+        for line in text.lines().filter(|line| !line.trim().is_empty()) {
+            FEED.push_log(line.trim_end().to_string());
+        }
+    }
+}
+
+/// 定期查询钱包 SOL / WSOL 余额并通过 `events::wallet_balance` 上报。
+pub fn spawn_balance_poller(
+    rpc: Arc<RpcClient>,
+    wallet: Pubkey,
+    interval: Duration,
+) -> JoinHandle<()> {
+    let wsol_account =
+        spl_associated_token_account::get_associated_token_address(&wallet, &wsol::WSOL_MINT);
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval.max(Duration::from_secs(1)));
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        loop {
+            ticker.tick().await;
+            let sol = match rpc.get_balance(&wallet).await {
+                Ok(value) => value,
+                Err(err) => {
+                    warn!(
+                        target: "monitoring::dashboard",
+                        wallet = %wallet,
+                        error = %err,
+                        "查询钱包 SOL 余额失败"
+                    );
+                    continue;
+                }
+            };
+            let wsol = match rpc
+                .get_account_with_commitment(&wsol_account, rpc.commitment())
+                .await
+            {
+                Ok(response) => response
+                    .value
+                    .filter(|account| account.owner == spl_token::ID)
+                    .and_then(|account| spl_token::state::Account::unpack(&account.data).ok())
+                    .map(|account| account.amount)
+                    .unwrap_or(0),
+                Err(err) => {
+                    warn!(
+                        target: "monitoring::dashboard",
+                        wallet = %wallet,
+                        error = %err,
+                        "查询钱包 WSOL 余额失败"
+                    );
+                    continue;
+                }
+            };
+            events::wallet_balance(&wallet, sol, wsol);
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rate_window_drops_buckets_older_than_a_minute() {
+        let mut window = RateWindow::default();
+        window.record(0, 3);
+        window.record(0, 2);
+        window.record(30, 4);
+        assert_eq!(window.recent(30), 9);
+
+        window.record(65, 1);
+        assert_eq!(window.total, 10);
+        assert_eq!(window.recent(65), 5);
+        assert_eq!(window.recent(200), 0);
+    }
+
+    #[test]
+    fn snapshot_reports_ip_states_routes_and_fees() {
+        let feed = DashboardFeed::new();
+        let busy: IpAddr = "10.0.0.1".parse().unwrap();
+        let cooling: IpAddr = "10.0.0.2".parse().unwrap();
+        feed.record_ip_inventory(busy, "shared");
+        feed.record_ip_inventory(cooling, "shared");
+        feed.record_ip_inflight(busy, "shared", 2);
+        feed.record_ip_cooldown(cooling, "rate_limited", Duration::from_secs(30));
+
+        feed.record_route_active("a->b", 2, "dynamic");
+        feed.record_route_orders("a->b", "dynamic", 4);
+        feed.record_route_active("c->d", 2, "dynamic");
+        feed.record_route_retired("c->d");
+
+        feed.record_lander_attempt("jito");
+        feed.record_lander_result("jito", true, Some(("fixed", 10_000)), None);
+        feed.record_lander_result("jito", false, None, Some(("percentile", 5_000)));
+
+        let snapshot = feed.snapshot();
+        let states: Vec<IpSlotState> = snapshot.ip_slots.iter().map(|slot| slot.state).collect();
+        assert_eq!(states, vec![IpSlotState::Busy, IpSlotState::Cooling]);
+        assert_eq!(
+            snapshot.ip_slots[1].cooldown_reason.as_deref(),
+            Some("rate_limited")
+        );
+
+        assert_eq!(snapshot.routes_active, 1);
+        assert_eq!(snapshot.routes[0].route, "a->b");
+        assert_eq!(snapshot.routes[0].orders_per_minute, 4);
+
+        assert_eq!(snapshot.landers[0].success_rate(), Some(0.5));
+        assert_eq!(snapshot.tip.as_ref().map(|fee| fee.value), Some(10_000));
+        assert_eq!(
+            snapshot.compute_unit_price.as_ref().map(|fee| fee.value),
+            Some(5_000)
+        );
+    }
+}
@@ -0,0 +1,441 @@
+use std::fs::{self, File, OpenOptions};
+use std::io::{ErrorKind, Write};
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use once_cell::sync::OnceCell;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use tracing::{debug, info, warn};
+
+use crate::api::serde_helpers::field_as_string;
+use crate::engine::RouteKey;
+use crate::engine::landing::ExecutionPlan;
+use crate::engine::landing::assembler::LandingPlanEntry;
+use crate::instructions::wsol::WSOL_MINT;
+use crate::lander::{LanderError, LanderReceipt};
+use crate::rpc::transaction_meta::{fetch_transaction_meta, token_balance};
+
+use super::{events, now_secs};
+
+pub const DEFAULT_LEDGER_PATH: &str = "monitoring/trade_ledger.jsonl";
+
+/// 提交成功后等待多久开始回查落地结果，以及最多回查几次。
+const SETTLE_DELAY: Duration = Duration::from_secs(5);
+const SETTLE_ATTEMPTS: usize = 6;
+
+static LEDGER: OnceCell<TradeLedger> = OnceCell::new();
+
+struct TradeLedger {
+    file: Mutex<File>,
+    rpc: Arc<RpcClient>,
+    owner: Pubkey,
+}
+
+/// 打开（或创建）账本文件；`owner` 为提交交易的钱包，用于解析落地后的余额变化。
+pub fn init_trade_ledger(path: &str, rpc: Arc<RpcClient>, owner: Pubkey) -> Result<()> {
+    LEDGER
+        .get_or_try_init(|| {
+            let path = Path::new(path);
+            if let Some(parent) = path.parent() {
+                if !parent.as_os_str().is_empty() {
+                    fs::create_dir_all(parent)
+                        .with_context(|| format!("创建账本目录失败: {}", parent.display()))?;
+                }
+            }
+            let file = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(path)
+                .with_context(|| format!("打开账本文件失败: {}", path.display()))?;
+            info!(
+                target: "monitoring::ledger",
+                path = %path.display(),
+                owner = %owner,
+                "交易账本已启用"
+            );
+            Ok(TradeLedger {
+                file: Mutex::new(file),
+                rpc,
+                owner,
+            })
+        })
+        .map(|_| ())
+}
+
+pub fn ledger_enabled() -> bool {
+    LEDGER.get().is_some()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LedgerOutcome {
+    Landed,
+    FailedOnChain,
+    Unconfirmed,
+    SubmitFailed,
+}
+
+impl LedgerOutcome {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            LedgerOutcome::Landed => "landed",
+            LedgerOutcome::FailedOnChain => "failed_on_chain",
+            LedgerOutcome::Unconfirmed => "unconfirmed",
+            LedgerOutcome::SubmitFailed => "submit_failed",
+        }
+    }
+}
+
+/// 账本中的一行，按签名标识一次提交。
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LedgerEntry {
+    pub signature: Option<String>,
+    /// 写入时的 unix 秒。
+    pub recorded_at: u64,
+    pub strategy: String,
+    pub input_mint: String,
+    pub output_mint: String,
+    /// 路线指纹，仅在能从指令推导时存在。
+    #[serde(default)]
+    pub route: Option<String>,
+    #[serde(with = "field_as_string")]
+    pub base_mint: Pubkey,
+    pub amount_in: u64,
+    pub expected_profit: u64,
+    pub tip_lamports: u64,
+    pub prioritization_fee_lamports: u64,
+    #[serde(default)]
+    pub lander: Option<String>,
+    #[serde(default)]
+    pub endpoint: Option<String>,
+    #[serde(default)]
+    pub slot: Option<u64>,
+    pub outcome: LedgerOutcome,
+    /// 钱包 `base_mint` 余额的实际变化（最小单位）；base 为 WSOL 时包含 SOL 余额变化。
+    #[serde(default)]
+    pub realized_delta: Option<i64>,
+    #[serde(default)]
+    pub fee_lamports: Option<u64>,
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
+/// 提交前收集的交易信息，提交结束后由 [`record_dispatch`] 补全结果。
+#[derive(Debug, Clone)]
+pub struct TradeDraft {
+    strategy: String,
+    input_mint: String,
+    output_mint: String,
+    route: Option<String>,
+    base_mint: Pubkey,
+    amount_in: u64,
+    expected_profit: u64,
+    tip_lamports: u64,
+    prioritization_fee_lamports: u64,
+    signature: Option<String>,
+}
+
+impl TradeDraft {
+    pub fn from_plan(
+        strategy: &str,
+        plan: &ExecutionPlan,
+        entry: Option<&LandingPlanEntry>,
+    ) -> Self {
+        let opportunity = &plan.opportunity;
+        Self {
+            strategy: strategy.to_string(),
+            input_mint: opportunity.pair.input_mint.clone(),
+            output_mint: opportunity.pair.output_mint.clone(),
+            route: None,
+            base_mint: plan.base_mint,
+            amount_in: opportunity.amount_in,
+            expected_profit: opportunity.profit_lamports,
+            tip_lamports: entry
+                .map(|entry| entry.tip.lamports)
+                .unwrap_or(opportunity.tip_lamports),
+            prioritization_fee_lamports: entry
+                .map(|entry| entry.prioritization_fee_lamports)
+                .unwrap_or(plan.prioritization_fee_lamports),
+            signature: None,
+        }
+    }
+
+    pub fn with_route(mut self, route: Option<RouteKey>) -> Self {
+        self.route = route.map(|key| key.to_string());
+        self
+    }
+
+    pub fn with_signature(mut self, signature: Option<String>) -> Self {
+        self.signature = signature;
+        self
+    }
+
+    fn into_entry(self, outcome: LedgerOutcome) -> LedgerEntry {
+        LedgerEntry {
+            signature: self.signature,
+            recorded_at: now_secs(),
+            strategy: self.strategy,
+            input_mint: self.input_mint,
+            output_mint: self.output_mint,
+            route: self.route,
+            base_mint: self.base_mint,
+            amount_in: self.amount_in,
+            expected_profit: self.expected_profit,
+            tip_lamports: self.tip_lamports,
+            prioritization_fee_lamports: self.prioritization_fee_lamports,
+            lander: None,
+            endpoint: None,
+            slot: None,
+            outcome,
+            realized_delta: None,
+            fee_lamports: None,
+            error: None,
+        }
+    }
+}
+
+/// 记录一次提交结果；账本未启用时直接忽略。提交成功的交易在后台回查链上结果后写入。
+pub fn record_dispatch(draft: TradeDraft, outcome: &Result<LanderReceipt, LanderError>) {
+    let Some(ledger) = LEDGER.get() else {
+        return;
+    };
+    match outcome {
+        Ok(receipt) => {
+            let signature = receipt.signature.clone().or(draft.signature.clone());
+            let mut entry = draft
+                .with_signature(signature)
+                .into_entry(LedgerOutcome::Unconfirmed);
+            entry.lander = Some(receipt.lander.to_string());
+            entry.endpoint = Some(receipt.endpoint.clone());
+            entry.slot = Some(receipt.slot);
+            let parsed = entry
+                .signature
+                .as_deref()
+                .and_then(|value| Signature::from_str(value).ok());
+            match parsed {
+                Some(signature) => {
+                    tokio::spawn(settle_entry(ledger, signature, entry));
+                }
+                None => ledger.append(&entry),
+            }
+        }
+        Err(err) => {
+            let mut entry = draft.into_entry(LedgerOutcome::SubmitFailed);
+            entry.error = Some(err.to_string());
+            ledger.append(&entry);
+        }
+    }
+}
+
+async fn settle_entry(ledger: &'static TradeLedger, signature: Signature, mut entry: LedgerEntry) {
+    for attempt in 1..=SETTLE_ATTEMPTS {
+        tokio::time::sleep(SETTLE_DELAY).await;
+        match fetch_transaction_meta(&ledger.rpc, &signature).await {
+            Ok(Some(meta)) => {
+                let settled = settle_from_meta(&meta, &ledger.owner, &entry.base_mint);
+                entry.outcome = settled.outcome;
+                entry.realized_delta = settled.realized_delta;
+                entry.fee_lamports = settled.fee_lamports;
+                break;
+            }
+            Ok(None) => {}
+            Err(err) => {
+                debug!(
+                    target: "monitoring::ledger",
+                    signature = %signature,
+                    attempt,
+                    error = %err,
+                    "查询落地结果失败"
+                );
+            }
+        }
+    }
+    ledger.append(&entry);
+}
+
+struct SettledTrade {
+    outcome: LedgerOutcome,
+    realized_delta: Option<i64>,
+    fee_lamports: Option<u64>,
+}
+
+/// 从 `getTransaction` 的 meta 解析落地结果与 `owner` 的 `base_mint` 余额变化。
+/// 交易费与 tip 由 fee payer（账户 0，即 `owner`）支付，base 为 WSOL 时一并计入。
+fn settle_from_meta(meta: &Value, owner: &Pubkey, base_mint: &Pubkey) -> SettledTrade {
+    let failed = meta.get("err").is_some_and(|err| !err.is_null());
+    let token_delta = i128::from(token_balance(
+        meta.get("postTokenBalances"),
+        owner,
+        base_mint,
+    )) - i128::from(token_balance(
+        meta.get("preTokenBalances"),
+        owner,
+        base_mint,
+    ));
+    let lamport_delta = if *base_mint == WSOL_MINT {
+        let first_balance = |key: &str| {
+            meta.get(key)
+                .and_then(|balances| balances.get(0))
+                .and_then(Value::as_u64)
+                .unwrap_or_default()
+        };
+        i128::from(first_balance("postBalances")) - i128::from(first_balance("preBalances"))
+    } else {
+        0
+    };
+    SettledTrade {
+        outcome: if failed {
+            LedgerOutcome::FailedOnChain
+        } else {
+            LedgerOutcome::Landed
+        },
+        realized_delta: i64::try_from(token_delta + lamport_delta).ok(),
+        fee_lamports: meta.get("fee").and_then(Value::as_u64),
+    }
+}
+
+impl TradeLedger {
+    fn append(&self, entry: &LedgerEntry) {
+        events::trade_settled(
+            &entry.strategy,
+            entry.lander.as_deref(),
+            entry.endpoint.as_deref(),
+            entry.outcome.as_str(),
+        );
+        let mut line = match serde_json::to_string(entry) {
+            Ok(line) => line,
+            Err(err) => {
+                warn!(target: "monitoring::ledger", error = %err, "账本记录序列化失败");
+                return;
+            }
+        };
+        line.push('\n');
+        if let Err(err) = self.file.lock().write_all(line.as_bytes()) {
+            warn!(
+                target: "monitoring::ledger",
+                signature = entry.signature.as_deref().unwrap_or("-"),
+                error = %err,
+                "写入账本失败"
+            );
+        }
+    }
+}
+
+/// 读取账本全部记录，跳过无法解析的行。
+pub fn read_ledger(path: &Path) -> Result<Vec<LedgerEntry>> {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(err) if err.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => {
+            return Err(err).with_context(|| format!("读取账本文件失败: {}", path.display()));
+        }
+    };
+    let mut entries = Vec::new();
+    for (index, line) in content.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        match serde_json::from_str::<LedgerEntry>(line) {
+            Ok(entry) => entries.push(entry),
+            Err(err) => {
+                warn!(
+                    target: "monitoring::ledger",
+                    line = index + 1,
+                    error = %err,
+                    "跳过无法解析的账本记录"
+                );
+            }
+        }
+    }
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn sample_entry(signature: &str) -> LedgerEntry {
+        LedgerEntry {
+            signature: Some(signature.to_string()),
+            recorded_at: 1_700_000_000,
+            strategy: "blind".to_string(),
+            input_mint: WSOL_MINT.to_string(),
+            output_mint: Pubkey::new_unique().to_string(),
+            route: Some("00000000000000ff".to_string()),
+            base_mint: WSOL_MINT,
+            amount_in: 1_000_000_000,
+            expected_profit: 20_000,
+            tip_lamports: 5_000,
+            prioritization_fee_lamports: 1_000,
+            lander: Some("jito".to_string()),
+            endpoint: Some("https://example.invalid".to_string()),
+            slot: Some(42),
+            outcome: LedgerOutcome::Landed,
+            realized_delta: Some(12_000),
+            fee_lamports: Some(5_000),
+            error: None,
+        }
+    }
+
+    #[test]
+    fn ledger_lines_roundtrip_and_skip_garbage() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join("ledger.jsonl");
+        let first = sample_entry("sig-a");
+        let second = sample_entry("sig-b");
+        let content = format!(
+            "{}\nnot json\n\n{}\n",
+            serde_json::to_string(&first).expect("encode"),
+            serde_json::to_string(&second).expect("encode"),
+        );
+        fs::write(&path, content).expect("write");
+
+        let entries = read_ledger(&path).expect("read");
+        assert_eq!(entries, vec![first, second]);
+        assert!(
+            read_ledger(&dir.path().join("missing.jsonl"))
+                .expect("missing")
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn settle_counts_token_and_sol_delta_for_wsol_base() {
+        let owner = Pubkey::new_unique();
+        let meta = json!({
+            "err": null,
+            "fee": 5000,
+            "preBalances": [1_000_000, 0],
+            "postBalances": [990_000, 0],
+            "preTokenBalances": [{
+                "owner": owner.to_string(),
+                "mint": WSOL_MINT.to_string(),
+                "uiTokenAmount": {"amount": "500000"}
+            }],
+            "postTokenBalances": [{
+                "owner": owner.to_string(),
+                "mint": WSOL_MINT.to_string(),
+                "uiTokenAmount": {"amount": "530000"}
+            }]
+        });
+        let settled = settle_from_meta(&meta, &owner, &WSOL_MINT);
+        assert_eq!(settled.outcome, LedgerOutcome::Landed);
+        assert_eq!(settled.realized_delta, Some(20_000));
+        assert_eq!(settled.fee_lamports, Some(5_000));
+
+        let other = Pubkey::new_unique();
+        let failed = json!({"err": {"InstructionError": [0, "Custom"]}, "fee": 5000});
+        let settled = settle_from_meta(&failed, &owner, &other);
+        assert_eq!(settled.outcome, LedgerOutcome::FailedOnChain);
+        assert_eq!(settled.realized_delta, Some(0));
+    }
+}
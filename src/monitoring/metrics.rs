@@ -2,12 +2,31 @@ use std::net::SocketAddr;
 use std::sync::atomic::{AtomicBool, Ordering};
 
 use anyhow::{Context, Result};
-use metrics_exporter_prometheus::PrometheusBuilder;
+use metrics_exporter_prometheus::{Matcher, PrometheusBuilder};
 use once_cell::sync::OnceCell;
 
 static EXPORTER: OnceCell<()> = OnceCell::new();
 static PROMETHEUS_ENABLED: AtomicBool = AtomicBool::new(false);
 
+/// 以 `_ms` / `_lamports` 结尾的直方图导出为 bucket，其余保持 summary。
+const LATENCY_MS_BUCKETS: &[f64] = &[
+    1.0, 2.5, 5.0, 10.0, 25.0, 50.0, 100.0, 250.0, 500.0, 1_000.0, 2_500.0, 5_000.0, 10_000.0,
+];
+const LAMPORTS_BUCKETS: &[f64] = &[
+    1_000.0,
+    5_000.0,
+    10_000.0,
+    50_000.0,
+    100_000.0,
+    500_000.0,
+    1_000_000.0,
+    5_000_000.0,
+    10_000_000.0,
+    50_000_000.0,
+    100_000_000.0,
+    1_000_000_000.0,
+];
+
 pub fn try_init_prometheus(listen: &str) -> Result<()> {
     EXPORTER
         .get_or_try_init(|| {
@@ -16,6 +35,10 @@ pub fn try_init_prometheus(listen: &str) -> Result<()> {
                 .with_context(|| format!("invalid prometheus listen address: {listen}"))?;
             PrometheusBuilder::new()
                 .with_http_listener(addr)
+                .set_buckets_for_metric(Matcher::Suffix("_ms".to_string()), LATENCY_MS_BUCKETS)
+                .context("invalid latency buckets")?
+                .set_buckets_for_metric(Matcher::Suffix("_lamports".to_string()), LAMPORTS_BUCKETS)
+                .context("invalid lamports buckets")?
                 .install()
                 .context("failed to install prometheus exporter")?;
             PROMETHEUS_ENABLED.store(true, Ordering::Relaxed);
@@ -1,6 +1,10 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub mod dashboard;
 pub mod events;
 pub mod format;
 pub mod latency;
+pub mod ledger;
 pub mod metrics;
 pub mod types;
 
@@ -8,3 +12,11 @@ pub use format::short_mint_str;
 pub use latency::*;
 pub use metrics::try_init_prometheus;
 pub use types::*;
+
+/// 当前 unix 秒；账本、归集与持仓记录共用。
+pub(crate) fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or_default()
+}
@@ -16,6 +16,7 @@ use crate::engine::multi_leg::types::{
 };
 use crate::monitoring::events;
 
+use super::slot::IpSlotStatsSnapshot;
 use super::{IpInventory, IpSlot, IpSlotKind, IpSlotState, IpSource, NetworkError, NetworkResult};
 
 const DEFAULT_UNBOUNDED_PERMITS: usize = usize::MAX >> 8;
@@ -132,6 +133,25 @@ impl IpAllocator {
             .collect::<Vec<_>>()
     }
 
+    /// 各 IP 槽位的当前状态，供控制接口展示。
+    pub fn slot_statuses(&self) -> Vec<IpSlotStatus> {
+        let now = Instant::now();
+        self.inner
+            .slots
+            .iter()
+            .map(|slot| IpSlotStatus {
+                ip: slot.ip(),
+                kind: slot.kind_label(),
+                state: slot.slot.state(),
+                inflight: slot.slot.inflight(),
+                cooldown_remaining: slot
+                    .cooldown_delay(now)
+                    .map(|deadline| deadline.saturating_duration_since(now)),
+                stats: slot.slot.stats().snapshot(),
+            })
+            .collect()
+    }
+
     pub async fn acquire_specific(
         &self,
         kind: IpTaskKind,
@@ -309,6 +329,16 @@ pub struct IpAllocatorSummary {
     pub source: IpSource,
 }
 
+#[derive(Debug, Clone)]
+pub struct IpSlotStatus {
+    pub ip: std::net::IpAddr,
+    pub kind: &'static str,
+    pub state: IpSlotState,
+    pub inflight: usize,
+    pub cooldown_remaining: Option<Duration>,
+    pub stats: IpSlotStatsSnapshot,
+}
+
 #[derive(Debug)]
 struct IpAllocatorInner {
     slots: Vec<Arc<SlotState>>,
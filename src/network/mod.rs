@@ -10,7 +10,8 @@ use std::sync::Arc;
 
 #[allow(unused_imports)]
 pub use allocator::{
-    CooldownConfig, IpAllocator, IpLease, IpLeaseHandle, IpLeaseMode, IpLeaseOutcome, IpTaskKind,
+    CooldownConfig, IpAllocator, IpLease, IpLeaseHandle, IpLeaseMode, IpLeaseOutcome, IpSlotStatus,
+    IpTaskKind,
 };
 pub use client::HttpClientFactory;
 pub use error::{NetworkError, NetworkResult};
@@ -27,6 +27,15 @@ impl IpSlotState {
             _ => Self::Idle,
         }
     }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Idle => "idle",
+            Self::Busy => "busy",
+            Self::CoolingDown => "cooling_down",
+            Self::LongLived => "long_lived",
+        }
+    }
 }
 
 #[derive(Debug, Default)]
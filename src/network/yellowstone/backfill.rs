@@ -0,0 +1,276 @@
+use std::str::FromStr;
+
+use anyhow::{Context, Result, anyhow};
+use serde::Deserialize;
+use serde_json::{Value, json};
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_client::rpc_client::GetConfirmedSignaturesForAddress2Config;
+use solana_client::rpc_request::RpcRequest;
+use solana_commitment_config::CommitmentConfig;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use tracing::warn;
+use yellowstone_grpc_proto::convert_to::create_transaction;
+use yellowstone_grpc_proto::geyser::SubscribeUpdateTransactionInfo;
+use yellowstone_grpc_proto::solana::storage::confirmed_block::{
+    TokenBalance, TransactionError, TransactionStatusMeta, UiTokenAmount,
+};
+
+use crate::engine::multi_leg::transaction::decoder::decode_base64_transaction;
+
+/// 通过 `getSignaturesForAddress` 回补 `wallet` 在 `from_slot` 之后的成功交易，按时间正序返回。
+pub(super) async fn fetch_wallet_transactions(
+    rpc: &RpcClient,
+    wallet: &Pubkey,
+    from_slot: u64,
+    limit: usize,
+) -> Result<Vec<(u64, SubscribeUpdateTransactionInfo)>> {
+    let config = GetConfirmedSignaturesForAddress2Config {
+        before: None,
+        until: None,
+        limit: Some(limit.max(1)),
+        commitment: Some(CommitmentConfig::confirmed()),
+    };
+    let statuses = rpc
+        .get_signatures_for_address_with_config(wallet, config)
+        .await
+        .context("getSignaturesForAddress 失败")?;
+
+    let mut transactions = Vec::new();
+    for status in statuses
+        .into_iter()
+        .filter(|status| status.slot >= from_slot && status.err.is_none())
+        .rev()
+    {
+        let signature = match Signature::from_str(&status.signature) {
+            Ok(signature) => signature,
+            Err(err) => {
+                warn!(
+                    target: "network::yellowstone",
+                    wallet = %wallet,
+                    signature = %status.signature,
+                    error = %err,
+                    "回补签名解析失败"
+                );
+                continue;
+            }
+        };
+        match fetch_transaction(rpc, &signature).await {
+            Ok(Some(info)) => transactions.push((status.slot, info)),
+            Ok(None) => {}
+            Err(err) => {
+                warn!(
+                    target: "network::yellowstone",
+                    wallet = %wallet,
+                    signature = %signature,
+                    error = %err,
+                    "回补交易查询失败"
+                );
+            }
+        }
+    }
+    Ok(transactions)
+}
+
+async fn fetch_transaction(
+    rpc: &RpcClient,
+    signature: &Signature,
+) -> Result<Option<SubscribeUpdateTransactionInfo>> {
+    let params = json!([
+        signature.to_string(),
+        {
+            "encoding": "base64",
+            "commitment": "confirmed",
+            "maxSupportedTransactionVersion": 0,
+        }
+    ]);
+    let value: Value = rpc.send(RpcRequest::GetTransaction, params).await?;
+    if value.is_null() {
+        return Ok(None);
+    }
+    transaction_info_from_rpc(signature, value).map(Some)
+}
+
+#[derive(Deserialize)]
+struct RpcTransaction {
+    transaction: (String, String),
+    meta: Option<RpcTransactionMeta>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RpcTransactionMeta {
+    #[serde(default)]
+    err: Option<Value>,
+    #[serde(default)]
+    pre_token_balances: Vec<RpcTokenBalance>,
+    #[serde(default)]
+    post_token_balances: Vec<RpcTokenBalance>,
+    #[serde(default)]
+    loaded_addresses: Option<RpcLoadedAddresses>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RpcTokenBalance {
+    account_index: u32,
+    mint: String,
+    #[serde(default)]
+    owner: Option<String>,
+    #[serde(default)]
+    program_id: Option<String>,
+    ui_token_amount: RpcUiTokenAmount,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RpcUiTokenAmount {
+    amount: String,
+    decimals: u32,
+    #[serde(default)]
+    ui_amount: Option<f64>,
+    #[serde(default)]
+    ui_amount_string: String,
+}
+
+#[derive(Deserialize, Default)]
+struct RpcLoadedAddresses {
+    #[serde(default)]
+    writable: Vec<String>,
+    #[serde(default)]
+    readonly: Vec<String>,
+}
+
+/// 将 `getTransaction`（base64）结果转换为与 Yellowstone 推送一致的结构，复用下游解析逻辑。
+fn transaction_info_from_rpc(
+    signature: &Signature,
+    value: Value,
+) -> Result<SubscribeUpdateTransactionInfo> {
+    let rpc_tx: RpcTransaction =
+        serde_json::from_value(value).context("解析 getTransaction 响应失败")?;
+    let (encoded, encoding) = rpc_tx.transaction;
+    if encoding != "base64" {
+        return Err(anyhow!("getTransaction 返回了非 base64 编码: {encoding}"));
+    }
+    let transaction =
+        decode_base64_transaction(&encoded).map_err(|err| anyhow!("回补交易解码失败: {err}"))?;
+    let meta = rpc_tx
+        .meta
+        .ok_or_else(|| anyhow!("getTransaction 缺少 meta"))?;
+    let loaded = meta.loaded_addresses.unwrap_or_default();
+
+    Ok(SubscribeUpdateTransactionInfo {
+        signature: signature.as_ref().to_vec(),
+        is_vote: false,
+        transaction: Some(create_transaction(&transaction)),
+        meta: Some(TransactionStatusMeta {
+            err: meta
+                .err
+                .filter(|err| !err.is_null())
+                .map(|_| TransactionError { err: Vec::new() }),
+            pre_token_balances: meta
+                .pre_token_balances
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            post_token_balances: meta
+                .post_token_balances
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            loaded_writable_addresses: decode_addresses(&loaded.writable)?,
+            loaded_readonly_addresses: decode_addresses(&loaded.readonly)?,
+            ..Default::default()
+        }),
+        index: 0,
+    })
+}
+
+impl From<RpcTokenBalance> for TokenBalance {
+    fn from(balance: RpcTokenBalance) -> Self {
+        Self {
+            account_index: balance.account_index,
+            mint: balance.mint,
+            ui_token_amount: Some(UiTokenAmount {
+                ui_amount: balance.ui_token_amount.ui_amount.unwrap_or_default(),
+                decimals: balance.ui_token_amount.decimals,
+                amount: balance.ui_token_amount.amount,
+                ui_amount_string: balance.ui_token_amount.ui_amount_string,
+            }),
+            owner: balance.owner.unwrap_or_default(),
+            program_id: balance.program_id.unwrap_or_default(),
+        }
+    }
+}
+
+fn decode_addresses(values: &[String]) -> Result<Vec<Vec<u8>>> {
+    values
+        .iter()
+        .map(|value| {
+            Pubkey::from_str(value)
+                .map(|pubkey| pubkey.to_bytes().to_vec())
+                .map_err(|err| anyhow!("loadedAddresses `{value}` 解析失败: {err}"))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use base64::Engine as _;
+    use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
+    use bincode::serde::encode_to_vec;
+    use solana_sdk::instruction::Instruction;
+    use solana_sdk::message::{Message, VersionedMessage};
+    use solana_sdk::transaction::VersionedTransaction;
+
+    #[test]
+    fn rpc_transaction_converts_to_update_info() {
+        let payer = Pubkey::new_unique();
+        let instruction = Instruction::new_with_bytes(Pubkey::new_unique(), &[7], vec![]);
+        let tx = VersionedTransaction {
+            signatures: vec![Signature::default()],
+            message: VersionedMessage::Legacy(Message::new(&[instruction], Some(&payer))),
+        };
+        let config = bincode::config::standard()
+            .with_fixed_int_encoding()
+            .with_little_endian();
+        let encoded = BASE64_STANDARD.encode(encode_to_vec(&tx, config).expect("encode"));
+        let mint = Pubkey::new_unique();
+        let loaded = Pubkey::new_unique();
+        let value = json!({
+            "slot": 42,
+            "transaction": [encoded, "base64"],
+            "meta": {
+                "err": null,
+                "preTokenBalances": [{
+                    "accountIndex": 1,
+                    "mint": mint.to_string(),
+                    "owner": payer.to_string(),
+                    "programId": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+                    "uiTokenAmount": {"amount": "100", "decimals": 6, "uiAmount": 0.0001, "uiAmountString": "0.0001"}
+                }],
+                "postTokenBalances": [],
+                "loadedAddresses": {"writable": [loaded.to_string()], "readonly": []}
+            }
+        });
+
+        let info = transaction_info_from_rpc(&Signature::default(), value).expect("convert");
+        let meta = info.meta.expect("meta");
+        assert!(meta.err.is_none());
+        assert_eq!(meta.pre_token_balances.len(), 1);
+        assert_eq!(meta.pre_token_balances[0].mint, mint.to_string());
+        assert_eq!(
+            meta.pre_token_balances[0]
+                .ui_token_amount
+                .as_ref()
+                .map(|amount| amount.amount.as_str()),
+            Some("100")
+        );
+        assert_eq!(
+            meta.loaded_writable_addresses,
+            vec![loaded.to_bytes().to_vec()]
+        );
+        assert_eq!(info.transaction.expect("transaction").signatures.len(), 1);
+    }
+}
@@ -1,3 +1,8 @@
+mod backfill;
+mod multiplex;
+
+pub use multiplex::{WalletStreamSettings, WalletTransaction, spawn_wallet_stream};
+
 use anyhow::{Result, anyhow};
 use futures::{Stream, TryStreamExt};
 use solana_sdk::pubkey::Pubkey;
@@ -5,8 +10,8 @@ use tokio::sync::mpsc;
 use tokio_stream::wrappers::ReceiverStream;
 use yellowstone_grpc_proto::geyser::geyser_client::GeyserClient;
 use yellowstone_grpc_proto::geyser::{
-    CommitmentLevel, SubscribeRequest, SubscribeRequestFilterTransactions, SubscribeUpdate,
-    SubscribeUpdateTransactionInfo, subscribe_update,
+    CommitmentLevel, SubscribeRequest, SubscribeRequestFilterSlots,
+    SubscribeRequestFilterTransactions, SubscribeUpdate,
 };
 use yellowstone_grpc_proto::tonic::metadata::AsciiMetadataValue;
 use yellowstone_grpc_proto::tonic::service::{Interceptor, interceptor::InterceptedService};
@@ -32,15 +37,28 @@ impl YellowstoneTransactionClient {
         })
     }
 
-    pub async fn subscribe_transactions(
+    /// 单条订阅携带多个钱包过滤器，过滤器以钱包地址命名，便于按 `SubscribeUpdate::filters` 分发。
+    /// `from_slot` 用于断线后从上次处理的 slot 续订；`with_slots` 额外订阅 slot 更新以检测跳变。
+    pub async fn subscribe_wallets(
         &mut self,
-        wallet: Pubkey,
+        wallets: &[Pubkey],
+        from_slot: Option<u64>,
+        with_slots: bool,
     ) -> Result<impl Stream<Item = Result<SubscribeUpdate, Status>>> {
         let mut request = SubscribeRequest::default();
-        let mut tx_filter = SubscribeRequestFilterTransactions::default();
-        tx_filter.account_required.push(wallet.to_string());
+        for wallet in wallets {
+            let mut tx_filter = SubscribeRequestFilterTransactions::default();
+            tx_filter.account_required.push(wallet.to_string());
+            request.transactions.insert(wallet.to_string(), tx_filter);
+        }
+        if with_slots {
+            request.slots.insert(
+                SLOT_FILTER.to_string(),
+                SubscribeRequestFilterSlots::default(),
+            );
+        }
         request.commitment = Some(CommitmentLevel::Processed as i32);
-        request.transactions.insert(wallet.to_string(), tx_filter);
+        request.from_slot = from_slot;
 
         let (sender, receiver) = mpsc::channel(4);
         sender
@@ -59,6 +77,8 @@ impl YellowstoneTransactionClient {
     }
 }
 
+const SLOT_FILTER: &str = "slots";
+
 #[derive(Clone)]
 struct TokenInterceptor {
     token: Option<AsciiMetadataValue>,
@@ -72,12 +92,3 @@ impl Interceptor for TokenInterceptor {
         Ok(request)
     }
 }
-
-pub fn parse_transaction_update(
-    update: &SubscribeUpdate,
-) -> Option<SubscribeUpdateTransactionInfo> {
-    match &update.update_oneof {
-        Some(subscribe_update::UpdateOneof::Transaction(tx)) => tx.transaction.clone(),
-        _ => None,
-    }
-}
@@ -0,0 +1,361 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::{Context, Result};
+use futures::StreamExt;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tracing::{info, warn};
+use yellowstone_grpc_proto::geyser::{
+    SubscribeUpdate, SubscribeUpdateTransactionInfo, subscribe_update,
+};
+use yellowstone_grpc_proto::tonic::metadata::AsciiMetadataValue;
+
+use crate::control::runtime_control;
+use crate::monitoring::events;
+
+use super::YellowstoneTransactionClient;
+use super::backfill::fetch_wallet_transactions;
+
+const DEFAULT_RECONNECT_INITIAL: Duration = Duration::from_millis(500);
+const DEFAULT_RECONNECT_MAX: Duration = Duration::from_secs(30);
+const DEFAULT_BACKFILL_LIMIT: usize = 100;
+/// 连续 slot 更新之间跳过超过该数量时视为缺口并触发回补。
+const SLOT_GAP_BACKFILL_THRESHOLD: u64 = 64;
+const RECENT_SIGNATURE_CAPACITY: usize = 8_192;
+
+/// 多钱包复用订阅的连接、重连与回补参数。
+#[derive(Clone)]
+pub struct WalletStreamSettings {
+    pub endpoint: String,
+    pub token: Option<AsciiMetadataValue>,
+    /// 指标与日志中区分不同订阅的标签。
+    pub label: String,
+    pub reconnect_initial: Duration,
+    pub reconnect_max: Duration,
+    /// 断线重连或 slot 跳变后通过 RPC 回补缺失交易；为空时不回补。
+    pub backfill_rpc: Option<Arc<RpcClient>>,
+    pub backfill_limit: usize,
+}
+
+impl WalletStreamSettings {
+    pub fn new(
+        endpoint: String,
+        token: Option<AsciiMetadataValue>,
+        label: impl Into<String>,
+    ) -> Self {
+        Self {
+            endpoint,
+            token,
+            label: label.into(),
+            reconnect_initial: DEFAULT_RECONNECT_INITIAL,
+            reconnect_max: DEFAULT_RECONNECT_MAX,
+            backfill_rpc: None,
+            backfill_limit: DEFAULT_BACKFILL_LIMIT,
+        }
+    }
+
+    pub fn with_reconnect(mut self, initial: Duration, max: Duration) -> Self {
+        self.reconnect_initial = initial.max(Duration::from_millis(1));
+        self.reconnect_max = max.max(self.reconnect_initial);
+        self
+    }
+
+    pub fn with_backfill(mut self, rpc: Arc<RpcClient>, limit: usize) -> Self {
+        self.backfill_rpc = Some(rpc);
+        self.backfill_limit = limit.max(1);
+        self
+    }
+}
+
+/// 分发给单个钱包订阅方的交易。
+pub struct WalletTransaction {
+    pub wallet: Pubkey,
+    pub slot: u64,
+    pub info: SubscribeUpdateTransactionInfo,
+    /// 是否来自 RPC 回补而非实时推送。
+    pub backfilled: bool,
+}
+
+/// 以单条 Yellowstone 订阅服务 `sinks` 中的全部钱包，断线后指数退避重连并从上次 slot 续订。
+/// 所有订阅方关闭后任务退出。
+pub fn spawn_wallet_stream(
+    settings: WalletStreamSettings,
+    sinks: HashMap<Pubkey, mpsc::Sender<WalletTransaction>>,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut state = StreamState::new(sinks);
+        let mut backoff = settings.reconnect_initial;
+        loop {
+            state.received = false;
+            let result = stream_once(&settings, &mut state).await;
+            if state.sinks.is_empty() {
+                info!(
+                    target: "network::yellowstone",
+                    stream = %settings.label,
+                    "所有订阅方已关闭，Yellowstone 订阅退出"
+                );
+                return;
+            }
+            if state.received {
+                backoff = settings.reconnect_initial;
+            }
+            let error = match result {
+                Ok(()) => "stream closed".to_string(),
+                Err(err) => format!("{err:#}"),
+            };
+            runtime_control().stream_disconnected(&settings.label, &settings.endpoint, &error);
+            events::yellowstone_stream_reconnect(&settings.label, backoff, &error);
+            tokio::time::sleep(backoff).await;
+            backoff = next_backoff(backoff, settings.reconnect_max);
+        }
+    })
+}
+
+fn next_backoff(current: Duration, max: Duration) -> Duration {
+    current.saturating_mul(2).min(max)
+}
+
+struct StreamState {
+    sinks: HashMap<Pubkey, mpsc::Sender<WalletTransaction>>,
+    last_slot: Option<u64>,
+    received: bool,
+    recent: RecentSignatures,
+}
+
+impl StreamState {
+    fn new(sinks: HashMap<Pubkey, mpsc::Sender<WalletTransaction>>) -> Self {
+        Self {
+            sinks,
+            last_slot: None,
+            received: false,
+            recent: RecentSignatures::new(RECENT_SIGNATURE_CAPACITY),
+        }
+    }
+
+    fn observe_slot(&mut self, slot: u64) {
+        self.last_slot = Some(self.last_slot.map_or(slot, |last| last.max(slot)));
+    }
+
+    async fn dispatch(
+        &mut self,
+        wallet: Pubkey,
+        slot: u64,
+        info: SubscribeUpdateTransactionInfo,
+        backfilled: bool,
+    ) {
+        let Ok(signature) = Signature::try_from(info.signature.as_slice()) else {
+            return;
+        };
+        if !self.recent.insert(wallet, signature) {
+            return;
+        }
+        let Some(sender) = self.sinks.get(&wallet) else {
+            return;
+        };
+        let transaction = WalletTransaction {
+            wallet,
+            slot,
+            info,
+            backfilled,
+        };
+        if sender.send(transaction).await.is_err() {
+            self.sinks.remove(&wallet);
+        }
+    }
+}
+
+async fn stream_once(settings: &WalletStreamSettings, state: &mut StreamState) -> Result<()> {
+    let mut client =
+        YellowstoneTransactionClient::connect(settings.endpoint.clone(), settings.token.clone())
+            .await?;
+    let wallets: Vec<Pubkey> = state.sinks.keys().copied().collect();
+    let resume_from = state.last_slot;
+
+    let mut stream = match client.subscribe_wallets(&wallets, resume_from, true).await {
+        Ok(stream) => stream,
+        Err(err) if resume_from.is_some() => {
+            warn!(
+                target: "network::yellowstone",
+                stream = %settings.label,
+                error = %err,
+                "from_slot 续订失败，改为实时订阅并依赖 RPC 回补"
+            );
+            client
+                .subscribe_wallets(&wallets, None, true)
+                .await
+                .context("订阅 Yellowstone gRPC 失败")?
+        }
+        Err(err) => return Err(err).context("订阅 Yellowstone gRPC 失败"),
+    };
+
+    info!(
+        target: "network::yellowstone",
+        stream = %settings.label,
+        wallets = wallets.len(),
+        from_slot = ?resume_from,
+        "Yellowstone 多钱包订阅已建立"
+    );
+    runtime_control().stream_connected(&settings.label, &settings.endpoint);
+
+    if let Some(from_slot) = resume_from {
+        backfill(settings, state, from_slot).await;
+    }
+
+    while let Some(update) = stream.next().await.transpose()? {
+        state.received = true;
+        runtime_control().stream_update(&settings.label);
+        let SubscribeUpdate {
+            filters,
+            update_oneof,
+            created_at,
+        } = update;
+        match update_oneof {
+            Some(subscribe_update::UpdateOneof::Slot(slot)) => {
+                if let Some(lag) = created_at
+                    .as_ref()
+                    .and_then(|ts| lag_since(ts.seconds, ts.nanos))
+                {
+                    events::yellowstone_stream_lag(&settings.label, lag, slot.slot);
+                }
+                let previous = state.last_slot;
+                state.observe_slot(slot.slot);
+                if let Some(previous) = previous {
+                    if slot.slot > previous.saturating_add(SLOT_GAP_BACKFILL_THRESHOLD) {
+                        warn!(
+                            target: "network::yellowstone",
+                            stream = %settings.label,
+                            previous,
+                            current = slot.slot,
+                            "检测到 slot 跳变，开始 RPC 回补"
+                        );
+                        backfill(settings, state, previous).await;
+                    }
+                }
+            }
+            Some(subscribe_update::UpdateOneof::Transaction(tx)) => {
+                state.observe_slot(tx.slot);
+                let Some(info) = tx.transaction else {
+                    continue;
+                };
+                let targets: Vec<Pubkey> = filters
+                    .iter()
+                    .filter_map(|name| Pubkey::from_str(name).ok())
+                    .collect();
+                for wallet in targets {
+                    state.dispatch(wallet, tx.slot, info.clone(), false).await;
+                }
+                if state.sinks.is_empty() {
+                    return Ok(());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+async fn backfill(settings: &WalletStreamSettings, state: &mut StreamState, from_slot: u64) {
+    let Some(rpc) = settings.backfill_rpc.as_ref() else {
+        return;
+    };
+    let wallets: Vec<Pubkey> = state.sinks.keys().copied().collect();
+    for wallet in wallets {
+        match fetch_wallet_transactions(rpc, &wallet, from_slot, settings.backfill_limit).await {
+            Ok(transactions) => {
+                events::yellowstone_backfill(
+                    &settings.label,
+                    &wallet,
+                    from_slot,
+                    transactions.len(),
+                );
+                for (slot, info) in transactions {
+                    state.dispatch(wallet, slot, info, true).await;
+                }
+            }
+            Err(err) => {
+                warn!(
+                    target: "network::yellowstone",
+                    stream = %settings.label,
+                    wallet = %wallet,
+                    from_slot,
+                    error = %err,
+                    "RPC 回补失败"
+                );
+            }
+        }
+    }
+}
+
+fn lag_since(seconds: i64, nanos: i32) -> Option<Duration> {
+    let created = Duration::new(u64::try_from(seconds).ok()?, u32::try_from(nanos).ok()?);
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).ok()?;
+    Some(now.saturating_sub(created))
+}
+
+/// 近期已分发的 (钱包, 签名)，用于去除续订重放与 RPC 回补之间的重复。
+struct RecentSignatures {
+    capacity: usize,
+    order: VecDeque<(Pubkey, Signature)>,
+    seen: HashSet<(Pubkey, Signature)>,
+}
+
+impl RecentSignatures {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            order: VecDeque::with_capacity(capacity),
+            seen: HashSet::with_capacity(capacity),
+        }
+    }
+
+    fn insert(&mut self, wallet: Pubkey, signature: Signature) -> bool {
+        if !self.seen.insert((wallet, signature)) {
+            return false;
+        }
+        self.order.push_back((wallet, signature));
+        if self.order.len() > self.capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                self.seen.remove(&oldest);
+            }
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_doubles_until_cap() {
+        let max = Duration::from_secs(4);
+        let mut backoff = Duration::from_millis(500);
+        let mut seen = Vec::new();
+        for _ in 0..5 {
+            backoff = next_backoff(backoff, max);
+            seen.push(backoff.as_millis());
+        }
+        assert_eq!(seen, vec![1_000, 2_000, 4_000, 4_000, 4_000]);
+    }
+
+    #[test]
+    fn recent_signatures_dedupe_per_wallet() {
+        let mut recent = RecentSignatures::new(2);
+        let wallet_a = Pubkey::new_unique();
+        let wallet_b = Pubkey::new_unique();
+        let sig = Signature::from([1u8; 64]);
+        assert!(recent.insert(wallet_a, sig));
+        assert!(!recent.insert(wallet_a, sig));
+        assert!(recent.insert(wallet_b, sig));
+        assert!(recent.insert(wallet_b, Signature::from([2u8; 64])));
+        // 容量为 2，最早的记录被淘汰后可再次分发。
+        assert!(recent.insert(wallet_a, sig));
+    }
+}
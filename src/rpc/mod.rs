@@ -1,3 +1,4 @@
+pub mod transaction_meta;
 pub mod yellowstone;
 
 use solana_sdk::hash::Hash;
@@ -0,0 +1,64 @@
+//! `getTransaction` 落地结果查询：账本回查与 copy 风控共用。
+
+use serde_json::{Value, json};
+use solana_client::client_error::ClientError;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_client::rpc_request::RpcRequest;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+
+/// 查询交易的 `meta`；交易尚未可查时返回 None。
+pub(crate) async fn fetch_transaction_meta(
+    rpc: &RpcClient,
+    signature: &Signature,
+) -> Result<Option<Value>, ClientError> {
+    let params = json!([
+        signature.to_string(),
+        {
+            "encoding": "base64",
+            "commitment": "confirmed",
+            "maxSupportedTransactionVersion": 0,
+        }
+    ]);
+    let value: Value = rpc.send(RpcRequest::GetTransaction, params).await?;
+    Ok(value.get("meta").filter(|meta| !meta.is_null()).cloned())
+}
+
+/// 汇总 `pre/postTokenBalances` 中 `owner` 持有的 `mint` 数量（最小单位）。
+pub(crate) fn token_balance(balances: Option<&Value>, owner: &Pubkey, mint: &Pubkey) -> u64 {
+    let owner = owner.to_string();
+    let mint = mint.to_string();
+    balances
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter(|entry| {
+            entry.get("owner").and_then(Value::as_str) == Some(owner.as_str())
+                && entry.get("mint").and_then(Value::as_str) == Some(mint.as_str())
+        })
+        .filter_map(|entry| {
+            entry
+                .get("uiTokenAmount")
+                .and_then(|amount| amount.get("amount"))
+                .and_then(Value::as_str)
+                .and_then(|amount| amount.parse::<u64>().ok())
+        })
+        .fold(0u64, |acc, amount| acc.saturating_add(amount))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn token_balance_sums_owner_entries() {
+        let owner = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let balances = json!([
+            {"owner": owner.to_string(), "mint": mint.to_string(), "uiTokenAmount": {"amount": "1500"}},
+            {"owner": Pubkey::new_unique().to_string(), "mint": mint.to_string(), "uiTokenAmount": {"amount": "9"}},
+        ]);
+        assert_eq!(token_balance(Some(&balances), &owner, &mint), 1_500);
+        assert_eq!(token_balance(None, &owner, &mint), 0);
+    }
+}
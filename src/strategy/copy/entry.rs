@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::str::FromStr;
 use std::sync::Arc;
 use std::time::Duration;
@@ -18,8 +19,8 @@ use crate::config::launch::resources::{
 };
 use crate::config::{AppConfig, LanderSettings, StrategyToggle};
 use crate::engine::{
-    ComputeUnitPriceMode, EngineIdentity, LighthouseSettings, SolPriceFeedSettings,
-    TransactionBuilder,
+    ComputeUnitPriceMode, EngineIdentity, GuardSettlement, LighthouseSettings,
+    SolPriceFeedSettings, TransactionBuilder,
 };
 use crate::lander::LanderFactory;
 
@@ -75,6 +76,7 @@ pub async fn run_copy_strategy(
     let submission_client =
         build_http_client_with_options(effective_proxy.as_ref(), false, None, None)?;
     let submission_client_pool = build_http_client_pool(effective_proxy.clone(), false, None);
+    let api_http_client = build_http_client_with_options(global_proxy.as_ref(), false, None, None)?;
 
     let tx_builder = TransactionBuilder::new(
         rpc_client.clone(),
@@ -107,7 +109,13 @@ pub async fn run_copy_strategy(
                 .saturating_mul(60),
         ))
     };
-    let lighthouse_settings = build_lighthouse_settings(&config.galileo.bot.light_house)?;
+    let mut lighthouse_settings = build_lighthouse_settings(&config.galileo.bot.light_house)?;
+    if lighthouse_settings.enable {
+        lighthouse_settings
+            .resolve_token_programs(&rpc_client)
+            .await
+            .map_err(|err| anyhow!(err))?;
+    }
 
     let runner = CopyStrategyRunner {
         config: copy_config.clone(),
@@ -123,6 +131,9 @@ pub async fn run_copy_strategy(
         dry_run: dry_run_enabled,
         wallet_refresh_interval,
         lighthouse_settings,
+        api_http_client,
+        api_timeouts: config.galileo.engine.time_out.clone(),
+        api_logging: config.galileo.global.logging.clone(),
     };
 
     runner.run().await
@@ -210,11 +221,28 @@ fn build_lighthouse_settings(
         }
     });
 
+    let mut settlements = HashMap::with_capacity(cfg.settle_accounts.len());
+    for (mint_text, target) in &cfg.settle_accounts {
+        let trimmed = mint_text.trim();
+        let mint = Pubkey::from_str(trimmed).map_err(|err| {
+            anyhow!(
+                "light_house.settle_accounts 中的 mint `{}` 无效: {err}",
+                trimmed
+            )
+        })?;
+        let settlement = GuardSettlement::from_str(target)
+            .map_err(|err| anyhow!("light_house.settle_accounts[{mint}]: {err}"))?;
+        settlements.insert(mint, settlement);
+    }
+
     Ok(LighthouseSettings {
         enable: true,
         profit_guard_mints: mints,
         memory_slots,
         existing_memory_ids: Vec::new(),
         sol_price_feed,
+        native_sol_guard: cfg.native_sol_guard,
+        settlements,
+        token_programs: HashMap::new(),
     })
 }
@@ -3,7 +3,10 @@
 
 mod constants;
 mod entry;
+mod positions;
+mod risk;
 mod runner;
+mod swap;
 pub mod transaction;
 mod wallet;
 
@@ -0,0 +1,270 @@
+use std::collections::BTreeMap;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+use tokio::fs;
+use tracing::warn;
+
+use crate::monitoring::now_secs;
+
+use crate::api::serde_helpers::field_as_string;
+
+pub(crate) const DEFAULT_POSITIONS_FILE: &str = "monitoring/copy_positions.json";
+
+/// 复制买入后持有的仓位（按被复制钱包 + mint 区分）。
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub(crate) struct CopyPosition {
+    #[serde(with = "field_as_string")]
+    pub wallet: Pubkey,
+    #[serde(with = "field_as_string")]
+    pub mint: Pubkey,
+    /// 买入时花费的 mint，卖出时换回该 mint。
+    #[serde(with = "field_as_string")]
+    pub base_mint: Pubkey,
+    /// 当前持有数量（最小单位）。
+    pub amount: u64,
+    /// 剩余持仓对应的 base 成本（最小单位）。
+    pub cost: u64,
+    /// 首次建仓的 unix 秒。
+    pub opened_at: u64,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct PositionsFile {
+    positions: Vec<CopyPosition>,
+}
+
+/// 一次减仓的结果。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct ExitFill {
+    pub remaining: u64,
+    /// 卖出部分对应的 base 成本，用于计算已实现盈亏。
+    pub cost_released: u64,
+}
+
+/// 持仓簿，每次变更后整体写回磁盘，重启时恢复。
+pub(crate) struct PositionBook {
+    path: PathBuf,
+    positions: Mutex<BTreeMap<(Pubkey, Pubkey), CopyPosition>>,
+    write_lock: tokio::sync::Mutex<()>,
+}
+
+impl PositionBook {
+    pub(crate) async fn load(path: PathBuf) -> Result<Self> {
+        let stored = match fs::read(&path).await {
+            Ok(bytes) => serde_json::from_slice::<PositionsFile>(&bytes)
+                .with_context(|| format!("解析持仓文件失败: {}", path.display()))?,
+            Err(err) if err.kind() == ErrorKind::NotFound => PositionsFile::default(),
+            Err(err) => {
+                return Err(err).with_context(|| format!("读取持仓文件失败: {}", path.display()));
+            }
+        };
+        let positions = stored
+            .positions
+            .into_iter()
+            .filter(|position| position.amount > 0)
+            .map(|position| ((position.wallet, position.mint), position))
+            .collect();
+        Ok(Self {
+            path,
+            positions: Mutex::new(positions),
+            write_lock: tokio::sync::Mutex::new(()),
+        })
+    }
+
+    pub(crate) fn get(&self, wallet: &Pubkey, mint: &Pubkey) -> Option<CopyPosition> {
+        self.positions.lock().get(&(*wallet, *mint)).cloned()
+    }
+
+    pub(crate) fn for_wallet(&self, wallet: &Pubkey) -> Vec<CopyPosition> {
+        self.positions
+            .lock()
+            .values()
+            .filter(|position| position.wallet == *wallet)
+            .cloned()
+            .collect()
+    }
+
+    /// 源钱包 `mint` 余额从 `source_pre` 降到 `source_post` 时，我方应卖出的数量。
+    pub(crate) fn exit_amount(
+        &self,
+        wallet: &Pubkey,
+        mint: &Pubkey,
+        source_pre: u64,
+        source_post: u64,
+    ) -> Option<u64> {
+        let position = self.get(wallet, mint)?;
+        exit_fraction_amount(position.amount, source_pre, source_post)
+    }
+
+    pub(crate) async fn record_entry(
+        &self,
+        wallet: Pubkey,
+        mint: Pubkey,
+        base_mint: Pubkey,
+        amount: u64,
+        cost: u64,
+    ) {
+        if amount == 0 {
+            return;
+        }
+        {
+            let mut positions = self.positions.lock();
+            let entry = positions
+                .entry((wallet, mint))
+                .or_insert_with(|| CopyPosition {
+                    wallet,
+                    mint,
+                    base_mint,
+                    amount: 0,
+                    cost: 0,
+                    opened_at: now_secs(),
+                });
+            entry.amount = entry.amount.saturating_add(amount);
+            entry.cost = entry.cost.saturating_add(cost);
+        }
+        self.persist().await;
+    }
+
+    /// 记录卖出数量，成本按比例扣减；清仓后删除记录。返回剩余持仓与释放的成本。
+    pub(crate) async fn record_exit(&self, wallet: &Pubkey, mint: &Pubkey, sold: u64) -> ExitFill {
+        let fill = {
+            let mut positions = self.positions.lock();
+            let Some(position) = positions.get_mut(&(*wallet, *mint)) else {
+                return ExitFill {
+                    remaining: 0,
+                    cost_released: 0,
+                };
+            };
+            let sold = sold.min(position.amount);
+            let cost_released = (u128::from(position.cost) * u128::from(sold)
+                / u128::from(position.amount.max(1))) as u64;
+            position.amount -= sold;
+            position.cost = position.cost.saturating_sub(cost_released);
+            let remaining = position.amount;
+            if remaining == 0 {
+                positions.remove(&(*wallet, *mint));
+            }
+            ExitFill {
+                remaining,
+                cost_released,
+            }
+        };
+        self.persist().await;
+        fill
+    }
+
+    async fn persist(&self) {
+        let _guard = self.write_lock.lock().await;
+        let snapshot = PositionsFile {
+            positions: self.positions.lock().values().cloned().collect(),
+        };
+        if let Err(err) = write_positions(&self.path, &snapshot).await {
+            warn!(
+                target: "strategy::copy",
+                path = %self.path.display(),
+                error = %err,
+                "写入复制持仓文件失败"
+            );
+        }
+    }
+}
+
+fn exit_fraction_amount(held: u64, source_pre: u64, source_post: u64) -> Option<u64> {
+    if held == 0 || source_pre == 0 || source_post >= source_pre {
+        return None;
+    }
+    if source_post == 0 {
+        return Some(held);
+    }
+    let sold = source_pre - source_post;
+    let amount = (u128::from(held) * u128::from(sold) / u128::from(source_pre)) as u64;
+    (amount > 0).then_some(amount)
+}
+
+async fn write_positions(path: &Path, snapshot: &PositionsFile) -> Result<()> {
+    if let Some(parent) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+        fs::create_dir_all(parent)
+            .await
+            .with_context(|| format!("创建持仓目录失败: {}", parent.display()))?;
+    }
+    let data = serde_json::to_vec_pretty(snapshot).context("序列化持仓失败")?;
+    let tmp_path = path.with_extension("tmp");
+    fs::write(&tmp_path, data)
+        .await
+        .with_context(|| format!("写入临时持仓文件失败: {}", tmp_path.display()))?;
+    fs::rename(&tmp_path, path)
+        .await
+        .with_context(|| format!("替换持仓文件失败: {}", path.display()))?;
+    Ok(())
+}
+
+/// 报价估值 `value` 低于成本 `cost` 的 `pct`% 以上时触发止损。
+pub(crate) fn stop_loss_triggered(cost: u64, value: u64, pct: f64) -> bool {
+    if cost == 0 || pct <= 0.0 {
+        return false;
+    }
+    (value as f64) < cost as f64 * (1.0 - pct / 100.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn exit_amount_follows_source_fraction() {
+        assert_eq!(exit_fraction_amount(1_000, 400, 300), Some(250));
+        assert_eq!(exit_fraction_amount(1_000, 400, 0), Some(1_000));
+        assert_eq!(exit_fraction_amount(1_000, 400, 400), None);
+        assert_eq!(exit_fraction_amount(1_000, 400, 500), None);
+        assert_eq!(exit_fraction_amount(0, 400, 100), None);
+    }
+
+    #[test]
+    fn stop_loss_compares_quote_with_cost() {
+        assert!(stop_loss_triggered(1_000, 790, 20.0));
+        assert!(!stop_loss_triggered(1_000, 800, 20.0));
+        assert!(!stop_loss_triggered(1_000, 10, 0.0));
+        assert!(!stop_loss_triggered(0, 10, 20.0));
+    }
+
+    #[tokio::test]
+    async fn positions_survive_reload() {
+        let temp = TempDir::new().expect("temp dir");
+        let path = temp.path().join("positions.json");
+        let wallet = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let base = Pubkey::new_unique();
+
+        let book = PositionBook::load(path.clone()).await.expect("empty book");
+        book.record_entry(wallet, mint, base, 1_000, 500).await;
+        assert_eq!(
+            book.record_exit(&wallet, &mint, 400).await,
+            ExitFill {
+                remaining: 600,
+                cost_released: 200,
+            }
+        );
+
+        let reloaded = PositionBook::load(path).await.expect("reload");
+        let position = reloaded.get(&wallet, &mint).expect("position persisted");
+        assert_eq!(position.amount, 600);
+        assert_eq!(position.cost, 300);
+        assert_eq!(position.base_mint, base);
+        assert_eq!(reloaded.exit_amount(&wallet, &mint, 200, 100), Some(300));
+
+        assert_eq!(
+            reloaded.record_exit(&wallet, &mint, 600).await,
+            ExitFill {
+                remaining: 0,
+                cost_released: 300,
+            }
+        );
+        assert!(reloaded.for_wallet(&wallet).is_empty());
+    }
+}
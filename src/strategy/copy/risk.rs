@@ -0,0 +1,395 @@
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::fmt;
+use std::str::FromStr;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use anyhow::{Result, anyhow, bail};
+use parking_lot::Mutex;
+use solana_client::client_error::ClientError;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+
+use crate::config::{CopyRiskConfig, CopySizingConfig, CopySizingMode};
+use crate::rpc::transaction_meta::{fetch_transaction_meta, token_balance};
+
+const HOURLY_WINDOW: Duration = Duration::from_secs(3_600);
+const SECONDS_PER_DAY: u64 = 86_400;
+
+/// 复制规模策略，决定每笔复制交易的目标 in_amount（尚未经过余额与风控裁剪）。
+#[derive(Debug, Clone)]
+pub(crate) enum CopySizing {
+    Mirror,
+    Fixed(HashMap<Pubkey, u64>),
+    SourcePercent(f64),
+    BalancePercent(f64),
+}
+
+impl CopySizing {
+    pub(crate) fn from_config(cfg: &CopySizingConfig) -> Result<Self> {
+        let percent = || {
+            if cfg.percent > 0.0 && cfg.percent <= 100.0 {
+                Ok(cfg.percent / 100.0)
+            } else {
+                Err(anyhow!(
+                    "sizing.percent 必须位于 (0, 100]，当前为 {}",
+                    cfg.percent
+                ))
+            }
+        };
+        Ok(match cfg.mode {
+            CopySizingMode::Mirror => Self::Mirror,
+            CopySizingMode::Fixed => {
+                let amounts = parse_mint_map(&cfg.fixed_amounts, "sizing.fixed_amounts")?;
+                if amounts.is_empty() {
+                    bail!("sizing.mode = fixed 时 fixed_amounts 不能为空");
+                }
+                Self::Fixed(amounts)
+            }
+            CopySizingMode::SourcePercent => Self::SourcePercent(percent()?),
+            CopySizingMode::BalancePercent => Self::BalancePercent(percent()?),
+        })
+    }
+
+    /// 目标 in_amount；返回 None 表示该 mint 没有可用的规模配置。
+    pub(crate) fn target_amount(
+        &self,
+        mint: &Pubkey,
+        source_in: u64,
+        available: u64,
+    ) -> Option<u64> {
+        let target = match self {
+            Self::Mirror => source_in,
+            Self::Fixed(amounts) => *amounts.get(mint)?,
+            Self::SourcePercent(ratio) => scale(source_in, *ratio),
+            Self::BalancePercent(ratio) => scale(available, *ratio),
+        };
+        (target > 0).then_some(target)
+    }
+
+    /// 镜像模式保留源交易的绝对收益；其余模式按规模等比例缩放 quoted_out_amount。
+    pub(crate) fn scales_quoted_out(&self) -> bool {
+        !matches!(self, Self::Mirror)
+    }
+}
+
+fn scale(amount: u64, ratio: f64) -> u64 {
+    ((amount as f64) * ratio).floor().min(u64::MAX as f64) as u64
+}
+
+/// 风控拒绝复制的原因。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum RiskRejection {
+    DeniedMint(Pubkey),
+    MintNotAllowed(Pubkey),
+    HourlyCapReached,
+    DailyLossStop,
+}
+
+impl RiskRejection {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            Self::DeniedMint(_) => "denied_mint",
+            Self::MintNotAllowed(_) => "mint_not_allowed",
+            Self::HourlyCapReached => "hourly_cap",
+            Self::DailyLossStop => "daily_loss_stop",
+        }
+    }
+}
+
+impl fmt::Display for RiskRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DeniedMint(mint) => write!(f, "mint {mint} 在黑名单中"),
+            Self::MintNotAllowed(mint) => write!(f, "mint {mint} 不在白名单中"),
+            Self::HourlyCapReached => f.write_str("已达到每小时名义金额上限"),
+            Self::DailyLossStop => f.write_str("已触发当日亏损止损"),
+        }
+    }
+}
+
+#[derive(Default)]
+struct RiskState {
+    hourly: HashMap<Pubkey, VecDeque<(Instant, u64)>>,
+    day: u64,
+    realized: HashMap<Pubkey, i128>,
+}
+
+/// 单个复制钱包的风控限制与运行状态。
+pub(crate) struct CopyRiskLimits {
+    max_per_trade: HashMap<Pubkey, u64>,
+    max_per_hour: HashMap<Pubkey, u64>,
+    daily_loss_limit: HashMap<Pubkey, u64>,
+    allow_mints: HashSet<Pubkey>,
+    deny_mints: HashSet<Pubkey>,
+    state: Mutex<RiskState>,
+}
+
+impl CopyRiskLimits {
+    pub(crate) fn from_config(cfg: &CopyRiskConfig) -> Result<Self> {
+        Ok(Self {
+            max_per_trade: parse_mint_map(&cfg.max_per_trade, "risk.max_per_trade")?,
+            max_per_hour: parse_mint_map(&cfg.max_per_hour, "risk.max_per_hour")?,
+            daily_loss_limit: parse_mint_map(&cfg.daily_loss_limit, "risk.daily_loss_limit")?,
+            allow_mints: parse_mint_list(&cfg.allow_mints, "risk.allow_mints")?,
+            deny_mints: parse_mint_list(&cfg.deny_mints, "risk.deny_mints")?,
+            state: Mutex::new(RiskState::default()),
+        })
+    }
+
+    /// 校验源交易涉及的全部 mint。
+    pub(crate) fn check_mints<'a>(
+        &self,
+        mints: impl IntoIterator<Item = &'a Pubkey>,
+    ) -> Result<(), RiskRejection> {
+        for mint in mints {
+            if self.deny_mints.contains(mint) {
+                return Err(RiskRejection::DeniedMint(*mint));
+            }
+            if !self.allow_mints.is_empty() && !self.allow_mints.contains(mint) {
+                return Err(RiskRejection::MintNotAllowed(*mint));
+            }
+        }
+        Ok(())
+    }
+
+    /// 按单笔上限、小时剩余额度与当日止损裁剪目标金额。
+    pub(crate) fn clamp_amount(&self, mint: &Pubkey, amount: u64) -> Result<u64, RiskRejection> {
+        self.clamp_amount_at(mint, amount, Instant::now(), current_day())
+    }
+
+    fn clamp_amount_at(
+        &self,
+        mint: &Pubkey,
+        amount: u64,
+        now: Instant,
+        day: u64,
+    ) -> Result<u64, RiskRejection> {
+        let mut state = self.state.lock();
+        roll_day(&mut state, day);
+
+        if let Some(limit) = self.daily_loss_limit.get(mint) {
+            let realized = state.realized.get(mint).copied().unwrap_or(0);
+            if realized <= -i128::from(*limit) {
+                return Err(RiskRejection::DailyLossStop);
+            }
+        }
+
+        let mut amount = amount;
+        if let Some(cap) = self.max_per_trade.get(mint) {
+            amount = amount.min(*cap);
+        }
+        if let Some(cap) = self.max_per_hour.get(mint) {
+            let used = hourly_usage(&mut state, mint, now);
+            let remaining = cap.saturating_sub(used);
+            if remaining == 0 {
+                return Err(RiskRejection::HourlyCapReached);
+            }
+            amount = amount.min(remaining);
+        }
+        Ok(amount)
+    }
+
+    /// 复制交易已提交，计入小时额度。
+    pub(crate) fn record_dispatch(&self, mint: &Pubkey, amount: u64) {
+        self.record_dispatch_at(mint, amount, Instant::now());
+    }
+
+    fn record_dispatch_at(&self, mint: &Pubkey, amount: u64, now: Instant) {
+        if !self.max_per_hour.contains_key(mint) {
+            return;
+        }
+        let mut state = self.state.lock();
+        state
+            .hourly
+            .entry(*mint)
+            .or_default()
+            .push_back((now, amount));
+    }
+
+    /// 是否需要回查落地交易的实际盈亏。
+    pub(crate) fn tracks_realized(&self, mint: &Pubkey) -> bool {
+        self.daily_loss_limit.contains_key(mint)
+    }
+
+    /// 记录一笔已实现盈亏（套利的 base 实际变化，或平仓换回的 base 减去持仓成本），返回当日累计值。
+    pub(crate) fn record_realized(&self, mint: &Pubkey, delta: i128) -> i128 {
+        self.record_realized_on(mint, delta, current_day())
+    }
+
+    fn record_realized_on(&self, mint: &Pubkey, delta: i128, day: u64) -> i128 {
+        let mut state = self.state.lock();
+        roll_day(&mut state, day);
+        let total = state.realized.entry(*mint).or_insert(0);
+        *total = total.saturating_add(delta);
+        *total
+    }
+}
+
+/// 读取落地交易中 `owner` 持有的 `mint` 余额变化；交易尚未可查时返回 None，失败交易视为 0。
+pub(crate) async fn fetch_realized_delta(
+    rpc: &RpcClient,
+    signature: &Signature,
+    owner: &Pubkey,
+    mint: &Pubkey,
+) -> Result<Option<i128>, ClientError> {
+    let Some(meta) = fetch_transaction_meta(rpc, signature).await? else {
+        return Ok(None);
+    };
+    if meta.get("err").is_some_and(|err| !err.is_null()) {
+        return Ok(Some(0));
+    }
+    let pre = token_balance(meta.get("preTokenBalances"), owner, mint);
+    let post = token_balance(meta.get("postTokenBalances"), owner, mint);
+    Ok(Some(i128::from(post) - i128::from(pre)))
+}
+
+fn roll_day(state: &mut RiskState, day: u64) {
+    if state.day != day {
+        state.day = day;
+        state.realized.clear();
+    }
+}
+
+fn hourly_usage(state: &mut RiskState, mint: &Pubkey, now: Instant) -> u64 {
+    let Some(window) = state.hourly.get_mut(mint) else {
+        return 0;
+    };
+    while window
+        .front()
+        .is_some_and(|(at, _)| now.saturating_duration_since(*at) >= HOURLY_WINDOW)
+    {
+        window.pop_front();
+    }
+    window
+        .iter()
+        .fold(0u64, |acc, (_, amount)| acc.saturating_add(*amount))
+}
+
+fn current_day() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs() / SECONDS_PER_DAY)
+        .unwrap_or(0)
+}
+
+fn parse_mint_map(raw: &BTreeMap<String, u64>, field: &str) -> Result<HashMap<Pubkey, u64>> {
+    raw.iter()
+        .map(|(mint, value)| {
+            let trimmed = mint.trim();
+            Pubkey::from_str(trimmed)
+                .map(|mint| (mint, *value))
+                .map_err(|err| anyhow!("{field} 中的 mint `{trimmed}` 无效: {err}"))
+        })
+        .collect()
+}
+
+fn parse_mint_list(raw: &[String], field: &str) -> Result<HashSet<Pubkey>> {
+    raw.iter()
+        .map(|mint| mint.trim())
+        .filter(|mint| !mint.is_empty())
+        .map(|mint| {
+            Pubkey::from_str(mint).map_err(|err| anyhow!("{field} 中的 mint `{mint}` 无效: {err}"))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limits(cfg: CopyRiskConfig) -> CopyRiskLimits {
+        CopyRiskLimits::from_config(&cfg).expect("risk config")
+    }
+
+    #[test]
+    fn sizing_modes_compute_targets() {
+        let mint = Pubkey::new_unique();
+        let other = Pubkey::new_unique();
+        assert_eq!(
+            CopySizing::Mirror.target_amount(&mint, 1_000, 10),
+            Some(1_000)
+        );
+        assert_eq!(
+            CopySizing::SourcePercent(0.25).target_amount(&mint, 1_000, 10),
+            Some(250)
+        );
+        assert_eq!(
+            CopySizing::BalancePercent(0.5).target_amount(&mint, 1_000, 10),
+            Some(5)
+        );
+        let fixed = CopySizing::Fixed(HashMap::from([(mint, 42)]));
+        assert_eq!(fixed.target_amount(&mint, 1_000, 10), Some(42));
+        assert_eq!(fixed.target_amount(&other, 1_000, 10), None);
+    }
+
+    #[test]
+    fn sizing_rejects_invalid_percent() {
+        let cfg = CopySizingConfig {
+            mode: CopySizingMode::SourcePercent,
+            percent: 150.0,
+            fixed_amounts: BTreeMap::new(),
+        };
+        assert!(CopySizing::from_config(&cfg).is_err());
+    }
+
+    #[test]
+    fn mint_lists_filter_routes() {
+        let allowed = Pubkey::new_unique();
+        let denied = Pubkey::new_unique();
+        let limits = limits(CopyRiskConfig {
+            allow_mints: vec![allowed.to_string(), denied.to_string()],
+            deny_mints: vec![denied.to_string()],
+            ..CopyRiskConfig::default()
+        });
+        assert!(limits.check_mints([&allowed]).is_ok());
+        assert_eq!(
+            limits.check_mints([&allowed, &denied]),
+            Err(RiskRejection::DeniedMint(denied))
+        );
+        let stranger = Pubkey::new_unique();
+        assert_eq!(
+            limits.check_mints([&stranger]),
+            Err(RiskRejection::MintNotAllowed(stranger))
+        );
+    }
+
+    #[test]
+    fn hourly_cap_clamps_and_expires() {
+        let mint = Pubkey::new_unique();
+        let limits = limits(CopyRiskConfig {
+            max_per_trade: BTreeMap::from([(mint.to_string(), 600)]),
+            max_per_hour: BTreeMap::from([(mint.to_string(), 1_000)]),
+            ..CopyRiskConfig::default()
+        });
+        let start = Instant::now();
+        assert_eq!(limits.clamp_amount_at(&mint, 900, start, 1), Ok(600));
+        limits.record_dispatch_at(&mint, 600, start);
+        assert_eq!(limits.clamp_amount_at(&mint, 900, start, 1), Ok(400));
+        limits.record_dispatch_at(&mint, 400, start);
+        assert_eq!(
+            limits.clamp_amount_at(&mint, 900, start, 1),
+            Err(RiskRejection::HourlyCapReached)
+        );
+        let later = start + HOURLY_WINDOW;
+        assert_eq!(limits.clamp_amount_at(&mint, 900, later, 1), Ok(600));
+    }
+
+    #[test]
+    fn daily_loss_stop_resets_next_day() {
+        let mint = Pubkey::new_unique();
+        let limits = limits(CopyRiskConfig {
+            daily_loss_limit: BTreeMap::from([(mint.to_string(), 1_000)]),
+            ..CopyRiskConfig::default()
+        });
+        let now = Instant::now();
+        assert_eq!(limits.record_realized_on(&mint, -700, 5), -700);
+        assert_eq!(limits.clamp_amount_at(&mint, 50, now, 5), Ok(50));
+        assert_eq!(limits.record_realized_on(&mint, -300, 5), -1_000);
+        assert_eq!(
+            limits.clamp_amount_at(&mint, 50, now, 5),
+            Err(RiskRejection::DailyLossStop)
+        );
+        assert_eq!(limits.clamp_amount_at(&mint, 50, now, 6), Ok(50));
+    }
+}
@@ -1,18 +1,30 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::str::FromStr;
 use std::sync::Arc;
 use std::time::Duration;
 
 use anyhow::{Result, anyhow};
 use futures::StreamExt;
 use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::pubkey::Pubkey;
+use tokio::sync::mpsc;
 use tracing::warn;
+use yellowstone_grpc_proto::tonic::metadata::AsciiMetadataValue;
 
-use crate::config::{CopyStrategyConfig, LanderSettings};
+use crate::api::jupiter::JupiterApiClient;
+use crate::config::{
+    CopySourceKind, CopyStrategyConfig, CopyWalletConfig, EngineTimeoutConfig, LanderSettings,
+    LoggingConfig,
+};
 use crate::engine::{
     ComputeUnitPriceMode, DispatchStrategy, EngineIdentity, LighthouseRuntime, LighthouseSettings,
     TransactionBuilder,
 };
 use crate::network::IpAllocator;
+use crate::network::yellowstone::{WalletStreamSettings, WalletTransaction, spawn_wallet_stream};
 
+use super::positions::{DEFAULT_POSITIONS_FILE, PositionBook};
 use super::wallet::CopyWalletRunner;
 
 pub struct CopyStrategyRunner {
@@ -29,6 +41,9 @@ pub struct CopyStrategyRunner {
     pub(crate) dry_run: bool,
     pub(crate) wallet_refresh_interval: Option<Duration>,
     pub(crate) lighthouse_settings: LighthouseSettings,
+    pub(crate) api_http_client: reqwest::Client,
+    pub(crate) api_timeouts: EngineTimeoutConfig,
+    pub(crate) api_logging: LoggingConfig,
 }
 
 impl CopyStrategyRunner {
@@ -52,6 +67,9 @@ impl CopyStrategyRunner {
             dry_run,
             wallet_refresh_interval,
             lighthouse_settings,
+            api_http_client,
+            api_timeouts,
+            api_logging,
         } = self;
 
         let allocator_summary = ip_allocator.summary();
@@ -61,13 +79,35 @@ impl CopyStrategyRunner {
             .max(1)
             .saturating_mul(per_ip_capacity);
         let lighthouse_runtime = LighthouseRuntime::new(&lighthouse_settings, ip_capacity_hint);
+        let control = crate::control::runtime_control();
+        control.register_strategy("copy");
+        control.register_ip_allocator(Arc::clone(&ip_allocator));
         let lighthouse = Arc::new(tokio::sync::Mutex::new(lighthouse_runtime));
 
+        let positions = if config.wallets.iter().any(|wallet| wallet.exits.enable) {
+            let path = config
+                .positions_file
+                .as_deref()
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+                .unwrap_or(DEFAULT_POSITIONS_FILE);
+            let book = PositionBook::load(PathBuf::from(path)).await?;
+            Some(Arc::new(book))
+        } else {
+            None
+        };
+
+        let mut streams = build_wallet_streams(&config.wallets, &rpc_client)?;
+
         let mut tasks = futures::stream::FuturesUnordered::new();
         let wallets = config.wallets.clone();
         let copy_dispatch = config.copy_dispatch.clone();
 
         for wallet in wallets {
+            let transactions = wallet_pubkey(&wallet)
+                .ok()
+                .and_then(|pubkey| streams.remove(&pubkey));
+            let exit_api = build_exit_api(&wallet, &api_http_client, &api_timeouts, &api_logging);
             let runner = CopyWalletRunner::new(
                 wallet,
                 copy_dispatch.clone(),
@@ -83,11 +123,13 @@ impl CopyStrategyRunner {
                 wallet_refresh_interval,
                 dry_run,
                 Arc::clone(&lighthouse),
+                positions.clone(),
+                exit_api,
             )
             .await
             .map_err(|err| anyhow!("初始化 copy wallet runner 失败: {err}"))?;
 
-            tasks.push(tokio::spawn(runner.run()));
+            tasks.push(tokio::spawn(runner.run(transactions)));
         }
 
         while let Some(result) = tasks.next().await {
@@ -107,3 +149,95 @@ impl CopyStrategyRunner {
         Ok(())
     }
 }
+
+fn build_exit_api(
+    wallet: &CopyWalletConfig,
+    http_client: &reqwest::Client,
+    timeouts: &EngineTimeoutConfig,
+    logging: &LoggingConfig,
+) -> Option<JupiterApiClient> {
+    if !wallet.exits.enable {
+        return None;
+    }
+    let quote_base = wallet
+        .exits
+        .api_quote_base
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())?;
+    let swap_base = wallet
+        .exits
+        .api_swap_base
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .unwrap_or(quote_base);
+    Some(JupiterApiClient::new(
+        http_client.clone(),
+        quote_base.to_string(),
+        swap_base.to_string(),
+        timeouts,
+        logging,
+    ))
+}
+
+const WALLET_STREAM_CAPACITY: usize = 1_024;
+
+fn wallet_pubkey(wallet: &CopyWalletConfig) -> Result<Pubkey> {
+    Pubkey::from_str(wallet.address.trim())
+        .map_err(|err| anyhow!("wallet address `{}` 解析失败: {err}", wallet.address))
+}
+
+/// gRPC 来源的钱包按 (endpoint, token) 分组，每组共用一条复用订阅；返回各钱包的交易接收端。
+fn build_wallet_streams(
+    wallets: &[CopyWalletConfig],
+    rpc_client: &Arc<RpcClient>,
+) -> Result<HashMap<Pubkey, mpsc::Receiver<WalletTransaction>>> {
+    let mut groups: HashMap<(String, String), Vec<&CopyWalletConfig>> = HashMap::new();
+    for wallet in wallets {
+        if !matches!(wallet.source.kind, CopySourceKind::Grpc) {
+            continue;
+        }
+        let grpc = &wallet.source.grpc;
+        let endpoint = grpc.yellowstone_grpc_url.trim();
+        if endpoint.is_empty() {
+            return Err(anyhow!(
+                "wallet {} 未配置 yellowstone_grpc_url",
+                wallet.address
+            ));
+        }
+        groups
+            .entry((
+                endpoint.to_string(),
+                grpc.yellowstone_grpc_token.trim().to_string(),
+            ))
+            .or_default()
+            .push(wallet);
+    }
+
+    let mut receivers = HashMap::new();
+    for ((endpoint, token), members) in groups {
+        // 同一订阅的重连与回补参数取组内第一个钱包的配置。
+        let grpc = &members[0].source.grpc;
+        let label = format!("copy@{}", endpoint.split('?').next().unwrap_or_default());
+        let mut settings =
+            WalletStreamSettings::new(endpoint, token.parse::<AsciiMetadataValue>().ok(), label)
+                .with_reconnect(
+                    Duration::from_millis(grpc.reconnect_initial_ms),
+                    Duration::from_millis(grpc.reconnect_max_ms),
+                );
+        if grpc.backfill_limit > 0 {
+            settings = settings.with_backfill(Arc::clone(rpc_client), grpc.backfill_limit);
+        }
+
+        let mut sinks = HashMap::with_capacity(members.len());
+        for wallet in members {
+            let pubkey = wallet_pubkey(wallet)?;
+            let (sender, receiver) = mpsc::channel(WALLET_STREAM_CAPACITY);
+            sinks.insert(pubkey, sender);
+            receivers.insert(pubkey, receiver);
+        }
+        spawn_wallet_stream(settings, sinks);
+    }
+    Ok(receivers)
+}
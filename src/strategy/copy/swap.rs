@@ -0,0 +1,496 @@
+//! copy 源交易中可复制 swap 指令的识别与金额重编码。
+//!
+//! 支持三类来源：Jupiter Route、各 `BlindDex` 程序的直连 swap、DFlow 聚合器（`idls/dflow.json`）。
+
+use anyhow::{Result, anyhow};
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::dexes::clmm::RAYDIUM_CLMM_PROGRAM_ID;
+use crate::dexes::dlmm::METEORA_DLMM_PROGRAM_ID;
+use crate::dexes::humidifi::HUMIDIFI_PROGRAM_ID;
+use crate::dexes::obric_v2::OBRIC_V2_PROGRAM_ID;
+use crate::dexes::saros::SAROS_PROGRAM_ID;
+use crate::dexes::solfi_v2::SOLFI_V2_PROGRAM_ID;
+use crate::dexes::tessera_v::TESSERA_V_PROGRAM_ID;
+use crate::dexes::whirlpool::ORCA_WHIRLPOOL_PROGRAM_ID;
+use crate::dexes::zerofi::ZEROFI_PROGRAM_ID;
+use crate::instructions::jupiter::parser::{RouteKind, RouteV2Accounts};
+use crate::instructions::jupiter::types::JUPITER_V6_PROGRAM_ID;
+use crate::strategy::types::BlindDex;
+
+use super::transaction::{
+    read_route_in_amount, read_route_quoted_out_amount, update_route_in_amount,
+    update_route_quoted_out_amount,
+};
+
+pub(crate) const DFLOW_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("DF1ow4tspfHX9JwWJsAb9epbkA8hmpSEAtxXy1V27QBH");
+
+const ANCHOR_SWAP: [u8; 8] = [248, 198, 158, 145, 225, 117, 135, 200];
+const ANCHOR_SWAP_V2: [u8; 8] = [43, 4, 237, 11, 26, 201, 30, 98];
+const ANCHOR_SWAP2: [u8; 8] = [65, 75, 63, 76, 235, 91, 91, 136];
+const ANCHOR_SWAP_X_TO_Y: [u8; 8] = [226, 74, 41, 166, 87, 155, 41, 75];
+const ANCHOR_SWAP_Y_TO_X: [u8; 8] = [219, 168, 219, 174, 169, 221, 164, 95];
+
+/// DFlow swap 系列指令的 discriminator，第二项标记是否为 `Swap2Params`。
+const DFLOW_SWAPS: [([u8; 8], bool); 6] = [
+    ([248, 198, 158, 145, 225, 117, 135, 200], false),
+    ([168, 172, 24, 77, 197, 156, 135, 101], false),
+    ([205, 77, 127, 108, 241, 32, 196, 195], false),
+    ([65, 75, 63, 76, 235, 91, 91, 136], true),
+    ([95, 123, 213, 246, 122, 1, 86, 231], true),
+    ([222, 100, 184, 146, 186, 196, 105, 165], true),
+];
+/// DFlow swap 指令中 `user_token_authority` 的账户下标。
+const DFLOW_AUTHORITY_INDEX: usize = 3;
+
+/// 直连 DEX swap 指令的数据布局（仅 exact-in）。
+#[derive(Debug)]
+pub(crate) struct DexSwapLayout {
+    discriminator: &'static [u8],
+    amount_in: usize,
+    min_out: usize,
+    /// exact-in 标志所在偏移与期望值。
+    exact_in_flag: Option<(usize, u8)>,
+}
+
+impl DexSwapLayout {
+    const fn new(discriminator: &'static [u8], amount_in: usize, min_out: usize) -> Self {
+        Self {
+            discriminator,
+            amount_in,
+            min_out,
+            exact_in_flag: None,
+        }
+    }
+
+    const fn exact_in(mut self, offset: usize, value: u8) -> Self {
+        self.exact_in_flag = Some((offset, value));
+        self
+    }
+
+    fn matches(&self, data: &[u8]) -> bool {
+        if !data.starts_with(self.discriminator) {
+            return false;
+        }
+        if data.len() < self.amount_in.max(self.min_out) + 8 {
+            return false;
+        }
+        match self.exact_in_flag {
+            Some((offset, value)) => data.get(offset) == Some(&value),
+            None => true,
+        }
+    }
+}
+
+static SOLFI_V2_LAYOUTS: [DexSwapLayout; 1] = [DexSwapLayout::new(&[7], 1, 9)];
+static TESSERA_V_LAYOUTS: [DexSwapLayout; 1] = [DexSwapLayout::new(&[16], 2, 10)];
+static SAROS_LAYOUTS: [DexSwapLayout; 1] = [DexSwapLayout::new(&[1], 1, 9)];
+static ZEROFI_LAYOUTS: [DexSwapLayout; 1] = [DexSwapLayout::new(&[6], 1, 9)];
+static OBRIC_V2_LAYOUTS: [DexSwapLayout; 3] = [
+    DexSwapLayout::new(&ANCHOR_SWAP, 9, 17),
+    DexSwapLayout::new(&ANCHOR_SWAP_X_TO_Y, 8, 16),
+    DexSwapLayout::new(&ANCHOR_SWAP_Y_TO_X, 8, 16),
+];
+static RAYDIUM_CLMM_LAYOUTS: [DexSwapLayout; 2] = [
+    DexSwapLayout::new(&ANCHOR_SWAP, 8, 16).exact_in(40, 1),
+    DexSwapLayout::new(&ANCHOR_SWAP_V2, 8, 16).exact_in(40, 1),
+];
+static METEORA_DLMM_LAYOUTS: [DexSwapLayout; 2] = [
+    DexSwapLayout::new(&ANCHOR_SWAP, 8, 16),
+    DexSwapLayout::new(&ANCHOR_SWAP2, 8, 16),
+];
+static WHIRLPOOL_LAYOUTS: [DexSwapLayout; 2] = [
+    DexSwapLayout::new(&ANCHOR_SWAP, 8, 16).exact_in(40, 1),
+    DexSwapLayout::new(&ANCHOR_SWAP_V2, 8, 16).exact_in(40, 1),
+];
+/// 可复制的直连 DEX。HumidiFi 指令数据按 swap_id 混淆，无法改写金额，不参与复制。
+const ALL_DEXES: [BlindDex; 8] = [
+    BlindDex::SolFiV2,
+    BlindDex::TesseraV,
+    BlindDex::Saros,
+    BlindDex::ZeroFi,
+    BlindDex::ObricV2,
+    BlindDex::RaydiumClmm,
+    BlindDex::MeteoraDlmm,
+    BlindDex::Whirlpool,
+];
+
+fn dex_program_id(dex: BlindDex) -> Pubkey {
+    match dex {
+        BlindDex::SolFiV2 => SOLFI_V2_PROGRAM_ID,
+        BlindDex::HumidiFi => HUMIDIFI_PROGRAM_ID,
+        BlindDex::TesseraV => TESSERA_V_PROGRAM_ID,
+        BlindDex::Saros => SAROS_PROGRAM_ID,
+        BlindDex::ZeroFi => ZEROFI_PROGRAM_ID,
+        BlindDex::ObricV2 => OBRIC_V2_PROGRAM_ID,
+        BlindDex::RaydiumClmm => RAYDIUM_CLMM_PROGRAM_ID,
+        BlindDex::MeteoraDlmm => METEORA_DLMM_PROGRAM_ID,
+        BlindDex::Whirlpool => ORCA_WHIRLPOOL_PROGRAM_ID,
+    }
+}
+
+fn dex_layouts(dex: BlindDex) -> &'static [DexSwapLayout] {
+    match dex {
+        BlindDex::SolFiV2 => &SOLFI_V2_LAYOUTS,
+        BlindDex::HumidiFi => &[],
+        BlindDex::TesseraV => &TESSERA_V_LAYOUTS,
+        BlindDex::Saros => &SAROS_LAYOUTS,
+        BlindDex::ZeroFi => &ZEROFI_LAYOUTS,
+        BlindDex::ObricV2 => &OBRIC_V2_LAYOUTS,
+        BlindDex::RaydiumClmm => &RAYDIUM_CLMM_LAYOUTS,
+        BlindDex::MeteoraDlmm => &METEORA_DLMM_LAYOUTS,
+        BlindDex::Whirlpool => &WHIRLPOOL_LAYOUTS,
+    }
+}
+
+/// copy 默认订阅的全部程序：Jupiter、DFlow 与各直连 DEX。
+pub(crate) fn copy_program_ids() -> Vec<Pubkey> {
+    let mut ids = vec![JUPITER_V6_PROGRAM_ID, DFLOW_PROGRAM_ID];
+    ids.extend(ALL_DEXES.iter().map(|dex| dex_program_id(*dex)));
+    ids
+}
+
+/// 源交易中一条可复制的 swap 指令。
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum SwapKind {
+    Jupiter(RouteKind),
+    Dex {
+        dex: BlindDex,
+        layout: &'static DexSwapLayout,
+    },
+    DFlow {
+        swap2: bool,
+    },
+}
+
+impl SwapKind {
+    pub fn classify(instruction: &Instruction) -> Option<Self> {
+        let data = instruction.data.as_slice();
+        if instruction.program_id == JUPITER_V6_PROGRAM_ID {
+            return match crate::instructions::jupiter::parser::classify(data) {
+                RouteKind::Other => None,
+                kind => Some(SwapKind::Jupiter(kind)),
+            };
+        }
+        if instruction.program_id == DFLOW_PROGRAM_ID {
+            return DFLOW_SWAPS
+                .iter()
+                .find(|(discriminator, _)| data.starts_with(discriminator))
+                .map(|(_, swap2)| SwapKind::DFlow { swap2: *swap2 });
+        }
+        let dex = ALL_DEXES
+            .iter()
+            .copied()
+            .find(|dex| dex_program_id(*dex) == instruction.program_id)?;
+        dex_layouts(dex)
+            .iter()
+            .find(|layout| layout.matches(data))
+            .map(|layout| SwapKind::Dex { dex, layout })
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            SwapKind::Jupiter(_) => "Jupiter",
+            SwapKind::Dex { dex, .. } => dex.as_str(),
+            SwapKind::DFlow { .. } => "DFlow",
+        }
+    }
+
+    pub fn is_jupiter(&self) -> bool {
+        matches!(self, SwapKind::Jupiter(_))
+    }
+
+    /// 源交易中发起 swap 的钱包。直连 DEX 取第一个 signer。
+    pub fn authority(&self, instruction: &Instruction) -> Option<Pubkey> {
+        match self {
+            SwapKind::Jupiter(RouteKind::Route) => {
+                instruction.accounts.get(1).map(|meta| meta.pubkey)
+            }
+            SwapKind::Jupiter(_) => {
+                RouteV2Accounts::parse(instruction).map(|parsed| parsed.user_transfer_authority)
+            }
+            SwapKind::Dex { .. } => instruction
+                .accounts
+                .iter()
+                .find(|meta| meta.is_signer)
+                .map(|meta| meta.pubkey),
+            SwapKind::DFlow { .. } => instruction
+                .accounts
+                .get(DFLOW_AUTHORITY_INDEX)
+                .map(|meta| meta.pubkey),
+        }
+    }
+
+    /// 输入 token 账户；仅 Jupiter 指令账户布局固定，其余依赖余额变化推断 base mint。
+    pub fn source_token_account(&self, instruction: &Instruction) -> Option<Pubkey> {
+        match self {
+            SwapKind::Jupiter(RouteKind::Route) => {
+                instruction.accounts.get(2).map(|meta| meta.pubkey)
+            }
+            SwapKind::Jupiter(_) => {
+                RouteV2Accounts::parse(instruction).map(|parsed| parsed.user_source_token_account)
+            }
+            SwapKind::Dex { .. } | SwapKind::DFlow { .. } => None,
+        }
+    }
+
+    pub fn read_in_amount(&self, data: &[u8]) -> Option<u64> {
+        match self {
+            SwapKind::Jupiter(kind) => read_route_in_amount(*kind, data),
+            SwapKind::Dex { layout, .. } => read_u64_at(data, layout.amount_in),
+            SwapKind::DFlow { swap2 } => {
+                let layout = DFlowLayout::parse(data, *swap2)?;
+                read_u64_at(data, *layout.amounts.first()?)
+            }
+        }
+    }
+
+    /// 写入新的输入金额。DFlow 多个 action 按同一比例缩放，保持拆单比例。
+    pub fn update_in_amount(&self, data: &mut [u8], value: u64) -> Result<()> {
+        match self {
+            SwapKind::Jupiter(kind) => update_route_in_amount(*kind, data, value),
+            SwapKind::Dex { layout, .. } => write_u64_at(data, layout.amount_in, value),
+            SwapKind::DFlow { swap2 } => {
+                let layout = DFlowLayout::parse(data, *swap2)
+                    .ok_or_else(|| anyhow!("DFlow 指令 actions 解析失败，无法写入 in_amount"))?;
+                let first = layout
+                    .amounts
+                    .first()
+                    .and_then(|offset| read_u64_at(data, *offset))
+                    .filter(|amount| *amount > 0)
+                    .ok_or_else(|| anyhow!("DFlow 指令缺少有效的 swap action 金额"))?;
+                for offset in &layout.amounts {
+                    let current = read_u64_at(data, *offset).unwrap_or(0);
+                    let scaled = u128::from(current) * u128::from(value) / u128::from(first);
+                    write_u64_at(data, *offset, scaled.min(u128::from(u64::MAX)) as u64)?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// 报价输出（Jupiter/DFlow）或最小输出（直连 DEX）。
+    pub fn read_quoted_out_amount(&self, data: &[u8]) -> Option<u64> {
+        match self {
+            SwapKind::Jupiter(kind) => read_route_quoted_out_amount(*kind, data),
+            SwapKind::Dex { layout, .. } => read_u64_at(data, layout.min_out),
+            SwapKind::DFlow { swap2 } => {
+                let layout = DFlowLayout::parse(data, *swap2)?;
+                read_u64_at(data, layout.quoted_out)
+            }
+        }
+    }
+
+    pub fn update_quoted_out_amount(&self, data: &mut [u8], value: u64) -> Result<()> {
+        match self {
+            SwapKind::Jupiter(kind) => update_route_quoted_out_amount(*kind, data, value),
+            SwapKind::Dex { layout, .. } => write_u64_at(data, layout.min_out, value),
+            SwapKind::DFlow { swap2 } => {
+                let layout = DFlowLayout::parse(data, *swap2).ok_or_else(|| {
+                    anyhow!("DFlow 指令 actions 解析失败，无法写入 quoted_out_amount")
+                })?;
+                write_u64_at(data, layout.quoted_out, value)
+            }
+        }
+    }
+}
+
+/// DFlow `SwapParams` / `Swap2Params` 中需要改写的字段偏移。
+struct DFlowLayout {
+    /// 各 swap action 的 `amount` 偏移（不含 TransferFee / RecordId）。
+    amounts: Vec<usize>,
+    quoted_out: usize,
+}
+
+impl DFlowLayout {
+    fn parse(data: &[u8], swap2: bool) -> Option<Self> {
+        let count = u32::from_le_bytes(data.get(8..12)?.try_into().ok()?) as usize;
+        let mut cursor = 12usize;
+        let mut amounts = Vec::with_capacity(count);
+        for _ in 0..count {
+            let variant = *data.get(cursor)?;
+            cursor += 1;
+            match variant {
+                // amount + 单字节参数 + orchestrator_flags
+                0 | 1 | 4 | 5 | 6 | 7 | 10 | 22 | 24 | 25 => {
+                    amounts.push(cursor);
+                    cursor += 10;
+                }
+                // HumidiFiSwap: amount + swap_id + orchestrator_flags
+                23 => {
+                    amounts.push(cursor);
+                    cursor += 17;
+                }
+                2 | 3 | 8 | 9 | 11..=21 | 26..=30 | 32..=34 => {
+                    amounts.push(cursor);
+                    cursor += 9;
+                }
+                // DFlowDynamicRouteV1: candidate_actions + amount + orchestrator_flags
+                31 => {
+                    let candidates =
+                        u32::from_le_bytes(data.get(cursor..cursor + 4)?.try_into().ok()?);
+                    cursor += 4;
+                    for _ in 0..candidates {
+                        let candidate = *data.get(cursor)?;
+                        cursor += 1;
+                        if candidate == 3 {
+                            cursor += 8;
+                        }
+                    }
+                    amounts.push(cursor);
+                    cursor += 9;
+                }
+                // TransferFee / TransferFeeWithMint
+                35 | 36 => cursor += 8,
+                37 => cursor += 76,
+                38 => cursor += 4,
+                _ => return None,
+            }
+        }
+        // quoted_out_amount + slippage_bps + platform_fee_bps (+ positive_slippage_fee_limit_pct)
+        let trailer = if swap2 { 13 } else { 12 };
+        if data.len() != cursor + trailer {
+            return None;
+        }
+        Some(Self {
+            amounts,
+            quoted_out: cursor,
+        })
+    }
+}
+
+fn read_u64_at(data: &[u8], offset: usize) -> Option<u64> {
+    let bytes = data.get(offset..offset + 8)?;
+    Some(u64::from_le_bytes(bytes.try_into().ok()?))
+}
+
+fn write_u64_at(data: &mut [u8], offset: usize, value: u64) -> Result<()> {
+    let target = data
+        .get_mut(offset..offset + 8)
+        .ok_or_else(|| anyhow!("swap 指令数据长度不足，无法写入金额"))?;
+    target.copy_from_slice(&value.to_le_bytes());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::instruction::AccountMeta;
+
+    fn instruction(program_id: Pubkey, accounts: Vec<AccountMeta>, data: Vec<u8>) -> Instruction {
+        Instruction {
+            program_id,
+            accounts,
+            data,
+        }
+    }
+
+    fn whirlpool_swap_data(amount: u64, threshold: u64, exact_in: bool) -> Vec<u8> {
+        let mut data = ANCHOR_SWAP_V2.to_vec();
+        data.extend_from_slice(&amount.to_le_bytes());
+        data.extend_from_slice(&threshold.to_le_bytes());
+        data.extend_from_slice(&0u128.to_le_bytes());
+        data.push(exact_in as u8);
+        data.push(1);
+        data
+    }
+
+    #[test]
+    fn whirlpool_exact_in_swap_is_rewritten() {
+        let authority = Pubkey::new_unique();
+        let mut ix = instruction(
+            ORCA_WHIRLPOOL_PROGRAM_ID,
+            vec![
+                AccountMeta::new_readonly(Pubkey::new_unique(), false),
+                AccountMeta::new_readonly(authority, true),
+            ],
+            whirlpool_swap_data(1_000, 990, true),
+        );
+        let kind = SwapKind::classify(&ix).expect("whirlpool swap");
+        assert_eq!(kind.label(), "Whirlpool");
+        assert_eq!(kind.authority(&ix), Some(authority));
+        assert_eq!(kind.read_in_amount(&ix.data), Some(1_000));
+        assert_eq!(kind.read_quoted_out_amount(&ix.data), Some(990));
+
+        kind.update_in_amount(&mut ix.data, 500).unwrap();
+        kind.update_quoted_out_amount(&mut ix.data, 495).unwrap();
+        assert_eq!(kind.read_in_amount(&ix.data), Some(500));
+        assert_eq!(kind.read_quoted_out_amount(&ix.data), Some(495));
+
+        let exact_out = instruction(
+            ORCA_WHIRLPOOL_PROGRAM_ID,
+            Vec::new(),
+            whirlpool_swap_data(1_000, 990, false),
+        );
+        assert!(SwapKind::classify(&exact_out).is_none());
+    }
+
+    #[test]
+    fn humidifi_swap_is_not_copyable() {
+        let ix = instruction(
+            HUMIDIFI_PROGRAM_ID,
+            vec![AccountMeta::new(Pubkey::new_unique(), true)],
+            vec![0xAB; 24],
+        );
+        assert!(SwapKind::classify(&ix).is_none());
+        assert!(!copy_program_ids().contains(&HUMIDIFI_PROGRAM_ID));
+    }
+
+    fn dflow_swap2_data() -> Vec<u8> {
+        let mut data = DFLOW_SWAPS[3].0.to_vec();
+        data.extend_from_slice(&3u32.to_le_bytes());
+        // SolFiV2Swap { amount: 1_000, flags }
+        data.push(29);
+        data.extend_from_slice(&1_000u64.to_le_bytes());
+        data.push(0);
+        // HumidiFiSwap { amount: 400, swap_id, flags }
+        data.push(23);
+        data.extend_from_slice(&400u64.to_le_bytes());
+        data.extend_from_slice(&7u64.to_le_bytes());
+        data.push(0);
+        // TransferFee { amount: 5 }
+        data.push(35);
+        data.extend_from_slice(&5u64.to_le_bytes());
+        data.extend_from_slice(&1_020u64.to_le_bytes());
+        data.extend_from_slice(&50u16.to_le_bytes());
+        data.extend_from_slice(&0u16.to_le_bytes());
+        data.push(0);
+        data
+    }
+
+    #[test]
+    fn dflow_actions_are_scaled_together() {
+        let authority = Pubkey::new_unique();
+        let mut accounts: Vec<AccountMeta> = (0..6)
+            .map(|_| AccountMeta::new_readonly(Pubkey::new_unique(), false))
+            .collect();
+        accounts[DFLOW_AUTHORITY_INDEX] = AccountMeta::new_readonly(authority, true);
+        let mut ix = instruction(DFLOW_PROGRAM_ID, accounts, dflow_swap2_data());
+
+        let kind = SwapKind::classify(&ix).expect("dflow swap2");
+        assert_eq!(kind.authority(&ix), Some(authority));
+        assert_eq!(kind.read_in_amount(&ix.data), Some(1_000));
+        assert_eq!(kind.read_quoted_out_amount(&ix.data), Some(1_020));
+
+        kind.update_in_amount(&mut ix.data, 500).unwrap();
+        let layout = DFlowLayout::parse(&ix.data, true).unwrap();
+        let amounts: Vec<u64> = layout
+            .amounts
+            .iter()
+            .map(|offset| read_u64_at(&ix.data, *offset).unwrap())
+            .collect();
+        assert_eq!(amounts, vec![500, 200]);
+
+        kind.update_quoted_out_amount(&mut ix.data, 510).unwrap();
+        assert_eq!(kind.read_quoted_out_amount(&ix.data), Some(510));
+    }
+
+    #[test]
+    fn dflow_layout_rejects_trailing_bytes() {
+        let mut data = dflow_swap2_data();
+        data.push(0);
+        assert!(DFlowLayout::parse(&data, true).is_none());
+    }
+}
@@ -22,10 +22,11 @@ use yellowstone_grpc_proto::solana::storage::confirmed_block;
 use crate::cache::cached_associated_token_address;
 use crate::engine::ComputeUnitPriceMode;
 use crate::instructions::jupiter::parser::PROGRAM_ID as PARSER_PROGRAM_ID;
-use crate::instructions::jupiter::parser::{RouteKind, RouteV2Accounts};
+use crate::instructions::jupiter::parser::RouteKind;
 use crate::instructions::jupiter::types::JUPITER_V6_PROGRAM_ID;
 
 use super::constants::{ASSOCIATED_TOKEN_PROGRAM_ID, COMPUTE_BUDGET_PROGRAM_ID, SYSTEM_PROGRAM_ID};
+use super::swap::SwapKind;
 
 #[derive(Clone, Debug)]
 pub(crate) struct RouteContext {
@@ -34,34 +35,17 @@ pub(crate) struct RouteContext {
 }
 
 impl RouteContext {
+    /// 从任意可复制的 swap 指令（Jupiter / 直连 DEX / DFlow）提取源钱包信息。
     pub fn from_instruction(instruction: &Instruction) -> Option<Self> {
         debug_assert_eq!(
             JUPITER_V6_PROGRAM_ID, PARSER_PROGRAM_ID,
             "Jupiter program id mismatch"
         );
-        if instruction.program_id != JUPITER_V6_PROGRAM_ID {
-            return None;
-        }
-        match crate::instructions::jupiter::parser::classify(&instruction.data) {
-            RouteKind::RouteV2 | RouteKind::SharedRouteV2 | RouteKind::ExactRouteV2 => {
-                let parsed = RouteV2Accounts::parse(instruction)?;
-                Some(Self {
-                    authority: parsed.user_transfer_authority,
-                    user_source_token_account: Some(parsed.user_source_token_account),
-                })
-            }
-            RouteKind::Route => {
-                let accounts = &instruction.accounts;
-                if accounts.len() < 3 {
-                    return None;
-                }
-                Some(Self {
-                    authority: accounts[1].pubkey,
-                    user_source_token_account: Some(accounts[2].pubkey),
-                })
-            }
-            RouteKind::Other => None,
-        }
+        let kind = SwapKind::classify(instruction)?;
+        Some(Self {
+            authority: kind.authority(instruction)?,
+            user_source_token_account: kind.source_token_account(instruction),
+        })
     }
 }
 
@@ -6,7 +6,6 @@ use std::time::{Duration, Instant};
 
 use anyhow::{Context, Result, anyhow};
 use bincode::serde::decode_from_slice;
-use futures::StreamExt;
 use solana_client::nonblocking::rpc_client::RpcClient;
 use solana_sdk::instruction::Instruction;
 use solana_sdk::native_token::LAMPORTS_PER_SOL;
@@ -14,27 +13,28 @@ use solana_sdk::pubkey::Pubkey;
 use solana_sdk::signature::Signature;
 use solana_sdk::transaction::VersionedTransaction;
 use solana_system_interface::instruction::SystemInstruction;
+use tokio::sync::mpsc;
 use tracing::{debug, error, info, warn};
-use yellowstone_grpc_proto::tonic::metadata::AsciiMetadataValue;
 
+use crate::api::jupiter::{JupiterApiClient, QuoteRequest, QuoteResponse, SwapInstructionsRequest};
 use crate::config::{
     CopyDispatchConfig, CopyDispatchMode, CopySourceKind, CopyWalletConfig, LanderSettings,
 };
 use crate::engine::landing::ExecutionPlan;
 use crate::engine::landing::assembler::{
-    DefaultLandingAssembler, LandingAssembler, LandingAssemblyContext,
+    DefaultLandingAssembler, LandingAssembler, LandingAssemblyContext, LandingPlanEntry,
 };
 use crate::engine::landing::profile::{
     ComputeUnitPriceStrategy, GuardBudgetKind, LanderKind, LandingProfile, TipStrategy,
 };
 use crate::engine::{
     ComputeUnitPriceMode, DispatchStrategy, EngineIdentity, JitoTipPlan, LighthouseRuntime,
-    MultiLegInstructions, SwapInstructionsVariant, SwapOpportunity, TransactionBuilder,
+    MultiLegInstructions, RouteKey, SwapInstructionsVariant, SwapOpportunity, TransactionBuilder,
     TxVariantPlanner,
 };
-use crate::instructions::jupiter::types::JUPITER_V6_PROGRAM_ID;
 use crate::lander::{Deadline, LanderFactory, LanderStack, LanderVariant};
-use crate::monitoring::events;
+use crate::monitoring::ledger::{self, TradeDraft};
+use crate::monitoring::{events, now_secs};
 use crate::network::IpAllocator;
 use crate::strategy::types::TradePair;
 use crate::wallet::WalletStateManager;
@@ -42,16 +42,17 @@ use flume::{self, Receiver, Sender, TrySendError};
 use parking_lot::Mutex as ParkingMutex;
 
 use super::constants::{MAX_SEEN_SIGNATURES, SYSTEM_PROGRAM_ID};
+use super::positions::{CopyPosition, PositionBook, stop_loss_triggered};
+use super::risk::{CopyRiskLimits, CopySizing, RiskRejection, fetch_realized_delta};
+use super::swap::{SwapKind, copy_program_ids};
 use super::transaction::filter_transaction;
 use super::transaction::{
     RouteContext, TransactionLoadedAddresses, TransactionTokenBalances, apply_replacements,
     build_create_ata_instruction, collect_instruction_signers, decode_versioned_transaction,
     derive_associated_token_address, extract_compute_unit_limit, instructions_from_message,
-    lookup_addresses, message_required_signatures, read_route_in_amount,
-    read_route_quoted_out_amount, resolve_lookup_accounts, split_compute_budget,
-    update_route_in_amount, update_route_quoted_out_amount,
+    lookup_addresses, message_required_signatures, resolve_lookup_accounts, split_compute_budget,
 };
-use crate::network::yellowstone::{YellowstoneTransactionClient, parse_transaction_update};
+use crate::network::yellowstone::WalletTransaction;
 pub(crate) struct CopyWalletRunner {
     wallet: CopyWalletConfig,
     dispatch: CopyDispatchConfig,
@@ -69,6 +70,10 @@ pub(crate) struct CopyWalletRunner {
     seen_signatures: tokio::sync::Mutex<SeenSignatures>,
     wallet_state: Arc<WalletStateManager>,
     lighthouse: Arc<tokio::sync::Mutex<LighthouseRuntime>>,
+    sizing: CopySizing,
+    risk: Arc<CopyRiskLimits>,
+    positions: Option<Arc<PositionBook>>,
+    exit_api: Option<JupiterApiClient>,
 }
 
 struct CopyTask {
@@ -132,6 +137,8 @@ impl TipDebugInfo {
 const BASE_GUARD_LAMPORTS: u64 = 5_000;
 const TEMP_WALLET_TIP_DEDUCTION_LAMPORTS: u64 = LAMPORTS_PER_SOL / 1_000;
 const MIN_SOURCE_TIP_LAMPORTS: u64 = 1_000;
+const REALIZED_PROBE_DELAY: Duration = Duration::from_secs(5);
+const REALIZED_PROBE_ATTEMPTS: usize = 6;
 
 fn compute_prioritization_fee(limit: u32, price: Option<u64>) -> u64 {
     match price {
@@ -146,6 +153,93 @@ fn compute_prioritization_fee(limit: u32, price: Option<u64>) -> u64 {
     }
 }
 
+/// 按 `numerator / denominator` 等比缩放金额（u128 中间值，结果饱和到 u64）。
+fn scale_amount(value: u64, numerator: u64, denominator: u64) -> u64 {
+    let scaled = u128::from(value) * u128::from(numerator) / u128::from(denominator.max(1));
+    scaled.min(u128::from(u64::MAX)) as u64
+}
+
+fn swap_legs(instructions: &[Instruction]) -> Vec<(usize, SwapKind)> {
+    instructions
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, ix)| SwapKind::classify(ix).map(|kind| (idx, kind)))
+        .collect()
+}
+
+/// swap 链首腿输入与末腿报价输出。
+fn chain_amounts(instructions: &[Instruction], legs: &[(usize, SwapKind)]) -> Option<(u64, u64)> {
+    let &(first_idx, first) = legs.first()?;
+    let &(last_idx, last) = legs.last()?;
+    let current_in = first.read_in_amount(&instructions[first_idx].data)?;
+    let current_out = last.read_quoted_out_amount(&instructions[last_idx].data)?;
+    Some((current_in, current_out))
+}
+
+/// 首腿输入改为 `target_in`、末腿输出改为 `target_out`，中间各腿按 `target_in / current_in` 缩放。
+fn rescale_swap_legs(
+    instructions: &mut [Instruction],
+    legs: &[(usize, SwapKind)],
+    current_in: u64,
+    target_in: u64,
+    target_out: u64,
+) -> Result<()> {
+    let (Some(&(first_idx, _)), Some(&(last_idx, _))) = (legs.first(), legs.last()) else {
+        return Ok(());
+    };
+    for &(idx, kind) in legs {
+        let data = &mut instructions[idx].data;
+        let leg_in = if idx == first_idx {
+            Some(target_in)
+        } else {
+            kind.read_in_amount(data)
+                .map(|amount| scale_amount(amount, target_in, current_in))
+        };
+        let leg_out = if idx == last_idx {
+            Some(target_out)
+        } else {
+            kind.read_quoted_out_amount(data)
+                .map(|amount| scale_amount(amount, target_in, current_in))
+        };
+        match (leg_in, leg_out) {
+            (Some(leg_in), Some(leg_out)) => kind
+                .update_in_amount(data, leg_in)
+                .and_then(|_| kind.update_quoted_out_amount(data, leg_out))
+                .map_err(|err| anyhow!("{}: {err}", kind.label()))?,
+            _ => return Err(anyhow!("{} 指令金额无法解析", kind.label())),
+        }
+    }
+    Ok(())
+}
+
+/// 轮询落地交易中 `owner` 持有的 `mint` 余额变化，交易未确认时重试。
+async fn probe_landed_delta(
+    rpc: &RpcClient,
+    signature: &Signature,
+    owner: &Pubkey,
+    mint: &Pubkey,
+    wallet: &Pubkey,
+) -> Option<i128> {
+    for _ in 0..REALIZED_PROBE_ATTEMPTS {
+        tokio::time::sleep(REALIZED_PROBE_DELAY).await;
+        match fetch_realized_delta(rpc, signature, owner, mint).await {
+            Ok(Some(delta)) => return Some(delta),
+            Ok(None) => continue,
+            Err(err) => {
+                debug!(
+                    target: "strategy::copy",
+                    wallet = %wallet,
+                    signature = %signature,
+                    error = %err,
+                    "查询复制交易实际余额变化失败"
+                );
+                return None;
+            }
+        }
+    }
+    None
+}
+
 impl CopyTaskQueue {
     fn new(capacity: usize, wallet: Pubkey) -> Self {
         let (sender, receiver) = flume::bounded(capacity);
@@ -252,6 +346,10 @@ impl BaseMintInfo {
 
 enum AmountAdjustment {
     NotNeeded,
+    Unchanged {
+        mint: Pubkey,
+        amount_in: u64,
+    },
     Applied {
         original_in: u64,
         adjusted_in: u64,
@@ -261,6 +359,22 @@ enum AmountAdjustment {
         available: u64,
     },
     Skip,
+    Rejected(RiskRejection),
+}
+
+/// 源钱包减仓时需要跟随卖出的持仓。
+#[derive(Clone, Copy, Debug)]
+struct ExitPlan {
+    mint: Pubkey,
+    base_mint: Pubkey,
+    amount: u64,
+    held: u64,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum PositionChange {
+    Entry { base_mint: Pubkey, cost: u64 },
+    Exit { base_mint: Pubkey },
 }
 
 impl CopyWalletRunner {
@@ -279,6 +393,8 @@ impl CopyWalletRunner {
         wallet_refresh_interval: Option<Duration>,
         dry_run: bool,
         lighthouse: Arc<tokio::sync::Mutex<LighthouseRuntime>>,
+        positions: Option<Arc<PositionBook>>,
+        exit_api: Option<JupiterApiClient>,
     ) -> Result<Self> {
         let wallet_pubkey = Pubkey::from_str(wallet.address.trim())
             .map_err(|err| anyhow!("wallet address `{}` 解析失败: {err}", wallet.address))?;
@@ -316,6 +432,7 @@ impl CopyWalletRunner {
                 ip_allocator,
             )
             .map_err(|err| anyhow!(err))?;
+        crate::control::runtime_control().register_landers(&lander_stack);
         let lander_stack = Arc::new(lander_stack);
 
         let cu_limit_multiplier = if wallet.cu_limit_multiplier <= 0.0 {
@@ -330,6 +447,28 @@ impl CopyWalletRunner {
             wallet.cu_limit_multiplier
         };
 
+        let sizing = CopySizing::from_config(&wallet.sizing)
+            .map_err(|err| anyhow!("wallet `{}` {err}", wallet.address))?;
+        let risk = CopyRiskLimits::from_config(&wallet.risk)
+            .map_err(|err| anyhow!("wallet `{}` {err}", wallet.address))?;
+
+        let exits = &wallet.exits;
+        if exits.enable {
+            if !(0.0..100.0).contains(&exits.stop_loss_pct) {
+                return Err(anyhow!(
+                    "wallet `{}` exits.stop_loss_pct 必须位于 [0, 100)",
+                    wallet.address
+                ));
+            }
+            if (exits.max_hold_secs > 0 || exits.stop_loss_pct > 0.0) && exit_api.is_none() {
+                return Err(anyhow!(
+                    "wallet `{}` 启用定时/止损卖出时必须配置 exits.api_quote_base",
+                    wallet.address
+                ));
+            }
+        }
+        let positions = positions.filter(|_| exits.enable);
+
         let wallet_state =
             WalletStateManager::new(rpc_client.clone(), identity.pubkey, wallet_refresh_interval)
                 .await
@@ -352,48 +491,45 @@ impl CopyWalletRunner {
             seen_signatures: tokio::sync::Mutex::new(SeenSignatures::new(MAX_SEEN_SIGNATURES)),
             wallet_state,
             lighthouse,
+            sizing,
+            risk: Arc::new(risk),
+            positions,
+            exit_api,
         })
     }
 
-    pub async fn run(self) -> Result<()> {
+    /// `transactions` 为共享 Yellowstone 订阅分发给本钱包的交易，gRPC 来源必须提供。
+    pub async fn run(self, transactions: Option<mpsc::Receiver<WalletTransaction>>) -> Result<()> {
         match self.wallet.source.kind {
             CopySourceKind::Rpc => self.run_rpc().await,
-            CopySourceKind::Grpc => self.run_grpc().await,
+            CopySourceKind::Grpc => {
+                let transactions = transactions.ok_or_else(|| {
+                    anyhow!("wallet {} 缺少 Yellowstone 订阅", self.wallet.address)
+                })?;
+                self.run_grpc(transactions).await
+            }
         }
     }
 
-    async fn run_grpc(self) -> Result<()> {
+    async fn run_grpc(self, transactions: mpsc::Receiver<WalletTransaction>) -> Result<()> {
         let runner = Arc::new(self);
-        CopyWalletRunner::run_grpc_internal(runner).await
+        CopyWalletRunner::spawn_exit_monitor(Arc::clone(&runner));
+        CopyWalletRunner::run_grpc_internal(runner, transactions).await
     }
 
-    async fn run_grpc_internal(self: Arc<Self>) -> Result<()> {
+    async fn run_grpc_internal(
+        self: Arc<Self>,
+        mut transactions: mpsc::Receiver<WalletTransaction>,
+    ) -> Result<()> {
         let grpc = &self.wallet.source.grpc;
-        let endpoint = if !grpc.yellowstone_grpc_url.trim().is_empty() {
-            grpc.yellowstone_grpc_url.trim().to_string()
-        } else {
-            return Err(anyhow!(
-                "wallet {} 未配置 yellowstone_grpc_url",
-                self.wallet.address
-            ));
-        };
-
-        let token = grpc
-            .yellowstone_grpc_token
-            .trim()
-            .parse::<AsciiMetadataValue>()
-            .ok();
-
-        let mut client = YellowstoneTransactionClient::connect(endpoint.clone(), token).await?;
-
         let mut include_ids: HashSet<Pubkey> = HashSet::new();
         if grpc.include_program_ids.is_empty() {
-            include_ids.insert(JUPITER_V6_PROGRAM_ID);
+            include_ids.extend(copy_program_ids());
         } else {
             for value in &grpc.include_program_ids {
                 let trimmed = value.trim();
                 if trimmed.is_empty() {
-                    include_ids.insert(JUPITER_V6_PROGRAM_ID);
+                    include_ids.extend(copy_program_ids());
                 } else if let Ok(pk) = Pubkey::from_str(trimmed) {
                     include_ids.insert(pk);
                 }
@@ -408,17 +544,12 @@ impl CopyWalletRunner {
         info!(
             target: "strategy::copy",
             wallet = %self.wallet_pubkey,
-            endpoint = %endpoint,
+            endpoint = %grpc.yellowstone_grpc_url.trim(),
             include = ?include_ids,
             exclude = ?exclude_ids,
             "Yellowstone gRPC 订阅启动"
         );
 
-        let mut stream = client
-            .subscribe_transactions(self.wallet_pubkey)
-            .await
-            .context("订阅 Yellowstone gRPC 失败")?;
-
         let fanout_count = self.dispatch.fanout_count.max(1);
         let replay_interval = Duration::from_millis(self.dispatch.replay_interval_ms);
         let max_inflight = usize::try_from(self.dispatch.max_inflight.max(1)).unwrap_or(1);
@@ -474,108 +605,121 @@ impl CopyWalletRunner {
 
         let task_queue = queue_resources.as_ref().map(|(queue, _)| Arc::clone(queue));
 
-        while let Some(update) = stream.next().await.transpose()? {
-            if let Some(info) = parse_transaction_update(&update) {
-                let signature = match Signature::try_from(info.signature.as_slice()) {
-                    Ok(sig) => sig,
+        while let Some(WalletTransaction {
+            info,
+            slot,
+            backfilled,
+            ..
+        }) = transactions.recv().await
+        {
+            let signature = match Signature::try_from(info.signature.as_slice()) {
+                Ok(sig) => sig,
+                Err(err) => {
+                    warn!(
+                        target: "strategy::copy",
+                        wallet = %self.wallet_pubkey,
+                        error = %err,
+                        "签名解析失败，跳过"
+                    );
+                    continue;
+                }
+            };
+            if !self.should_process(&signature).await {
+                continue;
+            }
+            if backfilled {
+                debug!(
+                    target: "strategy::copy",
+                    wallet = %self.wallet_pubkey,
+                    signature = %signature,
+                    slot,
+                    "处理 RPC 回补的源交易"
+                );
+            }
+
+            if let Some(proto_tx) = info.transaction.as_ref() {
+                let meta = match info.meta.as_ref() {
+                    Some(meta) => meta,
+                    None => {
+                        debug!(
+                            target: "strategy::copy",
+                            wallet = %self.wallet_pubkey,
+                            signature = %signature,
+                            "Yellowstone meta 缺失，无法复原账户"
+                        );
+                        continue;
+                    }
+                };
+                let loaded_addresses = match TransactionLoadedAddresses::try_from(meta) {
+                    Ok(addresses) => addresses,
                     Err(err) => {
                         warn!(
                             target: "strategy::copy",
                             wallet = %self.wallet_pubkey,
+                            signature = %signature,
                             error = %err,
-                            "签名解析失败，跳过"
+                            "解析 Yellowstone loaded addresses 失败"
                         );
                         continue;
                     }
                 };
-                if !self.should_process(&signature).await {
-                    continue;
-                }
-
-                if let Some(proto_tx) = info.transaction.as_ref() {
-                    let meta = match info.meta.as_ref() {
-                        Some(meta) => meta,
-                        None => {
-                            debug!(
-                                target: "strategy::copy",
-                                wallet = %self.wallet_pubkey,
-                                signature = %signature,
-                                "Yellowstone meta 缺失，无法复原账户"
-                            );
-                            continue;
-                        }
-                    };
-                    let loaded_addresses = match TransactionLoadedAddresses::try_from(meta) {
-                        Ok(addresses) => addresses,
-                        Err(err) => {
-                            warn!(
-                                target: "strategy::copy",
-                                wallet = %self.wallet_pubkey,
-                                signature = %signature,
-                                error = %err,
-                                "解析 Yellowstone loaded addresses 失败"
-                            );
-                            continue;
-                        }
-                    };
-                    let token_balances = match TransactionTokenBalances::try_from(meta) {
-                        Ok(balances) => balances,
-                        Err(err) => {
-                            warn!(
-                                target: "strategy::copy",
-                                wallet = %self.wallet_pubkey,
-                                signature = %signature,
-                                error = %err,
-                                "解析 Yellowstone token balance 失败"
-                            );
-                            continue;
-                        }
-                    };
-                    let versioned = decode_versioned_transaction(proto_tx)?;
-                    if !filter_transaction(
-                        &versioned,
-                        &include_ids,
-                        &exclude_ids,
-                        Some(&loaded_addresses),
-                    ) {
+                let token_balances = match TransactionTokenBalances::try_from(meta) {
+                    Ok(balances) => balances,
+                    Err(err) => {
+                        warn!(
+                            target: "strategy::copy",
+                            wallet = %self.wallet_pubkey,
+                            signature = %signature,
+                            error = %err,
+                            "解析 Yellowstone token balance 失败"
+                        );
                         continue;
                     }
-                    if replay_interval != Duration::ZERO {
-                        let now = Instant::now();
-                        if let Some(prev) = last_replay_at {
-                            let target = prev + replay_interval;
-                            if now < target {
-                                tokio::time::sleep(target - now).await;
-                            }
+                };
+                let versioned = decode_versioned_transaction(proto_tx)?;
+                if !filter_transaction(
+                    &versioned,
+                    &include_ids,
+                    &exclude_ids,
+                    Some(&loaded_addresses),
+                ) {
+                    continue;
+                }
+                if replay_interval != Duration::ZERO {
+                    let now = Instant::now();
+                    if let Some(prev) = last_replay_at {
+                        let target = prev + replay_interval;
+                        if now < target {
+                            tokio::time::sleep(target - now).await;
                         }
-                        last_replay_at = Some(Instant::now());
                     }
+                    last_replay_at = Some(Instant::now());
+                }
 
-                    let task = CopyTask {
-                        signature,
-                        transaction: versioned,
-                        token_balances: Some(token_balances),
-                        loaded_addresses: Some(loaded_addresses),
-                    };
+                let task = CopyTask {
+                    signature,
+                    transaction: versioned,
+                    token_balances: Some(token_balances),
+                    loaded_addresses: Some(loaded_addresses),
+                };
 
-                    if let Some(queue) = task_queue.as_ref() {
-                        let dropped = queue.push(task).await;
-                        if dropped {
-                            warn!(
-                                target: "strategy::copy",
-                                wallet = %self.wallet_pubkey,
-                                signature = %signature,
-                                "copy 队列已满，已丢弃最旧任务以保留最新交易"
-                            );
-                        }
-                    } else {
-                        CopyWalletRunner::spawn_replay_task(
-                            Arc::clone(&self),
-                            Arc::clone(&semaphore),
-                            task,
-                            fanout_count,
+                if let Some(queue) = task_queue.as_ref() {
+                    let dropped = queue.push(task).await;
+                    if dropped {
+                        warn!(
+                            target: "strategy::copy",
+                            wallet = %self.wallet_pubkey,
+                            signature = %signature,
+                            "copy 队列已满，已丢弃最旧任务以保留最新交易"
                         );
                     }
+                } else {
+                    CopyWalletRunner::spawn_replay_task(
+                        Arc::clone(&self),
+                        Arc::clone(&semaphore),
+                        task,
+                        fanout_count,
+                    );
                 }
             }
         }
@@ -675,6 +819,16 @@ impl CopyWalletRunner {
         loaded_addresses: Option<&TransactionLoadedAddresses>,
         fanout_count: u32,
     ) -> Result<()> {
+        // 暂停只停止跟单入场，已有持仓的退出检查照常运行。
+        if crate::control::strategy_paused("copy") {
+            debug!(
+                target: "strategy::copy",
+                wallet = %self.wallet_pubkey,
+                signature = %signature,
+                "copy 策略已通过控制接口暂停，跳过复制"
+            );
+            return Ok(());
+        }
         let mut tip_debug = TipDebugInfo::default();
         let required_signers = message_required_signatures(&transaction.message);
         if required_signers > 1 {
@@ -734,7 +888,7 @@ impl CopyWalletRunner {
                     target: "strategy::copy",
                     wallet = %self.wallet_pubkey,
                     signature = %signature,
-                    "未找到可复制的 Jupiter Route 指令"
+                    "未找到可复制的 swap 指令"
                 );
                 return Ok(());
             }
@@ -751,26 +905,59 @@ impl CopyWalletRunner {
             return Ok(());
         };
 
+        let exit_plan = self.detect_exit(&route_ctx.authority, token_balances);
+        let traded_mints: HashSet<Pubkey> = token_balances
+            .entries()
+            .filter(|entry| entry.owner == Some(route_ctx.authority))
+            .map(|entry| entry.mint)
+            .collect();
+        // 卖出跟随用于平掉已有持仓，不受 mint 黑白名单限制。
+        let mint_check = if exit_plan.is_some() {
+            Ok(())
+        } else {
+            self.risk.check_mints(&traded_mints)
+        };
+        if let Err(rejection) = mint_check {
+            events::copy_risk_rejected(&self.wallet_pubkey, signature, rejection.as_str());
+            debug!(
+                target: "strategy::copy",
+                wallet = %self.wallet_pubkey,
+                signature = %signature,
+                reason = %rejection,
+                "源交易涉及的 mint 未通过风控，跳过复制"
+            );
+            return Ok(());
+        }
+
         let replacement = self
             .build_replacement_plan(&route_ctx, &account_keys, token_balances)
             .await?;
 
         let mut compute_budget_instructions = Vec::new();
         let mut jupiter_instructions = Vec::new();
+        let mut venue_instructions = Vec::new();
         for ix in &instructions {
             if ix.program_id == super::constants::COMPUTE_BUDGET_PROGRAM_ID {
                 compute_budget_instructions.push(ix.clone());
             } else if ix.program_id == crate::instructions::jupiter::types::JUPITER_V6_PROGRAM_ID {
                 jupiter_instructions.push(ix.clone());
+            } else if SwapKind::classify(ix).is_some() {
+                venue_instructions.push(ix.clone());
             }
         }
+        // 同时存在时以 Jupiter Route 为准，直连 DEX / DFlow 指令仅在没有 Jupiter 时复制。
+        let mut swap_instructions = if jupiter_instructions.is_empty() {
+            venue_instructions
+        } else {
+            jupiter_instructions
+        };
 
-        if jupiter_instructions.is_empty() {
+        if swap_instructions.is_empty() {
             debug!(
                 target: "strategy::copy",
                 wallet = %self.wallet_pubkey,
                 signature = %signature,
-                "Jupiter 指令为空，跳过复制"
+                "swap 指令为空，跳过复制"
             );
             return Ok(());
         }
@@ -782,12 +969,26 @@ impl CopyWalletRunner {
             &account_keys,
         );
 
+        // 源钱包换入了 base 以外的 mint 即为买入；base → base 套利的中间 mint 净变化为 0。
+        let acquired_mint = base_info.and_then(|info| {
+            Self::detect_acquired_mint(&route_ctx.authority, token_balances, &info.mint)
+        });
+
         let mut amount_adjustment_summary = String::from("not_adjusted");
+        let mut committed_amount: Option<(Pubkey, u64)> = None;
 
-        match self
-            .adjust_route_amounts(base_info, &mut jupiter_instructions)
-            .await?
-        {
+        let adjustment = match exit_plan.as_ref() {
+            Some(exit) => self.adjust_exit_amounts(exit, &mut swap_instructions),
+            None => {
+                self.adjust_route_amounts(
+                    base_info,
+                    acquired_mint.is_some(),
+                    &mut swap_instructions,
+                )
+                .await?
+            }
+        };
+        match adjustment {
             AmountAdjustment::Skip => {
                 debug!(
                     target: "strategy::copy",
@@ -797,6 +998,17 @@ impl CopyWalletRunner {
                 );
                 return Ok(());
             }
+            AmountAdjustment::Rejected(rejection) => {
+                events::copy_risk_rejected(&self.wallet_pubkey, signature, rejection.as_str());
+                debug!(
+                    target: "strategy::copy",
+                    wallet = %self.wallet_pubkey,
+                    signature = %signature,
+                    reason = %rejection,
+                    "复制金额未通过风控，跳过复制"
+                );
+                return Ok(());
+            }
             AmountAdjustment::Applied {
                 original_in,
                 adjusted_in,
@@ -809,18 +1021,32 @@ impl CopyWalletRunner {
                     "applied mint={} original_in={} adjusted_in={} original_out={} adjusted_out={} available={}",
                     mint, original_in, adjusted_in, original_out, adjusted_out, available
                 );
+                committed_amount = Some((mint, adjusted_in));
+            }
+            AmountAdjustment::Unchanged { mint, amount_in } => {
+                committed_amount = Some((mint, amount_in));
             }
             AmountAdjustment::NotNeeded => {}
         }
 
-        let base_mint = base_info.map(|info| info.mint);
-
-        let original_accounts_snapshot = describe_jupiter_accounts(&jupiter_instructions);
-        apply_replacements(&mut jupiter_instructions, &replacement.mapping);
-        let replaced_accounts_snapshot = describe_jupiter_accounts(&jupiter_instructions);
+        if exit_plan.is_some() {
+            // 卖出不计入风控名义金额。
+            committed_amount = None;
+        }
+        let entry_mint = committed_amount
+            .and(acquired_mint)
+            .filter(|_| self.positions.is_some());
+        let base_mint = exit_plan
+            .as_ref()
+            .map(|exit| exit.base_mint)
+            .or(base_info.map(|info| info.mint));
+
+        let original_accounts_snapshot = describe_swap_accounts(&swap_instructions);
+        apply_replacements(&mut swap_instructions, &replacement.mapping);
+        let replaced_accounts_snapshot = describe_swap_accounts(&swap_instructions);
 
         let mut patched_instructions =
-            Vec::with_capacity(compute_budget_instructions.len() + jupiter_instructions.len() + 1);
+            Vec::with_capacity(compute_budget_instructions.len() + swap_instructions.len() + 1);
         patched_instructions.extend(compute_budget_instructions);
 
         let mut scheduled_accounts = HashSet::new();
@@ -852,7 +1078,7 @@ impl CopyWalletRunner {
             patched_instructions.push(ix);
         }
 
-        patched_instructions.extend(jupiter_instructions);
+        patched_instructions.extend(swap_instructions);
         let patched_signers = collect_instruction_signers(&patched_instructions);
         let patched_signers_snapshot: Vec<String> =
             patched_signers.iter().map(|pk| pk.to_string()).collect();
@@ -942,9 +1168,13 @@ impl CopyWalletRunner {
             .map(|(mint, amount)| (Some(mint), amount))
             .unwrap_or((None, 0));
 
-        let profit_lamports = base_info
-            .map(|info| info.delta().max(0) as u64)
-            .unwrap_or_default();
+        let profit_lamports = if exit_plan.is_some() {
+            0
+        } else {
+            base_info
+                .map(|info| info.delta().max(0) as u64)
+                .unwrap_or_default()
+        };
         let guard_base_mint = base_mint.or(source_mint);
         let (plan_base_mint, base_guard_lamports) = match guard_base_mint {
             Some(mint) => (mint, BASE_GUARD_LAMPORTS),
@@ -978,24 +1208,7 @@ impl CopyWalletRunner {
         );
 
         let profiles = self.build_landing_profiles(sampled_price, jito_tip_plan.clone());
-        let assembler = DefaultLandingAssembler::new();
-        let mut entries = Vec::with_capacity(profiles.len());
-        {
-            let mut lighthouse = self.lighthouse.lock().await;
-            for profile in profiles {
-                let mut context = LandingAssemblyContext::new(
-                    &self.identity,
-                    &self.tx_builder,
-                    None,
-                    &mut *lighthouse,
-                );
-                let entry = assembler
-                    .assemble_landing(&mut context, &profile, &execution_plan)
-                    .await
-                    .map_err(|err| anyhow!("复制交易装配失败: {err}"))?;
-                entries.push(entry);
-            }
-        }
+        let entries = self.assemble_entries(&execution_plan, profiles).await?;
 
         if entries.is_empty() {
             warn!(
@@ -1061,14 +1274,59 @@ impl CopyWalletRunner {
             );
         }
 
+        let ledger_draft = ledger::ledger_enabled().then(|| {
+            TradeDraft::from_plan("copy", &execution_plan, entries.first())
+                .with_route(
+                    prepared
+                        .first()
+                        .and_then(|tx| RouteKey::from_instructions(tx.instructions.iter())),
+                )
+                .with_signature(copy_signature.clone())
+        });
+
         let deadline = Deadline::from_instant(deadline_instant);
-        match self
+        let outcome = self
             .lander_stack
             .submit_plan(&dispatch_plan, deadline, "copy")
-            .await
-        {
+            .await;
+        if let Some(draft) = ledger_draft {
+            ledger::record_dispatch(draft, &outcome);
+        }
+        match outcome {
             Ok(receipt) => {
                 events::copy_transaction_dispatched(&self.wallet_pubkey, signature, 0);
+                let landed = receipt
+                    .signature
+                    .as_deref()
+                    .and_then(|value| Signature::from_str(value).ok());
+                if let Some((mint, amount_in)) = committed_amount {
+                    self.risk.record_dispatch(&mint, amount_in);
+                    if let Some(landed) = landed {
+                        // 买入只是把 base 换成持仓成本，盈亏在平仓时结算
+                        if acquired_mint.is_none() {
+                            self.track_realized(landed, mint);
+                        }
+                        if let Some(acquired) = entry_mint {
+                            self.track_position(
+                                landed,
+                                acquired,
+                                PositionChange::Entry {
+                                    base_mint: mint,
+                                    cost: amount_in,
+                                },
+                            );
+                        }
+                    }
+                }
+                if let (Some(exit), Some(landed)) = (exit_plan, landed) {
+                    self.track_position(
+                        landed,
+                        exit.mint,
+                        PositionChange::Exit {
+                            base_mint: exit.base_mint,
+                        },
+                    );
+                }
                 info!(
                     target: "strategy::copy",
                     wallet = %self.wallet_pubkey,
@@ -1310,6 +1568,7 @@ impl CopyWalletRunner {
     async fn adjust_route_amounts(
         &self,
         base: Option<BaseMintInfo>,
+        buy: bool,
         instructions: &mut [Instruction],
     ) -> Result<AmountAdjustment> {
         let Some(base) = base else {
@@ -1349,6 +1608,14 @@ impl CopyWalletRunner {
             return Ok(AmountAdjustment::Skip);
         }
 
+        if instructions
+            .iter()
+            .filter_map(SwapKind::classify)
+            .any(|kind| !kind.is_jupiter())
+        {
+            return self.adjust_swap_chain(&base, available, buy, instructions);
+        }
+
         let mut target_in: Option<u64> = None;
         let mut target_out: Option<u64> = None;
         let mut original_in: Option<u64> = None;
@@ -1357,50 +1624,25 @@ impl CopyWalletRunner {
         let mut encountered = false;
 
         for instruction in instructions.iter_mut() {
-            if instruction.program_id != JUPITER_V6_PROGRAM_ID {
+            let Some(kind) = SwapKind::classify(instruction) else {
                 continue;
-            }
-
-            let kind = crate::instructions::jupiter::parser::classify(&instruction.data);
-            let current_in = match read_route_in_amount(kind, &instruction.data) {
+            };
+            let current_in = match kind.read_in_amount(&instruction.data) {
                 Some(value) => value,
                 None => continue,
             };
-            let current_out = match read_route_quoted_out_amount(kind, &instruction.data) {
+            let current_out = match kind.read_quoted_out_amount(&instruction.data) {
                 Some(value) => value,
                 None => continue,
             };
 
             encountered = true;
             if target_in.is_none() {
-                if current_out < current_in {
-                    debug!(
-                        target: "strategy::copy",
-                        mint = %base.mint,
-                        current_in,
-                        current_out,
-                        "copy 指令净收益为负，跳过复制"
-                    );
-                    return Ok(AmountAdjustment::Skip);
-                }
-
-                let profit = current_out - current_in;
-                let capped_in = current_in.min(available);
-                if capped_in == 0 {
-                    return Ok(AmountAdjustment::Skip);
-                }
-                let desired_out = match capped_in.checked_add(profit) {
-                    Some(value) => value,
-                    None => {
-                        debug!(
-                            target: "strategy::copy",
-                            mint = %base.mint,
-                            profit,
-                            "base mint 裁剪导致金额溢出，跳过复制"
-                        );
-                        return Ok(AmountAdjustment::Skip);
-                    }
-                };
+                let (capped_in, desired_out) =
+                    match self.size_route(&base, current_in, current_out, available, buy) {
+                        Ok(target) => target,
+                        Err(adjustment) => return Ok(adjustment),
+                    };
                 target_in = Some(capped_in);
                 target_out = Some(desired_out);
                 original_in = Some(current_in);
@@ -1411,7 +1653,7 @@ impl CopyWalletRunner {
             let desired_out = target_out.expect("target out must exist");
 
             if desired_in != current_in {
-                if let Err(err) = update_route_in_amount(kind, &mut instruction.data, desired_in) {
+                if let Err(err) = kind.update_in_amount(&mut instruction.data, desired_in) {
                     warn!(
                         target: "strategy::copy",
                         mint = %base.mint,
@@ -1423,8 +1665,7 @@ impl CopyWalletRunner {
                 updated = true;
             }
             if desired_out != current_out {
-                if let Err(err) =
-                    update_route_quoted_out_amount(kind, &mut instruction.data, desired_out)
+                if let Err(err) = kind.update_quoted_out_amount(&mut instruction.data, desired_out)
                 {
                     warn!(
                         target: "strategy::copy",
@@ -1442,7 +1683,13 @@ impl CopyWalletRunner {
             return Ok(AmountAdjustment::NotNeeded);
         }
         if !updated {
-            return Ok(AmountAdjustment::NotNeeded);
+            return Ok(match target_in {
+                Some(amount_in) => AmountAdjustment::Unchanged {
+                    mint: base.mint,
+                    amount_in,
+                },
+                None => AmountAdjustment::NotNeeded,
+            });
         }
 
         if let Some(adjusted_in) = target_in {
@@ -1462,6 +1709,479 @@ impl CopyWalletRunner {
         Ok(AmountAdjustment::NotNeeded)
     }
 
+    /// 按 sizing 与风控计算复制的输入金额与期望输出。
+    ///
+    /// 套利（base → base）按净收益推算输出；买入（base → 其他 mint）两侧单位不同，输出按
+    /// `capped_in / current_in` 等比缩放。
+    fn size_route(
+        &self,
+        base: &BaseMintInfo,
+        current_in: u64,
+        current_out: u64,
+        available: u64,
+        buy: bool,
+    ) -> std::result::Result<(u64, u64), AmountAdjustment> {
+        if !buy && current_out < current_in {
+            debug!(
+                target: "strategy::copy",
+                mint = %base.mint,
+                current_in,
+                current_out,
+                "copy 指令净收益为负，跳过复制"
+            );
+            return Err(AmountAdjustment::Skip);
+        }
+
+        let profit = current_out - current_in;
+        let Some(sized_in) = self.sizing.target_amount(&base.mint, current_in, available) else {
+            debug!(
+                target: "strategy::copy",
+                mint = %base.mint,
+                "sizing 未配置该 base mint 的复制金额，跳过复制"
+            );
+            return Err(AmountAdjustment::Skip);
+        };
+        let capped_in = self
+            .risk
+            .clamp_amount(&base.mint, sized_in.min(available))
+            .map_err(AmountAdjustment::Rejected)?;
+        if capped_in == 0 {
+            return Err(AmountAdjustment::Skip);
+        }
+        if buy {
+            let desired_out = scale_amount(current_out, capped_in, current_in);
+            if desired_out == 0 {
+                return Err(AmountAdjustment::Skip);
+            }
+            return Ok((capped_in, desired_out));
+        }
+        let profit = if self.sizing.scales_quoted_out() {
+            scale_amount(profit, capped_in, current_in)
+        } else {
+            profit
+        };
+        match capped_in.checked_add(profit) {
+            Some(desired_out) => Ok((capped_in, desired_out)),
+            None => {
+                debug!(
+                    target: "strategy::copy",
+                    mint = %base.mint,
+                    profit,
+                    "base mint 裁剪导致金额溢出，跳过复制"
+                );
+                Err(AmountAdjustment::Skip)
+            }
+        }
+    }
+
+    /// 直连 DEX / DFlow 的 swap 链：首条指令输入 base，末条指令输出 base（买入时为标的），
+    /// 中间各腿按比例缩放。
+    fn adjust_swap_chain(
+        &self,
+        base: &BaseMintInfo,
+        available: u64,
+        buy: bool,
+        instructions: &mut [Instruction],
+    ) -> Result<AmountAdjustment> {
+        let legs = swap_legs(instructions);
+        if legs.is_empty() {
+            return Ok(AmountAdjustment::NotNeeded);
+        }
+        let Some((current_in, current_out)) = chain_amounts(instructions, &legs) else {
+            debug!(
+                target: "strategy::copy",
+                mint = %base.mint,
+                "swap 指令金额无法解析，跳过复制"
+            );
+            return Ok(AmountAdjustment::Skip);
+        };
+
+        let (target_in, target_out) =
+            match self.size_route(base, current_in, current_out, available, buy) {
+                Ok(target) => target,
+                Err(adjustment) => return Ok(adjustment),
+            };
+        if target_in == current_in && target_out == current_out {
+            return Ok(AmountAdjustment::Unchanged {
+                mint: base.mint,
+                amount_in: target_in,
+            });
+        }
+
+        if let Err(err) = rescale_swap_legs(instructions, &legs, current_in, target_in, target_out)
+        {
+            warn!(
+                target: "strategy::copy",
+                mint = %base.mint,
+                error = %err,
+                "调整 swap 指令金额失败，跳过此次复制"
+            );
+            return Ok(AmountAdjustment::Skip);
+        }
+
+        Ok(AmountAdjustment::Applied {
+            original_in: current_in,
+            adjusted_in: target_in,
+            original_out: current_out,
+            adjusted_out: target_out,
+            mint: base.mint,
+            available,
+        })
+    }
+
+    /// 卖出跟随：输入改为我方应卖出的数量，期望输出按源交易的成交比例缩放。
+    fn adjust_exit_amounts(
+        &self,
+        exit: &ExitPlan,
+        instructions: &mut [Instruction],
+    ) -> AmountAdjustment {
+        let legs = swap_legs(instructions);
+        let Some((current_in, current_out)) =
+            chain_amounts(instructions, &legs).filter(|(current_in, _)| *current_in > 0)
+        else {
+            debug!(
+                target: "strategy::copy",
+                mint = %exit.mint,
+                "卖出 swap 指令金额无法解析，跳过跟随"
+            );
+            return AmountAdjustment::Skip;
+        };
+
+        if exit.amount == current_in {
+            return AmountAdjustment::Unchanged {
+                mint: exit.mint,
+                amount_in: exit.amount,
+            };
+        }
+        let target_out = scale_amount(current_out, exit.amount, current_in);
+        if let Err(err) =
+            rescale_swap_legs(instructions, &legs, current_in, exit.amount, target_out)
+        {
+            warn!(
+                target: "strategy::copy",
+                mint = %exit.mint,
+                error = %err,
+                "调整卖出 swap 指令金额失败，跳过跟随"
+            );
+            return AmountAdjustment::Skip;
+        }
+
+        AmountAdjustment::Applied {
+            original_in: current_in,
+            adjusted_in: exit.amount,
+            original_out: current_out,
+            adjusted_out: target_out,
+            mint: exit.mint,
+            available: exit.held,
+        }
+    }
+
+    /// 源钱包减少了我方持有的 mint 时，返回应跟随卖出的数量。
+    fn detect_exit(
+        &self,
+        authority: &Pubkey,
+        balances: &TransactionTokenBalances,
+    ) -> Option<ExitPlan> {
+        let book = self.positions.as_ref()?;
+        balances
+            .entries()
+            .filter(|entry| entry.owner == Some(*authority))
+            .find_map(|entry| {
+                let pre = entry.pre_amount?;
+                let post = entry.post_amount.unwrap_or(0);
+                let position = book.get(&self.wallet_pubkey, &entry.mint)?;
+                let amount = book.exit_amount(&self.wallet_pubkey, &entry.mint, pre, post)?;
+                Some(ExitPlan {
+                    mint: entry.mint,
+                    base_mint: position.base_mint,
+                    amount,
+                    held: position.amount,
+                })
+            })
+    }
+
+    /// 源钱包在本笔交易中增加最多的 mint（排除花费的 base mint），视为买入的标的。
+    fn detect_acquired_mint(
+        authority: &Pubkey,
+        balances: &TransactionTokenBalances,
+        spent_mint: &Pubkey,
+    ) -> Option<Pubkey> {
+        balances
+            .entries()
+            .filter(|entry| entry.owner == Some(*authority) && entry.mint != *spent_mint)
+            .filter_map(|entry| {
+                let pre = entry.pre_amount.unwrap_or(0);
+                let post = entry.post_amount.unwrap_or(0);
+                (post > pre).then_some((entry.mint, post - pre))
+            })
+            .max_by_key(|(_, delta)| *delta)
+            .map(|(mint, _)| mint)
+    }
+
+    /// 后台回查套利交易中身份钱包 base mint 的实际变化，用于当日亏损止损。
+    fn track_realized(&self, signature: Signature, mint: Pubkey) {
+        if self.dry_run || !self.risk.tracks_realized(&mint) {
+            return;
+        }
+        let rpc = Arc::clone(&self.rpc_client);
+        let risk = Arc::clone(&self.risk);
+        let owner = self.identity.pubkey;
+        let wallet = self.wallet_pubkey;
+        tokio::spawn(async move {
+            if let Some(delta) = probe_landed_delta(&rpc, &signature, &owner, &mint, &wallet).await
+            {
+                let day_total = risk.record_realized(&mint, delta);
+                events::copy_realized_delta(&wallet, &mint, delta, day_total);
+            }
+        });
+    }
+
+    /// 后台回查落地交易中身份钱包持仓 mint 的实际变化并更新持仓簿；平仓时按持仓成本结算已实现盈亏。
+    fn track_position(&self, signature: Signature, mint: Pubkey, change: PositionChange) {
+        if self.dry_run {
+            return;
+        }
+        let Some(book) = self.positions.clone() else {
+            return;
+        };
+        let rpc = Arc::clone(&self.rpc_client);
+        let risk = Arc::clone(&self.risk);
+        let owner = self.identity.pubkey;
+        let wallet = self.wallet_pubkey;
+        tokio::spawn(async move {
+            let Some(delta) = probe_landed_delta(&rpc, &signature, &owner, &mint, &wallet).await
+            else {
+                return;
+            };
+            match change {
+                PositionChange::Entry { base_mint, cost } if delta > 0 => {
+                    let amount = u64::try_from(delta).unwrap_or(u64::MAX);
+                    book.record_entry(wallet, mint, base_mint, amount, cost)
+                        .await;
+                    events::copy_position_changed(&wallet, &mint, "entry", amount);
+                }
+                PositionChange::Exit { base_mint } if delta < 0 => {
+                    let sold = u64::try_from(delta.unsigned_abs()).unwrap_or(u64::MAX);
+                    let fill = book.record_exit(&wallet, &mint, sold).await;
+                    events::copy_position_changed(&wallet, &mint, "exit", sold);
+                    debug!(
+                        target: "strategy::copy",
+                        wallet = %wallet,
+                        mint = %mint,
+                        sold,
+                        remaining = fill.remaining,
+                        "复制持仓已减仓"
+                    );
+                    if !risk.tracks_realized(&base_mint) {
+                        return;
+                    }
+                    let Some(proceeds) =
+                        probe_landed_delta(&rpc, &signature, &owner, &base_mint, &wallet).await
+                    else {
+                        return;
+                    };
+                    let realized = proceeds - i128::from(fill.cost_released);
+                    let day_total = risk.record_realized(&base_mint, realized);
+                    events::copy_realized_delta(&wallet, &base_mint, realized, day_total);
+                }
+                _ => {}
+            }
+        });
+    }
+
+    /// 定时检查持仓，超过最长持有时间或触发止损时通过 API 报价主动卖出。
+    fn spawn_exit_monitor(runner: Arc<Self>) {
+        let exits = &runner.wallet.exits;
+        if runner.positions.is_none()
+            || runner.exit_api.is_none()
+            || (exits.max_hold_secs == 0 && exits.stop_loss_pct <= 0.0)
+        {
+            return;
+        }
+        let interval = Duration::from_secs(exits.check_interval_secs.max(1));
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+            loop {
+                ticker.tick().await;
+                runner.check_exits().await;
+            }
+        });
+    }
+
+    async fn check_exits(&self) {
+        let (Some(book), Some(api)) = (self.positions.as_ref(), self.exit_api.as_ref()) else {
+            return;
+        };
+        let exits = &self.wallet.exits;
+        let now = now_secs();
+        for position in book.for_wallet(&self.wallet_pubkey) {
+            let expired = exits.max_hold_secs > 0
+                && now.saturating_sub(position.opened_at) >= exits.max_hold_secs;
+            if !expired && exits.stop_loss_pct <= 0.0 {
+                continue;
+            }
+
+            let mut request = QuoteRequest::new(position.mint, position.base_mint, position.amount);
+            request.slippage_bps = Some(exits.slippage_bps);
+            let quote = match api.quote_with_ip(&request, None).await {
+                Ok(quote) => quote,
+                Err(err) => {
+                    warn!(
+                        target: "strategy::copy",
+                        wallet = %self.wallet_pubkey,
+                        mint = %position.mint,
+                        error = %err,
+                        "持仓估值报价失败"
+                    );
+                    continue;
+                }
+            };
+
+            let reason = if expired {
+                "max_hold"
+            } else if stop_loss_triggered(
+                position.cost,
+                quote.payload().out_amount,
+                exits.stop_loss_pct,
+            ) {
+                "stop_loss"
+            } else {
+                continue;
+            };
+
+            if let Err(err) = self.submit_exit(api, &position, quote, reason).await {
+                warn!(
+                    target: "strategy::copy",
+                    wallet = %self.wallet_pubkey,
+                    mint = %position.mint,
+                    reason,
+                    error = %err,
+                    "主动卖出持仓失败"
+                );
+            }
+        }
+    }
+
+    async fn submit_exit(
+        &self,
+        api: &JupiterApiClient,
+        position: &CopyPosition,
+        quote: QuoteResponse,
+        reason: &'static str,
+    ) -> Result<()> {
+        let request =
+            SwapInstructionsRequest::from_quote(quote.into_payload(), self.identity.pubkey);
+        let response = api
+            .swap_instructions_with_ip(&request, None)
+            .await
+            .map_err(|err| anyhow!("获取卖出 swap 指令失败: {err}"))?;
+
+        let compute_unit_limit = self.scale_compute_unit_limit(response.compute_unit_limit);
+        let prioritization_fee = response.prioritization_fee_lamports.unwrap_or(0);
+        let jito_tip_plan = self.lander_stack.draw_jito_tip_plan();
+        let tip_lamports = jito_tip_plan
+            .as_ref()
+            .map(|plan| plan.lamports)
+            .unwrap_or(0);
+
+        let opportunity = SwapOpportunity {
+            pair: TradePair::from_pubkeys(position.mint, position.base_mint),
+            amount_in: position.amount,
+            profit_lamports: 0,
+            tip_lamports,
+            merged_quote: None,
+            ultra_legs: None,
+        };
+        let deadline_instant = Instant::now() + self.landing_timeout;
+        let execution_plan = ExecutionPlan::new(
+            opportunity,
+            SwapInstructionsVariant::Jupiter(response),
+            position.base_mint,
+            tip_lamports,
+            0,
+            compute_unit_limit,
+            prioritization_fee,
+            deadline_instant,
+        );
+
+        let profiles = self.build_landing_profiles(None, jito_tip_plan);
+        let entries = self.assemble_entries(&execution_plan, profiles).await?;
+        if entries.is_empty() {
+            return Err(anyhow!("未生成任何落地计划"));
+        }
+        let prepared: Vec<_> = entries.iter().map(|entry| entry.prepared.clone()).collect();
+        let layout = self.lander_stack.variant_layout(self.dispatch_strategy);
+        let dispatch_plan = self
+            .planner
+            .plan(self.dispatch_strategy, &prepared, &layout);
+
+        let ledger_draft = ledger::ledger_enabled()
+            .then(|| TradeDraft::from_plan("copy_exit", &execution_plan, entries.first()));
+
+        let outcome = self
+            .lander_stack
+            .submit_plan(
+                &dispatch_plan,
+                Deadline::from_instant(deadline_instant),
+                "copy_exit",
+            )
+            .await;
+        if let Some(draft) = ledger_draft {
+            ledger::record_dispatch(draft, &outcome);
+        }
+        let receipt = outcome.map_err(|err| anyhow!("卖出交易提交失败: {err}"))?;
+        events::copy_position_changed(&self.wallet_pubkey, &position.mint, reason, position.amount);
+        info!(
+            target: "strategy::copy",
+            wallet = %self.wallet_pubkey,
+            mint = %position.mint,
+            amount = position.amount,
+            reason,
+            lander = receipt.lander,
+            endpoint = %receipt.endpoint,
+            "持仓卖出交易提交成功"
+        );
+        if let Some(landed) = receipt
+            .signature
+            .as_deref()
+            .and_then(|value| Signature::from_str(value).ok())
+        {
+            self.track_position(
+                landed,
+                position.mint,
+                PositionChange::Exit {
+                    base_mint: position.base_mint,
+                },
+            );
+        }
+        Ok(())
+    }
+
+    async fn assemble_entries(
+        &self,
+        execution_plan: &ExecutionPlan,
+        profiles: Vec<LandingProfile>,
+    ) -> Result<Vec<LandingPlanEntry>> {
+        let assembler = DefaultLandingAssembler::new();
+        let mut entries = Vec::with_capacity(profiles.len());
+        let mut lighthouse = self.lighthouse.lock().await;
+        for profile in profiles {
+            let mut context = LandingAssemblyContext::new(
+                &self.identity,
+                &self.tx_builder,
+                None,
+                &mut *lighthouse,
+            );
+            let entry = assembler
+                .assemble_landing(&mut context, &profile, execution_plan)
+                .await
+                .map_err(|err| anyhow!("复制交易装配失败: {err}"))?;
+            entries.push(entry);
+        }
+        Ok(entries)
+    }
+
     fn log_copy_snapshot(
         &self,
         source_signature: &Signature,
@@ -1624,14 +2344,14 @@ impl SeenSignatures {
     }
 }
 
-fn describe_jupiter_accounts(instructions: &[Instruction]) -> Vec<String> {
+fn describe_swap_accounts(instructions: &[Instruction]) -> Vec<String> {
     let mut lines = Vec::new();
 
     for ix in instructions {
-        if ix.program_id != JUPITER_V6_PROGRAM_ID {
+        let Some(kind) = SwapKind::classify(ix) else {
             continue;
-        }
-        lines.push(ix.program_id.to_string());
+        };
+        lines.push(format!("{} {}", kind.label(), ix.program_id));
 
         for (idx, meta) in ix.accounts.iter().enumerate() {
             let mut flags = Vec::new();
@@ -1651,7 +2371,7 @@ fn describe_jupiter_accounts(instructions: &[Instruction]) -> Vec<String> {
     }
 
     if lines.is_empty() {
-        lines.push("no_jupiter_instructions".to_string());
+        lines.push("no_swap_instructions".to_string());
     }
 
     lines
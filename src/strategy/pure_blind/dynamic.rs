@@ -22,6 +22,7 @@ use crate::dexes::solfi_v2::{SOLFI_V2_PROGRAM_ID, SolFiV2Adapter};
 use crate::dexes::tessera_v::{TESSERA_V_PROGRAM_ID, TesseraVAdapter};
 use crate::dexes::whirlpool::{ORCA_WHIRLPOOL_PROGRAM_ID, WhirlpoolAdapter};
 use crate::dexes::zerofi::{ZEROFI_PROGRAM_ID, ZeroFiAdapter};
+use crate::monitoring::events;
 use crate::strategy::types::{BlindAsset, BlindDex, BlindMarketMeta, BlindStep};
 
 use super::observer::{
@@ -113,6 +114,12 @@ async fn handle_event(
         RouteCatalogEvent::Activated { profile, stats } => {
             match build_dynamic_route(Arc::clone(&profile), rpc_client).await {
                 Ok(payload) => {
+                    events::pure_blind_route_activation(
+                        &profile.label(),
+                        "activated",
+                        "policy",
+                        payload.steps.len(),
+                    );
                     let update = DynamicRouteUpdate::Activated {
                         profile,
                         stats,
@@ -122,6 +129,12 @@ async fn handle_event(
                     let _ = tx.send(update);
                 }
                 Err(err) => {
+                    events::pure_blind_route_activation(
+                        &profile.label(),
+                        "build_failed",
+                        "policy",
+                        profile.steps.len(),
+                    );
                     warn!(
                         target: "pure_blind::dynamic",
                         error = %err,
@@ -134,6 +147,12 @@ async fn handle_event(
         RouteCatalogEvent::Deactivated {
             profile, reason, ..
         } => {
+            events::pure_blind_route_activation(
+                &profile.label(),
+                "retired",
+                reason.as_str(),
+                profile.steps.len(),
+            );
             let update = DynamicRouteUpdate::Retired {
                 key: profile.key.clone(),
                 _reason: reason,
@@ -1,3 +1,5 @@
+use std::fmt;
+use std::str::FromStr;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
@@ -88,6 +90,15 @@ impl RouteProfile {
         &self.key.markets
     }
 
+    /// 以 `->` 连接池子地址的展示标签，监控事件中用来标识动态路线。
+    pub fn label(&self) -> String {
+        self.markets()
+            .iter()
+            .map(|market| market.to_string())
+            .collect::<Vec<_>>()
+            .join("->")
+    }
+
     pub(crate) fn new_from_parts(
         key: RouteKey,
         steps: Vec<PoolProfile>,
@@ -119,6 +130,35 @@ impl RouteKey {
     }
 }
 
+/// 以逗号连接的池子地址表示路线，控制接口据此定位路线。
+impl fmt::Display for RouteKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (idx, market) in self.markets.iter().enumerate() {
+            if idx > 0 {
+                f.write_str(",")?;
+            }
+            write!(f, "{market}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for RouteKey {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let markets = value
+            .split(',')
+            .map(str::trim)
+            .filter(|part| !part.is_empty())
+            .map(|part| {
+                Pubkey::from_str(part).map_err(|err| format!("无效的池子地址 `{part}`: {err}"))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        RouteKey::new(markets).ok_or_else(|| "路线至少需要一个有效的池子地址".to_string())
+    }
+}
+
 #[derive(Clone, Debug, Default)]
 pub struct RouteStats {
     pub observations: u64,
@@ -168,6 +208,8 @@ struct RouteRecord {
 #[derive(Clone, Debug)]
 struct ActivationState {
     active: bool,
+    /// 通过控制接口手动下线，之后的观测不再重新激活。
+    retired: bool,
     consecutive_failures: u32,
     last_observed_at: Option<Instant>,
 }
@@ -176,6 +218,7 @@ impl ActivationState {
     fn new(now: Instant) -> Self {
         Self {
             active: false,
+            retired: false,
             consecutive_failures: 0,
             last_observed_at: Some(now),
         }
@@ -225,6 +268,18 @@ pub enum RouteDeactivateReason {
     Decay,
     Failure,
     Pruned,
+    Manual,
+}
+
+impl RouteDeactivateReason {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RouteDeactivateReason::Decay => "decay",
+            RouteDeactivateReason::Failure => "failure",
+            RouteDeactivateReason::Pruned => "pruned",
+            RouteDeactivateReason::Manual => "manual",
+        }
+    }
 }
 
 pub struct RouteCatalog {
@@ -306,6 +361,7 @@ impl RouteCatalog {
                 stats,
                 activation: ActivationState {
                     active: is_active,
+                    retired: false,
                     consecutive_failures: 0,
                     last_observed_at: Some(now),
                 },
@@ -412,6 +468,26 @@ impl RouteCatalog {
         }
     }
 
+    /// 手动下线路线；返回路线此前是否处于激活状态。路线不存在时返回 None。
+    pub fn retire(&self, key: &RouteKey) -> Option<bool> {
+        let mut entry = self.entries.get_mut(key)?;
+        let value = entry.value_mut();
+        let was_active = value.activation.active;
+        value.activation.active = false;
+        value.activation.retired = true;
+        if was_active {
+            let stats = value.stats.snapshot();
+            let profile = Arc::clone(&value.profile);
+            drop(entry);
+            self.push_event(RouteCatalogEvent::Deactivated {
+                profile,
+                _stats: stats,
+                reason: RouteDeactivateReason::Manual,
+            });
+        }
+        Some(was_active)
+    }
+
     pub fn enforce_decay(&self) {
         let now = Instant::now();
         if self.policy.decay_duration().is_zero() {
@@ -491,6 +567,9 @@ impl RouteCatalog {
 
 impl RouteRecord {
     fn evaluate_activation(&mut self, policy: &RouteActivationPolicy) -> Option<RouteCatalogEvent> {
+        if self.activation.retired {
+            return None;
+        }
         if policy.should_activate(&self.stats) && !self.activation.active {
             self.activation.active = true;
             return Some(RouteCatalogEvent::Activated {
@@ -591,4 +670,37 @@ mod tests {
         }
         assert_eq!(catalog.active_routes().len(), 1);
     }
+
+    #[test]
+    fn retired_route_is_not_reactivated() {
+        let policy = RouteActivationPolicy::new(1, None, Duration::from_secs(60));
+        let catalog = RouteCatalog::new(policy, 16, 8);
+        let observation = RouteObservation {
+            steps: vec![dummy_pool_profile(), dummy_pool_profile()],
+            lookup_tables: Vec::new(),
+            base_asset: None,
+            estimated_profit: Some(5),
+            slot: 1,
+        };
+        catalog.ingest(observation.clone());
+        let key = catalog.active_routes()[0].profile.key.clone();
+
+        let parsed: RouteKey = key.to_string().parse().expect("route key");
+        assert_eq!(parsed, key);
+
+        let mut receiver = catalog.subscribe();
+        assert_eq!(catalog.retire(&key), Some(true));
+        match receiver.try_recv() {
+            Ok(RouteCatalogEvent::Deactivated {
+                reason: RouteDeactivateReason::Manual,
+                ..
+            }) => {}
+            other => panic!("expected manual deactivation, got {other:?}"),
+        }
+
+        catalog.ingest(observation);
+        assert!(catalog.active_routes().is_empty());
+        assert_eq!(catalog.retire(&key), Some(false));
+        assert!("not-a-pubkey".parse::<RouteKey>().is_err());
+    }
 }
@@ -3,10 +3,11 @@
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
-use anyhow::{Context, Result, anyhow};
-use futures::StreamExt;
+use anyhow::{Result, anyhow};
+use solana_client::nonblocking::rpc_client::RpcClient;
 use solana_sdk::message::VersionedMessage;
 use solana_sdk::pubkey::Pubkey;
+use tokio::sync::mpsc;
 use tokio::task::JoinHandle;
 use tracing::{info, warn};
 use yellowstone_grpc_proto::geyser::SubscribeUpdateTransactionInfo;
@@ -14,7 +15,7 @@ use yellowstone_grpc_proto::solana::storage::confirmed_block;
 use yellowstone_grpc_proto::tonic::metadata::AsciiMetadataValue;
 
 use crate::instructions::jupiter::parser::PROGRAM_ID as JUPITER_PROGRAM_ID;
-use crate::network::yellowstone::{YellowstoneTransactionClient, parse_transaction_update};
+use crate::network::yellowstone::{WalletStreamSettings, WalletTransaction, spawn_wallet_stream};
 use crate::strategy::copy::transaction::{
     TokenBalanceEntry, TransactionLoadedAddresses, TransactionTokenBalances,
     decode_versioned_transaction, instructions_from_message,
@@ -27,11 +28,13 @@ use super::routes::{RouteCatalog, RouteObservation};
 use crate::instructions::jupiter::decoder::ParsedSwapAccounts;
 use crate::instructions::jupiter::types::resolve_swap_discriminant;
 
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub struct PoolObserverSettings {
     pub endpoint: String,
     pub token: Option<AsciiMetadataValue>,
     pub wallets: Vec<Pubkey>,
+    /// 断线重连后用于回补缺失交易的 RPC；为空时仅依赖 from_slot 续订。
+    pub backfill_rpc: Option<Arc<RpcClient>>,
 }
 
 pub struct PoolObserverHandle {
@@ -60,62 +63,48 @@ pub async fn spawn_pool_observer(
         return Ok(PoolObserverHandle::new(Vec::new()));
     }
 
-    let mut tasks = Vec::with_capacity(settings.wallets.len());
-    for wallet in &settings.wallets {
-        let endpoint = settings.endpoint.clone();
-        let token = settings.token.clone();
-        let pool_catalog = Arc::clone(&pool_catalog);
-        let route_catalog = Arc::clone(&route_catalog);
-        let wallet = *wallet;
-        let handle = tokio::spawn(async move {
-            if let Err(err) =
-                run_wallet_observer(endpoint, token, wallet, pool_catalog, route_catalog).await
-            {
-                warn!(
-                    target: "pure_blind::observer",
-                    wallet = %wallet,
-                    error = %err,
-                    "池子观察器任务退出"
-                );
-            }
-        });
-        tasks.push(handle);
+    let mut stream_settings =
+        WalletStreamSettings::new(settings.endpoint, settings.token, "pure_blind_observer");
+    if let Some(rpc) = settings.backfill_rpc {
+        stream_settings = stream_settings.with_backfill(rpc, OBSERVER_BACKFILL_LIMIT);
     }
-
-    Ok(PoolObserverHandle::new(tasks))
+    let (sender, receiver) = mpsc::channel(OBSERVER_CHANNEL_CAPACITY);
+    let sinks: HashMap<Pubkey, mpsc::Sender<WalletTransaction>> = settings
+        .wallets
+        .iter()
+        .map(|wallet| (*wallet, sender.clone()))
+        .collect();
+    drop(sender);
+
+    let stream = spawn_wallet_stream(stream_settings, sinks);
+    let consumer = tokio::spawn(run_wallet_observer(receiver, pool_catalog, route_catalog));
+    info!(
+        target: "pure_blind::observer",
+        wallets = settings.wallets.len(),
+        "池子观察器已启动多钱包订阅"
+    );
+
+    Ok(PoolObserverHandle::new(vec![stream, consumer]))
 }
 
+const OBSERVER_CHANNEL_CAPACITY: usize = 1_024;
+const OBSERVER_BACKFILL_LIMIT: usize = 100;
+
 async fn run_wallet_observer(
-    endpoint: String,
-    token: Option<AsciiMetadataValue>,
-    wallet: Pubkey,
+    mut receiver: mpsc::Receiver<WalletTransaction>,
     pool_catalog: Arc<PoolCatalog>,
     route_catalog: Arc<RouteCatalog>,
-) -> Result<()> {
-    let mut client = YellowstoneTransactionClient::connect(endpoint, token).await?;
-    let mut stream = client
-        .subscribe_transactions(wallet)
-        .await
-        .context("订阅 Yellowstone gRPC 失败")?;
-
-    info!(target: "pure_blind::observer", wallet = %wallet, "已连接至 Yellowstone gRPC");
-
-    while let Some(update) = stream.next().await.transpose()? {
-        if let Some(tx_info) = parse_transaction_update(&update) {
-            if let Err(err) =
-                process_transaction(&pool_catalog, &route_catalog, wallet, tx_info).await
-            {
-                warn!(
-                    target: "pure_blind::observer",
-                    wallet = %wallet,
-                    error = %err,
-                    "处理交易时出错"
-                );
-            }
+) {
+    while let Some(WalletTransaction { wallet, info, .. }) = receiver.recv().await {
+        if let Err(err) = process_transaction(&pool_catalog, &route_catalog, wallet, info).await {
+            warn!(
+                target: "pure_blind::observer",
+                wallet = %wallet,
+                error = %err,
+                "处理交易时出错"
+            );
         }
     }
-
-    Ok(())
 }
 
 async fn process_transaction(
@@ -697,6 +697,20 @@ impl PureBlindStrategy {
             base_min_profit,
         })
     }
+
+    /// 热重载：替换手动路由与各 base mint 的最小利润，动态路由与目录保持不变。
+    pub fn reload(
+        &mut self,
+        routes: Vec<BlindRoutePlan>,
+        config: &config::PureBlindStrategyConfig,
+    ) -> Result<()> {
+        if routes.is_empty() && config.observer.as_ref().map_or(true, |cfg| !cfg.enable) {
+            bail!("纯盲发模式需要至少一个盲发路由或启用观测器");
+        }
+        self.base_min_profit = build_min_profit_map(&config.assets.base_mints)?;
+        self.routes = routes;
+        Ok(())
+    }
 }
 
 impl Strategy for PureBlindStrategy {
@@ -791,13 +805,7 @@ impl Strategy for PureBlindStrategy {
                             }
                         }
 
-                        let route_label = route
-                            .profile
-                            .markets()
-                            .iter()
-                            .map(|market| market.to_string())
-                            .collect::<Vec<_>>()
-                            .join("->");
+                        let route_label = route.profile.label();
                         let source_label = "dynamic";
                         let count = amounts.len();
                         events::pure_blind_orders_prepared(
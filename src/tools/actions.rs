@@ -0,0 +1,557 @@
+use std::str::FromStr;
+use std::sync::Arc;
+
+use anyhow::{Result, anyhow, bail};
+use rust_decimal::Decimal;
+use serde_json::json;
+use solana_account_decoder::{UiAccountData, UiAccountEncoding};
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_client::rpc_config::{RpcAccountInfoConfig, RpcTokenAccountsFilter};
+use solana_client::rpc_request::RpcRequest;
+use solana_client::rpc_response::{Response as RpcResponse, RpcKeyedAccount};
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use spl_token::solana_program::program_pack::Pack;
+
+use super::plan::{ToolPlan, ToolTransaction, format_sol};
+use super::sol;
+use crate::api::jupiter::{JupiterApiClient, QuoteRequest, SwapInstructionsRequest};
+use crate::cache::AltCache;
+use crate::engine::EngineIdentity;
+use crate::engine::plugins::flashloan::marginfi::find_marginfi_account_by_authority;
+use crate::instructions::flashloan::marginfi::build_close_instruction;
+use crate::instructions::guards::lighthouse::memory_close::build_memory_close_instruction;
+use crate::instructions::guards::lighthouse::program::LIGHTHOUSE_PROGRAM_ID;
+use crate::instructions::wsol;
+
+/// 单笔交易内最多关闭的账户数量，避免超出交易大小限制。
+const CLOSE_BATCH_SIZE: usize = 10;
+const MULTIPLE_ACCOUNTS_CHUNK: usize = 100;
+
+/// 钱包持有的代币账户快照。
+#[derive(Clone, Debug)]
+pub struct TokenAccountInfo {
+    pub account: Pubkey,
+    pub mint: Pubkey,
+    pub token_program: Pubkey,
+    pub amount: u64,
+    pub lamports: u64,
+    pub frozen: bool,
+}
+
+impl TokenAccountInfo {
+    fn closable(&self) -> bool {
+        self.amount == 0 && !self.frozen
+    }
+}
+
+/// 钱包持有某个 mint 的来源账户信息。
+struct TokenSource {
+    account: Pubkey,
+    token_program: Pubkey,
+    decimals: u8,
+    balance: u64,
+}
+
+pub fn plan_transfer_sol(
+    identity: &EngineIdentity,
+    recipient: &Pubkey,
+    amount: &Decimal,
+    compute_unit_lamports: u64,
+) -> Result<ToolPlan> {
+    let lamports = sol::decimal_sol_to_lamports(amount)?;
+    let instructions = sol::transfer_sol_instructions(
+        &identity.pubkey,
+        recipient,
+        lamports,
+        compute_unit_lamports,
+    );
+    Ok(ToolPlan::new(
+        vec![format!(
+            "向 {recipient} 转账 {amount} SOL（{lamports} lamports）"
+        )],
+        vec![ToolTransaction::new(instructions)],
+    ))
+}
+
+pub fn plan_wrap_sol(
+    identity: &EngineIdentity,
+    amount: &Decimal,
+    compute_unit_lamports: u64,
+) -> Result<ToolPlan> {
+    let lamports = sol::decimal_sol_to_lamports(amount)?;
+    let instructions =
+        sol::wrap_sol_instructions(&identity.pubkey, lamports, compute_unit_lamports);
+    Ok(ToolPlan::new(
+        vec![format!("将 {amount} SOL 包装为 WSOL")],
+        vec![ToolTransaction::new(instructions)],
+    ))
+}
+
+pub async fn plan_partial_unwrap(
+    rpc: &Arc<RpcClient>,
+    identity: &EngineIdentity,
+    amount: &Decimal,
+    compute_unit_lamports: u64,
+) -> Result<ToolPlan> {
+    let lamports = sol::decimal_sol_to_lamports(amount)?;
+    let (instructions, temp) =
+        sol::partial_unwrap_instructions(rpc, &identity.pubkey, lamports, compute_unit_lamports)
+            .await?;
+    Ok(ToolPlan::new(
+        vec![format!("从 WSOL 账户解包 {amount} SOL，临时账户随交易关闭")],
+        vec![ToolTransaction::new(instructions).with_signer(temp)],
+    ))
+}
+
+/// 转出任意 SPL / Token-2022 代币，接收方 ATA 不存在时一并创建（由钱包支付租金）。
+pub async fn plan_transfer_token(
+    rpc: &Arc<RpcClient>,
+    identity: &EngineIdentity,
+    mint: &Pubkey,
+    recipient: &Pubkey,
+    amount: &Decimal,
+    compute_unit_lamports: u64,
+) -> Result<ToolPlan> {
+    let source = resolve_token_source(rpc, &identity.pubkey, mint).await?;
+    let base_units = sol::decimal_to_base_units(amount, source.decimals)?;
+    if base_units > source.balance {
+        bail!(
+            "代币余额不足：可用 {}，需要 {}",
+            format_units(source.balance, source.decimals),
+            amount
+        );
+    }
+    let instructions = sol::transfer_token_instructions(
+        &identity.pubkey,
+        mint,
+        source.decimals,
+        &source.account,
+        &source.token_program,
+        recipient,
+        base_units,
+        compute_unit_lamports,
+    )?;
+    Ok(ToolPlan::new(
+        vec![
+            format!(
+                "代币：{mint}（{}）",
+                token_program_label(&source.token_program)
+            ),
+            format!("向 {recipient} 转账 {amount}（{base_units} 最小单位）"),
+            "接收方关联账户不存在时将由当前钱包创建".to_string(),
+        ],
+        vec![ToolTransaction::new(instructions)],
+    ))
+}
+
+/// 批量关闭余额为 0 的代币账户，租金退回钱包。
+pub async fn plan_close_empty_accounts(
+    rpc: &Arc<RpcClient>,
+    identity: &EngineIdentity,
+    compute_unit_lamports: u64,
+) -> Result<ToolPlan> {
+    let accounts = list_token_accounts(rpc, &identity.pubkey).await?;
+    let closable: Vec<&TokenAccountInfo> = accounts.iter().filter(|a| a.closable()).collect();
+    if closable.is_empty() {
+        bail!("没有可关闭的空代币账户");
+    }
+    let reclaimed: u64 = closable.iter().map(|a| a.lamports).sum();
+    let transactions = close_account_batches(&identity.pubkey, &closable, compute_unit_lamports)?;
+    let frozen = accounts
+        .iter()
+        .filter(|a| a.amount == 0 && a.frozen)
+        .count();
+    let mut summary = vec![
+        format!(
+            "关闭 {} 个空代币账户，回收租金 {} SOL",
+            closable.len(),
+            format_sol(reclaimed)
+        ),
+        format!("共拆分为 {} 笔交易", transactions.len()),
+    ];
+    if frozen > 0 {
+        summary.push(format!("跳过 {frozen} 个已冻结的空账户"));
+    }
+    Ok(ToolPlan::new(summary, transactions))
+}
+
+/// 解包全部 WSOL 并关闭 WSOL 关联账户。
+pub async fn plan_unwrap_all(
+    rpc: &Arc<RpcClient>,
+    identity: &EngineIdentity,
+    compute_unit_lamports: u64,
+) -> Result<ToolPlan> {
+    let owner = identity.pubkey;
+    let wsol_ata =
+        spl_associated_token_account::get_associated_token_address(&owner, &wsol::WSOL_MINT);
+    let account = rpc
+        .get_account(&wsol_ata)
+        .await
+        .map_err(|err| anyhow!("未找到 WSOL 关联账户 {wsol_ata}: {err}"))?;
+    let token_account = spl_token::state::Account::unpack(&account.data)
+        .map_err(|err| anyhow!("解析 WSOL 账户失败: {err}"))?;
+    if token_account.owner != owner {
+        bail!("WSOL 账户所有者与当前钱包不匹配");
+    }
+    let rent = account.lamports.saturating_sub(token_account.amount);
+
+    let mut instructions: Vec<Instruction> = wsol::unwrap_sequence(&owner).as_ref().clone();
+    sol::maybe_insert_compute_unit_price(&mut instructions, compute_unit_lamports);
+    Ok(ToolPlan::new(
+        vec![
+            format!("解包全部 WSOL：{} SOL", format_sol(token_account.amount)),
+            format!("关闭 {wsol_ata}，回收租金 {} SOL", format_sol(rent)),
+        ],
+        vec![ToolTransaction::new(instructions)],
+    ))
+}
+
+/// 通过聚合器把代币换回 SOL；`amount` 为空时兑换全部余额。
+pub async fn plan_swap_to_sol(
+    rpc: &Arc<RpcClient>,
+    identity: &EngineIdentity,
+    api: &JupiterApiClient,
+    mint: &Pubkey,
+    amount: Option<&Decimal>,
+    slippage_bps: u16,
+    compute_unit_lamports: u64,
+) -> Result<ToolPlan> {
+    if *mint == wsol::WSOL_MINT {
+        bail!("WSOL 请使用 Unwrap All WSOL 操作");
+    }
+    let source = resolve_token_source(rpc, &identity.pubkey, mint).await?;
+    let base_units = match amount {
+        Some(value) => sol::decimal_to_base_units(value, source.decimals)?,
+        None => source.balance,
+    };
+    if base_units == 0 {
+        bail!("代币余额为 0，无需兑换");
+    }
+    if base_units > source.balance {
+        bail!(
+            "代币余额不足：可用 {}",
+            format_units(source.balance, source.decimals)
+        );
+    }
+
+    let mut quote_request = QuoteRequest::new(*mint, wsol::WSOL_MINT, base_units);
+    quote_request.slippage_bps = Some(slippage_bps);
+    let quote = api
+        .quote_with_ip(&quote_request, None)
+        .await
+        .map_err(|err| anyhow!("获取报价失败: {err}"))?
+        .into_payload();
+    let expected_out = quote.out_amount;
+    let min_out = quote.other_amount_threshold;
+    let price_impact = quote.price_impact_pct;
+
+    let mut swap_request = SwapInstructionsRequest::from_quote(quote, identity.pubkey);
+    if compute_unit_lamports > 0 {
+        swap_request.compute_unit_price_micro_lamports = Some(compute_unit_lamports);
+    }
+    let mut swap = api
+        .swap_instructions_with_ip(&swap_request, None)
+        .await
+        .map_err(|err| anyhow!("获取兑换指令失败: {err}"))?;
+    let lookup_tables = if swap.address_lookup_table_addresses.is_empty() {
+        Vec::new()
+    } else {
+        AltCache::new()
+            .fetch_many(rpc, &swap.address_lookup_table_addresses)
+            .await?
+    };
+    if swap.needs_lookup_resolution() {
+        swap.resolve_with_lookup_accounts(&lookup_tables)
+            .map_err(|err| anyhow!("解析兑换交易失败: {err}"))?;
+    }
+
+    Ok(ToolPlan::new(
+        vec![
+            format!(
+                "兑换 {} {mint} → SOL",
+                format_units(base_units, source.decimals)
+            ),
+            format!(
+                "预计获得 {} SOL，最少 {} SOL（滑点 {slippage_bps} bps）",
+                format_sol(expected_out),
+                format_sol(min_out)
+            ),
+            format!("价格影响：{price_impact}%"),
+        ],
+        vec![ToolTransaction::new(swap.flatten_instructions()).with_lookup_tables(lookup_tables)],
+    ))
+}
+
+/// 关闭钱包名下全部 Lighthouse memory 账户，回收租金。
+pub async fn plan_close_lighthouse_memory(
+    rpc: &Arc<RpcClient>,
+    identity: &EngineIdentity,
+    compute_unit_lamports: u64,
+) -> Result<ToolPlan> {
+    let owner = identity.pubkey;
+    let candidates: Vec<(u8, Pubkey, u8)> = (0..=u8::MAX)
+        .map(|id| {
+            let (pda, bump) = Pubkey::find_program_address(
+                &[b"memory", owner.as_ref(), &[id]],
+                &LIGHTHOUSE_PROGRAM_ID,
+            );
+            (id, pda, bump)
+        })
+        .collect();
+
+    let mut instructions = Vec::new();
+    let mut reclaimed = 0u64;
+    for chunk in candidates.chunks(MULTIPLE_ACCOUNTS_CHUNK) {
+        let keys: Vec<Pubkey> = chunk.iter().map(|(_, pda, _)| *pda).collect();
+        let accounts = rpc
+            .get_multiple_accounts(&keys)
+            .await
+            .map_err(|err| anyhow!("查询 Lighthouse memory 账户失败: {err}"))?;
+        for ((id, pda, bump), account) in chunk.iter().zip(accounts) {
+            let Some(account) = account else {
+                continue;
+            };
+            if account.owner != LIGHTHOUSE_PROGRAM_ID {
+                continue;
+            }
+            reclaimed = reclaimed.saturating_add(account.lamports);
+            instructions.push(build_memory_close_instruction(owner, *pda, *id, *bump));
+        }
+    }
+    if instructions.is_empty() {
+        bail!("没有需要关闭的 Lighthouse memory 账户");
+    }
+
+    let count = instructions.len();
+    let transactions = batch_instructions(instructions, compute_unit_lamports);
+    Ok(ToolPlan::new(
+        vec![format!(
+            "关闭 {count} 个 Lighthouse memory 账户，回收租金 {} SOL",
+            format_sol(reclaimed)
+        )],
+        transactions,
+    ))
+}
+
+/// 关闭钱包的 MarginFi 账户，租金退回钱包；账户仍有存款或借款时 MarginFi 会拒绝关闭。
+pub async fn plan_close_marginfi(
+    rpc: &Arc<RpcClient>,
+    identity: &EngineIdentity,
+    compute_unit_lamports: u64,
+) -> Result<ToolPlan> {
+    let owner = identity.pubkey;
+    let account = find_marginfi_account_by_authority(rpc, &owner)
+        .await
+        .map_err(|err| anyhow!("查询 MarginFi 账户失败: {err}"))?
+        .ok_or_else(|| anyhow!("未找到当前钱包的 MarginFi 账户"))?;
+    let lamports = rpc
+        .get_balance(&account)
+        .await
+        .map_err(|err| anyhow!("查询 MarginFi 账户余额失败: {err}"))?;
+    let mut instructions = vec![
+        build_close_instruction(account, &owner, &owner)
+            .map_err(|err| anyhow!("构建 MarginFi 关闭指令失败: {err}"))?,
+    ];
+    sol::maybe_insert_compute_unit_price(&mut instructions, compute_unit_lamports);
+    Ok(ToolPlan::new(
+        vec![
+            format!(
+                "关闭 MarginFi 账户 {account}，回收租金 {} SOL",
+                format_sol(lamports)
+            ),
+            "账户仍有存款或借款时交易会失败，请先在 MarginFi 取出资产".to_string(),
+        ],
+        vec![ToolTransaction::new(instructions)],
+    ))
+}
+
+/// 列出钱包在 Token / Token-2022 下的全部代币账户。
+pub async fn list_token_accounts(
+    rpc: &Arc<RpcClient>,
+    owner: &Pubkey,
+) -> Result<Vec<TokenAccountInfo>> {
+    let mut result = Vec::new();
+    for program_id in [spl_token::id(), spl_token_2022::id()] {
+        let filter = RpcTokenAccountsFilter::ProgramId(program_id.to_string());
+        let config = RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::JsonParsed),
+            commitment: Some(rpc.commitment()),
+            data_slice: None,
+            min_context_slot: None,
+        };
+        let params = json!([owner.to_string(), filter, config]);
+        let response: RpcResponse<Vec<RpcKeyedAccount>> = rpc
+            .send(RpcRequest::GetTokenAccountsByOwner, params)
+            .await
+            .map_err(|err| anyhow!("获取 Token Accounts 失败: {err}"))?;
+
+        for keyed in response.value {
+            let UiAccountData::Json(parsed) = &keyed.account.data else {
+                continue;
+            };
+            let Some(info) = parsed.parsed.get("info") else {
+                continue;
+            };
+            let Some(mint) = info
+                .get("mint")
+                .and_then(|value| value.as_str())
+                .and_then(|value| Pubkey::from_str(value).ok())
+            else {
+                continue;
+            };
+            let amount = info
+                .get("tokenAmount")
+                .and_then(|ta| ta.get("amount"))
+                .and_then(|value| value.as_str())
+                .and_then(|value| value.parse::<u64>().ok())
+                .unwrap_or_default();
+            let frozen = info.get("state").and_then(|value| value.as_str()) == Some("frozen");
+            result.push(TokenAccountInfo {
+                account: Pubkey::from_str(&keyed.pubkey).map_err(|err| anyhow!(err))?,
+                mint,
+                token_program: program_id,
+                amount,
+                lamports: keyed.account.lamports,
+                frozen,
+            });
+        }
+    }
+    Ok(result)
+}
+
+fn close_account_batches(
+    owner: &Pubkey,
+    accounts: &[&TokenAccountInfo],
+    compute_unit_lamports: u64,
+) -> Result<Vec<ToolTransaction>> {
+    let mut instructions = Vec::with_capacity(accounts.len());
+    for account in accounts {
+        instructions.push(
+            spl_token_2022::instruction::close_account(
+                &account.token_program,
+                &account.account,
+                owner,
+                owner,
+                &[],
+            )
+            .map_err(|err| anyhow!("构建关闭账户指令失败 {}: {err}", account.account))?,
+        );
+    }
+    Ok(batch_instructions(instructions, compute_unit_lamports))
+}
+
+fn batch_instructions(
+    instructions: Vec<Instruction>,
+    compute_unit_lamports: u64,
+) -> Vec<ToolTransaction> {
+    instructions
+        .chunks(CLOSE_BATCH_SIZE)
+        .map(|chunk| {
+            let mut batch = chunk.to_vec();
+            sol::maybe_insert_compute_unit_price(&mut batch, compute_unit_lamports);
+            ToolTransaction::new(batch)
+        })
+        .collect()
+}
+
+async fn resolve_token_source(
+    rpc: &Arc<RpcClient>,
+    owner: &Pubkey,
+    mint: &Pubkey,
+) -> Result<TokenSource> {
+    let token_program = rpc
+        .get_account(mint)
+        .await
+        .map_err(|err| anyhow!("查询 mint {mint} 失败: {err}"))?
+        .owner;
+    if token_program != spl_token::id() && token_program != spl_token_2022::id() {
+        bail!("{mint} 不是 SPL Token / Token-2022 mint");
+    }
+    let decimals = rpc
+        .get_token_supply(mint)
+        .await
+        .map_err(|err| anyhow!("查询 mint {mint} 精度失败: {err}"))?
+        .decimals;
+    let account = spl_associated_token_account::get_associated_token_address_with_program_id(
+        owner,
+        mint,
+        &token_program,
+    );
+    let balance = rpc
+        .get_token_account_balance(&account)
+        .await
+        .map_err(|err| anyhow!("查询代币账户 {account} 失败: {err}"))?
+        .amount
+        .parse::<u64>()
+        .map_err(|err| anyhow!("解析代币余额失败: {err}"))?;
+    Ok(TokenSource {
+        account,
+        token_program,
+        decimals,
+        balance,
+    })
+}
+
+fn format_units(amount: u64, decimals: u8) -> String {
+    Decimal::from_i128_with_scale(i128::from(amount), u32::from(decimals))
+        .normalize()
+        .to_string()
+}
+
+fn token_program_label(program: &Pubkey) -> &'static str {
+    if *program == spl_token_2022::id() {
+        "Token-2022"
+    } else {
+        "SPL Token"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn token_account(amount: u64, frozen: bool, token_program: Pubkey) -> TokenAccountInfo {
+        TokenAccountInfo {
+            account: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            token_program,
+            amount,
+            lamports: 2_039_280,
+            frozen,
+        }
+    }
+
+    #[test]
+    fn close_batches_skip_funded_and_frozen_accounts() {
+        let owner = Pubkey::new_unique();
+        let mut accounts = Vec::new();
+        for index in 0..12 {
+            let program = if index % 2 == 0 {
+                spl_token::id()
+            } else {
+                spl_token_2022::id()
+            };
+            accounts.push(token_account(0, false, program));
+        }
+        accounts.push(token_account(5, false, spl_token::id()));
+        accounts.push(token_account(0, true, spl_token::id()));
+
+        let closable: Vec<&TokenAccountInfo> = accounts.iter().filter(|a| a.closable()).collect();
+        assert_eq!(closable.len(), 12);
+
+        let batches = close_account_batches(&owner, &closable, 1_000).expect("batches");
+        assert_eq!(batches.len(), 2);
+        assert_eq!(batches[0].instructions.len(), CLOSE_BATCH_SIZE + 1);
+        assert_eq!(batches[1].instructions.len(), 3);
+        assert_eq!(batches[1].instructions[2].program_id, spl_token_2022::id());
+    }
+
+    #[test]
+    fn token_amounts_respect_mint_decimals() {
+        let amount = Decimal::from_str("1.5").unwrap();
+        assert_eq!(sol::decimal_to_base_units(&amount, 6).unwrap(), 1_500_000);
+        assert!(sol::decimal_to_base_units(&Decimal::from_str("0.0000001").unwrap(), 6).is_err());
+        assert_eq!(format_units(1_500_000, 6), "1.5");
+    }
+}
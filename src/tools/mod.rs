@@ -1,2 +1,4 @@
+pub mod actions;
+pub mod plan;
 pub mod sol;
 pub mod tui;
@@ -0,0 +1,216 @@
+use std::sync::Arc;
+
+use anyhow::{Result, anyhow, bail};
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::hash::Hash;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::message::v0::Message as V0Message;
+use solana_sdk::message::{AddressLookupTableAccount, VersionedMessage};
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signature, Signer};
+use solana_sdk::transaction::VersionedTransaction;
+
+use crate::engine::{COMPUTE_BUDGET_PROGRAM_ID, EngineIdentity};
+use crate::instructions::guards::lighthouse::program::LIGHTHOUSE_PROGRAM_ID;
+
+/// 工具操作预先构建的一笔交易。
+#[derive(Clone, Debug)]
+pub struct ToolTransaction {
+    pub instructions: Vec<Instruction>,
+    pub lookup_tables: Vec<AddressLookupTableAccount>,
+    /// 除钱包外需要参与签名的账户（如部分解包的临时账户）。
+    pub extra_signers: Vec<Arc<Keypair>>,
+}
+
+impl ToolTransaction {
+    pub fn new(instructions: Vec<Instruction>) -> Self {
+        Self {
+            instructions,
+            lookup_tables: Vec::new(),
+            extra_signers: Vec::new(),
+        }
+    }
+
+    pub fn with_lookup_tables(mut self, tables: Vec<AddressLookupTableAccount>) -> Self {
+        self.lookup_tables = tables;
+        self
+    }
+
+    pub fn with_signer(mut self, signer: Keypair) -> Self {
+        self.extra_signers.push(Arc::new(signer));
+        self
+    }
+
+    fn compile(&self, payer: &Pubkey, blockhash: Hash) -> Result<V0Message> {
+        V0Message::try_compile(payer, &self.instructions, &self.lookup_tables, blockhash)
+            .map_err(|err| anyhow!("编译交易失败: {err}"))
+    }
+}
+
+/// 确认前展示的交易预览：操作摘要、每笔交易涉及的程序与预估网络费用；确认后逐笔签名发送。
+#[derive(Clone, Debug)]
+pub struct ToolPlan {
+    pub summary: Vec<String>,
+    pub transactions: Vec<ToolTransaction>,
+    /// 全部交易的预估网络费用（基础费 + 优先费），由 `estimate_fees` 填充。
+    pub fee_lamports: Option<u64>,
+}
+
+impl ToolPlan {
+    pub fn new(summary: Vec<String>, transactions: Vec<ToolTransaction>) -> Self {
+        Self {
+            summary,
+            transactions,
+            fee_lamports: None,
+        }
+    }
+
+    pub async fn estimate_fees(&mut self, rpc: &Arc<RpcClient>, payer: &Pubkey) -> Result<()> {
+        if self.transactions.is_empty() {
+            bail!("没有需要执行的交易");
+        }
+        let blockhash = rpc
+            .get_latest_blockhash()
+            .await
+            .map_err(|err| anyhow!("获取最新区块哈希失败: {err}"))?;
+        let mut total = 0u64;
+        for transaction in &self.transactions {
+            let message = VersionedMessage::V0(transaction.compile(payer, blockhash)?);
+            let fee = rpc
+                .get_fee_for_message(&message)
+                .await
+                .map_err(|err| anyhow!("预估交易费用失败: {err}"))?;
+            total = total.saturating_add(fee);
+        }
+        self.fee_lamports = Some(total);
+        Ok(())
+    }
+
+    pub fn describe(&self) -> Vec<String> {
+        let mut lines = self.summary.clone();
+        lines.push(String::new());
+        for (index, transaction) in self.transactions.iter().enumerate() {
+            let programs: Vec<&str> = transaction
+                .instructions
+                .iter()
+                .map(|ix| program_label(&ix.program_id))
+                .collect();
+            lines.push(format!(
+                "交易 {}/{}：{} 条指令 [{}]",
+                index + 1,
+                self.transactions.len(),
+                transaction.instructions.len(),
+                programs.join(", ")
+            ));
+        }
+        match self.fee_lamports {
+            Some(fee) => lines.push(format!(
+                "预估网络费用：{} lamports（{} SOL）",
+                fee,
+                format_sol(fee)
+            )),
+            None => lines.push("预估网络费用：未知".to_string()),
+        }
+        lines
+    }
+
+    /// 逐笔签名并等待确认；任一笔失败即停止，已成功的签名会包含在错误信息中。
+    pub async fn execute(
+        &self,
+        rpc: &Arc<RpcClient>,
+        identity: &EngineIdentity,
+    ) -> Result<Vec<Signature>> {
+        let mut signatures = Vec::with_capacity(self.transactions.len());
+        for (index, transaction) in self.transactions.iter().enumerate() {
+            match send_versioned(rpc, identity, transaction).await {
+                Ok(signature) => signatures.push(signature),
+                Err(err) if signatures.is_empty() => return Err(err),
+                Err(err) => {
+                    let landed: Vec<String> = signatures.iter().map(ToString::to_string).collect();
+                    bail!(
+                        "第 {} 笔交易失败: {err}；已成功 {}",
+                        index + 1,
+                        landed.join(", ")
+                    );
+                }
+            }
+        }
+        Ok(signatures)
+    }
+}
+
+async fn send_versioned(
+    rpc: &Arc<RpcClient>,
+    identity: &EngineIdentity,
+    transaction: &ToolTransaction,
+) -> Result<Signature> {
+    let blockhash = rpc
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| anyhow!("获取最新区块哈希失败: {err}"))?;
+    let message = transaction.compile(&identity.pubkey, blockhash)?;
+    let mut signers: Vec<&dyn Signer> = Vec::with_capacity(1 + transaction.extra_signers.len());
+    signers.push(identity.signer.as_ref());
+    for signer in &transaction.extra_signers {
+        signers.push(signer.as_ref());
+    }
+    let tx = VersionedTransaction::try_new(VersionedMessage::V0(message), &signers)
+        .map_err(|err| anyhow!("交易签名失败: {err}"))?;
+    rpc.send_and_confirm_transaction(&tx)
+        .await
+        .map_err(|err| anyhow!("交易提交失败: {err}"))
+}
+
+pub fn format_sol(lamports: u64) -> String {
+    format!(
+        "{}.{:09}",
+        lamports / LAMPORTS_PER_SOL,
+        lamports % LAMPORTS_PER_SOL
+    )
+}
+
+fn program_label(program: &Pubkey) -> &'static str {
+    if *program == solana_system_interface::program::ID {
+        "System"
+    } else if *program == COMPUTE_BUDGET_PROGRAM_ID {
+        "ComputeBudget"
+    } else if *program == spl_token::id() {
+        "Token"
+    } else if *program == spl_token_2022::id() {
+        "Token-2022"
+    } else if *program == spl_associated_token_account::id() {
+        "AssociatedToken"
+    } else if *program == LIGHTHOUSE_PROGRAM_ID {
+        "Lighthouse"
+    } else if *program == *crate::engine::plugins::flashloan::marginfi::PROGRAM_ID {
+        "MarginFi"
+    } else {
+        "Other"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn preview_lists_programs_and_fee() {
+        let payer = Pubkey::new_unique();
+        let recipient = Pubkey::new_unique();
+        let instructions =
+            crate::tools::sol::transfer_sol_instructions(&payer, &recipient, 1_000, 5);
+        let mut plan = ToolPlan::new(
+            vec!["转账 1000 lamports".to_string()],
+            vec![ToolTransaction::new(instructions)],
+        );
+        plan.fee_lamports = Some(1_505_000_000);
+
+        let lines = plan.describe();
+        assert!(lines.contains(&"交易 1/1：2 条指令 [ComputeBudget, System]".to_string()));
+        assert_eq!(
+            lines.last().map(String::as_str),
+            Some("预估网络费用：1505000000 lamports（1.505000000 SOL）")
+        );
+    }
+}
@@ -6,7 +6,6 @@ use rust_decimal::prelude::ToPrimitive;
 use solana_client::nonblocking::rpc_client::RpcClient;
 use solana_compute_budget_interface::ComputeBudgetInstruction;
 use solana_sdk::instruction::Instruction;
-use solana_sdk::native_token::LAMPORTS_PER_SOL;
 use solana_sdk::pubkey::Pubkey;
 use solana_sdk::signature::{Keypair, Signature, Signer};
 use solana_sdk::transaction::Transaction;
@@ -18,14 +17,22 @@ use crate::instructions::wsol;
 
 /// 将 SOL 数量转换为 lamports。
 pub fn decimal_sol_to_lamports(amount: &Decimal) -> Result<u64> {
+    decimal_to_base_units(amount, 9)
+}
+
+/// 按 mint 精度将 UI 数量转换为最小单位。
+pub fn decimal_to_base_units(amount: &Decimal, decimals: u8) -> Result<u64> {
     if amount <= &Decimal::ZERO {
         bail!("金额必须大于 0");
     }
+    let factor = 10u64
+        .checked_pow(u32::from(decimals))
+        .ok_or_else(|| anyhow!("mint 精度 {decimals} 超出范围"))?;
     let scaled = amount
-        .checked_mul(Decimal::from(LAMPORTS_PER_SOL))
+        .checked_mul(Decimal::from(factor))
         .ok_or_else(|| anyhow!("金额超出可表示范围"))?;
     if !scaled.fract().is_zero() {
-        bail!("金额最多支持 9 位小数");
+        bail!("金额最多支持 {decimals} 位小数");
     }
     scaled
         .to_u64()
@@ -43,30 +50,108 @@ pub async fn transfer_sol(
     if lamports == 0 {
         bail!("转账金额必须大于 0");
     }
+    let instructions =
+        transfer_sol_instructions(&identity.pubkey, recipient, lamports, compute_unit_lamports);
+    send_transaction(rpc, identity, &instructions, &[]).await
+}
+
+pub fn transfer_sol_instructions(
+    payer: &Pubkey,
+    recipient: &Pubkey,
+    lamports: u64,
+    compute_unit_lamports: u64,
+) -> Vec<Instruction> {
     let mut instructions = Vec::with_capacity(2);
     maybe_insert_compute_unit_price(&mut instructions, compute_unit_lamports);
-    instructions.push(system_instruction::transfer(
+    instructions.push(system_instruction::transfer(payer, recipient, lamports));
+    instructions
+}
+
+/// 发送 SPL 代币（兼容 Token-2022），接收方关联账户不存在时一并创建。
+#[allow(clippy::too_many_arguments)]
+pub async fn transfer_token(
+    rpc: &Arc<RpcClient>,
+    identity: &EngineIdentity,
+    mint: &Pubkey,
+    source: &Pubkey,
+    token_program: &Pubkey,
+    recipient: &Pubkey,
+    amount: u64,
+    compute_unit_lamports: u64,
+) -> Result<Signature> {
+    if amount == 0 {
+        bail!("转账金额必须大于 0");
+    }
+    let decimals = rpc
+        .get_token_supply(mint)
+        .await
+        .map_err(|err| anyhow!("查询 mint {mint} 精度失败: {err}"))?
+        .decimals;
+    let instructions = transfer_token_instructions(
         &identity.pubkey,
+        mint,
+        decimals,
+        source,
+        token_program,
         recipient,
-        lamports,
-    ));
+        amount,
+        compute_unit_lamports,
+    )?;
     send_transaction(rpc, identity, &instructions, &[]).await
 }
 
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_token_instructions(
+    payer: &Pubkey,
+    mint: &Pubkey,
+    decimals: u8,
+    source: &Pubkey,
+    token_program: &Pubkey,
+    recipient: &Pubkey,
+    amount: u64,
+    compute_unit_lamports: u64,
+) -> Result<Vec<Instruction>> {
+    let destination = spl_associated_token_account::get_associated_token_address_with_program_id(
+        recipient,
+        mint,
+        token_program,
+    );
+    let mut instructions = Vec::with_capacity(3);
+    instructions.push(
+        spl_associated_token_account::instruction::create_associated_token_account_idempotent(
+            payer,
+            recipient,
+            mint,
+            token_program,
+        ),
+    );
+    instructions.push(
+        spl_token_2022::instruction::transfer_checked(
+            token_program,
+            source,
+            mint,
+            &destination,
+            payer,
+            &[],
+            amount,
+            decimals,
+        )
+        .map_err(|err| anyhow!("构建代币转账指令失败: {err}"))?,
+    );
+    maybe_insert_compute_unit_price(&mut instructions, compute_unit_lamports);
+    Ok(instructions)
+}
+
 /// 将 SOL 转换为 WSOL（创建 ATA + 转账 + sync）。
-pub async fn wrap_sol(
-    rpc: &Arc<RpcClient>,
-    identity: &EngineIdentity,
+pub fn wrap_sol_instructions(
+    payer: &Pubkey,
     lamports: u64,
     compute_unit_lamports: u64,
-) -> Result<Signature> {
-    if lamports == 0 {
-        bail!("包裹金额必须大于 0");
-    }
-    let cached = wsol::wrap_sequence(&identity.pubkey, lamports);
+) -> Vec<Instruction> {
+    let cached = wsol::wrap_sequence(payer, lamports);
     let mut instructions: Vec<Instruction> = cached.as_ref().clone();
     maybe_insert_compute_unit_price(&mut instructions, compute_unit_lamports);
-    send_transaction(rpc, identity, &instructions, &[]).await
+    instructions
 }
 
 /// 仅解包部分 WSOL：通过临时账户获取指定 lamports。
@@ -76,13 +161,25 @@ pub async fn partial_unwrap_wsol(
     lamports: u64,
     compute_unit_lamports: u64,
 ) -> Result<Signature> {
+    let (instructions, temp) =
+        partial_unwrap_instructions(rpc, &identity.pubkey, lamports, compute_unit_lamports).await?;
+    let extra_signers = [&temp];
+    send_transaction(rpc, identity, &instructions, &extra_signers).await
+}
+
+/// 构建部分解包指令，返回的临时账户需要一同签名。
+pub async fn partial_unwrap_instructions(
+    rpc: &Arc<RpcClient>,
+    owner: &Pubkey,
+    lamports: u64,
+    compute_unit_lamports: u64,
+) -> Result<(Vec<Instruction>, Keypair)> {
     if lamports == 0 {
         bail!("解包金额必须大于 0");
     }
 
     let wsol_mint = wsol::WSOL_MINT;
-    let wsol_ata =
-        spl_associated_token_account::get_associated_token_address(&identity.pubkey, &wsol_mint);
+    let wsol_ata = spl_associated_token_account::get_associated_token_address(owner, &wsol_mint);
     let account = rpc
         .get_account(&wsol_ata)
         .await
@@ -92,7 +189,7 @@ pub async fn partial_unwrap_wsol(
     }
     let token_account = spl_token::state::Account::unpack(&account.data)
         .map_err(|err| anyhow!("解析 WSOL 账户失败: {err}"))?;
-    if token_account.owner != identity.pubkey {
+    if token_account.owner != *owner {
         bail!("WSOL 账户所有者与当前钱包不匹配");
     }
     if token_account.amount < lamports {
@@ -107,7 +204,7 @@ pub async fn partial_unwrap_wsol(
 
     let mut instructions = Vec::with_capacity(4);
     instructions.push(system_instruction::create_account(
-        &identity.pubkey,
+        owner,
         &temp.pubkey(),
         rent,
         spl_token::state::Account::LEN as u64,
@@ -118,7 +215,7 @@ pub async fn partial_unwrap_wsol(
             &spl_token::ID,
             &temp.pubkey(),
             &wsol::WSOL_MINT,
-            &identity.pubkey,
+            owner,
         )
         .map_err(|err| anyhow!("初始化临时 WSOL 账户失败: {err}"))?,
     );
@@ -127,29 +224,22 @@ pub async fn partial_unwrap_wsol(
             &spl_token::ID,
             &wsol_ata,
             &temp.pubkey(),
-            &identity.pubkey,
+            owner,
             &[],
             lamports,
         )
         .map_err(|err| anyhow!("WSOL 转账失败: {err}"))?,
     );
     instructions.push(
-        spl_token::instruction::close_account(
-            &spl_token::ID,
-            &temp.pubkey(),
-            &identity.pubkey,
-            &identity.pubkey,
-            &[],
-        )
-        .map_err(|err| anyhow!("关闭临时 WSOL 账户失败: {err}"))?,
+        spl_token::instruction::close_account(&spl_token::ID, &temp.pubkey(), owner, owner, &[])
+            .map_err(|err| anyhow!("关闭临时 WSOL 账户失败: {err}"))?,
     );
 
     maybe_insert_compute_unit_price(&mut instructions, compute_unit_lamports);
-    let extra_signers = [&temp];
-    send_transaction(rpc, identity, &instructions, &extra_signers).await
+    Ok((instructions, temp))
 }
 
-fn maybe_insert_compute_unit_price(
+pub(crate) fn maybe_insert_compute_unit_price(
     instructions: &mut Vec<Instruction>,
     compute_unit_lamports: u64,
 ) {
@@ -162,7 +252,7 @@ fn maybe_insert_compute_unit_price(
     );
 }
 
-async fn send_transaction(
+pub(crate) async fn send_transaction(
     rpc: &Arc<RpcClient>,
     identity: &EngineIdentity,
     instructions: &[Instruction],
@@ -20,8 +20,10 @@ use tokio::runtime::Handle;
 use tokio::task;
 
 use super::ui;
+use crate::api::jupiter::JupiterApiClient;
 use crate::engine::EngineIdentity;
-use crate::tools::sol;
+use crate::tools::actions;
+use crate::tools::plan::ToolPlan;
 use rust_decimal::Decimal;
 use solana_client::nonblocking::rpc_client::RpcClient;
 use solana_sdk::pubkey::Pubkey;
@@ -39,6 +41,8 @@ pub struct ExecutionResources {
     pub rpc: Arc<RpcClient>,
     pub identity: EngineIdentity,
     pub compute_unit_lamports: u64,
+    /// 兑换回 SOL 使用的聚合器客户端，仅 Jupiter / 自托管 Jupiter 引擎可用。
+    pub swap_api: Option<JupiterApiClient>,
 }
 
 #[derive(Clone, Debug)]
@@ -69,16 +73,28 @@ impl WalletSummary {
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ToolsAction {
     Transfer,
+    TransferToken,
     WrapSol,
     PartialUnwrap,
+    UnwrapAll,
+    SwapToSol,
+    CloseEmptyAccounts,
+    CloseLighthouseMemory,
+    CloseMarginfi,
 }
 
 impl ToolsAction {
     pub fn title(&self) -> &'static str {
         match self {
             Self::Transfer => "Transfer SOL",
+            Self::TransferToken => "Transfer Token",
             Self::WrapSol => "Wrap SOL",
             Self::PartialUnwrap => "Partial Unwrap",
+            Self::UnwrapAll => "Unwrap All WSOL",
+            Self::SwapToSol => "Swap To SOL",
+            Self::CloseEmptyAccounts => "Close Empty ATAs",
+            Self::CloseLighthouseMemory => "Close Lighthouse Memory",
+            Self::CloseMarginfi => "Close MarginFi Account",
         }
     }
 
@@ -87,6 +103,12 @@ impl ToolsAction {
             Self::Transfer => "发送原生 SOL 到目标地址，适合日常转账或风控调账。",
             Self::WrapSol => "将指定数量的 SOL 包装为 WSOL 并同步到 ATA。",
             Self::PartialUnwrap => "从 WSOL 账户中提取一部分 SOL，自动关闭临时账户。",
+            Self::TransferToken => "发送任意 SPL / Token-2022 代币，接收方 ATA 不存在时自动创建。",
+            Self::UnwrapAll => "解包全部 WSOL 并关闭 WSOL 账户，租金一并退回。",
+            Self::SwapToSol => "通过当前配置的聚合器将代币兑换回 SOL，数量留空则兑换全部余额。",
+            Self::CloseEmptyAccounts => "批量关闭余额为 0 的代币账户，回收租金。",
+            Self::CloseLighthouseMemory => "关闭钱包名下的 Lighthouse memory 账户，回收租金。",
+            Self::CloseMarginfi => "关闭钱包的 MarginFi 账户（需先取出存款），回收租金。",
         }
     }
 }
@@ -162,8 +184,14 @@ impl ToolsApp {
             wallet_index: 0,
             actions: vec![
                 ToolsAction::Transfer,
+                ToolsAction::TransferToken,
                 ToolsAction::WrapSol,
                 ToolsAction::PartialUnwrap,
+                ToolsAction::UnwrapAll,
+                ToolsAction::SwapToSol,
+                ToolsAction::CloseEmptyAccounts,
+                ToolsAction::CloseLighthouseMemory,
+                ToolsAction::CloseMarginfi,
             ],
             action_index: 0,
             focus: FocusArea::Wallets,
@@ -402,15 +430,33 @@ impl ToolsApp {
     }
 
     fn attempt_form_submission(&mut self) {
-        if let Some(form) = self.form.as_mut() {
-            match form.validate() {
-                Ok(pending) => {
-                    self.pending = Some(pending);
-                    self.view = ViewMode::Confirm;
-                }
-                Err(err) => {
-                    form.message = Some(err);
-                }
+        let Some(form) = self.form.as_mut() else {
+            return;
+        };
+        let mut pending = match form.validate() {
+            Ok(pending) => pending,
+            Err(err) => {
+                form.message = Some(err);
+                return;
+            }
+        };
+        let executor = self.executor.clone();
+        let params = pending.params.clone();
+        let plan = self.runtime.block_on(async move {
+            let mut plan = build_plan(&params, &executor).await?;
+            plan.estimate_fees(&executor.rpc, &executor.identity.pubkey)
+                .await?;
+            Ok::<_, Error>(plan)
+        });
+        match plan {
+            Ok(plan) => {
+                form.message = None;
+                pending.plan = Some(plan);
+                self.pending = Some(pending);
+                self.view = ViewMode::Confirm;
+            }
+            Err(err) => {
+                form.message = Some(format!("生成交易预览失败：{err}"));
             }
         }
     }
@@ -431,48 +477,17 @@ impl ToolsApp {
             Some(value) => value,
             None => return,
         };
+        let Some(plan) = pending.plan.clone() else {
+            return;
+        };
         self.view = ViewMode::Executing;
         let executor = self.executor.clone();
         let mut logs = Vec::new();
+        let title = pending.action.title();
         let result: Result<String, Error> = self.runtime.block_on(async move {
-            match pending.params {
-                ActionParams::Transfer { recipient, amount } => {
-                    let recipient_pubkey = Pubkey::from_str(&recipient)
-                        .map_err(|err| anyhow!("接收者地址格式错误: {err}"))?;
-                    let lamports = sol::decimal_sol_to_lamports(&amount)?;
-                    let signature = sol::transfer_sol(
-                        &executor.rpc,
-                        &executor.identity,
-                        &recipient_pubkey,
-                        lamports,
-                        executor.compute_unit_lamports,
-                    )
-                    .await?;
-                    Ok(format!("Transfer 成功：{}", signature))
-                }
-                ActionParams::Wrap { amount } => {
-                    let lamports = sol::decimal_sol_to_lamports(&amount)?;
-                    let signature = sol::wrap_sol(
-                        &executor.rpc,
-                        &executor.identity,
-                        lamports,
-                        executor.compute_unit_lamports,
-                    )
-                    .await?;
-                    Ok(format!("Wrap 成功：{}", signature))
-                }
-                ActionParams::Unwrap { amount } => {
-                    let lamports = sol::decimal_sol_to_lamports(&amount)?;
-                    let signature = sol::partial_unwrap_wsol(
-                        &executor.rpc,
-                        &executor.identity,
-                        lamports,
-                        executor.compute_unit_lamports,
-                    )
-                    .await?;
-                    Ok(format!("Partial Unwrap 成功：{}", signature))
-                }
-            }
+            let signatures = plan.execute(&executor.rpc, &executor.identity).await?;
+            let joined: Vec<String> = signatures.iter().map(ToString::to_string).collect();
+            Ok(format!("{} 成功：{}", title, joined.join(", ")))
         });
 
         match result {
@@ -495,6 +510,64 @@ impl ToolsApp {
     }
 }
 
+async fn build_plan(params: &ActionParams, executor: &ExecutionResources) -> Result<ToolPlan> {
+    let rpc = &executor.rpc;
+    let identity = &executor.identity;
+    let cu = executor.compute_unit_lamports;
+    match params {
+        ActionParams::Transfer { recipient, amount } => {
+            let recipient = parse_pubkey(recipient, "接收者地址")?;
+            actions::plan_transfer_sol(identity, &recipient, amount, cu)
+        }
+        ActionParams::TransferToken {
+            mint,
+            recipient,
+            amount,
+        } => {
+            let mint = parse_pubkey(mint, "代币 Mint")?;
+            let recipient = parse_pubkey(recipient, "接收者地址")?;
+            actions::plan_transfer_token(rpc, identity, &mint, &recipient, amount, cu).await
+        }
+        ActionParams::Wrap { amount } => actions::plan_wrap_sol(identity, amount, cu),
+        ActionParams::Unwrap { amount } => {
+            actions::plan_partial_unwrap(rpc, identity, amount, cu).await
+        }
+        ActionParams::UnwrapAll => actions::plan_unwrap_all(rpc, identity, cu).await,
+        ActionParams::SwapToSol {
+            mint,
+            amount,
+            slippage_bps,
+        } => {
+            let api = executor
+                .swap_api
+                .as_ref()
+                .ok_or_else(|| anyhow!("当前引擎不支持兑换，请使用 Jupiter 或自托管 Jupiter"))?;
+            let mint = parse_pubkey(mint, "代币 Mint")?;
+            actions::plan_swap_to_sol(
+                rpc,
+                identity,
+                api,
+                &mint,
+                amount.as_ref(),
+                *slippage_bps,
+                cu,
+            )
+            .await
+        }
+        ActionParams::CloseEmptyAccounts => {
+            actions::plan_close_empty_accounts(rpc, identity, cu).await
+        }
+        ActionParams::CloseLighthouseMemory => {
+            actions::plan_close_lighthouse_memory(rpc, identity, cu).await
+        }
+        ActionParams::CloseMarginfi => actions::plan_close_marginfi(rpc, identity, cu).await,
+    }
+}
+
+fn parse_pubkey(value: &str, label: &str) -> Result<Pubkey> {
+    Pubkey::from_str(value.trim()).map_err(|err| anyhow!("{label}格式错误: {err}"))
+}
+
 pub async fn run_interactive_tui(
     context: InteractiveContext,
     executor: ExecutionResources,
@@ -611,6 +684,20 @@ impl ActionForm {
                 "解包的 SOL 数量",
                 InputKind::Amount,
             )],
+            ToolsAction::TransferToken => vec![
+                InputField::new("代币 Mint", "输入 Base58 地址", InputKind::Address),
+                InputField::new("接收地址", "输入 Base58 地址", InputKind::Address),
+                InputField::new("数量", "按代币精度，例如 12.5", InputKind::Amount),
+            ],
+            ToolsAction::SwapToSol => vec![
+                InputField::new("代币 Mint", "输入 Base58 地址", InputKind::Address),
+                InputField::new("数量", "留空兑换全部余额", InputKind::Amount),
+                InputField::new("滑点 (bps)", "默认 50", InputKind::Amount),
+            ],
+            ToolsAction::UnwrapAll
+            | ToolsAction::CloseEmptyAccounts
+            | ToolsAction::CloseLighthouseMemory
+            | ToolsAction::CloseMarginfi => Vec::new(),
         };
         Self {
             action,
@@ -669,6 +756,9 @@ impl ActionForm {
                 field.display_value()
             ));
         }
+        if self.fields.is_empty() {
+            lines.push("无需输入，按 Enter 查询账户并生成交易预览".to_string());
+        }
         lines.push(String::new());
         lines.push("Tab/方向键：切换输入 | Enter：提交 | Esc：取消".to_string());
         if let Some(message) = &self.message {
@@ -689,32 +779,102 @@ impl ActionForm {
                     return Err("请输入接收地址".into());
                 }
                 let amount = parse_decimal(self.fields[1].value.trim())?;
-                Ok(PendingAction {
-                    action: self.action,
-                    params: ActionParams::Transfer {
+                Ok(PendingAction::new(
+                    self.action,
+                    ActionParams::Transfer {
                         recipient: address.to_string(),
                         amount,
                     },
-                })
+                ))
             }
             ToolsAction::WrapSol => {
                 let amount = parse_decimal(self.fields[0].value.trim())?;
-                Ok(PendingAction {
-                    action: self.action,
-                    params: ActionParams::Wrap { amount },
-                })
+                Ok(PendingAction::new(
+                    self.action,
+                    ActionParams::Wrap { amount },
+                ))
             }
             ToolsAction::PartialUnwrap => {
                 let amount = parse_decimal(self.fields[0].value.trim())?;
-                Ok(PendingAction {
-                    action: self.action,
-                    params: ActionParams::Unwrap { amount },
-                })
+                Ok(PendingAction::new(
+                    self.action,
+                    ActionParams::Unwrap { amount },
+                ))
+            }
+            ToolsAction::TransferToken => {
+                if self.fields.len() < 3 {
+                    return Err("表单缺少字段".into());
+                }
+                let mint = self.fields[0].value.trim();
+                if mint.is_empty() {
+                    return Err("请输入代币 Mint".into());
+                }
+                let recipient = self.fields[1].value.trim();
+                if recipient.is_empty() {
+                    return Err("请输入接收地址".into());
+                }
+                let amount = parse_decimal(self.fields[2].value.trim())?;
+                Ok(PendingAction::new(
+                    self.action,
+                    ActionParams::TransferToken {
+                        mint: mint.to_string(),
+                        recipient: recipient.to_string(),
+                        amount,
+                    },
+                ))
+            }
+            ToolsAction::SwapToSol => {
+                if self.fields.len() < 3 {
+                    return Err("表单缺少字段".into());
+                }
+                let mint = self.fields[0].value.trim();
+                if mint.is_empty() {
+                    return Err("请输入代币 Mint".into());
+                }
+                let amount_raw = self.fields[1].value.trim();
+                let amount = if amount_raw.is_empty() {
+                    None
+                } else {
+                    Some(parse_decimal(amount_raw)?)
+                };
+                let slippage_raw = self.fields[2].value.trim();
+                let slippage_bps = if slippage_raw.is_empty() {
+                    DEFAULT_SWAP_SLIPPAGE_BPS
+                } else {
+                    slippage_raw
+                        .parse::<u16>()
+                        .map_err(|err| format!("滑点格式错误: {err}"))?
+                };
+                if slippage_bps == 0 || slippage_bps > 10_000 {
+                    return Err("滑点需在 1-10000 bps 之间".into());
+                }
+                Ok(PendingAction::new(
+                    self.action,
+                    ActionParams::SwapToSol {
+                        mint: mint.to_string(),
+                        amount,
+                        slippage_bps,
+                    },
+                ))
+            }
+            ToolsAction::UnwrapAll => Ok(PendingAction::new(self.action, ActionParams::UnwrapAll)),
+            ToolsAction::CloseEmptyAccounts => Ok(PendingAction::new(
+                self.action,
+                ActionParams::CloseEmptyAccounts,
+            )),
+            ToolsAction::CloseLighthouseMemory => Ok(PendingAction::new(
+                self.action,
+                ActionParams::CloseLighthouseMemory,
+            )),
+            ToolsAction::CloseMarginfi => {
+                Ok(PendingAction::new(self.action, ActionParams::CloseMarginfi))
             }
         }
     }
 }
 
+const DEFAULT_SWAP_SLIPPAGE_BPS: u16 = 50;
+
 fn parse_decimal(input: &str) -> Result<Decimal, String> {
     let trimmed = input.trim();
     if trimmed.is_empty() {
@@ -819,9 +979,19 @@ impl InputKind {
 pub struct PendingAction {
     pub action: ToolsAction,
     pub params: ActionParams,
+    /// 提交表单后构建的交易预览，确认执行时直接签名发送。
+    pub plan: Option<ToolPlan>,
 }
 
 impl PendingAction {
+    fn new(action: ToolsAction, params: ActionParams) -> Self {
+        Self {
+            action,
+            params,
+            plan: None,
+        }
+    }
+
     pub fn describe_confirmation(&self) -> String {
         let mut lines = Vec::new();
         lines.push("确认操作".to_string());
@@ -837,6 +1007,36 @@ impl PendingAction {
             ActionParams::Unwrap { amount } => {
                 lines.push(format!("Partial Unwrap 金额：{} SOL", amount));
             }
+            ActionParams::TransferToken {
+                mint,
+                recipient,
+                amount,
+            } => {
+                lines.push(format!("代币：{}", mint));
+                lines.push(format!("接收者：{}", recipient));
+                lines.push(format!("数量：{}", amount));
+            }
+            ActionParams::SwapToSol {
+                mint,
+                amount,
+                slippage_bps,
+            } => {
+                lines.push(format!("代币：{}", mint));
+                match amount {
+                    Some(amount) => lines.push(format!("数量：{}", amount)),
+                    None => lines.push("数量：全部余额".to_string()),
+                }
+                lines.push(format!("滑点：{} bps", slippage_bps));
+            }
+            ActionParams::UnwrapAll
+            | ActionParams::CloseEmptyAccounts
+            | ActionParams::CloseLighthouseMemory
+            | ActionParams::CloseMarginfi => {}
+        }
+        if let Some(plan) = &self.plan {
+            lines.push(String::new());
+            lines.push("交易预览".to_string());
+            lines.extend(plan.describe());
         }
         lines.push(String::new());
         lines.push("Enter：确认并执行 | Esc：返回表单 | q：退出".to_string());
@@ -846,7 +1046,28 @@ impl PendingAction {
 
 #[derive(Clone, Debug)]
 pub enum ActionParams {
-    Transfer { recipient: String, amount: Decimal },
-    Wrap { amount: Decimal },
-    Unwrap { amount: Decimal },
+    Transfer {
+        recipient: String,
+        amount: Decimal,
+    },
+    TransferToken {
+        mint: String,
+        recipient: String,
+        amount: Decimal,
+    },
+    Wrap {
+        amount: Decimal,
+    },
+    Unwrap {
+        amount: Decimal,
+    },
+    UnwrapAll,
+    SwapToSol {
+        mint: String,
+        amount: Option<Decimal>,
+        slippage_bps: u16,
+    },
+    CloseEmptyAccounts,
+    CloseLighthouseMemory,
+    CloseMarginfi,
 }
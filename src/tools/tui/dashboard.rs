@@ -0,0 +1,544 @@
+use std::future::Future;
+use std::io::stdout;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+use anyhow::{Result, anyhow};
+use crossterm::{
+    event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    execute,
+    terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
+};
+use ratatui::{
+    Frame, Terminal,
+    backend::CrosstermBackend,
+    prelude::*,
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, Wrap},
+};
+use tokio::runtime::Handle;
+use tokio::task;
+use tracing::warn;
+
+use crate::monitoring::dashboard::{DashboardSnapshot, IpSlotState, feed};
+use crate::tools::plan::format_sol;
+
+const LOCAL_REFRESH: Duration = Duration::from_millis(500);
+const TICK_RATE: Duration = Duration::from_millis(200);
+
+/// 面板数据来源：本进程事件流，或远端实例的控制接口。
+enum DashboardSource {
+    Local,
+    Remote {
+        client: reqwest::Client,
+        url: String,
+        token: Option<String>,
+        handle: Handle,
+    },
+}
+
+impl DashboardSource {
+    fn label(&self) -> String {
+        match self {
+            DashboardSource::Local => "本地进程".to_string(),
+            DashboardSource::Remote { url, .. } => url.clone(),
+        }
+    }
+
+    fn fetch(&self) -> Result<DashboardSnapshot> {
+        match self {
+            DashboardSource::Local => Ok(feed().snapshot()),
+            DashboardSource::Remote {
+                client,
+                url,
+                token,
+                handle,
+            } => handle.block_on(async {
+                let mut request = client.get(format!("{url}/dashboard"));
+                if let Some(token) = token {
+                    request = request.bearer_auth(token);
+                }
+                let response = request
+                    .send()
+                    .await
+                    .map_err(|err| anyhow!("请求控制接口失败: {err}"))?
+                    .error_for_status()
+                    .map_err(|err| anyhow!("控制接口返回错误: {err}"))?;
+                response
+                    .json::<DashboardSnapshot>()
+                    .await
+                    .map_err(|err| anyhow!("解析仪表盘数据失败: {err}"))
+            }),
+        }
+    }
+}
+
+struct DashboardApp {
+    source: DashboardSource,
+    refresh: Duration,
+    snapshot: DashboardSnapshot,
+    error: Option<String>,
+    last_refresh: Option<Instant>,
+    should_quit: bool,
+}
+
+impl DashboardApp {
+    fn new(source: DashboardSource, refresh: Duration) -> Self {
+        Self {
+            source,
+            refresh,
+            snapshot: DashboardSnapshot::default(),
+            error: None,
+            last_refresh: None,
+            should_quit: false,
+        }
+    }
+
+    fn maybe_refresh(&mut self) {
+        let due = self
+            .last_refresh
+            .map(|at| at.elapsed() >= self.refresh)
+            .unwrap_or(true);
+        if !due {
+            return;
+        }
+        match self.source.fetch() {
+            Ok(snapshot) => {
+                self.snapshot = snapshot;
+                self.error = None;
+            }
+            Err(err) => self.error = Some(err.to_string()),
+        }
+        self.last_refresh = Some(Instant::now());
+    }
+
+    fn on_key(&mut self, key: KeyEvent) {
+        if key.kind != KeyEventKind::Press {
+            return;
+        }
+        match key.code {
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.should_quit = true;
+            }
+            KeyCode::Char('q') | KeyCode::Esc => self.should_quit = true,
+            KeyCode::Char('r') => self.last_refresh = None,
+            _ => {}
+        }
+    }
+
+    fn shows_logs(&self) -> bool {
+        matches!(self.source, DashboardSource::Local)
+    }
+}
+
+/// `run --tui`：在策略运行期间渲染本地面板；策略结束时恢复终端，用户退出面板即停止运行。
+pub async fn run_local_dashboard<F>(strategy: F) -> Result<()>
+where
+    F: Future<Output = Result<()>>,
+{
+    let stop = Arc::new(AtomicBool::new(false));
+    let ui_stop = stop.clone();
+    let mut ui = task::spawn_blocking(move || {
+        run_blocking_dashboard(DashboardSource::Local, LOCAL_REFRESH, ui_stop)
+    });
+
+    tokio::select! {
+        result = strategy => {
+            stop.store(true, Ordering::Relaxed);
+            match ui.await {
+                Ok(Ok(())) => {}
+                Ok(Err(err)) => warn!(target: "dashboard", error = %err, "仪表盘异常退出"),
+                Err(err) => warn!(target: "dashboard", error = %err, "仪表盘线程异常退出"),
+            }
+            result
+        }
+        ui_result = &mut ui => ui_result?,
+    }
+}
+
+/// `galileo dashboard`：轮询远端实例的 `/dashboard` 接口。
+pub async fn run_remote_dashboard(
+    client: reqwest::Client,
+    url: String,
+    token: Option<String>,
+    refresh: Duration,
+) -> Result<()> {
+    let source = DashboardSource::Remote {
+        client,
+        url,
+        token,
+        handle: Handle::current(),
+    };
+    let stop = Arc::new(AtomicBool::new(false));
+    task::spawn_blocking(move || run_blocking_dashboard(source, refresh, stop)).await?
+}
+
+fn run_blocking_dashboard(
+    source: DashboardSource,
+    refresh: Duration,
+    stop: Arc<AtomicBool>,
+) -> Result<()> {
+    enable_raw_mode()?;
+    let mut stdout = stdout();
+    execute!(stdout, EnterAlternateScreen)?;
+    let backend = CrosstermBackend::new(stdout);
+    let mut terminal = Terminal::new(backend)?;
+    terminal.clear()?;
+    let res = run_dashboard_loop(&mut terminal, DashboardApp::new(source, refresh), &stop);
+    disable_raw_mode()?;
+    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+    terminal.show_cursor()?;
+    res
+}
+
+fn run_dashboard_loop<B: ratatui::backend::Backend>(
+    terminal: &mut Terminal<B>,
+    mut app: DashboardApp,
+    stop: &AtomicBool,
+) -> Result<()> {
+    loop {
+        if app.should_quit || stop.load(Ordering::Relaxed) {
+            break;
+        }
+        app.maybe_refresh();
+        terminal.draw(|f| render(f, &app))?;
+        if !event::poll(TICK_RATE)? {
+            continue;
+        }
+        if let Event::Key(key) = event::read()? {
+            app.on_key(key);
+        }
+    }
+    Ok(())
+}
+
+fn render(frame: &mut Frame, app: &DashboardApp) {
+    let mut constraints = vec![
+        Constraint::Length(3),
+        Constraint::Length(9),
+        Constraint::Min(8),
+    ];
+    if app.shows_logs() {
+        constraints.push(Constraint::Percentage(30));
+    }
+    let root = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(constraints)
+        .split(frame.area());
+
+    draw_header(frame, root[0], app);
+
+    let top = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage(30),
+            Constraint::Percentage(35),
+            Constraint::Percentage(35),
+        ])
+        .split(root[1]);
+    draw_strategies(frame, top[0], &app.snapshot);
+    draw_landers(frame, top[1], &app.snapshot);
+    draw_fees_and_wallets(frame, top[2], &app.snapshot);
+
+    let middle = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(45), Constraint::Percentage(55)])
+        .split(root[2]);
+    draw_ip_slots(frame, middle[0], &app.snapshot);
+    draw_routes(frame, middle[1], &app.snapshot);
+
+    if app.shows_logs() {
+        draw_logs(frame, root[3]);
+    }
+}
+
+fn panel(title: &str) -> Block<'_> {
+    Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::DarkGray))
+}
+
+fn header_style() -> Style {
+    Style::default()
+        .fg(Color::LightBlue)
+        .add_modifier(Modifier::BOLD)
+}
+
+fn draw_header(frame: &mut Frame, area: Rect, app: &DashboardApp) {
+    let snapshot = &app.snapshot;
+    let mut spans = vec![
+        Span::styled(
+            " Galileo Dashboard ",
+            Style::default()
+                .fg(Color::Black)
+                .bg(Color::LightBlue)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(" "),
+        Span::styled(
+            format!("运行 {}", format_uptime(snapshot.uptime_secs)),
+            Style::default().fg(Color::White),
+        ),
+        Span::raw("  ·  "),
+    ];
+    match &snapshot.trading_halt {
+        Some(reason) => spans.push(Span::styled(
+            format!("交易已暂停: {reason}"),
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+        )),
+        None => spans.push(Span::styled("交易中", Style::default().fg(Color::Green))),
+    }
+    spans.push(Span::raw("  ·  "));
+    spans.push(Span::styled(
+        format!("来源: {}", app.source.label()),
+        Style::default().fg(Color::Gray),
+    ));
+
+    let hints = match &app.error {
+        Some(err) => Line::from(Span::styled(
+            format!("刷新失败: {err}"),
+            Style::default().fg(Color::Red),
+        )),
+        None => Line::from("q / Esc 退出 · r 立即刷新"),
+    };
+    let text = vec![Line::from(spans), hints];
+    frame.render_widget(Paragraph::new(text).block(panel("")), area);
+}
+
+fn draw_strategies(frame: &mut Frame, area: Rect, snapshot: &DashboardSnapshot) {
+    let rows = snapshot.strategies.iter().map(|row| {
+        Row::new(vec![
+            Cell::from(row.name.clone()),
+            Cell::from(row.opportunities_per_minute.to_string()),
+            Cell::from(row.opportunities_total.to_string()),
+        ])
+    });
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Percentage(50),
+            Constraint::Percentage(25),
+            Constraint::Percentage(25),
+        ],
+    )
+    .header(Row::new(vec!["策略", "机会/分", "累计"]).style(header_style()))
+    .block(panel("机会"));
+    frame.render_widget(table, area);
+}
+
+fn draw_landers(frame: &mut Frame, area: Rect, snapshot: &DashboardSnapshot) {
+    let rows = snapshot.landers.iter().map(|row| {
+        let rate = row
+            .success_rate()
+            .map(|rate| format!("{:.1}%", rate * 100.0))
+            .unwrap_or_else(|| "-".to_string());
+        Row::new(vec![
+            Cell::from(row.name.clone()),
+            Cell::from(row.attempts.to_string()),
+            Cell::from(Span::styled(
+                row.success.to_string(),
+                Style::default().fg(Color::Green),
+            )),
+            Cell::from(Span::styled(
+                row.failure.to_string(),
+                Style::default().fg(Color::Red),
+            )),
+            Cell::from(rate),
+        ])
+    });
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Percentage(28),
+            Constraint::Percentage(18),
+            Constraint::Percentage(18),
+            Constraint::Percentage(18),
+            Constraint::Percentage(18),
+        ],
+    )
+    .header(Row::new(vec!["落地器", "尝试", "成功", "失败", "成功率"]).style(header_style()))
+    .block(panel("落地"));
+    frame.render_widget(table, area);
+}
+
+fn draw_fees_and_wallets(frame: &mut Frame, area: Rect, snapshot: &DashboardSnapshot) {
+    let mut lines = Vec::new();
+    lines.push(fee_line(
+        "Tip",
+        snapshot
+            .tip
+            .as_ref()
+            .map(|sample| (sample.strategy.as_str(), sample.value)),
+        "lamports",
+    ));
+    lines.push(fee_line(
+        "CU 价格",
+        snapshot
+            .compute_unit_price
+            .as_ref()
+            .map(|sample| (sample.strategy.as_str(), sample.value)),
+        "micro-lamports",
+    ));
+    lines.push(Line::from(""));
+    if snapshot.wallets.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "暂无钱包余额数据",
+            Style::default().fg(Color::Gray),
+        )));
+    }
+    for wallet in &snapshot.wallets {
+        lines.push(Line::from(vec![
+            Span::styled(short_key(&wallet.wallet), Style::default().fg(Color::White)),
+            Span::raw("  "),
+            Span::styled(
+                format!("SOL {}", format_sol(wallet.sol_lamports)),
+                Style::default().fg(Color::LightGreen),
+            ),
+            Span::raw("  "),
+            Span::styled(
+                format!("WSOL {}", format_sol(wallet.wsol_lamports)),
+                Style::default().fg(Color::LightCyan),
+            ),
+            Span::styled(
+                format!("  {}s 前", wallet.age_secs),
+                Style::default().fg(Color::Gray),
+            ),
+        ]));
+    }
+    let paragraph = Paragraph::new(lines)
+        .block(panel("费用 / 钱包"))
+        .wrap(Wrap { trim: true });
+    frame.render_widget(paragraph, area);
+}
+
+fn fee_line(label: &str, sample: Option<(&str, u64)>, unit: &str) -> Line<'static> {
+    match sample {
+        Some((strategy, value)) => Line::from(vec![
+            Span::styled(format!("{label}: "), Style::default().fg(Color::Gray)),
+            Span::styled(
+                format!("{value} {unit}"),
+                Style::default().fg(Color::Yellow),
+            ),
+            Span::styled(format!("  ({strategy})"), Style::default().fg(Color::Gray)),
+        ]),
+        None => Line::from(Span::styled(
+            format!("{label}: -"),
+            Style::default().fg(Color::Gray),
+        )),
+    }
+}
+
+fn draw_ip_slots(frame: &mut Frame, area: Rect, snapshot: &DashboardSnapshot) {
+    let mut idle = 0usize;
+    let mut busy = 0usize;
+    let mut cooling = 0usize;
+    let rows: Vec<Row> = snapshot
+        .ip_slots
+        .iter()
+        .map(|slot| {
+            let color = match slot.state {
+                IpSlotState::Idle => {
+                    idle += 1;
+                    Color::Green
+                }
+                IpSlotState::Busy => {
+                    busy += 1;
+                    Color::Yellow
+                }
+                IpSlotState::Cooling => {
+                    cooling += 1;
+                    Color::Red
+                }
+            };
+            let cooldown = match (slot.cooldown_remaining_ms, &slot.cooldown_reason) {
+                (Some(ms), Some(reason)) => format!("{:.1}s {reason}", ms as f64 / 1000.0),
+                (Some(ms), None) => format!("{:.1}s", ms as f64 / 1000.0),
+                _ => "-".to_string(),
+            };
+            Row::new(vec![
+                Cell::from(slot.ip.clone()),
+                Cell::from(slot.kind.clone()),
+                Cell::from(Span::styled(
+                    slot.state.as_str(),
+                    Style::default().fg(color),
+                )),
+                Cell::from(slot.inflight.to_string()),
+                Cell::from(cooldown),
+                Cell::from(slot.cooldowns.to_string()),
+            ])
+        })
+        .collect();
+    let title = format!("IP 槽位  ·  空闲 {idle} / 忙碌 {busy} / 冷却 {cooling}");
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Percentage(26),
+            Constraint::Percentage(12),
+            Constraint::Percentage(12),
+            Constraint::Percentage(10),
+            Constraint::Percentage(28),
+            Constraint::Percentage(12),
+        ],
+    )
+    .header(
+        Row::new(vec!["IP", "类型", "状态", "在途", "冷却剩余", "冷却次数"]).style(header_style()),
+    )
+    .block(panel(&title));
+    frame.render_widget(table, area);
+}
+
+fn draw_routes(frame: &mut Frame, area: Rect, snapshot: &DashboardSnapshot) {
+    let rows = snapshot.routes.iter().map(|route| {
+        Row::new(vec![
+            Cell::from(route.route.clone()),
+            Cell::from(route.source.clone()),
+            Cell::from(route.legs.to_string()),
+            Cell::from(route.orders_per_minute.to_string()),
+            Cell::from(route.orders_total.to_string()),
+        ])
+    });
+    let title = format!("纯盲发路由  ·  活跃 {}", snapshot.routes_active);
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Percentage(52),
+            Constraint::Percentage(12),
+            Constraint::Percentage(8),
+            Constraint::Percentage(14),
+            Constraint::Percentage(14),
+        ],
+    )
+    .header(Row::new(vec!["路由", "来源", "腿数", "订单/分", "累计"]).style(header_style()))
+    .block(panel(&title));
+    frame.render_widget(table, area);
+}
+
+fn draw_logs(frame: &mut Frame, area: Rect) {
+    let available_height = area.height.saturating_sub(2).max(1) as usize;
+    let lines: Vec<Line> = feed()
+        .recent_logs(available_height)
+        .into_iter()
+        .map(|line| Line::from(Span::styled(line, Style::default().fg(Color::Gray))))
+        .collect();
+    let paragraph = Paragraph::new(lines)
+        .block(panel("日志"))
+        .wrap(Wrap { trim: false });
+    frame.render_widget(paragraph, area);
+}
+
+fn format_uptime(secs: u64) -> String {
+    format!(
+        "{:02}:{:02}:{:02}",
+        secs / 3600,
+        (secs % 3600) / 60,
+        secs % 60
+    )
+}
+
+fn short_key(key: &str) -> String {
+    if key.len() <= 12 {
+        return key.to_string();
+    }
+    format!("{}…{}", &key[..4], &key[key.len() - 4..])
+}
@@ -1,4 +1,5 @@
 pub mod app;
+pub mod dashboard;
 pub mod ui;
 
 pub use app::{ExecutionResources, InteractiveContext, WalletSummary, run_interactive_tui};
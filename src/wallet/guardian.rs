@@ -0,0 +1,202 @@
+//! 钱包守护任务：巡检付费钱包的 SOL 余额，低于阈值时部分解包 WSOL 补充手续费与小费，
+//! SOL 与 WSOL 同时耗尽时暂停全部策略。
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{Result, anyhow};
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::pubkey::Pubkey;
+use spl_token::solana_program::program_pack::Pack;
+use tokio::task::JoinHandle;
+use tokio::time::MissedTickBehavior;
+use tracing::{debug, info, warn};
+
+use crate::config::AutoUnwrapConfig;
+use crate::control::{ControlOutcome, runtime_control};
+use crate::engine::EngineIdentity;
+use crate::instructions::wsol;
+use crate::monitoring::events;
+use crate::tools::sol::partial_unwrap_wsol;
+
+/// 单次巡检的处理结论。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum GuardianAction {
+    Healthy,
+    /// SOL 低于阈值，解包指定 lamports 的 WSOL。
+    Unwrap(u64),
+    /// SOL 低于阈值但没有可解包的 WSOL。
+    Starved,
+    /// SOL 与 WSOL 均低于停机阈值。
+    Halt,
+}
+
+fn decide(config: &AutoUnwrapConfig, sol: u64, wsol: u64) -> GuardianAction {
+    if sol < config.halt_balance_lamports && wsol < config.halt_balance_lamports {
+        return GuardianAction::Halt;
+    }
+    if sol >= config.min_sol_balance_lamports {
+        return GuardianAction::Healthy;
+    }
+    match config.unwrap_amount_lamports.min(wsol) {
+        0 => GuardianAction::Starved,
+        amount => GuardianAction::Unwrap(amount),
+    }
+}
+
+pub struct WalletGuardian {
+    rpc: Arc<RpcClient>,
+    identity: EngineIdentity,
+    config: AutoUnwrapConfig,
+    wsol_account: Pubkey,
+}
+
+impl WalletGuardian {
+    pub fn new(rpc: Arc<RpcClient>, identity: EngineIdentity, config: AutoUnwrapConfig) -> Self {
+        let wsol_account = spl_associated_token_account::get_associated_token_address(
+            &identity.pubkey,
+            &wsol::WSOL_MINT,
+        );
+        Self {
+            rpc,
+            identity,
+            config,
+            wsol_account,
+        }
+    }
+
+    pub fn spawn(self) -> JoinHandle<()> {
+        tokio::spawn(async move { self.run().await })
+    }
+
+    async fn run(self) {
+        let period = Duration::from_secs(self.config.check_interval_secs.max(1));
+        let mut ticker = tokio::time::interval(period);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        info!(
+            target: "wallet::guardian",
+            wallet = %self.identity.pubkey,
+            min_sol_balance_lamports = self.config.min_sol_balance_lamports,
+            unwrap_amount_lamports = self.config.unwrap_amount_lamports,
+            halt_balance_lamports = self.config.halt_balance_lamports,
+            interval_secs = period.as_secs(),
+            "钱包守护任务已启动"
+        );
+        loop {
+            ticker.tick().await;
+            if let Err(err) = self.inspect().await {
+                warn!(
+                    target: "wallet::guardian",
+                    error = %err,
+                    "钱包余额巡检失败"
+                );
+            }
+        }
+    }
+
+    async fn inspect(&self) -> Result<()> {
+        let sol = self
+            .rpc
+            .get_balance(&self.identity.pubkey)
+            .await
+            .map_err(|err| anyhow!("查询 SOL 余额失败: {err}"))?;
+        let wsol = self.wsol_balance().await?;
+        events::wallet_balance(&self.identity.pubkey, sol, wsol);
+        let control = runtime_control();
+
+        match decide(&self.config, sol, wsol) {
+            GuardianAction::Halt => {
+                control.halt_trading(format!(
+                    "SOL 余额 {sol} 与 WSOL 余额 {wsol} lamports 均低于停机阈值 {}",
+                    self.config.halt_balance_lamports
+                ));
+                return Ok(());
+            }
+            GuardianAction::Healthy => {
+                debug!(target: "wallet::guardian", sol, wsol, "钱包余额充足");
+            }
+            GuardianAction::Starved => {
+                warn!(
+                    target: "wallet::guardian",
+                    sol,
+                    min_sol_balance_lamports = self.config.min_sol_balance_lamports,
+                    "SOL 余额低于阈值且没有可解包的 WSOL"
+                );
+            }
+            GuardianAction::Unwrap(amount) => {
+                let signature = partial_unwrap_wsol(
+                    &self.rpc,
+                    &self.identity,
+                    amount,
+                    self.config.compute_unit_price_micro_lamports,
+                )
+                .await?;
+                info!(
+                    target: "wallet::guardian",
+                    sol,
+                    wsol,
+                    amount,
+                    signature = %signature,
+                    "SOL 余额低于阈值，已自动解包 WSOL"
+                );
+            }
+        }
+
+        if control.resume_trading() == ControlOutcome::Changed {
+            info!(target: "wallet::guardian", sol, wsol, "资金已恢复，策略继续运行");
+        }
+        Ok(())
+    }
+
+    /// WSOL 关联账户不存在时视为余额为 0。
+    async fn wsol_balance(&self) -> Result<u64> {
+        let account = self
+            .rpc
+            .get_account_with_commitment(&self.wsol_account, self.rpc.commitment())
+            .await
+            .map_err(|err| anyhow!("查询 WSOL 账户失败: {err}"))?
+            .value;
+        let Some(account) = account else {
+            return Ok(0);
+        };
+        if account.owner != spl_token::ID {
+            return Ok(0);
+        }
+        let token_account = spl_token::state::Account::unpack(&account.data)
+            .map_err(|err| anyhow!("解析 WSOL 账户失败: {err}"))?;
+        Ok(token_account.amount)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> AutoUnwrapConfig {
+        AutoUnwrapConfig {
+            enable: true,
+            unwrap_amount_lamports: 500,
+            min_sol_balance_lamports: 1_000,
+            compute_unit_price_micro_lamports: 0,
+            check_interval_secs: 10,
+            halt_balance_lamports: 100,
+        }
+    }
+
+    #[test]
+    fn unwraps_up_to_available_wsol_when_sol_is_low() {
+        let config = config();
+        assert_eq!(decide(&config, 1_000, 0), GuardianAction::Healthy);
+        assert_eq!(decide(&config, 999, 10_000), GuardianAction::Unwrap(500));
+        assert_eq!(decide(&config, 999, 300), GuardianAction::Unwrap(300));
+        assert_eq!(decide(&config, 999, 0), GuardianAction::Starved);
+    }
+
+    #[test]
+    fn halts_only_when_both_balances_are_exhausted() {
+        let config = config();
+        assert_eq!(decide(&config, 50, 99), GuardianAction::Halt);
+        assert_eq!(decide(&config, 50, 100), GuardianAction::Unwrap(100));
+        assert_eq!(decide(&config, 100, 0), GuardianAction::Starved);
+    }
+}
@@ -1,3 +1,6 @@
+pub mod guardian;
+pub mod treasury;
+
 use std::collections::HashMap;
 use std::str::FromStr;
 use std::sync::Arc;
@@ -81,7 +84,7 @@ impl WalletStateManager {
         self.accounts.get(mint).map(|entry| entry.clone())
     }
 
-    async fn refresh_once(&self) -> Result<usize> {
+    pub async fn refresh_once(&self) -> Result<usize> {
         let accounts = Self::fetch_accounts(&self.rpc_client, &self.owner).await?;
         self.accounts.clear();
         for (mint, account) in accounts {
@@ -0,0 +1,489 @@
+//! 利润归集：按 mint 保留工作余额，定时把主钱包中超出的部分（SOL、WSOL 或任意代币）转入冷钱包，
+//! 每次归集写入独立账本，并按 UTC 自然日限制累计金额。
+
+use std::collections::HashMap;
+use std::fs::{self, File, OpenOptions};
+use std::io::{ErrorKind, Write};
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{Context, Result, anyhow, bail};
+use serde::{Deserialize, Serialize};
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::pubkey::Pubkey;
+use tokio::task::JoinHandle;
+use tokio::time::MissedTickBehavior;
+use tracing::{debug, info, warn};
+
+use crate::config::{GalileoConfig, StrategyToggle, TreasuryMintConfig};
+use crate::engine::EngineIdentity;
+use crate::monitoring::now_secs;
+use crate::tools::sol::{transfer_sol, transfer_token};
+
+use super::WalletStateManager;
+
+pub const DEFAULT_TREASURY_LEDGER_PATH: &str = "monitoring/treasury_ledger.jsonl";
+
+/// 原生 SOL 在配置与账本中的标识。
+const NATIVE_SOL: &str = "SOL";
+const SECS_PER_DAY: u64 = 86_400;
+/// 0 字节系统账户的免租最低余额。
+const NATIVE_RENT_EXEMPT_LAMPORTS: u64 = 890_880;
+const SIGNATURE_FEE_LAMPORTS: u64 = 5_000;
+/// 归集交易不设置 compute unit limit，按默认上限估算优先费。
+const DEFAULT_COMPUTE_UNIT_LIMIT: u64 = 200_000;
+
+/// 归集账本中的一行。
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SweepEntry {
+    /// 写入时的 unix 秒。
+    pub recorded_at: u64,
+    /// mint 地址，原生 SOL 记为 `SOL`。
+    pub mint: String,
+    pub destination: String,
+    /// 归集前的钱包余额（最小单位）。
+    pub balance: u64,
+    pub float: u64,
+    pub amount: u64,
+    pub dry_run: bool,
+    #[serde(default)]
+    pub signature: Option<String>,
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
+struct SweepTarget {
+    label: String,
+    /// `None` 表示原生 SOL。
+    mint: Option<Pubkey>,
+    float: u64,
+    min_sweep: u64,
+    max_per_day: u64,
+}
+
+/// 本次可归集的数量；受当日剩余额度限制，低于 `min_sweep` 时不归集。
+fn sweep_amount(
+    balance: u64,
+    float: u64,
+    min_sweep: u64,
+    max_per_day: u64,
+    swept_today: u64,
+) -> u64 {
+    let mut amount = balance.saturating_sub(float);
+    if max_per_day > 0 {
+        amount = amount.min(max_per_day.saturating_sub(swept_today));
+    }
+    if amount == 0 || amount < min_sweep {
+        0
+    } else {
+        amount
+    }
+}
+
+/// 原生 SOL 至少保留免租最低余额与一笔归集交易的手续费，避免钱包被转空后无法再付费或转账因免租检查失败。
+fn native_reserve(compute_unit_price: u64) -> u64 {
+    let priority_fee = compute_unit_price
+        .saturating_mul(DEFAULT_COMPUTE_UNIT_LIMIT)
+        .div_ceil(1_000_000);
+    NATIVE_RENT_EXEMPT_LAMPORTS
+        .saturating_add(SIGNATURE_FEE_LAMPORTS)
+        .saturating_add(priority_fee)
+}
+
+/// 工作余额取配置值与运行所需下限中的较大者：未启用闪电贷时需覆盖已启用盲发/纯盲发的最大交易规模，
+/// 原生 SOL 不低于手续费与免租余额，启用自动解包时也不低于解包阈值，避免归集后立刻触发解包。
+fn working_float(config: &GalileoConfig, entry: &TreasuryMintConfig) -> u64 {
+    let mint = entry.mint.trim();
+    if mint.eq_ignore_ascii_case(NATIVE_SOL) {
+        let float = entry.float.max(native_reserve(
+            config.treasury.compute_unit_price_micro_lamports,
+        ));
+        return if config.auto_unwrap.enable {
+            float.max(config.auto_unwrap.min_sol_balance_lamports)
+        } else {
+            float
+        };
+    }
+    let bot = &config.bot;
+    if !bot.flashloan.products.is_empty() {
+        return entry.float;
+    }
+    let mut largest = 0;
+    if bot.strategy_enabled(StrategyToggle::BlindStrategy) {
+        for base in &config.blind_strategy.base_mints {
+            if base.mint.trim() == mint {
+                largest = base
+                    .lanes
+                    .iter()
+                    .map(|lane| lane.max)
+                    .fold(largest, u64::max);
+            }
+        }
+    }
+    if bot.strategy_enabled(StrategyToggle::PureBlindStrategy) {
+        for base in &config.pure_blind_strategy.assets.base_mints {
+            if base.mint.trim() == mint {
+                largest = base
+                    .lanes
+                    .iter()
+                    .map(|lane| lane.max)
+                    .fold(largest, u64::max);
+            }
+        }
+    }
+    entry.float.max(largest)
+}
+
+struct SweepLedger {
+    file: File,
+    day: u64,
+    /// 当日已归集数量，键为账本中的 mint 标识。
+    swept: HashMap<String, u64>,
+}
+
+impl SweepLedger {
+    /// 打开账本并恢复当日已归集额度，重启后每日上限依旧生效；dry-run 记录不计入。
+    fn open(path: &str) -> Result<Self> {
+        let path = Path::new(path);
+        if let Some(parent) = path.parent() {
+            if !parent.as_os_str().is_empty() {
+                fs::create_dir_all(parent)
+                    .with_context(|| format!("创建归集账本目录失败: {}", parent.display()))?;
+            }
+        }
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == ErrorKind::NotFound => String::new(),
+            Err(err) => {
+                return Err(err).with_context(|| format!("读取归集账本失败: {}", path.display()));
+            }
+        };
+        let day = now_secs() / SECS_PER_DAY;
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .with_context(|| format!("打开归集账本失败: {}", path.display()))?;
+        Ok(Self {
+            file,
+            day,
+            swept: swept_on_day(&content, day),
+        })
+    }
+
+    fn roll(&mut self, now: u64) {
+        let day = now / SECS_PER_DAY;
+        if day != self.day {
+            self.day = day;
+            self.swept.clear();
+        }
+    }
+
+    fn swept_today(&mut self, mint: &str, now: u64) -> u64 {
+        self.roll(now);
+        self.swept.get(mint).copied().unwrap_or(0)
+    }
+
+    fn record(&mut self, entry: &SweepEntry) {
+        self.roll(entry.recorded_at);
+        if !entry.dry_run && entry.error.is_none() {
+            let swept = self.swept.entry(entry.mint.clone()).or_default();
+            *swept = swept.saturating_add(entry.amount);
+        }
+        let mut line = match serde_json::to_string(entry) {
+            Ok(line) => line,
+            Err(err) => {
+                warn!(target: "wallet::treasury", error = %err, "归集记录序列化失败");
+                return;
+            }
+        };
+        line.push('\n');
+        if let Err(err) = self.file.write_all(line.as_bytes()) {
+            warn!(
+                target: "wallet::treasury",
+                mint = %entry.mint,
+                error = %err,
+                "写入归集账本失败"
+            );
+        }
+    }
+}
+
+fn swept_on_day(content: &str, day: u64) -> HashMap<String, u64> {
+    let mut swept: HashMap<String, u64> = HashMap::new();
+    for entry in content
+        .lines()
+        .filter_map(|line| serde_json::from_str::<SweepEntry>(line).ok())
+        .filter(|entry| {
+            !entry.dry_run && entry.error.is_none() && entry.recorded_at / SECS_PER_DAY == day
+        })
+    {
+        let total = swept.entry(entry.mint).or_default();
+        *total = total.saturating_add(entry.amount);
+    }
+    swept
+}
+
+pub struct TreasurySweeper {
+    rpc: Arc<RpcClient>,
+    identity: EngineIdentity,
+    wallet: Arc<WalletStateManager>,
+    cold_wallet: Pubkey,
+    targets: Vec<SweepTarget>,
+    ledger: SweepLedger,
+    dry_run: bool,
+    interval: Duration,
+    compute_unit_price: u64,
+}
+
+impl TreasurySweeper {
+    pub async fn new(
+        rpc: Arc<RpcClient>,
+        identity: EngineIdentity,
+        config: &GalileoConfig,
+        dry_run: bool,
+    ) -> Result<Self> {
+        let treasury = &config.treasury;
+        let cold_wallet = Pubkey::from_str(treasury.cold_wallet.trim())
+            .map_err(|err| anyhow!("treasury.cold_wallet 无效: {err}"))?;
+        if cold_wallet == identity.pubkey {
+            bail!("treasury.cold_wallet 不能是交易钱包本身");
+        }
+
+        let mut targets = Vec::with_capacity(treasury.mints.len());
+        for entry in &treasury.mints {
+            let text = entry.mint.trim();
+            let (label, mint) = if text.eq_ignore_ascii_case(NATIVE_SOL) {
+                (NATIVE_SOL.to_string(), None)
+            } else {
+                let mint = Pubkey::from_str(text)
+                    .map_err(|err| anyhow!("treasury.mints 中的 mint `{text}` 无效: {err}"))?;
+                (mint.to_string(), Some(mint))
+            };
+            targets.push(SweepTarget {
+                label,
+                mint,
+                float: working_float(config, entry),
+                min_sweep: entry.min_sweep,
+                max_per_day: entry.max_per_day,
+            });
+        }
+
+        let wallet = WalletStateManager::new(rpc.clone(), identity.pubkey, None).await?;
+        let ledger = SweepLedger::open(&treasury.ledger_path)?;
+        Ok(Self {
+            rpc,
+            identity,
+            wallet,
+            cold_wallet,
+            targets,
+            ledger,
+            dry_run,
+            interval: Duration::from_secs(treasury.interval_secs.max(1)),
+            compute_unit_price: treasury.compute_unit_price_micro_lamports,
+        })
+    }
+
+    pub fn spawn(self) -> JoinHandle<()> {
+        tokio::spawn(async move { self.run().await })
+    }
+
+    async fn run(mut self) {
+        let mut ticker = tokio::time::interval(self.interval);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        info!(
+            target: "wallet::treasury",
+            wallet = %self.identity.pubkey,
+            cold_wallet = %self.cold_wallet,
+            mints = self.targets.len(),
+            dry_run = self.dry_run,
+            interval_secs = self.interval.as_secs(),
+            "利润归集任务已启动"
+        );
+        loop {
+            ticker.tick().await;
+            if let Err(err) = self.sweep_once().await {
+                warn!(target: "wallet::treasury", error = %err, "利润归集失败");
+            }
+        }
+    }
+
+    async fn sweep_once(&mut self) -> Result<()> {
+        self.wallet.refresh_once().await?;
+        for target in &self.targets {
+            let now = now_secs();
+            let token_account = match target.mint {
+                Some(mint) => match self.wallet.get_account(&mint) {
+                    Some(account) => Some(account),
+                    None => {
+                        debug!(target: "wallet::treasury", mint = %mint, "钱包中没有该 mint 的代币账户");
+                        continue;
+                    }
+                },
+                None => None,
+            };
+            let balance = match &token_account {
+                Some(account) => account.balance.unwrap_or_default(),
+                None => self
+                    .rpc
+                    .get_balance(&self.identity.pubkey)
+                    .await
+                    .map_err(|err| anyhow!("查询 SOL 余额失败: {err}"))?,
+            };
+            let swept = self.ledger.swept_today(&target.label, now);
+            let amount = sweep_amount(
+                balance,
+                target.float,
+                target.min_sweep,
+                target.max_per_day,
+                swept,
+            );
+            if amount == 0 {
+                debug!(
+                    target: "wallet::treasury",
+                    mint = %target.label,
+                    balance,
+                    float = target.float,
+                    swept_today = swept,
+                    "无需归集"
+                );
+                continue;
+            }
+
+            let mut entry = SweepEntry {
+                recorded_at: now,
+                mint: target.label.clone(),
+                destination: self.cold_wallet.to_string(),
+                balance,
+                float: target.float,
+                amount,
+                dry_run: self.dry_run,
+                signature: None,
+                error: None,
+            };
+            if !self.dry_run {
+                let result = match (target.mint, &token_account) {
+                    (Some(mint), Some(account)) => {
+                        transfer_token(
+                            &self.rpc,
+                            &self.identity,
+                            &mint,
+                            &account.account,
+                            &account.token_program,
+                            &self.cold_wallet,
+                            amount,
+                            self.compute_unit_price,
+                        )
+                        .await
+                    }
+                    _ => {
+                        transfer_sol(
+                            &self.rpc,
+                            &self.identity,
+                            &self.cold_wallet,
+                            amount,
+                            self.compute_unit_price,
+                        )
+                        .await
+                    }
+                };
+                match result {
+                    Ok(signature) => entry.signature = Some(signature.to_string()),
+                    Err(err) => entry.error = Some(err.to_string()),
+                }
+            }
+
+            match entry.error.as_deref() {
+                None => info!(
+                    target: "wallet::treasury",
+                    mint = %entry.mint,
+                    balance,
+                    float = entry.float,
+                    amount,
+                    dry_run = entry.dry_run,
+                    signature = entry.signature.as_deref().unwrap_or("-"),
+                    "已归集超出工作余额的部分"
+                ),
+                Some(error) => warn!(
+                    target: "wallet::treasury",
+                    mint = %entry.mint,
+                    amount,
+                    error,
+                    "归集转账失败"
+                ),
+            }
+            self.ledger.record(&entry);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sweeps_excess_above_float_within_daily_limit() {
+        assert_eq!(sweep_amount(1_500, 1_000, 0, 0, 0), 500);
+        assert_eq!(sweep_amount(900, 1_000, 0, 0, 0), 0);
+        assert_eq!(sweep_amount(1_500, 1_000, 600, 0, 0), 0);
+        assert_eq!(sweep_amount(5_000, 1_000, 0, 3_000, 2_000), 1_000);
+        assert_eq!(sweep_amount(5_000, 1_000, 0, 3_000, 3_000), 0);
+        assert_eq!(sweep_amount(5_000, 1_000, 1_500, 3_000, 2_000), 0);
+    }
+
+    #[test]
+    fn daily_totals_skip_other_days_dry_runs_and_failures() {
+        let day = 20_000;
+        let entry = |recorded_at: u64, mint: &str, amount: u64, dry_run: bool, failed: bool| {
+            let entry = SweepEntry {
+                recorded_at,
+                mint: mint.to_string(),
+                destination: Pubkey::new_unique().to_string(),
+                balance: amount * 2,
+                float: amount,
+                amount,
+                dry_run,
+                signature: (!dry_run && !failed).then(|| "sig".to_string()),
+                error: failed.then(|| "boom".to_string()),
+            };
+            serde_json::to_string(&entry).expect("encode")
+        };
+        let today = day * SECS_PER_DAY;
+        let content = [
+            entry(today + 10, NATIVE_SOL, 100, false, false),
+            entry(today + 20, NATIVE_SOL, 50, false, false),
+            entry(today - 1, NATIVE_SOL, 1_000, false, false),
+            entry(today + 30, NATIVE_SOL, 7, true, false),
+            entry(today + 40, "mint", 9, false, true),
+            "not json".to_string(),
+        ]
+        .join("\n");
+
+        let swept = swept_on_day(&content, day);
+        assert_eq!(swept.get(NATIVE_SOL), Some(&150));
+        assert_eq!(swept.get("mint"), None);
+    }
+
+    #[test]
+    fn native_float_keeps_fee_and_rent_reserve() {
+        let mut config = GalileoConfig::default();
+        config.auto_unwrap.enable = false;
+        config.treasury.compute_unit_price_micro_lamports = 10_000;
+        let entry = TreasuryMintConfig {
+            mint: NATIVE_SOL.to_string(),
+            float: 0,
+            min_sweep: 0,
+            max_per_day: 0,
+        };
+        assert_eq!(working_float(&config, &entry), 890_880 + 5_000 + 2_000);
+
+        let entry = TreasuryMintConfig {
+            float: 5_000_000,
+            ..entry
+        };
+        assert_eq!(working_float(&config, &entry), 5_000_000);
+    }
+}